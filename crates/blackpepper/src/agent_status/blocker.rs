@@ -17,18 +17,124 @@ pub enum BlockerConfidence {
     High,
 }
 
+impl BlockerConfidence {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+/// Which manifest supplied a blocker rule. Manifests are named by role, never
+/// by path.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockerManifestSource {
+    #[default]
+    Bundled,
+    /// A configured provider's `blocker_manifest` from the workspace config.
+    Workspace,
+    /// `$XDG_CONFIG_HOME/blackpepper/agent-status/<provider>.toml`.
+    User,
+}
+
+impl BlockerManifestSource {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Bundled => "bundled",
+            Self::Workspace => "workspace",
+            Self::User => "user",
+        }
+    }
+}
+
+impl std::fmt::Display for BlockerManifestSource {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// One keystroke a manifest declares a safe answer to its rule's prompt.
+/// Blackpepper sends it only when the user picks it for that exact blocker.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlockerAnswer {
+    pub label: String,
+    pub key: AnswerKey,
+}
+
+/// `Enter`, `Esc`, `Tab`, or one printable ASCII character such as `1` or `y`.
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum AnswerKey {
+    Enter,
+    Esc,
+    Tab,
+    Char(u8),
+}
+
+impl AnswerKey {
+    /// The byte a terminal receives for this key.
+    pub const fn byte(self) -> u8 {
+        match self {
+            Self::Enter => b'\r',
+            Self::Esc => 0x1b,
+            Self::Tab => b'\t',
+            Self::Char(byte) => byte,
+        }
+    }
+}
+
+impl TryFrom<String> for AnswerKey {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match (value.as_str(), value.as_bytes()) {
+            ("Enter", _) => Ok(Self::Enter),
+            ("Esc", _) => Ok(Self::Esc),
+            ("Tab", _) => Ok(Self::Tab),
+            (_, [byte]) if byte.is_ascii_graphic() => Ok(Self::Char(*byte)),
+            _ => Err(format!(
+                "answer key {value:?} must be Enter, Esc, Tab, or one printable ASCII character"
+            )),
+        }
+    }
+}
+
+impl From<AnswerKey> for String {
+    fn from(key: AnswerKey) -> Self {
+        key.to_string()
+    }
+}
+
+impl std::fmt::Display for AnswerKey {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Enter => formatter.write_str("Enter"),
+            Self::Esc => formatter.write_str("Esc"),
+            Self::Tab => formatter.write_str("Tab"),
+            Self::Char(byte) => write!(formatter, "{}", char::from(*byte)),
+        }
+    }
+}
+
 /// Redacted explanation for a visible blocker match.
 ///
 /// It deliberately contains no matched text, viewport, prompt, command, or
-/// filesystem path.
+/// filesystem path. `answers` is manifest metadata, like the rule id.
 #[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct BlockerExplain {
     pub provider: Provider,
+    #[serde(default)]
+    pub manifest: BlockerManifestSource,
     pub manifest_version: String,
     pub rule_id: String,
     pub confidence: BlockerConfidence,
     pub priority: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub answers: Vec<BlockerAnswer>,
 }
 
 /// Terminal data used locally by the matcher. This type is borrowed and is
@@ -66,16 +172,43 @@ impl BlockerObservation {
     }
 }
 
+/// How one rule fared against a captured screen. Only `bp-host blocker-test`
+/// builds these; the live watcher keeps using [`BlockerOverlay::evaluate`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleDiagnosis {
+    pub rule_id: String,
+    pub manifest: BlockerManifestSource,
+    pub manifest_version: String,
+    pub region: String,
+    pub confidence: BlockerConfidence,
+    pub priority: i32,
+    pub outcome: RuleOutcome,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RuleOutcome {
+    Matched,
+    /// Every positive matcher held but this `not_contains` entry was present.
+    NotContains(String),
+    /// Every positive matcher held but this `not_regex` pattern matched.
+    NotRegex(String),
+    /// The positive matchers hold on the whole viewport, just not inside the
+    /// rule's region.
+    OutsideRegion,
+    Unmatched,
+}
+
 #[derive(Debug)]
 pub struct BlockerOverlay {
     provider: Provider,
+    source: BlockerManifestSource,
     version: String,
     rules: Vec<CompiledRule>,
 }
 
 impl BlockerOverlay {
     pub fn from_toml(input: &str) -> Result<Self, BlockerManifestError> {
-        blocker_manifest::parse(input)
+        blocker_manifest::parse(input, BlockerManifestSource::Bundled)
     }
 
     pub fn bundled(provider: Provider) -> Result<Self, BlockerManifestError> {
@@ -83,8 +216,47 @@ impl BlockerOverlay {
             Provider::Codex => include_str!("../../assets/agent-status/codex.toml"),
             Provider::Claude => include_str!("../../assets/agent-status/claude.toml"),
             Provider::OpenCode => include_str!("../../assets/agent-status/opencode.toml"),
+            Provider::Custom(_) => return Err(BlockerManifestError::NotBundled(provider)),
         };
-        let overlay = Self::from_toml(input)?;
+        Self::for_provider(provider, input, BlockerManifestSource::Bundled)
+    }
+
+    /// A configured provider's own manifest, which must name that provider.
+    pub fn configured(provider: Provider, input: &str) -> Result<Self, BlockerManifestError> {
+        Self::for_provider(provider, input, BlockerManifestSource::Workspace)
+    }
+
+    /// Layer a user manifest over this one. A user rule replaces the rule
+    /// with the same id and any other rule is added, so the highest
+    /// priority match still wins and untouched rules keep their origin.
+    pub fn with_user_manifest(mut self, input: &str) -> Result<Self, BlockerManifestError> {
+        self.add_user_manifest(input)?;
+        Ok(self)
+    }
+
+    /// [`Self::with_user_manifest`] in place. A manifest that does not load
+    /// leaves these rules as they were.
+    pub fn add_user_manifest(&mut self, input: &str) -> Result<(), BlockerManifestError> {
+        let user = Self::for_provider(self.provider, input, BlockerManifestSource::User)?;
+        for rule in user.rules {
+            match self
+                .rules
+                .iter_mut()
+                .find(|existing| existing.id == rule.id)
+            {
+                Some(existing) => *existing = rule,
+                None => self.rules.push(rule),
+            }
+        }
+        Ok(())
+    }
+
+    fn for_provider(
+        provider: Provider,
+        input: &str,
+        source: BlockerManifestSource,
+    ) -> Result<Self, BlockerManifestError> {
+        let overlay = blocker_manifest::parse(input, source)?;
         if overlay.provider != provider {
             return Err(BlockerManifestError::ProviderMismatch {
                 expected: provider,
@@ -94,10 +266,26 @@ impl BlockerOverlay {
         Ok(overlay)
     }
 
+    /// An overlay that never matches, for a configured provider without a
+    /// blocker manifest: its status then comes from hooks and its process.
+    pub fn without_rules(provider: Provider) -> Self {
+        Self::from_parts(
+            provider,
+            BlockerManifestSource::Workspace,
+            "0".to_owned(),
+            Vec::new(),
+        )
+    }
+
     pub const fn provider(&self) -> Provider {
         self.provider
     }
 
+    /// The base manifest, before any user rules were layered over it.
+    pub const fn source(&self) -> BlockerManifestSource {
+        self.source
+    }
+
     pub fn version(&self) -> &str {
         &self.version
     }
@@ -109,20 +297,43 @@ impl BlockerOverlay {
             .max_by_key(|rule| rule.priority)
             .map(|rule| BlockerExplain {
                 provider: self.provider,
-                manifest_version: self.version.clone(),
+                manifest: rule.manifest,
+                manifest_version: rule.manifest_version.clone(),
                 rule_id: rule.id.clone(),
                 confidence: rule.confidence,
                 priority: rule.priority,
+                answers: rule.answers.clone(),
             })
     }
 
+    /// Every rule's outcome, highest priority first.
+    pub fn diagnose(&self, input: BlockerInput<'_>) -> Vec<RuleDiagnosis> {
+        let mut diagnoses = self
+            .rules
+            .iter()
+            .map(|rule| RuleDiagnosis {
+                rule_id: rule.id.clone(),
+                manifest: rule.manifest,
+                manifest_version: rule.manifest_version.clone(),
+                region: rule.region.to_string(),
+                confidence: rule.confidence,
+                priority: rule.priority,
+                outcome: rule.diagnose(input),
+            })
+            .collect::<Vec<_>>();
+        diagnoses.sort_by_key(|diagnosis| std::cmp::Reverse(diagnosis.priority));
+        diagnoses
+    }
+
     pub(super) fn from_parts(
         provider: Provider,
+        source: BlockerManifestSource,
         version: String,
         rules: Vec<CompiledRule>,
     ) -> Self {
         Self {
             provider,
+            source,
             version,
             rules,
         }
@@ -132,6 +343,8 @@ impl BlockerOverlay {
 #[derive(Debug)]
 pub(super) struct CompiledRule {
     pub(super) id: String,
+    pub(super) manifest: BlockerManifestSource,
+    pub(super) manifest_version: String,
     pub(super) confidence: BlockerConfidence,
     pub(super) priority: i32,
     pub(super) region: Region,
@@ -141,38 +354,64 @@ pub(super) struct CompiledRule {
     pub(super) regex_any: Vec<Regex>,
     pub(super) not_contains: Vec<String>,
     pub(super) not_regex: Vec<Regex>,
+    pub(super) answers: Vec<BlockerAnswer>,
 }
 
 impl CompiledRule {
     fn matches(&self, input: BlockerInput<'_>) -> bool {
         let text = self.region.select(input);
         let lower = text.to_lowercase();
+        self.positives_match(&text, &lower) && self.exclusion(&text, &lower).is_none()
+    }
 
+    fn diagnose(&self, input: BlockerInput<'_>) -> RuleOutcome {
+        let text = self.region.select(input);
+        let lower = text.to_lowercase();
+        if self.positives_match(&text, &lower) {
+            return self
+                .exclusion(&text, &lower)
+                .unwrap_or(RuleOutcome::Matched);
+        }
+        let whole = input.viewport;
+        if !matches!(self.region, Region::Viewport)
+            && self.positives_match(whole, &whole.to_lowercase())
+        {
+            return RuleOutcome::OutsideRegion;
+        }
+        RuleOutcome::Unmatched
+    }
+
+    fn positives_match(&self, text: &str, lower: &str) -> bool {
         if !self
             .contains_all
             .iter()
             .all(|needle| lower.contains(needle))
-            || !self.regex_all.iter().all(|pattern| pattern.is_match(&text))
+            || !self.regex_all.iter().all(|pattern| pattern.is_match(text))
         {
             return false;
         }
 
         let has_any = !self.contains_any.is_empty() || !self.regex_any.is_empty();
-        if has_any
-            && !self
+        !has_any
+            || self
                 .contains_any
                 .iter()
                 .any(|needle| lower.contains(needle))
-            && !self.regex_any.iter().any(|pattern| pattern.is_match(&text))
-        {
-            return false;
-        }
+            || self.regex_any.iter().any(|pattern| pattern.is_match(text))
+    }
 
-        !self
+    fn exclusion(&self, text: &str, lower: &str) -> Option<RuleOutcome> {
+        if let Some(needle) = self
             .not_contains
             .iter()
-            .any(|needle| lower.contains(needle))
-            && !self.not_regex.iter().any(|pattern| pattern.is_match(&text))
+            .find(|needle| lower.contains(needle.as_str()))
+        {
+            return Some(RuleOutcome::NotContains(needle.clone()));
+        }
+        self.not_regex
+            .iter()
+            .find(|pattern| pattern.is_match(text))
+            .map(|pattern| RuleOutcome::NotRegex(pattern.as_str().to_owned()))
     }
 }
 
@@ -202,6 +441,17 @@ impl Region {
     }
 }
 
+impl std::fmt::Display for Region {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Viewport => formatter.write_str("viewport"),
+            Self::TerminalTitle => formatter.write_str("terminal_title"),
+            Self::BottomLines(lines) => write!(formatter, "bottom_lines {lines}"),
+            Self::TopLines(lines) => write!(formatter, "top_lines {lines}"),
+        }
+    }
+}
+
 fn select_bottom(input: &str, lines: usize) -> String {
     let selected = input.lines().rev().take(lines).collect::<Vec<_>>();
     selected.into_iter().rev().collect::<Vec<_>>().join("\n")
@@ -4,8 +4,8 @@ use regex::Regex;
 use serde::Deserialize;
 
 use super::{
-    blocker::BlockerConfidence, blocker::BlockerOverlay, blocker::CompiledRule, blocker::Region,
-    Provider,
+    blocker::BlockerAnswer, blocker::BlockerConfidence, blocker::BlockerManifestSource,
+    blocker::BlockerOverlay, blocker::CompiledRule, blocker::Region, Provider,
 };
 
 const SCHEMA_VERSION: u32 = 1;
@@ -14,6 +14,8 @@ const MAX_RULES: usize = 64;
 const MAX_LINES: usize = 200;
 const MAX_MATCHERS_PER_RULE: usize = 64;
 const MAX_MATCHER_CHARS: usize = 512;
+const MAX_ANSWERS: usize = 4;
+const MAX_ANSWER_LABEL: usize = 16;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum BlockerManifestError {
@@ -36,6 +38,8 @@ pub enum BlockerManifestError {
         expected: Provider,
         actual: Provider,
     },
+    /// Configured providers bring their own manifest.
+    NotBundled(Provider),
 }
 
 impl fmt::Display for BlockerManifestError {
@@ -65,6 +69,9 @@ impl fmt::Display for BlockerManifestError {
                     "expected {expected} blocker manifest, found {actual}"
                 )
             }
+            Self::NotBundled(provider) => {
+                write!(formatter, "{provider} has no bundled blocker manifest")
+            }
         }
     }
 }
@@ -101,6 +108,8 @@ struct RawRule {
     not_contains: Vec<String>,
     #[serde(default)]
     not_regex: Vec<String>,
+    #[serde(default)]
+    answers: Vec<BlockerAnswer>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -121,7 +130,10 @@ enum RegionKind {
     TerminalTitle,
 }
 
-pub(super) fn parse(input: &str) -> Result<BlockerOverlay, BlockerManifestError> {
+pub(super) fn parse(
+    input: &str,
+    source: BlockerManifestSource,
+) -> Result<BlockerOverlay, BlockerManifestError> {
     if input.len() > MAX_MANIFEST_BYTES {
         return Err(BlockerManifestError::TooLarge);
     }
@@ -148,20 +160,27 @@ pub(super) fn parse(input: &str) -> Result<BlockerOverlay, BlockerManifestError>
         if !ids.insert(rule.id.clone()) {
             return Err(BlockerManifestError::DuplicateRule(rule.id));
         }
-        rules.push(compile_rule(rule)?);
+        rules.push(compile_rule(rule, source, &manifest.version)?);
     }
 
     Ok(BlockerOverlay::from_parts(
         manifest.provider,
+        source,
         manifest.version,
         rules,
     ))
 }
 
-fn compile_rule(raw: RawRule) -> Result<CompiledRule, BlockerManifestError> {
+fn compile_rule(
+    raw: RawRule,
+    manifest: BlockerManifestSource,
+    manifest_version: &str,
+) -> Result<CompiledRule, BlockerManifestError> {
     validate_rule(&raw)?;
     Ok(CompiledRule {
         id: raw.id.clone(),
+        manifest,
+        manifest_version: manifest_version.to_owned(),
         confidence: raw.confidence,
         priority: raw.priority,
         region: compile_region(&raw.id, raw.region)?,
@@ -171,6 +190,7 @@ fn compile_rule(raw: RawRule) -> Result<CompiledRule, BlockerManifestError> {
         regex_any: compile_regexes(&raw.id, raw.regex_any)?,
         not_contains: lower(raw.not_contains),
         not_regex: compile_regexes(&raw.id, raw.not_regex)?,
+        answers: raw.answers,
     })
 }
 
@@ -210,6 +230,27 @@ fn validate_rule(rule: &RawRule) -> Result<(), BlockerManifestError> {
     if !has_positive {
         return invalid_rule(&rule.id, "at least one positive matcher is required");
     }
+    if rule.answers.len() > MAX_ANSWERS {
+        return invalid_rule(&rule.id, "a rule declares at most 4 answers");
+    }
+    let mut keys = HashSet::new();
+    for answer in &rule.answers {
+        let label = &answer.label;
+        if label.is_empty()
+            || label.len() > MAX_ANSWER_LABEL
+            || !label
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-')
+        {
+            return invalid_rule(
+                &rule.id,
+                "answer labels must be 1-16 lowercase ASCII letters, digits or '-'",
+            );
+        }
+        if !keys.insert(answer.key) {
+            return invalid_rule(&rule.id, "each answer needs its own key");
+        }
+    }
     Ok(())
 }
 
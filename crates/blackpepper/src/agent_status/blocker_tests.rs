@@ -220,3 +220,232 @@ fn blockers_are_limited_to_the_selected_live_region() {
         None
     );
 }
+
+#[test]
+fn a_configured_provider_brings_its_own_manifest_or_none() {
+    let gemini = "gemini".parse::<Provider>().unwrap();
+    assert_eq!(
+        gemini,
+        Provider::Custom(ProviderName::new("gemini").unwrap())
+    );
+    assert_eq!(
+        BlockerOverlay::bundled(gemini).unwrap_err(),
+        BlockerManifestError::NotBundled(gemini)
+    );
+    let manifest = r#"
+schema_version = 1
+provider = "gemini"
+version = "1"
+
+[[blockers]]
+id = "approve"
+confidence = "high"
+contains_all = ["allow execution?"]
+"#;
+    let overlay = BlockerOverlay::configured(gemini, manifest).unwrap();
+    let result = overlay
+        .evaluate(BlockerInput {
+            viewport: "Allow execution? (y/n)",
+            terminal_title: None,
+        })
+        .unwrap();
+    assert_eq!(result.provider, gemini);
+    assert_eq!(
+        BlockerOverlay::configured(Provider::Codex, manifest).unwrap_err(),
+        BlockerManifestError::ProviderMismatch {
+            expected: Provider::Codex,
+            actual: gemini,
+        }
+    );
+    assert_eq!(
+        BlockerOverlay::without_rules(gemini).evaluate(BlockerInput {
+            viewport: "Allow execution? (y/n)",
+            terminal_title: None,
+        }),
+        None
+    );
+}
+
+#[test]
+fn provider_names_keep_to_one_canonical_spelling_on_the_wire() {
+    assert_eq!(
+        serde_json::to_string(&Provider::OpenCode).unwrap(),
+        "\"opencode\""
+    );
+    let aider = serde_json::from_str::<Provider>("\"aider\"").unwrap();
+    assert_eq!(aider.as_str(), "aider");
+    assert_eq!(serde_json::to_string(&aider).unwrap(), "\"aider\"");
+    assert_eq!("Claude-Code".parse::<Provider>().unwrap(), Provider::Claude);
+    assert!(serde_json::from_str::<Provider>("\"claude-code\"").is_err());
+    for invalid in ["", "9lives", "has space", "UPPER", "a".repeat(33).as_str()] {
+        assert!(ProviderName::new(invalid).is_none(), "accepted {invalid:?}");
+    }
+    assert!(ProviderName::new("open-code").is_none());
+}
+
+#[test]
+fn user_manifest_rules_replace_by_id_and_extend_the_bundled_rules() {
+    let user = r#"
+schema_version = 1
+provider = "codex"
+version = "7"
+
+[[blockers]]
+id = "yes_no_prompt"
+confidence = "high"
+priority = 700
+region = { kind = "bottom_lines", lines = 8 }
+contains_any = ["proceed? (y/n)"]
+
+[[blockers]]
+id = "new_sandbox_prompt"
+confidence = "high"
+priority = 2000
+contains_all = ["escalate sandbox"]
+"#;
+    let overlay = BlockerOverlay::bundled(Provider::Codex)
+        .unwrap()
+        .with_user_manifest(user)
+        .unwrap();
+    assert_eq!(overlay.source(), BlockerManifestSource::Bundled);
+    let explain = |viewport| {
+        overlay.evaluate(BlockerInput {
+            viewport,
+            terminal_title: None,
+        })
+    };
+
+    let replaced = explain("Proceed? (y/n)").unwrap();
+    assert_eq!(
+        (
+            replaced.rule_id.as_str(),
+            replaced.manifest,
+            replaced.manifest_version.as_str()
+        ),
+        ("yes_no_prompt", BlockerManifestSource::User, "7")
+    );
+    assert_eq!(replaced.confidence, BlockerConfidence::High);
+    assert_eq!(explain("Run it? [y/n]"), None);
+
+    let untouched = explain("Allow command?").unwrap();
+    assert_eq!(untouched.rule_id, "confirm_or_cancel");
+    assert_eq!(untouched.manifest, BlockerManifestSource::Bundled);
+    assert_ne!(untouched.manifest_version, "7");
+
+    let added = explain("Allow command? It would escalate sandbox").unwrap();
+    assert_eq!(
+        (added.rule_id.as_str(), added.manifest),
+        ("new_sandbox_prompt", BlockerManifestSource::User)
+    );
+
+    let other_provider = user.replace("\"codex\"", "\"claude\"");
+    assert!(matches!(
+        BlockerOverlay::bundled(Provider::Codex)
+            .unwrap()
+            .with_user_manifest(&other_provider),
+        Err(BlockerManifestError::ProviderMismatch { .. })
+    ));
+    assert_eq!(
+        BlockerOverlay::bundled(Provider::Codex)
+            .unwrap()
+            .with_user_manifest(&user.replace("2000", "\"high\""))
+            .unwrap_err()
+            .to_string()
+            .split(':')
+            .next(),
+        Some("invalid blocker manifest TOML")
+    );
+}
+
+#[test]
+fn rules_carry_their_declared_answers_to_a_match() {
+    let overlay = BlockerOverlay::bundled(Provider::Claude).unwrap();
+    let result = overlay
+        .evaluate(BlockerInput {
+            viewport: CLAUDE_BLOCKED,
+            terminal_title: None,
+        })
+        .unwrap();
+    assert_eq!(result.rule_id, "permission_prompt");
+    assert_eq!(
+        result
+            .answers
+            .iter()
+            .map(|answer| (answer.label.as_str(), answer.key.byte()))
+            .collect::<Vec<_>>(),
+        [("yes", b'1'), ("no", 0x1b)]
+    );
+    let wire = serde_json::to_string(&result).unwrap();
+    assert!(wire.contains(r#""answers":[{"label":"yes","key":"1"},{"label":"no","key":"Esc"}]"#));
+    assert_eq!(
+        serde_json::from_str::<BlockerExplain>(&wire).unwrap(),
+        result
+    );
+
+    let codex = BlockerOverlay::bundled(Provider::Codex).unwrap();
+    let result = codex
+        .evaluate(BlockerInput {
+            viewport: CODEX_BLOCKED,
+            terminal_title: None,
+        })
+        .unwrap();
+    assert!(result.answers.is_empty());
+    assert!(!serde_json::to_string(&result).unwrap().contains("answers"));
+}
+
+#[test]
+fn strict_schema_rejects_unsafe_or_ambiguous_answers() {
+    let manifest = |answers: &str| {
+        format!(
+            r#"
+schema_version = 1
+provider = "codex"
+version = "1.0.0"
+
+[[blockers]]
+id = "prompt"
+confidence = "high"
+contains_all = ["Allow command?"]
+answers = {answers}
+"#
+        )
+    };
+    let overlay =
+        BlockerOverlay::from_toml(&manifest(r#"[{ label = "allow", key = "Enter" }]"#)).unwrap();
+    let result = overlay
+        .evaluate(BlockerInput {
+            viewport: "Allow command?",
+            terminal_title: None,
+        })
+        .unwrap();
+    assert_eq!(result.answers[0].key, AnswerKey::Enter);
+
+    for answers in [
+        r#"[{ label = "yes", key = "yes" }]"#,
+        r#"[{ label = "yes", key = "\u0003" }]"#,
+        r#"[{ label = "yes", key = " " }]"#,
+        r#"[{ label = "yes", key = "y", text = "y" }]"#,
+    ] {
+        assert!(
+            matches!(
+                BlockerOverlay::from_toml(&manifest(answers)),
+                Err(BlockerManifestError::InvalidToml(_))
+            ),
+            "{answers} was accepted"
+        );
+    }
+    for answers in [
+        r#"[{ label = "Yes", key = "y" }]"#,
+        r#"[{ label = "", key = "y" }]"#,
+        r#"[{ label = "yes", key = "y" }, { label = "always", key = "y" }]"#,
+        r#"[{ label = "a", key = "a" }, { label = "b", key = "b" }, { label = "c", key = "c" }, { label = "d", key = "d" }, { label = "e", key = "e" }]"#,
+    ] {
+        assert!(
+            matches!(
+                BlockerOverlay::from_toml(&manifest(answers)),
+                Err(BlockerManifestError::InvalidRule { .. })
+            ),
+            "{answers} was accepted"
+        );
+    }
+}
@@ -19,12 +19,13 @@ mod store_schema;
 mod tracker;
 
 pub use blocker::{
-    BlockerConfidence, BlockerExplain, BlockerInput, BlockerObservation, BlockerOverlay,
+    AnswerKey, BlockerAnswer, BlockerConfidence, BlockerExplain, BlockerInput,
+    BlockerManifestSource, BlockerObservation, BlockerOverlay, RuleDiagnosis, RuleOutcome,
 };
 pub use blocker_manifest::BlockerManifestError;
 pub use model::{
     AgentEvent, AgentEventKind, AgentEventSource, AgentSnapshot, AgentState, IntegrationHealth,
-    IntegrationIssue, NeedsInputCapability, Provider, ProviderParseError,
+    IntegrationIssue, NeedsInputCapability, Provider, ProviderName, ProviderParseError,
 };
 pub use status_types::{
     AgentExplain, BlockerDisposition, EventDisposition, IgnoredUpdate, StatusAuthority,
@@ -4,22 +4,25 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{AgentRunId, HostId, PaneId, WorkspaceId};
 
-/// Coding-agent providers supported by the first status protocol.
-#[derive(Debug, Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-#[serde(rename_all = "snake_case")]
+/// Coding-agent providers: the three with bundled integrations, plus any
+/// named by a `[providers.<name>]` configuration table.
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Provider {
     Codex,
     Claude,
-    #[serde(rename = "opencode")]
     OpenCode,
+    Custom(ProviderName),
 }
 
 impl Provider {
-    pub const fn as_str(self) -> &'static str {
+    pub const BUILT_IN: [Self; 3] = [Self::Codex, Self::Claude, Self::OpenCode];
+
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Codex => "codex",
             Self::Claude => "claude",
             Self::OpenCode => "opencode",
+            Self::Custom(name) => name.as_str(),
         }
     }
 
@@ -27,7 +30,9 @@ impl Provider {
     /// installed. A healthy integration may advertise a stronger capability.
     pub const fn baseline_needs_input_capability(self) -> NeedsInputCapability {
         match self {
-            Self::Codex | Self::Claude | Self::OpenCode => NeedsInputCapability::BlockerOverlay,
+            Self::Codex | Self::Claude | Self::OpenCode | Self::Custom(_) => {
+                NeedsInputCapability::BlockerOverlay
+            }
         }
     }
 }
@@ -46,8 +51,92 @@ impl FromStr for Provider {
             "codex" => Ok(Self::Codex),
             "claude" | "claude-code" => Ok(Self::Claude),
             "opencode" | "open-code" => Ok(Self::OpenCode),
-            _ => Err(ProviderParseError(value.to_string())),
+            name => ProviderName::new(name)
+                .map(Self::Custom)
+                .ok_or_else(|| ProviderParseError(value.to_string())),
+        }
+    }
+}
+
+impl Serialize for Provider {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Provider {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        // The wire carries canonical names only; aliases are for typing.
+        let provider = value
+            .parse::<Self>()
+            .map_err(|_| serde::de::Error::custom(format!("unknown provider: {value}")))?;
+        if provider.as_str() != value {
+            return Err(serde::de::Error::custom(format!(
+                "unknown provider: {value}"
+            )));
+        }
+        Ok(provider)
+    }
+}
+
+/// The name of a configured provider. It is stored inline so [`Provider`]
+/// stays `Copy` like the built-in variants.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ProviderName {
+    bytes: [u8; ProviderName::MAX_LEN],
+    len: u8,
+}
+
+impl ProviderName {
+    pub const MAX_LEN: usize = 32;
+
+    /// A lowercase ASCII letter, then lowercase letters, digits, or '-', and
+    /// never a built-in provider's name or alias.
+    pub fn new(value: &str) -> Option<Self> {
+        let valid = value.len() <= Self::MAX_LEN
+            && value
+                .bytes()
+                .next()
+                .is_some_and(|byte| byte.is_ascii_lowercase())
+            && value
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-')
+            && !matches!(
+                value,
+                "codex" | "claude" | "claude-code" | "opencode" | "open-code"
+            );
+        if !valid {
+            return None;
         }
+        let mut bytes = [0; Self::MAX_LEN];
+        bytes[..value.len()].copy_from_slice(value.as_bytes());
+        Some(Self {
+            bytes,
+            len: value.len() as u8,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for ProviderName {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for ProviderName {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), formatter)
     }
 }
 
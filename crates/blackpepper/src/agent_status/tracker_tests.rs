@@ -50,10 +50,12 @@ fn blocker(run_id: AgentRunId, sequence: u64, observed_at_ms: u64) -> BlockerObs
         observed_at_ms,
         blocker: Some(BlockerExplain {
             provider: Provider::Codex,
+            manifest: crate::agent_status::BlockerManifestSource::Bundled,
             manifest_version: "1.0.0".to_string(),
             rule_id: "approval".to_string(),
             confidence: BlockerConfidence::High,
             priority: 100,
+            answers: Vec::new(),
         }),
     }
 }
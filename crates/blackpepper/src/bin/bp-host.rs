@@ -1,7 +1,7 @@
 use blackpepper::core::{serve_json_lines_with_extension, CorePaths, HostRegistry};
 use blackpepper::host_services::{
-    hold_session_lease, record_provider_hook, watch_blockers_cancellable, BlockerWatchArgs,
-    HostServices, ProviderHookArgs, SessionLeaseArgs,
+    blocker_test, hold_session_lease, record_provider_hook, watch_blockers_cancellable,
+    BlockerTestArgs, BlockerWatchArgs, HostServices, ProviderHookArgs, SessionLeaseArgs,
 };
 use std::{error::Error, fs, io, io::Read, process::ExitCode};
 
@@ -26,6 +26,18 @@ fn main() -> ExitCode {
             }
         };
     }
+    if arguments
+        .first()
+        .is_some_and(|argument| argument == "blocker-test")
+    {
+        return match run_blocker_test(arguments.into_iter().skip(1)) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("bp-host: {error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
     if arguments
         .first()
         .is_some_and(|argument| argument == "session-lease")
@@ -117,6 +129,13 @@ fn run_blocker_watch(arguments: impl IntoIterator<Item = String>) -> Result<(),
         .map_err(Into::into)
 }
 
+fn run_blocker_test(arguments: impl IntoIterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let arguments = BlockerTestArgs::parse(arguments).ok_or(
+        "usage: bp-host blocker-test --provider <name> [--manifest <rules.toml>] [--title <text>] <viewport.txt>",
+    )?;
+    blocker_test(&arguments, io::stdout().lock()).map_err(Into::into)
+}
+
 fn run_session_lease(arguments: impl IntoIterator<Item = String>) -> Result<(), Box<dyn Error>> {
     let arguments = SessionLeaseArgs::parse(arguments)
         .ok_or("invalid session-lease arguments; workspace and session IDs are required")?;
@@ -1,6 +1,7 @@
 mod agents;
 mod hosts;
 mod ports;
+mod services;
 mod theme;
 mod workspaces;
 mod worktrees;
@@ -9,15 +10,23 @@ use super::command::ClientCommand;
 use super::{ClientState, HostConnection};
 use crate::client::runtime::ClientRuntime;
 
-pub(super) use agents::{apply_explain, apply_spawned};
+pub(super) use agents::{
+    answer as answer_agent, apply_explain, apply_spawned, focus as focus_agent,
+    go_to as go_to_agent,
+};
 pub(super) use hosts::apply_import_preview;
 pub(super) use ports::existing_forward_message;
 pub(super) use ports::{
-    apply_cancelled, apply_forwarded, apply_list as apply_port_list, start_forward_target,
+    apply_cancelled, apply_declared_forwards, apply_forwarded, apply_list as apply_port_list,
+    apply_reverse_cancelled, apply_reversed, apply_socket_cancelled, apply_socket_forwarded,
+    start_declared_forwards, start_forward_target,
 };
+pub(super) use services::{apply_service_states, restart_services};
 pub(super) use workspaces::apply_ungrouped_workspace;
 pub(super) use worktrees::{
     apply_change as apply_worktree_change, apply_list as apply_worktree_list,
+    apply_pull_request_list, open_selected as open_selected_worktree, open_selected_pull_request,
+    registered_worktree, remove_selected as remove_selected_worktree,
 };
 
 pub(super) fn execute_command(
@@ -26,6 +35,7 @@ pub(super) fn execute_command(
     command: ClientCommand,
 ) {
     let result = execute(state, runtime, command);
+    state.agent_reply = None;
     if let Err(error) = result {
         state.set_output(error);
     }
@@ -53,6 +63,7 @@ fn execute(
         ClientCommand::WorkspaceUngroup => workspaces::ungroup(state, runtime)?,
         ClientCommand::WorkspaceTerminate => workspaces::terminate(state, runtime)?,
         ClientCommand::WorktreeList => worktrees::list(state, runtime)?,
+        ClientCommand::WorktreePullRequests => worktrees::list_pull_requests(state, runtime)?,
         ClientCommand::WorktreeCreate { branch, base } => {
             worktrees::create(state, runtime, branch, base)?;
         }
@@ -60,15 +71,31 @@ fn execute(
             worktrees::open(state, runtime, selector)?;
         }
         ClientCommand::WorktreeRemove => worktrees::remove(state, runtime)?,
+        ClientCommand::WorktreePruneMerged => worktrees::prune_merged(state, runtime)?,
         ClientCommand::Ports { all_host } => ports::list(state, runtime, all_host)?,
         ClientCommand::Forward {
             remote_port,
             bind_address,
-        } => ports::forward(state, runtime, remote_port, bind_address)?,
+            local_port,
+        } => ports::forward(state, runtime, remote_port, bind_address, local_port)?,
         ClientCommand::ForwardCancel {
             remote_port,
             bind_address,
         } => ports::cancel(state, runtime, remote_port, bind_address)?,
+        ClientCommand::ForwardSocket {
+            selector,
+            local_path,
+        } => ports::forward_socket(state, runtime, selector, local_path)?,
+        ClientCommand::ForwardSocketCancel { selector } => {
+            ports::cancel_socket(state, runtime, selector)?
+        }
+        ClientCommand::Reverse {
+            local_port,
+            remote_port,
+        } => ports::reverse(state, runtime, local_port, remote_port)?,
+        ClientCommand::ReverseCancel { remote_port } => {
+            ports::cancel_reverse(state, runtime, remote_port)?
+        }
         ClientCommand::Theme { name } => theme::apply(state, name)?,
         ClientCommand::StatusExplain => agents::explain(state, runtime)?,
         ClientCommand::Approve => worktrees::approve(state, runtime)?,
@@ -91,10 +118,26 @@ fn execute(
             }
         }
         ClientCommand::Help => show_help(state),
+        ClientCommand::Agents => show_agents(state),
         ClientCommand::Quit => state.should_quit = true,
         ClientCommand::AgentSpawn { provider } => agents::spawn(state, runtime, provider)?,
+        ClientCommand::AgentSend { text } => agents::send(state, runtime, text)?,
+        ClientCommand::AgentSpawnInWorktree {
+            provider,
+            branch,
+            base,
+        } => worktrees::spawn_in_worktree(state, runtime, provider, branch, base)?,
         ClientCommand::ServiceStart { name } => {
-            agents::start_service(state, runtime, &name)?;
+            services::start_service(state, runtime, &name)?;
+        }
+        ClientCommand::ServiceStop { name } => {
+            services::stop_service(state, runtime, &name)?;
+        }
+        ClientCommand::ServiceRestart { name } => {
+            services::restart_service(state, runtime, &name)?;
+        }
+        ClientCommand::ServiceStatus { name } => {
+            services::service_status(state, runtime, name.as_deref())?;
         }
     }
     Ok(())
@@ -105,10 +148,22 @@ fn execute(
 /// body cannot express.
 fn show_help(state: &mut ClientState) {
     state.close_detail();
+    state.agents = None;
+    state.worktrees = None;
+    state.pull_requests = None;
     state.help = Some(crate::client::state::HelpView::default());
     state.set_output("Command reference open. Use ↑/↓ or Page Up/Down; Esc closes it.");
 }
 
+fn show_agents(state: &mut ClientState) {
+    state.close_detail();
+    state.help = None;
+    state.worktrees = None;
+    state.pull_requests = None;
+    state.agents = Some(crate::client::state::AgentsView::default());
+    state.set_output("Agents open. Enter opens the selected tab; s changes the order; Esc closes.");
+}
+
 pub(super) fn selected_workspace(state: &ClientState) -> Result<crate::core::WorkspaceId, String> {
     state
         .selected_workspace
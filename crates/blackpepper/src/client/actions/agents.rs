@@ -1,6 +1,6 @@
 use super::super::state::AgentRunView;
-use super::super::ClientState;
-use crate::agent_status::Provider;
+use super::super::{ClientState, DisplayStatus};
+use crate::agent_status::{AgentState, Provider};
 use crate::client::runtime::{ClientRuntime, HostOperationContext, HostOperationValue};
 
 pub(super) fn explain(state: &mut ClientState, runtime: &mut ClientRuntime) -> Result<(), String> {
@@ -100,7 +100,8 @@ fn format_run(run: &AgentRunView) -> String {
         .as_ref()
         .map(|blocker| {
             format!(
-                ", blocker zellij_viewport manifest {} rule {} confidence {:?} observed {:?}",
+                ", blocker zellij_viewport manifest {} {} rule {} confidence {:?} observed {:?}",
+                blocker.manifest,
                 blocker.manifest_version,
                 blocker.rule_id,
                 blocker.confidence,
@@ -122,7 +123,13 @@ fn format_run(run: &AgentRunView) -> String {
         .snapshot_error
         .as_ref()
         .map(|error| format!(", refresh failure {error}"))
-        .unwrap_or_default();
+        .into_iter()
+        .chain(
+            run.manifest_warning
+                .as_ref()
+                .map(|warning| format!(", manifest warning {warning}")),
+        )
+        .collect::<String>();
     let detail = match &run.snapshot {
         Some(snapshot) => format!(
             "{} {}, health {:?}, needs_input {}, sequence {:?}, observed {:?}{authority}{failure}{blocker}",
@@ -202,40 +209,127 @@ pub(in crate::client) fn apply_spawned(
             blocker_sequence: 0,
             blocker_observed_at_ms: None,
             interrupted_after_sequence: None,
+            manifest_warning: None,
         });
     state
         .statuses
         .insert(workspace_id, super::super::DisplayStatus::Unknown);
     state.set_output(format!(
-        "Spawned {provider} in background tab {} (run {}; needs_input: {}). Use native Zellij tab selection to open it.",
+        "Spawned {provider} in background tab {} (run {}; needs_input: {}). Open it from :agents.",
         spawned.tab_id, spawned.run_id, spawned.capability
     ));
 }
 
-pub(super) fn start_service(
+/// Open the tab an agent runs in. A detached workspace attaches first and the
+/// focus follows once the attach result lands.
+pub(in crate::client) fn go_to(
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
-    name: &str,
+    workspace_id: crate::core::WorkspaceId,
+    run_id: crate::core::AgentRunId,
 ) -> Result<(), String> {
-    let workspace_id = super::selected_workspace(state)?;
     let host_id = state
         .host_for_workspace(workspace_id)
-        .ok_or_else(|| "The selected workspace host is unavailable.".to_owned())?;
-    let service_name = name.to_owned();
-    let worker_name = service_name.clone();
-    let label = format!("Starting service {service_name}");
+        .ok_or_else(|| "The agent's workspace host is unavailable.".to_owned())?;
+    state.agents = None;
+    state.selected_workspace = Some(workspace_id);
+    state.selected_host = Some(host_id);
+    if state.terminals.contains_key(&workspace_id) {
+        return focus(state, runtime, workspace_id, run_id);
+    }
+    state.pending_agent_focus = Some((workspace_id, run_id));
+    super::super::control::attach_selected(state, runtime);
+    if !state.host_operations.contains_key(&host_id) {
+        state.pending_agent_focus = None;
+    }
+    Ok(())
+}
+
+/// Focus an agent's tab inside an already attached workspace.
+pub(in crate::client) fn focus(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    workspace_id: crate::core::WorkspaceId,
+    run_id: crate::core::AgentRunId,
+) -> Result<(), String> {
+    let run = state
+        .agent_runs
+        .get(&workspace_id)
+        .and_then(|runs| runs.iter().find(|run| run.run_id == run_id))
+        .ok_or_else(|| "That agent run is no longer registered; run :refresh.".to_owned())?;
+    let (provider, tab_id, zellij_pane_id) = (run.provider, run.tab_id, run.zellij_pane_id.clone());
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The agent's workspace host is unavailable.".to_owned())?;
+    let label = format!("Opening the {provider} agent tab");
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::AgentFocus { workspace_id },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .focus_agent_tab(workspace_id, run_id, tab_id, &zellij_pane_id)
+                .map(|()| HostOperationValue::AgentFocused)
+        }),
+    )?;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.active_workspace = Some(workspace_id);
+    state.mode = super::super::ClientMode::Manage;
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+/// Type a prompt into an agent waiting for its next one: the dashboard row
+/// being replied to, or else the selected workspace's only idle or done run.
+/// The host re-reads the run's state and its screen blocker before anything
+/// is written.
+pub(super) fn send(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    text: String,
+) -> Result<(), String> {
+    let (workspace_id, run_id) = match state.agent_reply.take() {
+        Some(reply) => reply,
+        None => reply_target(state)?,
+    };
+    let run = state
+        .agent_runs
+        .get(&workspace_id)
+        .and_then(|runs| runs.iter().find(|run| run.run_id == run_id))
+        .ok_or_else(|| "That agent run is no longer registered; run :refresh.".to_owned())?;
+    let provider = run.provider;
+    let status = run.display_status();
+    if !accepts_prompt(status) {
+        return Err(format!(
+            "The {provider} agent is {}; only an idle or done agent takes a new prompt.",
+            status.public_word()
+        ));
+    }
+    let (tab_id, zellij_pane_id) = (run.tab_id, run.zellij_pane_id.clone());
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The agent's workspace host is unavailable.".to_owned())?;
+    let label = format!("Sending to the {provider} agent");
     let token = runtime.start_host_operation(
         host_id,
         label.clone(),
-        HostOperationContext::ServiceStart {
+        HostOperationContext::AgentInput {
             workspace_id,
-            name: service_name,
+            run_id,
+            provider,
         },
         state.event_tx.clone(),
         Box::new(move |runtime| {
+            let snapshot = runtime.agent_snapshot(host_id, run_id)?.ok_or_else(|| {
+                "The agent run is no longer registered on its host; nothing was sent.".to_owned()
+            })?;
+            host_accepts_prompt(provider, snapshot.snapshot.state, snapshot.blocker.as_ref())?;
             runtime
-                .start_named_service(workspace_id, &worker_name)
-                .map(|tab_id| HostOperationValue::ServiceStarted { tab_id })
+                .write_to_agent_pane(workspace_id, run_id, tab_id, &zellij_pane_id, &text, b"\r")
+                .map(|()| HostOperationValue::AgentInputSent)
         }),
     )?;
     state
@@ -245,6 +339,168 @@ pub(super) fn start_service(
     Ok(())
 }
 
+/// Send the `index`th answer the active blocker's rule declares. The run's
+/// latest blocker is read at the keypress itself, and the host checks that
+/// its watcher still reports that very blocker before the key is written, so
+/// a prompt that cleared, changed rule or was shown again is never answered.
+pub(in crate::client) fn answer(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    workspace_id: crate::core::WorkspaceId,
+    run_id: crate::core::AgentRunId,
+    index: usize,
+) -> Result<(), String> {
+    let run = state
+        .agent_runs
+        .get(&workspace_id)
+        .and_then(|runs| runs.iter().find(|run| run.run_id == run_id))
+        .ok_or_else(|| "That agent run is no longer registered; run :refresh.".to_owned())?;
+    let provider = run.provider;
+    let blocker = match (&run.blocker, run.display_status()) {
+        (Some(blocker), DisplayStatus::NeedsInput) => blocker,
+        _ => {
+            return Err(format!(
+                "The {provider} agent has no prompt on screen to answer; enter opens its tab."
+            ))
+        }
+    };
+    let rule_id = blocker.rule_id.clone();
+    let answer = match blocker.answers.get(index) {
+        Some(answer) => answer.clone(),
+        None if blocker.answers.is_empty() => {
+            return Err(format!(
+                "The {rule_id} prompt declares no answers; enter opens its tab."
+            ))
+        }
+        None => {
+            return Err(format!(
+                "The {rule_id} prompt declares {} answers.",
+                blocker.answers.len()
+            ))
+        }
+    };
+    let (tab_id, zellij_pane_id) = (run.tab_id, run.zellij_pane_id.clone());
+    let blocker_sequence = run.blocker_sequence;
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The agent's workspace host is unavailable.".to_owned())?;
+    let label = format!("Answering {} to the {provider} agent", answer.label);
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::AgentAnswer {
+            provider,
+            rule_id: rule_id.clone(),
+            answer: answer.label,
+        },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            let snapshot = runtime.agent_snapshot(host_id, run_id)?.ok_or_else(|| {
+                "The agent run is no longer registered on its host; nothing was sent.".to_owned()
+            })?;
+            host_still_asking(
+                provider,
+                &rule_id,
+                blocker_sequence,
+                snapshot.blocker.as_ref(),
+            )?;
+            runtime
+                .write_to_agent_pane(
+                    workspace_id,
+                    run_id,
+                    tab_id,
+                    &zellij_pane_id,
+                    "",
+                    &[answer.key.byte()],
+                )
+                .map(|()| HostOperationValue::AgentAnswered)
+        }),
+    )?;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+fn accepts_prompt(status: DisplayStatus) -> bool {
+    matches!(status, DisplayStatus::Ready | DisplayStatus::Done)
+}
+
+/// The host's word on a prompt: the agent must still be waiting for one and
+/// its watcher must not have a blocker on screen.
+fn host_accepts_prompt(
+    provider: Provider,
+    state: AgentState,
+    blocker: Option<&crate::core::HostBlockerState>,
+) -> Result<(), String> {
+    let state = DisplayStatus::from_agent(state);
+    if !accepts_prompt(state) {
+        return Err(format!(
+            "The {provider} agent is now {}; nothing was sent.",
+            state.public_word()
+        ));
+    }
+    match blocker.and_then(|blocker| blocker.rule_id.as_ref()) {
+        Some(rule_id) => Err(format!(
+            "The {provider} agent is waiting on its {rule_id} prompt; nothing was sent."
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The host's word on an answer: its watcher's latest transition must be
+/// the blocker the answer was picked from. The same rule at a later sequence
+/// is a prompt shown again, which the person has not seen yet.
+fn host_still_asking(
+    provider: Provider,
+    rule_id: &str,
+    sequence: u64,
+    blocker: Option<&crate::core::HostBlockerState>,
+) -> Result<(), String> {
+    let Some(blocker) = blocker else {
+        return Err(format!(
+            "The {provider} agent's {rule_id} prompt is no longer on screen; nothing was sent."
+        ));
+    };
+    match blocker.rule_id.as_deref() {
+        Some(current) if current == rule_id && blocker.sequence == sequence => Ok(()),
+        Some(current) if current == rule_id => Err(format!(
+            "The {provider} agent's {rule_id} prompt was shown again; nothing was sent."
+        )),
+        Some(current) => Err(format!(
+            "The {provider} agent now shows its {current} prompt instead; nothing was sent."
+        )),
+        None => Err(format!(
+            "The {provider} agent's {rule_id} prompt is no longer on screen; nothing was sent."
+        )),
+    }
+}
+
+fn reply_target(
+    state: &ClientState,
+) -> Result<(crate::core::WorkspaceId, crate::core::AgentRunId), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let waiting = state
+        .agent_runs
+        .get(&workspace_id)
+        .into_iter()
+        .flatten()
+        .filter(|run| accepts_prompt(run.display_status()))
+        .map(|run| run.run_id)
+        .collect::<Vec<_>>();
+    match waiting.as_slice() {
+        [run_id] => Ok((workspace_id, *run_id)),
+        [] => {
+            Err("No agent in this workspace is idle or done; :agents lists every run.".to_owned())
+        }
+        many => Err(format!(
+            "{} agents in this workspace are idle or done; pick one in :agents and press r.",
+            many.len()
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -267,6 +523,7 @@ mod tests {
             blocker_sequence: 0,
             blocker_observed_at_ms: None,
             interrupted_after_sequence: None,
+            manifest_warning: None,
         };
 
         let rendered = format_run(&run);
@@ -275,4 +532,105 @@ mod tests {
             assert!(!rendered.contains(internal));
         }
     }
+
+    #[test]
+    fn status_explain_names_the_manifest_that_produced_a_blocker() {
+        let run = AgentRunView {
+            run_id: crate::core::AgentRunId::new(),
+            pane_id: crate::core::PaneId::new(),
+            tab_id: 1,
+            provider: Provider::Codex,
+            zellij_pane_id: "1".to_owned(),
+            needs_input_capability: "partial".to_owned(),
+            snapshot: None,
+            explain: None,
+            snapshot_error: None,
+            seen_completion_revision: 0,
+            blocker: Some(crate::agent_status::BlockerExplain {
+                provider: Provider::Codex,
+                manifest: crate::agent_status::BlockerManifestSource::User,
+                manifest_version: "2026.9.1".to_owned(),
+                rule_id: "sandbox_escalation".to_owned(),
+                confidence: crate::agent_status::BlockerConfidence::High,
+                priority: 2000,
+                answers: Vec::new(),
+            }),
+            blocker_watcher_instance: None,
+            blocker_sequence: 0,
+            blocker_observed_at_ms: None,
+            interrupted_after_sequence: None,
+            manifest_warning: None,
+        };
+
+        assert!(format_run(&run)
+            .contains("blocker zellij_viewport manifest user 2026.9.1 rule sandbox_escalation"));
+    }
+
+    #[test]
+    fn the_host_refuses_an_answer_picked_from_a_stale_blocker() {
+        let blocker = |sequence, rule_id: Option<&str>| crate::core::HostBlockerState {
+            sequence,
+            rule_id: rule_id.map(str::to_owned),
+        };
+        let answer = |latest: Option<&crate::core::HostBlockerState>| {
+            host_still_asking(Provider::Claude, "permission_prompt", 3, latest)
+        };
+
+        assert_eq!(answer(Some(&blocker(3, Some("permission_prompt")))), Ok(()));
+        assert_eq!(
+            answer(Some(&blocker(5, Some("permission_prompt")))),
+            Err(
+                "The claude agent's permission_prompt prompt was shown again; nothing was sent."
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            answer(Some(&blocker(4, Some("connection_prompt")))),
+            Err(
+                "The claude agent now shows its connection_prompt prompt instead; nothing was sent."
+                    .to_owned()
+            )
+        );
+        for latest in [Some(blocker(4, None)), None] {
+            assert_eq!(
+                answer(latest.as_ref()),
+                Err(
+                    "The claude agent's permission_prompt prompt is no longer on screen; nothing was sent."
+                        .to_owned()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn the_host_refuses_a_prompt_while_its_watcher_sees_a_blocker() {
+        let blocker = |rule_id: Option<&str>| crate::core::HostBlockerState {
+            sequence: 4,
+            rule_id: rule_id.map(str::to_owned),
+        };
+
+        assert_eq!(
+            host_accepts_prompt(
+                Provider::Codex,
+                AgentState::Ready,
+                Some(&blocker(Some("sandbox_escalation")))
+            ),
+            Err(
+                "The codex agent is waiting on its sandbox_escalation prompt; nothing was sent."
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            host_accepts_prompt(Provider::Codex, AgentState::Working, None),
+            Err("The codex agent is now running; nothing was sent.".to_owned())
+        );
+        assert_eq!(
+            host_accepts_prompt(Provider::Codex, AgentState::Done, Some(&blocker(None))),
+            Ok(())
+        );
+        assert_eq!(
+            host_accepts_prompt(Provider::Codex, AgentState::Ready, None),
+            Ok(())
+        );
+    }
 }
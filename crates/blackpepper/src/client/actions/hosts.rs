@@ -148,13 +148,7 @@ pub(super) fn disconnect(
     let host = runtime.find_host(name)?;
     let report = runtime.disconnect_host_with_restores(host.id)?;
     super::super::runner::operations::apply_deferred_results(state, report.deferred_results);
-    for forward in state
-        .forwards
-        .iter_mut()
-        .filter(|forward| forward.host_id == host.id)
-    {
-        forward.mark_reconnecting();
-    }
+    state.mark_host_tunnels_reconnecting(host.id);
     state
         .connections
         .insert(host.id, HostConnection::Disconnected);
@@ -1,11 +1,17 @@
 mod presentation;
+mod reverse;
+mod sockets;
 
 use super::super::ClientState;
 use crate::client::runtime::{ClientRuntime, HostOperationContext, HostOperationValue};
 
 pub(in crate::client) use presentation::{
-    apply_cancelled, apply_forwarded, apply_list, existing_forward_message,
+    apply_cancelled, apply_declared_forwards, apply_forwarded, apply_list, existing_forward_message,
 };
+pub(in crate::client) use reverse::{apply_reverse_cancelled, apply_reversed};
+pub(super) use reverse::{cancel as cancel_reverse, reverse};
+pub(in crate::client) use sockets::{apply_socket_cancelled, apply_socket_forwarded};
+pub(super) use sockets::{cancel as cancel_socket, forward as forward_socket};
 
 pub(super) fn list(
     state: &mut ClientState,
@@ -49,6 +55,7 @@ pub(super) fn forward(
     runtime: &mut ClientRuntime,
     remote_port: u16,
     bind_address: Option<String>,
+    local_port: Option<u16>,
 ) -> Result<(), String> {
     let workspace_id = super::selected_workspace(state)?;
     let target = resolve_command_target(state, workspace_id, remote_port, bind_address.as_deref())?;
@@ -59,7 +66,19 @@ pub(super) fn forward(
     {
         return Err(existing_forward_message(existing));
     }
-    start_forward_target(state, runtime, workspace_id, target)
+    if let Some(port) = local_port {
+        if let Some(existing) = state
+            .forwards
+            .iter()
+            .find(|forward| forward.local_address.port() == port)
+        {
+            return Err(format!(
+                "Local port {port} already carries the forward to {}.",
+                existing.remote_endpoint()
+            ));
+        }
+    }
+    start_forward_target(state, runtime, workspace_id, target, local_port)
 }
 
 pub(in crate::client) fn start_forward_target(
@@ -67,6 +86,7 @@ pub(in crate::client) fn start_forward_target(
     runtime: &mut ClientRuntime,
     workspace_id: crate::core::WorkspaceId,
     target: crate::ports::RemotePortTarget,
+    local_port: Option<u16>,
 ) -> Result<(), String> {
     let host_id = state
         .host_for_workspace(workspace_id)
@@ -79,7 +99,7 @@ pub(in crate::client) fn start_forward_target(
         state.event_tx.clone(),
         Box::new(move |runtime| {
             runtime
-                .forward_workspace_port(workspace_id, target)
+                .forward_workspace_port(workspace_id, target, local_port)
                 .map(HostOperationValue::Forwarded)
         }),
     )?;
@@ -90,6 +110,92 @@ pub(in crate::client) fn start_forward_target(
     Ok(())
 }
 
+/// Forward `auto` declarations whose listeners just appeared on `host_id`,
+/// one batched operation per workspace. Returns what could not be scheduled;
+/// a busy host re-arms the declarations for the next refresh instead.
+pub(in crate::client) fn start_declared_forwards(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    host_id: crate::core::HostId,
+) -> Vec<String> {
+    let workspaces = state
+        .snapshot
+        .workspaces
+        .iter()
+        .map(|workspace| workspace.id)
+        .collect::<std::collections::BTreeSet<_>>();
+    state
+        .declared_forwards
+        .retain(|workspace_id, _| workspaces.contains(workspace_id));
+    let Some(snapshot) = state.ports.get(&host_id) else {
+        return Vec::new();
+    };
+    let mut notices = Vec::new();
+    for (workspace_id, declared) in &mut state.declared_forwards {
+        let Some(workspace) = state
+            .snapshot
+            .workspaces
+            .iter()
+            .find(|workspace| workspace.id == *workspace_id && workspace.host_id == host_id)
+        else {
+            continue;
+        };
+        let mut pending = Vec::new();
+        for (declaration, target) in declared.appeared(snapshot, &workspace.root_path) {
+            match target {
+                Ok(target)
+                    if state.forwards.iter().any(|forward| {
+                        forward.workspace_id == *workspace_id && forward.target() == target
+                    }) => {}
+                Ok(target) => pending.push((declaration, target)),
+                Err(error) => notices.push(format!(
+                    "declared forward for port {}: {error}",
+                    declaration.port
+                )),
+            }
+        }
+        if pending.is_empty() {
+            continue;
+        }
+        let workspace_id = *workspace_id;
+        let label = format!("Forwarding {} declared port(s)", pending.len());
+        let requests = pending
+            .iter()
+            .map(|(declaration, target)| (target.clone(), declaration.local_port))
+            .collect::<Vec<_>>();
+        let started = runtime.start_host_operation(
+            host_id,
+            label.clone(),
+            HostOperationContext::DeclaredForwards { workspace_id },
+            state.event_tx.clone(),
+            Box::new(move |runtime| {
+                Ok(HostOperationValue::DeclaredForwards(
+                    requests
+                        .into_iter()
+                        .map(|(target, local_port)| {
+                            let endpoint = target.endpoint();
+                            runtime
+                                .forward_workspace_port(workspace_id, target, local_port)
+                                .map_err(|error| format!("{endpoint}: {error}"))
+                        })
+                        .collect(),
+                ))
+            }),
+        );
+        match started {
+            Ok(token) => {
+                state.host_operations.insert(host_id, (token, label));
+            }
+            Err(_) => {
+                for (declaration, _) in &pending {
+                    declared.retry(declaration);
+                }
+            }
+        }
+    }
+    notices
+}
+
 pub(super) fn cancel(
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
@@ -169,19 +275,11 @@ fn resolve_command_target(
         "No port discovery result is available. Run :ports first (or :ports --all-host for unattributed services)."
             .to_string()
     })?;
-    let visible = snapshot.listeners.iter().filter(|listener| {
-        state.show_all_host_ports
-            || crate::client::runtime::ports::listener_matches_workspace(
-                listener.workspace_path.as_deref(),
-                &workspace.root_path,
-            )
-    });
-    let target = crate::ports::resolve_forward_target(visible, remote_port, bind_address)?;
-    if crate::ports::target_is_ambiguous(&snapshot.listeners, &target) {
-        return Err(format!(
-            "Multiple processes can accept {}; TCP forwarding cannot select one process. Stop the overlapping listener or use another port.",
-            target.endpoint()
-        ));
-    }
-    Ok(target)
+    crate::client::runtime::ports::resolve_workspace_target(
+        snapshot,
+        &workspace.root_path,
+        state.show_all_host_ports,
+        remote_port,
+        bind_address,
+    )
 }
@@ -31,9 +31,14 @@ pub(in crate::client) fn apply_list(
                 crate::ports::target_is_ambiguous(&snapshot.listeners, &target)
             });
             format!(
-                "{}  {}{}",
+                "{}  {}{}{}",
                 listener.bind_endpoint(),
                 listener.process.as_deref().unwrap_or("unknown process"),
+                listener
+                    .http
+                    .as_ref()
+                    .map(|http| format!(" · {}", http.label()))
+                    .unwrap_or_default(),
                 if ambiguous {
                     " — shared/overlapping; forwarding disabled"
                 } else {
@@ -116,3 +121,39 @@ pub(in crate::client) fn existing_forward_message(forward: &crate::ports::Forwar
         }
     }
 }
+
+pub(in crate::client) fn apply_declared_forwards(
+    state: &mut ClientState,
+    workspace_id: crate::core::WorkspaceId,
+    results: Vec<Result<crate::ports::ForwardState, String>>,
+) {
+    let mut active = Vec::new();
+    let mut failures = Vec::new();
+    for result in results {
+        match result {
+            Ok(forward) if forward.workspace_id == workspace_id => {
+                active.push(format!(
+                    "http://{} → remote {}",
+                    forward.local_address,
+                    forward.remote_endpoint()
+                ));
+                state.forwards.push(forward);
+            }
+            Ok(_) => {}
+            Err(error) => failures.push(error),
+        }
+    }
+    let mut message = Vec::new();
+    if !active.is_empty() {
+        message.push(format!("Declared forward active: {}.", active.join(", ")));
+    }
+    if !failures.is_empty() {
+        message.push(format!(
+            "Declared forward failed: {}.",
+            failures.join(" | ")
+        ));
+    }
+    if !message.is_empty() {
+        state.set_output(message.join(" "));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::client::runtime::{ClientRuntime, HostOperationContext, HostOperationValue};
+use crate::client::ClientState;
+use crate::ports::{ForwardStatus, ReverseForwardState};
+
+pub(in crate::client) fn reverse(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    local_port: u16,
+    remote_port: Option<u16>,
+) -> Result<(), String> {
+    let workspace_id = super::super::selected_workspace(state)?;
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The selected workspace host is unavailable.".to_owned())?;
+    let remote_port = remote_port.unwrap_or(local_port);
+    // One host port can carry only one client service, whichever workspace
+    // asked for it.
+    if let Some(existing) = state
+        .reverse_forwards
+        .iter()
+        .find(|forward| forward.host_id == host_id && forward.remote_port == remote_port)
+    {
+        return Err(existing_reverse_message(existing));
+    }
+    let label = format!("Forwarding host port {remote_port} to client port {local_port}");
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::ReverseStart { workspace_id },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .reverse_workspace_port(workspace_id, local_port, remote_port)
+                .map(HostOperationValue::Reversed)
+        }),
+    )?;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+pub(in crate::client) fn cancel(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    remote_port: u16,
+) -> Result<(), String> {
+    let workspace_id = super::super::selected_workspace(state)?;
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The selected workspace host is unavailable.".to_owned())?;
+    let index = state
+        .reverse_forwards
+        .iter()
+        .position(|forward| forward.host_id == host_id && forward.remote_port == remote_port)
+        .ok_or_else(|| format!("No reverse forward uses host port {remote_port}."))?;
+    let forward = state.reverse_forwards[index].clone();
+    if forward.status == ForwardStatus::Cancelling {
+        return Err(existing_reverse_message(&forward));
+    }
+    let forward_id = forward.id;
+    let label = format!("Cancelling reverse forward {}", forward.remote_endpoint());
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::ReverseCancel {
+            workspace_id: forward.workspace_id,
+            forward_id,
+        },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .cancel_reverse_forward(&forward)
+                .map(|()| HostOperationValue::ReverseCancelled(forward))
+        }),
+    )?;
+    state.reverse_forwards[index].status = ForwardStatus::Cancelling;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+pub(in crate::client) fn apply_reversed(state: &mut ClientState, forward: ReverseForwardState) {
+    let message = if forward.status == ForwardStatus::Direct {
+        format!(
+            "Port {} is already the same loopback on this machine; no reverse tunnel was created.",
+            forward.local_port
+        )
+    } else {
+        format!(
+            "Reverse forward active: host {} → client {}.",
+            forward.remote_endpoint(),
+            forward.local_endpoint()
+        )
+    };
+    state.reverse_forwards.push(forward);
+    state.set_output(message);
+}
+
+pub(in crate::client) fn apply_reverse_cancelled(
+    state: &mut ClientState,
+    forward_id: uuid::Uuid,
+    forward: ReverseForwardState,
+) {
+    state
+        .reverse_forwards
+        .retain(|candidate| candidate.id != forward_id);
+    state.set_output(format!(
+        "Cancelled reverse forward for host {}.",
+        forward.remote_endpoint()
+    ));
+}
+
+fn existing_reverse_message(forward: &ReverseForwardState) -> String {
+    let remote = forward.remote_endpoint();
+    let local = forward.local_endpoint();
+    match &forward.status {
+        ForwardStatus::Direct | ForwardStatus::Active => {
+            format!(
+                "Host {remote} already reaches client {local}; cancel it with :reverse cancel {}.",
+                forward.remote_port
+            )
+        }
+        ForwardStatus::Reconnecting | ForwardStatus::PortConflict => {
+            format!("Reverse forward for host {remote} is reconnecting; wait, or cancel it before retrying.")
+        }
+        ForwardStatus::Cancelling => {
+            format!("Reverse forward for host {remote} is already being cancelled.")
+        }
+        ForwardStatus::Failed(reason) => {
+            let reason = reason.chars().take(240).collect::<String>();
+            format!(
+                "Reverse forward for host {remote} failed: {reason}. Cancel it before retrying."
+            )
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::client::runtime::{ClientRuntime, HostOperationContext, HostOperationValue};
+use crate::client::ClientState;
+use crate::ports::{ForwardStatus, SocketForwardState};
+use std::path::PathBuf;
+
+pub(in crate::client) fn forward(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    selector: String,
+    local_path: Option<PathBuf>,
+) -> Result<(), String> {
+    let workspace_id = super::super::selected_workspace(state)?;
+    let workspace = state
+        .snapshot
+        .workspaces
+        .iter()
+        .find(|workspace| workspace.id == workspace_id)
+        .ok_or_else(|| "The selected workspace no longer exists.".to_owned())?;
+    let host_id = workspace.host_id;
+    let snapshot = state.ports.get(&host_id).ok_or_else(|| {
+        "No port discovery result is available. Run :ports first (or :ports --all-host for unattributed services)."
+            .to_owned()
+    })?;
+    let remote_path = crate::client::runtime::socket_ports::resolve_workspace_socket(
+        snapshot,
+        &workspace.root_path,
+        state.show_all_host_ports,
+        &selector,
+    )?;
+    if let Some(existing) = state
+        .socket_forwards
+        .iter()
+        .find(|forward| forward.host_id == host_id && forward.remote_path == remote_path)
+    {
+        return Err(existing_socket_message(existing));
+    }
+    if let Some(path) = &local_path {
+        if let Some(existing) = state
+            .socket_forwards
+            .iter()
+            .find(|forward| &forward.local_path == path)
+        {
+            return Err(format!(
+                "{} already carries the forward to {}.",
+                path.display(),
+                existing.remote_path
+            ));
+        }
+    }
+    let label = format!("Forwarding socket {remote_path}");
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::SocketForwardStart { workspace_id },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .forward_workspace_socket(workspace_id, remote_path, local_path)
+                .map(HostOperationValue::SocketForwarded)
+        }),
+    )?;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+pub(in crate::client) fn cancel(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    selector: String,
+) -> Result<(), String> {
+    let workspace_id = super::super::selected_workspace(state)?;
+    let matches = state
+        .socket_forwards
+        .iter()
+        .enumerate()
+        .filter(|(_, forward)| {
+            forward.workspace_id == workspace_id
+                && if selector.starts_with('/') {
+                    forward.remote_path == selector
+                } else {
+                    forward.remote_name() == selector
+                }
+        })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let index = match matches.as_slice() {
+        [index] => *index,
+        [] => return Err(format!("No socket forward matches {selector}.")),
+        _ => {
+            return Err(format!(
+                "Several socket forwards are named {selector}; cancel one with :forward cancel unix:<full path>."
+            ))
+        }
+    };
+    let forward = state.socket_forwards[index].clone();
+    if forward.status == ForwardStatus::Cancelling {
+        return Err(existing_socket_message(&forward));
+    }
+    let host_id = forward.host_id;
+    let forward_id = forward.id;
+    let label = format!("Cancelling socket forward {}", forward.remote_path);
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::SocketForwardCancel {
+            workspace_id,
+            forward_id,
+        },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .cancel_socket_forward(&forward)
+                .map(|()| HostOperationValue::SocketForwardCancelled(forward))
+        }),
+    )?;
+    state.socket_forwards[index].status = ForwardStatus::Cancelling;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+pub(in crate::client) fn apply_socket_forwarded(
+    state: &mut ClientState,
+    forward: SocketForwardState,
+) {
+    let message = if forward.status == ForwardStatus::Direct {
+        format!(
+            "{} is already on this machine; no socket tunnel was created.",
+            forward.remote_path
+        )
+    } else {
+        format!(
+            "Socket forward active: {} → {}.",
+            forward.local_path.display(),
+            forward.remote_path
+        )
+    };
+    state.socket_forwards.push(forward);
+    state.set_output(message);
+}
+
+pub(in crate::client) fn apply_socket_cancelled(
+    state: &mut ClientState,
+    forward_id: uuid::Uuid,
+    forward: SocketForwardState,
+) {
+    state
+        .socket_forwards
+        .retain(|candidate| candidate.id != forward_id);
+    state.set_output(format!(
+        "Cancelled socket forward for {}.",
+        forward.remote_path
+    ));
+}
+
+fn existing_socket_message(forward: &SocketForwardState) -> String {
+    let remote = &forward.remote_path;
+    let local = forward.local_path.display();
+    match &forward.status {
+        ForwardStatus::Direct | ForwardStatus::Active => format!(
+            "{remote} is already forwarded to {local}; cancel it with :forward cancel unix:{}.",
+            forward.remote_name()
+        ),
+        ForwardStatus::Reconnecting => {
+            format!(
+                "Socket forward for {remote} is reconnecting; wait, or cancel it before retrying."
+            )
+        }
+        ForwardStatus::PortConflict => {
+            format!("{local} is taken by another process; cancel the forward for {remote} before retrying.")
+        }
+        ForwardStatus::Cancelling => {
+            format!("Socket forward for {remote} is already being cancelled.")
+        }
+        ForwardStatus::Failed(reason) => {
+            let reason = reason.chars().take(240).collect::<String>();
+            format!("Socket forward for {remote} failed: {reason}. Cancel it before retrying.")
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use super::super::ClientState;
+use crate::client::runtime::{ClientRuntime, HostOperationContext, HostOperationValue};
+use crate::zellij::PaneProcessState;
+
+pub(super) fn start_service(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    name: &str,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let worker_name = name.to_owned();
+    service_operation(
+        state,
+        runtime,
+        workspace_id,
+        format!("Starting service {name}"),
+        HostOperationContext::ServiceStart {
+            workspace_id,
+            name: name.to_owned(),
+        },
+        move |runtime| {
+            runtime
+                .start_named_service(workspace_id, &worker_name)
+                .map(|tab_id| HostOperationValue::ServiceStarted { tab_id })
+        },
+    )
+}
+
+pub(super) fn stop_service(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    name: &str,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let worker_name = name.to_owned();
+    service_operation(
+        state,
+        runtime,
+        workspace_id,
+        format!("Stopping service {name}"),
+        HostOperationContext::ServiceStop {
+            workspace_id,
+            name: name.to_owned(),
+        },
+        move |runtime| {
+            runtime
+                .stop_named_service(workspace_id, &worker_name)
+                .map(HostOperationValue::ServiceStopped)
+        },
+    )
+}
+
+pub(super) fn restart_service(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    name: &str,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let worker_name = name.to_owned();
+    service_operation(
+        state,
+        runtime,
+        workspace_id,
+        format!("Restarting service {name}"),
+        HostOperationContext::ServiceRerun {
+            workspace_id,
+            name: name.to_owned(),
+        },
+        move |runtime| {
+            runtime
+                .restart_named_service(workspace_id, &worker_name)
+                .map(|tab_id| HostOperationValue::ServiceStarted { tab_id })
+        },
+    )
+}
+
+pub(super) fn service_status(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    name: Option<&str>,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let worker_name = name.map(str::to_owned);
+    service_operation(
+        state,
+        runtime,
+        workspace_id,
+        "Checking services".to_owned(),
+        HostOperationContext::ServiceStatus { workspace_id },
+        move |runtime| {
+            runtime
+                .service_states(workspace_id, worker_name.as_deref())
+                .map(HostOperationValue::ServiceStates)
+        },
+    )
+}
+
+/// One line per service; a single service reads as a sentence instead.
+pub(in crate::client) fn apply_service_states(
+    state: &mut ClientState,
+    workspace_id: crate::core::WorkspaceId,
+    states: Vec<(String, PaneProcessState)>,
+) {
+    let blocked_by = |name: &str| {
+        state
+            .services
+            .get(&workspace_id)?
+            .iter()
+            .find(|status| status.name == name)?
+            .blocked_by
+            .clone()
+    };
+    let describe = |name: &str, process: PaneProcessState| match process {
+        PaneProcessState::Live => "running".to_owned(),
+        PaneProcessState::Exited { code: Some(code) } => format!("exited with status {code}"),
+        PaneProcessState::Exited { code: None } => "exited".to_owned(),
+        PaneProcessState::Missing | PaneProcessState::UnverifiedIdentity { .. } => {
+            match blocked_by(name) {
+                Some(reason) => format!("blocked: {reason}"),
+                None => "not running".to_owned(),
+            }
+        }
+    };
+    match states.as_slice() {
+        [] => state.set_output("No services are configured for this workspace."),
+        [(name, process)] => {
+            let description = describe(name, *process);
+            state.set_output(format!("Service {name} is {description}."))
+        }
+        states => {
+            let body = states
+                .iter()
+                .map(|(name, process)| format!("{name}: {}", describe(name, *process)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            state.set_detail("Services", body);
+        }
+    }
+}
+
+fn service_operation(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    workspace_id: crate::core::WorkspaceId,
+    label: String,
+    context: HostOperationContext,
+    work: impl FnOnce(&mut ClientRuntime) -> Result<HostOperationValue, String> + Send + 'static,
+) -> Result<(), String> {
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The selected workspace host is unavailable.".to_owned())?;
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        context,
+        state.event_tx.clone(),
+        Box::new(work),
+    )?;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+/// Relaunch the first service whose `restart = "on-failure"` backoff has
+/// elapsed. A host runs one operation at a time, so the others stay due and
+/// start from a later refresh; nothing changes the selection or the mode.
+pub(in crate::client) fn restart_services(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    host_id: crate::core::HostId,
+    due: Vec<(crate::core::WorkspaceId, String)>,
+) {
+    let Some((workspace_id, name)) = due.into_iter().next() else {
+        return;
+    };
+    let worker_name = name.clone();
+    let label = format!("Restarting service {name}");
+    let Ok(token) = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::ServiceRestart {
+            workspace_id,
+            name: name.clone(),
+        },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .start_named_service(workspace_id, &worker_name)
+                .map(|tab_id| HostOperationValue::ServiceStarted { tab_id })
+        }),
+    ) else {
+        return;
+    };
+    state.host_operations.insert(host_id, (token, label));
+    if let Some(status) = state.service_status_mut(workspace_id, &name) {
+        status.begin_restart(std::time::Instant::now());
+    }
+}
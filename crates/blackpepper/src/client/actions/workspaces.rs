@@ -22,11 +22,13 @@ pub(super) fn register(
         state.event_tx.clone(),
         Box::new(move |runtime| {
             let workspace_id = runtime.register_workspace(host_id, &worker_path)?;
+            let declared_forwards = runtime.declared_forwards(workspace_id);
             let attachment = runtime.attach_workspace(workspace_id, 24, 80);
             Ok(HostOperationValue::RegisteredAndAttached {
                 workspace_id,
                 path: worker_path,
                 attachment,
+                declared_forwards,
             })
         }),
     )?;
@@ -29,28 +29,215 @@ pub(super) fn list(state: &mut ClientState, runtime: &mut ClientRuntime) -> Resu
     Ok(())
 }
 
-pub(in crate::client) fn apply_list(state: &mut ClientState, list: crate::worktrunk::WorktreeList) {
-    let lines = list
+pub(in crate::client) fn apply_list(
+    state: &mut ClientState,
+    workspace_id: crate::core::WorkspaceId,
+    list: crate::worktrunk::WorktreeList,
+) {
+    if list.items.is_empty() {
+        state.set_output("Worktrunk returned no worktrees.");
+        return;
+    }
+    let count = list.items.len();
+    // The cursor starts on the worktree this workspace already is, so Enter
+    // right away is a no-op switch rather than a surprise.
+    let root = state
+        .snapshot
+        .workspaces
+        .iter()
+        .find(|workspace| workspace.id == workspace_id)
+        .map(|workspace| std::path::PathBuf::from(&workspace.root_path));
+    let selected = list
         .items
         .iter()
-        .map(|item| {
-            let branch = item.branch.as_deref().unwrap_or("detached");
-            let path = item
-                .worktree
+        .position(|item| {
+            item.worktree
                 .as_ref()
-                .map(|worktree| worktree.path.display().to_string())
-                .unwrap_or_else(|| "not checked out".to_string());
-            format!("{branch}: {path}")
+                .is_some_and(|worktree| Some(&worktree.path) == root.as_ref())
         })
-        .collect::<Vec<_>>();
-    let message = if lines.is_empty() {
-        "Worktrunk returned no worktrees.".to_string()
-    } else {
-        let count = lines.len();
-        state.set_detail("Worktrees", lines.join("\n"));
-        format!("Listed {count} Worktrunk item(s). Esc closes the list.")
+        .unwrap_or(0);
+    state.close_detail();
+    state.help = None;
+    state.agents = None;
+    state.pull_requests = None;
+    state.worktrees = Some(crate::client::state::WorktreesView {
+        workspace_id,
+        list,
+        selected,
+    });
+    state.set_output(format!(
+        "Listed {count} Worktrunk item(s). Enter opens, d previews removal, Esc closes."
+    ));
+}
+
+pub(super) fn list_pull_requests(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let host_id = state
+        .host_for_workspace(workspace_id)
+        .ok_or_else(|| "The selected workspace host is unavailable.".to_owned())?;
+    let label = "Listing open pull requests".to_owned();
+    let token = runtime.start_host_operation(
+        host_id,
+        label.clone(),
+        HostOperationContext::PullRequestList { workspace_id },
+        state.event_tx.clone(),
+        Box::new(move |runtime| {
+            runtime
+                .list_pull_requests(workspace_id)
+                .map(HostOperationValue::PullRequests)
+        }),
+    )?;
+    state
+        .host_operations
+        .insert(host_id, (token, label.clone()));
+    state.set_output(format!("{label}… Press Esc in Manage mode to cancel."));
+    Ok(())
+}
+
+pub(in crate::client) fn apply_pull_request_list(
+    state: &mut ClientState,
+    workspace_id: crate::core::WorkspaceId,
+    pull_requests: Vec<crate::core::OpenPullRequest>,
+) {
+    if pull_requests.is_empty() {
+        state.set_output("This repository has no open pull requests.");
+        return;
+    }
+    let count = pull_requests.len();
+    state.close_detail();
+    state.help = None;
+    state.agents = None;
+    state.worktrees = None;
+    state.pull_requests = Some(crate::client::state::PullRequestsView {
+        workspace_id,
+        pull_requests,
+        filter: String::new(),
+        selected: 0,
+    });
+    state.set_output(format!(
+        "Listed {count} open pull request(s). Type to filter, Enter opens, Esc closes."
+    ));
+}
+
+/// Enter in the pull request picker: `pr:N` goes through the reviewed
+/// `:worktree open` flow exactly as if it had been typed.
+pub(in crate::client) fn open_selected_pull_request(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+) -> Result<(), String> {
+    let Some(view) = state.pull_requests.as_ref() else {
+        return Ok(());
     };
-    state.set_output(message);
+    let workspace_id = view.workspace_id;
+    let number = view
+        .selected_pull_request()
+        .map(|pull_request| pull_request.number)
+        .ok_or_else(|| "No pull request matches that filter.".to_owned())?;
+    state.pull_requests = None;
+    schedule_mutation(
+        state,
+        runtime,
+        workspace_id,
+        ClientCommand::WorktreeOpen {
+            selector: format!("pr:{number}"),
+        },
+        None,
+        Vec::new(),
+    )
+}
+
+/// Enter in the browser: the row's branch goes through the same reviewed
+/// `:worktree open` flow as typing it, which also checks out a branch that
+/// only exists on a remote.
+pub(in crate::client) fn open_selected(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+) -> Result<(), String> {
+    let Some(view) = state.worktrees.as_ref() else {
+        return Ok(());
+    };
+    let workspace_id = view.workspace_id;
+    let item = view
+        .selected_item()
+        .ok_or_else(|| "No worktree is selected.".to_owned())?;
+    let Some(branch) = item.branch.clone() else {
+        let path = item
+            .worktree
+            .as_ref()
+            .map(|worktree| worktree.path.display().to_string())
+            .unwrap_or_default();
+        return Err(format!(
+            "The detached worktree at {path} has no branch to open; register it with :workspace add {path}."
+        ));
+    };
+    state.worktrees = None;
+    schedule_mutation(
+        state,
+        runtime,
+        workspace_id,
+        ClientCommand::WorktreeOpen { selector: branch },
+        None,
+        Vec::new(),
+    )
+}
+
+/// `d` in the browser previews removing the row's worktree. Removal is
+/// workspace-scoped, so the row must already be a registered workspace.
+pub(in crate::client) fn remove_selected(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+) -> Result<(), String> {
+    let Some(view) = state.worktrees.as_ref() else {
+        return Ok(());
+    };
+    let item = view
+        .selected_item()
+        .ok_or_else(|| "No worktree is selected.".to_owned())?;
+    let name = item.branch.as_deref().unwrap_or("detached");
+    let Some(worktree) = item.worktree.as_ref() else {
+        return Err(format!("{name} has no worktree to remove."));
+    };
+    if worktree.main {
+        return Err("The main worktree cannot be removed.".to_owned());
+    }
+    let workspace_id =
+        registered_worktree(state, view.workspace_id, &worktree.path).ok_or_else(|| {
+            format!(
+                "{} is not a registered workspace; open it with Enter before removing it.",
+                worktree.path.display()
+            )
+        })?;
+    state.worktrees = None;
+    state.selected_workspace = Some(workspace_id);
+    schedule_mutation(
+        state,
+        runtime,
+        workspace_id,
+        ClientCommand::WorktreeRemove,
+        None,
+        Vec::new(),
+    )
+}
+
+/// The workspace registered at a listed worktree path on the same host as
+/// the workspace that was listed.
+pub(in crate::client) fn registered_worktree(
+    state: &ClientState,
+    listed: crate::core::WorkspaceId,
+    path: &std::path::Path,
+) -> Option<crate::core::WorkspaceId> {
+    let host_id = state.host_for_workspace(listed)?;
+    state
+        .snapshot
+        .workspaces
+        .iter()
+        .find(|workspace| {
+            workspace.host_id == host_id && std::path::Path::new(&workspace.root_path) == path
+        })
+        .map(|workspace| workspace.id)
 }
 
 pub(super) fn create(
@@ -64,7 +251,7 @@ pub(super) fn create(
         branch: branch.clone(),
         base: base.clone(),
     };
-    schedule_mutation(state, runtime, workspace_id, command, None)
+    schedule_mutation(state, runtime, workspace_id, command, None, Vec::new())
 }
 
 pub(super) fn open(
@@ -76,7 +263,26 @@ pub(super) fn open(
         selector: selector.clone(),
     };
     let workspace_id = super::selected_workspace(state)?;
-    schedule_mutation(state, runtime, workspace_id, command, None)
+    schedule_mutation(state, runtime, workspace_id, command, None, Vec::new())
+}
+
+/// `:agent spawn <provider> --worktree <branch>`: the create is previewed and
+/// approved like `:worktree create`, and the agent starts in the registered
+/// worktree only once its shell is up.
+pub(super) fn spawn_in_worktree(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    provider: crate::agent_status::Provider,
+    branch: String,
+    base: Option<String>,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    let command = ClientCommand::AgentSpawnInWorktree {
+        provider,
+        branch,
+        base,
+    };
+    schedule_mutation(state, runtime, workspace_id, command, None, Vec::new())
 }
 
 pub(super) fn remove(state: &mut ClientState, runtime: &mut ClientRuntime) -> Result<(), String> {
@@ -87,6 +293,22 @@ pub(super) fn remove(state: &mut ClientState, runtime: &mut ClientRuntime) -> Re
         workspace_id,
         ClientCommand::WorktreeRemove,
         None,
+        Vec::new(),
+    )
+}
+
+pub(super) fn prune_merged(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+) -> Result<(), String> {
+    let workspace_id = super::selected_workspace(state)?;
+    schedule_mutation(
+        state,
+        runtime,
+        workspace_id,
+        ClientCommand::WorktreePruneMerged,
+        None,
+        Vec::new(),
     )
 }
 
@@ -101,6 +323,7 @@ pub(super) fn approve(state: &mut ClientState, runtime: &mut ClientRuntime) -> R
         pending.workspace_id,
         pending.command.clone(),
         Some(pending.approval.clone()),
+        pending.removes.clone(),
     ) {
         state.pending_approval = Some(pending);
         return Err(error);
@@ -114,6 +337,12 @@ pub(in crate::client) fn apply_change(
     pending: Option<(crate::core::WorkspaceId, ClientCommand)>,
     session_error: Option<String>,
 ) {
+    let agent_skipped = match &pending {
+        Some((_, ClientCommand::AgentSpawnInWorktree { provider, .. })) => {
+            format!(" {provider} was not started.")
+        }
+        _ => String::new(),
+    };
     match change {
         WorktreeChange::ApprovalRequired {
             command,
@@ -124,11 +353,26 @@ pub(in crate::client) fn apply_change(
                 state.set_output("Worktrunk's approval plan changed; rerun the original command.");
                 return;
             };
+            let removes = match pending_command {
+                ClientCommand::WorktreeRemove => vec![workspace_id],
+                _ => Vec::new(),
+            };
+            let review = match &pending_command {
+                ClientCommand::AgentSpawnInWorktree { provider, .. } => review_sections(
+                    format!(
+                        "mutation\n{command}\n\nafter approval\n{provider} starts in the new worktree once its setup hooks and shell succeed"
+                    ),
+                    &unapproved_project_commands,
+                    "approval binds to this exact Worktrunk command and project hook plan.",
+                ),
+                _ => approval_review(&command, &unapproved_project_commands),
+            };
             state.pending_approval = Some(PendingWorktrunkApproval {
                 workspace_id,
                 command: pending_command,
                 approval,
-                review: approval_review(&command, &unapproved_project_commands),
+                review,
+                removes,
             });
             state.approval_scroll = 0;
             state.set_output(
@@ -143,7 +387,7 @@ pub(in crate::client) fn apply_change(
                     path.display()
                 )),
                 Some(error) => state.set_output(format!(
-                    "Registered worktree {}, but its shell could not start: {error}",
+                    "Registered worktree {}, but its shell could not start: {error}{agent_skipped}",
                     path.display()
                 )),
             }
@@ -155,7 +399,7 @@ pub(in crate::client) fn apply_change(
         } => {
             state.selected_workspace = Some(workspace_id);
             state.set_output(format!(
-                "Worktree {} exists but setup failed: {message}",
+                "Worktree {} exists but setup failed: {message}{agent_skipped}",
                 path.display()
             ));
         }
@@ -165,14 +409,104 @@ pub(in crate::client) fn apply_change(
         WorktreeChange::Removed => {
             state.set_output("Worktree removed through Worktrunk without force flags.");
         }
+        WorktreeChange::PruneApprovalRequired {
+            items,
+            approval,
+            unapproved_project_commands,
+        } => {
+            let Some((workspace_id, pending_command)) = pending else {
+                state.set_output("Worktrunk's approval plan changed; rerun the original command.");
+                return;
+            };
+            let count = items.len();
+            state.pending_approval = Some(PendingWorktrunkApproval {
+                workspace_id,
+                command: pending_command,
+                approval,
+                review: review_sections(
+                    prune_plan(&items),
+                    &unapproved_project_commands,
+                    "approval binds to every removal above, in order, and the project hook plan.",
+                ),
+                removes: items.iter().map(|item| item.workspace_id).collect(),
+            });
+            state.approval_scroll = 0;
+            state.set_output(format!(
+                "Review the {count} Worktrunk removal(s) and project commands above, then run :approve once for all of them. Any change invalidates this approval."
+            ));
+        }
+        WorktreeChange::Pruned { results } if results.is_empty() => state.set_output(
+            "No registered worktree is both clean and merged or gone upstream; nothing was removed.",
+        ),
+        WorktreeChange::Pruned { results } => {
+            let failed = results
+                .iter()
+                .filter(|result| result.error.is_some())
+                .count();
+            let lines = results
+                .iter()
+                .map(|result| match &result.error {
+                    None => format!("removed  {}", result.path.display()),
+                    Some(error) => format!("failed   {}\n         {error}", result.path.display()),
+                })
+                .collect::<Vec<_>>();
+            state.set_detail("Worktree prune", lines.join("\n"));
+            state.set_output(format!(
+                "Pruned {} of {} worktree(s) through Worktrunk without force flags{}.",
+                results.len() - failed,
+                results.len(),
+                if failed == 0 {
+                    String::new()
+                } else {
+                    format!("; {failed} failed — see the list above")
+                }
+            ));
+        }
     }
 }
 
+/// One entry per planned removal: the branch, why it qualifies, and the exact
+/// command that will run.
+fn prune_plan(items: &[crate::core::WorktrunkPruneItem]) -> String {
+    let entries = items
+        .iter()
+        .map(|item| {
+            let reason = match &item.reason {
+                crate::core::WorktrunkPruneReason::Merged { number } => {
+                    format!("pull request #{number} merged")
+                }
+                crate::core::WorktrunkPruneReason::UpstreamGone { upstream } => {
+                    format!("upstream {upstream} is gone")
+                }
+            };
+            format!(
+                "{} · {reason} · clean\n{}\n{}",
+                item.branch,
+                item.path.display(),
+                item.command
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("removals\n{}", entries.join("\n\n"))
+}
+
 fn approval_review(
     command: &str,
     projects: &[crate::worktrunk::WorktrunkProjectCommand],
 ) -> String {
-    let mut sections = vec![format!("mutation\n{command}")];
+    review_sections(
+        format!("mutation\n{command}"),
+        projects,
+        "approval binds to this exact Worktrunk command and project hook plan.",
+    )
+}
+
+fn review_sections(
+    mutation: String,
+    projects: &[crate::worktrunk::WorktrunkProjectCommand],
+    binding: &str,
+) -> String {
+    let mut sections = vec![mutation];
     if projects.is_empty() {
         sections.push("unapproved project hooks\nnone".to_string());
     } else {
@@ -190,28 +524,47 @@ fn approval_review(
             .join("\n");
         sections.push(format!("unapproved project hooks\n{hooks}"));
     }
-    sections.push(
-        "approval binds to this exact Worktrunk command and project hook plan.\n:approve  run · esc dismiss · ↑↓ scroll\nAny change invalidates this approval; Blackpepper never adds force or hook-skipping flags."
-            .to_string(),
-    );
+    sections.push(format!(
+        "{binding}\n:approve  run · esc dismiss · ↑↓ scroll\nAny change invalidates this approval; Blackpepper never adds force or hook-skipping flags."
+    ));
     sections.join("\n\n")
 }
 
+/// `removes` are the workspaces an approved command deletes. Their forwards
+/// are cancelled and their terminals dropped before the mutation runs.
 fn schedule_mutation(
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
     workspace_id: crate::core::WorkspaceId,
     command: ClientCommand,
     approval: Option<crate::worktrunk::WorktrunkApprovalToken>,
+    removes: Vec<crate::core::WorkspaceId>,
 ) -> Result<(), String> {
     let host_id = state
         .host_for_workspace(workspace_id)
         .ok_or_else(|| "The selected workspace host is unavailable.".to_owned())?;
-    let approved_removal = approval.is_some() && matches!(command, ClientCommand::WorktreeRemove);
+    let removes = if approval.is_some() {
+        removes
+    } else {
+        Vec::new()
+    };
+    let approved_removal = !removes.is_empty();
     let operation_forwards = state
         .forwards
         .iter()
-        .filter(|forward| approved_removal && forward.workspace_id == workspace_id)
+        .filter(|forward| removes.contains(&forward.workspace_id))
+        .cloned()
+        .collect::<Vec<_>>();
+    let operation_socket_forwards = state
+        .socket_forwards
+        .iter()
+        .filter(|forward| removes.contains(&forward.workspace_id))
+        .cloned()
+        .collect::<Vec<_>>();
+    let operation_reverse_forwards = state
+        .reverse_forwards
+        .iter()
+        .filter(|forward| removes.contains(&forward.workspace_id))
         .cloned()
         .collect::<Vec<_>>();
     let context_command = command.clone();
@@ -221,6 +574,14 @@ fn schedule_mutation(
         "Preparing Worktrunk mutation for review"
     }
     .to_owned();
+    let operation_removes = removes.clone();
+    let agent_provider = match &command {
+        ClientCommand::AgentSpawnInWorktree { provider, .. } if approval.is_some() => {
+            Some(*provider)
+        }
+        _ => None,
+    };
+    let blocker_events = state.event_tx.clone();
     let token = runtime.start_host_operation(
         host_id,
         label.clone(),
@@ -232,21 +593,30 @@ fn schedule_mutation(
         state.event_tx.clone(),
         Box::new(move |runtime| {
             let mut forwards = operation_forwards;
+            let mut socket_forwards = operation_socket_forwards;
+            let mut reverse_forwards = operation_reverse_forwards;
+            let mut cancel_forwards = |runtime: &mut ClientRuntime| {
+                operation_removes.iter().try_for_each(|removed| {
+                    runtime.cancel_workspace_forwards(&mut forwards, *removed)?;
+                    runtime.cancel_workspace_socket_forwards(&mut socket_forwards, *removed)?;
+                    runtime
+                        .cancel_workspace_reverse_forwards(&mut reverse_forwards, *removed)
+                        .map(|_| ())
+                })
+            };
             let change = match command {
-                ClientCommand::WorktreeCreate { branch, base } => {
+                ClientCommand::WorktreeCreate { branch, base }
+                | ClientCommand::AgentSpawnInWorktree { branch, base, .. } => {
                     runtime.create_worktree(workspace_id, &branch, base.as_deref(), approval)
                 }
                 ClientCommand::WorktreeOpen { selector } => {
                     runtime.open_worktree(workspace_id, &selector, approval)
                 }
-                ClientCommand::WorktreeRemove => {
-                    let cancellation = if approved_removal {
-                        runtime.cancel_workspace_forwards(&mut forwards, workspace_id)
-                    } else {
-                        Ok(0)
-                    };
-                    cancellation.and_then(|_| runtime.remove_worktree(workspace_id, approval))
-                }
+                ClientCommand::WorktreeRemove => cancel_forwards(runtime)
+                    .and_then(|()| runtime.remove_worktree(workspace_id, approval)),
+                ClientCommand::WorktreePruneMerged => cancel_forwards(runtime).and_then(|()| {
+                    runtime.prune_merged_worktrees(workspace_id, &operation_removes, approval)
+                }),
                 _ => Err("The background command is not a Worktrunk mutation.".to_owned()),
             };
             let session_error = match &change {
@@ -255,27 +625,52 @@ fn schedule_mutation(
                 }
                 _ => None,
             };
+            // A failed setup hook or shell leaves nothing ready to run in.
+            let agent = match (&change, &session_error, agent_provider) {
+                (Ok(WorktreeChange::Registered { workspace_id, .. }), None, Some(provider)) => {
+                    Some(runtime.spawn_agent(*workspace_id, provider, blocker_events))
+                }
+                _ => None,
+            };
             Ok(HostOperationValue::WorktreeMutation(
                 WorktreeMutationResult {
                     change,
                     forwards: approved_removal.then_some(forwards),
+                    socket_forwards: approved_removal.then_some(socket_forwards),
+                    reverse_forwards: approved_removal.then_some(reverse_forwards),
+                    removes: operation_removes,
                     session_error,
+                    agent,
                 },
             ))
         }),
     );
     let token = token?;
-    if approved_removal {
+    for removed in &removes {
         for forward in state
             .forwards
             .iter_mut()
-            .filter(|forward| forward.workspace_id == workspace_id)
+            .filter(|forward| forward.workspace_id == *removed)
+        {
+            forward.status = crate::ports::ForwardStatus::Cancelling;
+        }
+        for forward in state
+            .socket_forwards
+            .iter_mut()
+            .filter(|forward| forward.workspace_id == *removed)
+        {
+            forward.status = crate::ports::ForwardStatus::Cancelling;
+        }
+        for forward in state
+            .reverse_forwards
+            .iter_mut()
+            .filter(|forward| forward.workspace_id == *removed)
         {
             forward.status = crate::ports::ForwardStatus::Cancelling;
         }
-        state.terminals.remove(&workspace_id);
-        state.connected_clients.remove(&workspace_id);
-        if state.active_workspace == Some(workspace_id) {
+        state.terminals.remove(removed);
+        state.connected_clients.remove(removed);
+        if state.active_workspace == Some(*removed) {
             state.active_workspace = None;
             state.mode = ClientMode::Manage;
         }
@@ -1,4 +1,5 @@
-use super::approval_review;
+use super::{approval_review, prune_plan, review_sections};
+use crate::core::{WorkspaceId, WorktrunkPruneItem, WorktrunkPruneReason};
 use crate::worktrunk::WorktrunkProjectCommand;
 
 #[test]
@@ -35,3 +36,37 @@ fn approval_review_truthfully_handles_an_empty_hook_plan() {
     assert!(review.contains("unapproved project hooks\nnone"));
     assert!(review.contains(":approve"));
 }
+
+#[test]
+fn a_prune_review_names_each_branch_its_reason_and_exact_command() {
+    let item = |branch: &str, reason| WorktrunkPruneItem {
+        workspace_id: WorkspaceId::new(),
+        path: format!("/srv/app.{branch}").into(),
+        branch: branch.to_owned(),
+        reason,
+        command: format!("wt -C /srv/app remove /srv/app.{branch} --foreground"),
+    };
+    let review = review_sections(
+        prune_plan(&[
+            item("fix/login", WorktrunkPruneReason::Merged { number: 42 }),
+            item(
+                "spike",
+                WorktrunkPruneReason::UpstreamGone {
+                    upstream: "origin/spike".to_owned(),
+                },
+            ),
+        ]),
+        &[],
+        "approval binds to every removal above, in order, and the project hook plan.",
+    );
+
+    for required in [
+        "removals\nfix/login · pull request #42 merged · clean\n/srv/app.fix/login",
+        "wt -C /srv/app remove /srv/app.fix/login --foreground",
+        "spike · upstream origin/spike is gone · clean",
+        "unapproved project hooks\nnone",
+        "approval binds to every removal above",
+    ] {
+        assert!(review.contains(required), "missing {required:?}");
+    }
+}
@@ -47,8 +47,13 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
     let workspace = state.selected_workspace.or(state.active_workspace);
     let has_workspace = workspace.is_some();
     let attached = state.active_workspace.is_some();
+    let agent_runs = state.agent_runs.values().map(Vec::len).sum::<usize>();
 
-    let providers = "codex · claude · opencode".to_owned();
+    let providers = ["codex", "claude", "opencode"]
+        .into_iter()
+        .chain(state.config.providers.keys().map(|name| name.as_str()))
+        .collect::<Vec<_>>()
+        .join(" · ");
     let services = if state.config.startup.is_empty() {
         "no [[startup]] entries configured".to_owned()
     } else {
@@ -72,6 +77,7 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
         None => "no probe on this host yet".to_owned(),
     };
     let forwardable = super::completion::forward_target_count(state);
+    let sockets = super::completion::socket_target_count(state);
 
     let repository = workspace.and_then(|id| {
         state
@@ -129,6 +135,27 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
         entry(
             CommandGroup::Workspace,
             ":service start <name>",
+            services.clone(),
+            has_workspace && !state.config.startup.is_empty(),
+            "select a workspace first",
+        ),
+        entry(
+            CommandGroup::Workspace,
+            ":service stop <name>",
+            services.clone(),
+            has_workspace && !state.config.startup.is_empty(),
+            "select a workspace first",
+        ),
+        entry(
+            CommandGroup::Workspace,
+            ":service restart <name>",
+            services.clone(),
+            has_workspace && !state.config.startup.is_empty(),
+            "select a workspace first",
+        ),
+        entry(
+            CommandGroup::Workspace,
+            ":service status [name]",
             services,
             has_workspace && !state.config.startup.is_empty(),
             "select a workspace first",
@@ -158,6 +185,31 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
             active_forwards(state) > 0,
             "nothing forwarded from this client",
         ),
+        entry(
+            CommandGroup::Workspace,
+            ":forward unix:<name|path> [--local <path>]",
+            if sockets == 0 {
+                "no socket discovered yet".to_owned()
+            } else {
+                "to a client socket path".to_owned()
+            },
+            sockets > 0,
+            "no socket discovered yet",
+        ),
+        entry(
+            CommandGroup::Workspace,
+            ":reverse <local-port> [--remote <port>]",
+            "host loopback to this client".to_owned(),
+            has_workspace,
+            "select a workspace first",
+        ),
+        entry(
+            CommandGroup::Workspace,
+            ":reverse cancel <remote-port>",
+            format!("{} active on this client", state.reverse_forwards.len()),
+            !state.reverse_forwards.is_empty(),
+            "nothing reversed from this client",
+        ),
         entry(
             CommandGroup::Workspace,
             ":workspace ungroup",
@@ -179,6 +231,13 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
             repository.is_some(),
             "this workspace is not a git repository",
         ),
+        entry(
+            CommandGroup::Repository,
+            ":worktree prs",
+            "open pull requests via gh".to_owned(),
+            repository.is_some(),
+            "this workspace is not a git repository",
+        ),
         entry(
             CommandGroup::Repository,
             ":worktree create <branch> [--base <ref>]",
@@ -200,6 +259,13 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
             repository.is_some(),
             "this workspace is not a git repository",
         ),
+        entry(
+            CommandGroup::Repository,
+            ":worktree prune-merged",
+            "merged or gone · one review".to_owned(),
+            repository.is_some(),
+            "this workspace is not a git repository",
+        ),
         entry(
             CommandGroup::Repository,
             ":approve",
@@ -262,6 +328,16 @@ pub fn entries(state: &ClientState) -> Vec<CatalogEntry> {
             has_workspace,
             "select a workspace first",
         ),
+        entry(
+            CommandGroup::Hosts,
+            ":agents",
+            match agent_runs {
+                0 => "no agent runs observed yet".to_owned(),
+                count => format!("{count} run(s) across every host"),
+            },
+            agent_runs > 0,
+            "no agent runs observed yet",
+        ),
         entry(
             CommandGroup::Hosts,
             ":help",
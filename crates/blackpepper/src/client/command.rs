@@ -23,6 +23,8 @@ pub enum ClientCommand {
     WorkspaceUngroup,
     WorkspaceTerminate,
     WorktreeList,
+    /// Pick one of the repository's open pull requests to open as a worktree.
+    WorktreePullRequests,
     WorktreeCreate {
         branch: String,
         base: Option<String>,
@@ -31,28 +33,74 @@ pub enum ClientCommand {
         selector: String,
     },
     WorktreeRemove,
+    /// Remove every registered worktree whose branch merged or lost its
+    /// upstream, under one reviewed approval.
+    WorktreePruneMerged,
     AgentSpawn {
         provider: Provider,
     },
+    /// Create a worktree through the reviewed Worktrunk flow, then start the
+    /// agent in its freshly registered workspace.
+    AgentSpawnInWorktree {
+        provider: Provider,
+        branch: String,
+        base: Option<String>,
+    },
+    /// Type one line into an idle or done agent's pane, then press Enter.
+    AgentSend {
+        text: String,
+    },
     ServiceStart {
         name: String,
     },
+    ServiceStop {
+        name: String,
+    },
+    /// Close the service's tab and launch it again.
+    ServiceRestart {
+        name: String,
+    },
+    /// List every configured service, or just `name`, with its pane state.
+    ServiceStatus {
+        name: Option<String>,
+    },
     Ports {
         all_host: bool,
     },
     Forward {
         remote_port: u16,
         bind_address: Option<String>,
+        /// Client loopback port to listen on instead of the remote port.
+        local_port: Option<u16>,
     },
     ForwardCancel {
         remote_port: u16,
         bind_address: Option<String>,
     },
+    /// Forward a discovered Unix socket, chosen by full path or file name.
+    ForwardSocket {
+        selector: String,
+        /// Client socket path; one under the runtime directory when omitted.
+        local_path: Option<PathBuf>,
+    },
+    ForwardSocketCancel {
+        selector: String,
+    },
+    /// Carry a host-loopback port back to a client service.
+    Reverse {
+        local_port: u16,
+        /// Host port to listen on; the client port when omitted.
+        remote_port: Option<u16>,
+    },
+    ReverseCancel {
+        remote_port: u16,
+    },
     /// `None` lists the palettes; `Some` switches to one.
     Theme {
         name: Option<String>,
     },
     StatusExplain,
+    Agents,
     Approve,
     Refresh,
     Help,
@@ -64,6 +112,13 @@ pub fn parse(input: &str) -> Result<ClientCommand, String> {
         .trim()
         .strip_prefix(':')
         .ok_or_else(|| "Blackpepper commands begin with ':'.".to_string())?;
+    // A prompt is taken verbatim: its quotes and apostrophes belong to the
+    // agent, not to command quoting.
+    if let Some(text) = input.strip_prefix("agent send ") {
+        return Ok(ClientCommand::AgentSend {
+            text: validate_agent_text(text)?,
+        });
+    }
     let words =
         shell_words::split(input).map_err(|err| format!("Invalid command quoting: {err}"))?;
     let values = words.iter().map(String::as_str).collect::<Vec<_>>();
@@ -88,6 +143,7 @@ pub fn parse(input: &str) -> Result<ClientCommand, String> {
         ["workspace", "ungroup"] => Ok(ClientCommand::WorkspaceUngroup),
         ["workspace", "terminate"] => Ok(ClientCommand::WorkspaceTerminate),
         ["worktree", "list"] => Ok(ClientCommand::WorktreeList),
+        ["worktree", "prs"] => Ok(ClientCommand::WorktreePullRequests),
         ["worktree", "create", branch] => Ok(ClientCommand::WorktreeCreate {
             branch: selector(branch)?,
             base: None,
@@ -100,21 +156,76 @@ pub fn parse(input: &str) -> Result<ClientCommand, String> {
             selector: selector(target)?,
         }),
         ["worktree", "remove"] => Ok(ClientCommand::WorktreeRemove),
+        ["worktree", "prune-merged"] => Ok(ClientCommand::WorktreePruneMerged),
         ["agent", "spawn", provider] => Ok(ClientCommand::AgentSpawn {
             provider: provider
                 .parse::<Provider>()
                 .map_err(|err| err.to_string())?,
         }),
+        ["agent", "spawn", provider, "--worktree", branch] => {
+            Ok(ClientCommand::AgentSpawnInWorktree {
+                provider: provider
+                    .parse::<Provider>()
+                    .map_err(|err| err.to_string())?,
+                branch: selector(branch)?,
+                base: None,
+            })
+        }
+        ["agent", "spawn", provider, "--worktree", branch, "--base", base] => {
+            Ok(ClientCommand::AgentSpawnInWorktree {
+                provider: provider
+                    .parse::<Provider>()
+                    .map_err(|err| err.to_string())?,
+                branch: selector(branch)?,
+                base: Some(selector(base)?),
+            })
+        }
         ["service", "start", name] => Ok(ClientCommand::ServiceStart {
             name: validate_service_name(name)?,
         }),
+        ["service", "stop", name] => Ok(ClientCommand::ServiceStop {
+            name: validate_service_name(name)?,
+        }),
+        ["service", "restart", name] => Ok(ClientCommand::ServiceRestart {
+            name: validate_service_name(name)?,
+        }),
+        ["service", "status"] => Ok(ClientCommand::ServiceStatus { name: None }),
+        ["service", "status", name] => Ok(ClientCommand::ServiceStatus {
+            name: Some(validate_service_name(name)?),
+        }),
         ["ports"] => Ok(ClientCommand::Ports { all_host: false }),
         ["ports", "--all-host"] => Ok(ClientCommand::Ports { all_host: true }),
+        ["forward", "cancel", selector] if selector.starts_with("unix:") => {
+            Ok(ClientCommand::ForwardSocketCancel {
+                selector: parse_socket_selector(selector)?,
+            })
+        }
+        ["forward", selector] if selector.starts_with("unix:") => {
+            Ok(ClientCommand::ForwardSocket {
+                selector: parse_socket_selector(selector)?,
+                local_path: None,
+            })
+        }
+        ["forward", selector, "--local", local] if selector.starts_with("unix:") => {
+            Ok(ClientCommand::ForwardSocket {
+                selector: parse_socket_selector(selector)?,
+                local_path: Some(parse_socket_path(local)?),
+            })
+        }
         ["forward", selector] => {
             let (bind_address, remote_port) = parse_forward_selector(selector)?;
             Ok(ClientCommand::Forward {
                 remote_port,
                 bind_address,
+                local_port: None,
+            })
+        }
+        ["forward", selector, "--local", local] => {
+            let (bind_address, remote_port) = parse_forward_selector(selector)?;
+            Ok(ClientCommand::Forward {
+                remote_port,
+                bind_address,
+                local_port: Some(parse_local_port(local)?),
             })
         }
         ["forward", "cancel", selector] => {
@@ -124,11 +235,23 @@ pub fn parse(input: &str) -> Result<ClientCommand, String> {
                 bind_address,
             })
         }
+        ["reverse", "cancel", remote] => Ok(ClientCommand::ReverseCancel {
+            remote_port: parse_port(remote)?,
+        }),
+        ["reverse", local] => Ok(ClientCommand::Reverse {
+            local_port: parse_port(local)?,
+            remote_port: None,
+        }),
+        ["reverse", local, "--remote", remote] => Ok(ClientCommand::Reverse {
+            local_port: parse_port(local)?,
+            remote_port: Some(parse_port(remote)?),
+        }),
         ["theme"] => Ok(ClientCommand::Theme { name: None }),
         ["theme", name] => Ok(ClientCommand::Theme {
             name: Some(name.to_string()),
         }),
         ["status", "explain"] => Ok(ClientCommand::StatusExplain),
+        ["agents"] => Ok(ClientCommand::Agents),
         ["approve"] => Ok(ClientCommand::Approve),
         ["refresh"] => Ok(ClientCommand::Refresh),
         ["help"] => Ok(ClientCommand::Help),
@@ -145,12 +268,15 @@ fn usage_error(input: &str, values: &[&str]) -> String {
         }
         Some("workspace") => ":workspace add <path> | switch <name|id> | ungroup | terminate",
         Some("worktree") => {
-            ":worktree list | create <branch> [--base <ref>] | open <branch|pr:123|url> | remove"
+            ":worktree list | prs | create <branch> [--base <ref>] | open <branch|pr:123|url> | remove | prune-merged"
         }
-        Some("agent") => ":agent spawn <codex|claude|opencode>",
-        Some("service") => ":service start <name>",
+        Some("agent") => ":agent spawn <codex|claude|opencode|name> | send <text>",
+        Some("service") => ":service start|stop|restart <name> | status [name]",
         Some("ports") => ":ports [--all-host]",
-        Some("forward") => ":forward [cancel] <port|address:port>",
+        Some("forward") => {
+            ":forward <port|address:port|unix:socket> [--local <port|path>] | cancel <port|address:port|unix:socket>"
+        }
+        Some("reverse") => ":reverse <local-port> [--remote <port>] | cancel <remote-port>",
         Some("status") => ":status explain",
         _ => return format!("Unknown command: :{input}. Type :help for the full list."),
     };
@@ -175,7 +301,8 @@ pub const HELP: &[(&str, &str)] = &[
         ":workspace terminate",
         "Terminate its Zellij session, keep its folder",
     ),
-    (":worktree list", "List Worktrunk branches/worktrees"),
+    (":worktree list", "Browse Worktrunk branches/worktrees"),
+    (":worktree prs", "Pick an open pull request to open"),
     (
         ":worktree create <branch> [--base <ref>]",
         "Create and register a worktree",
@@ -189,21 +316,55 @@ pub const HELP: &[(&str, &str)] = &[
         "Remove through Worktrunk without force flags",
     ),
     (
-        ":agent spawn <codex|claude|opencode>",
-        "Start an integrated agent tab",
+        ":worktree prune-merged",
+        "Remove clean worktrees of merged or gone branches",
+    ),
+    (
+        ":agent spawn <codex|claude|opencode|name>",
+        "Start an integrated or [providers.<name>] agent tab",
+    ),
+    (
+        ":agent spawn <provider> --worktree <branch> [--base <ref>]",
+        "Create a worktree, then start the agent in it",
+    ),
+    (
+        ":agent send <text>",
+        "Type a line into an idle or done agent and press Enter",
     ),
     (":service start <name>", "Start a configured service tab"),
+    (":service stop <name>", "Close a configured service's tab"),
+    (
+        ":service restart <name>",
+        "Close a configured service's tab and launch it again",
+    ),
+    (
+        ":service status [name]",
+        "Show whether configured services are running",
+    ),
     (":ports [--all-host]", "Discover listening ports"),
     (
-        ":forward <port|address:port>",
+        ":forward <port|address:port> [--local <port>]",
         "Forward one discovered listener to client loopback",
     ),
     (
         ":forward cancel <port|address:port>",
         "Cancel this client's exact forward",
     ),
+    (
+        ":forward unix:<name|path> [--local <path>]",
+        "Forward one discovered Unix socket to a client path",
+    ),
+    (
+        ":reverse <local-port> [--remote <port>]",
+        "Reach a client port from host loopback",
+    ),
+    (
+        ":reverse cancel <remote-port>",
+        "Cancel this client's reverse forward",
+    ),
     (":theme [<name>]", "List palettes, or switch to one"),
     (":status explain", "Show redacted status evidence"),
+    (":agents", "List agent runs across every host"),
     (":approve", "Approve the displayed Worktrunk command"),
     (":refresh", "Refresh hosts, workspaces, agents, and ports"),
     (":help", "Show this command reference"),
@@ -241,6 +402,45 @@ fn parse_forward_selector(value: &str) -> Result<(Option<String>, u16), String>
     Ok((Some(target.remote_host), port))
 }
 
+/// `unix:` names a discovered socket by its file name or its absolute path.
+fn parse_socket_selector(value: &str) -> Result<String, String> {
+    let selector = value.strip_prefix("unix:").unwrap_or(value);
+    if selector.is_empty() || selector.contains(':') {
+        return Err(format!(
+            "Name a discovered socket as unix:<name> or unix:/absolute/path, not {value}."
+        ));
+    }
+    if selector.contains('/') {
+        return parse_socket_path(selector).map(|path| path.display().to_string());
+    }
+    Ok(selector.to_owned())
+}
+
+/// OpenSSH cannot quote ':' in a socket forward, and a relative path would
+/// depend on where the client happened to start.
+fn parse_socket_path(value: &str) -> Result<PathBuf, String> {
+    if !value.starts_with('/') || value.contains(':') || value.chars().any(char::is_whitespace) {
+        return Err(format!(
+            "Socket paths must be absolute and contain no ':' or spaces, not {value}."
+        ));
+    }
+    Ok(PathBuf::from(value))
+}
+
+/// Forwards only ever listen on client loopback, so `--local` takes a port or
+/// spells that address out; anything else would be a promise not kept.
+fn parse_local_port(value: &str) -> Result<u16, String> {
+    if let Ok(port) = parse_port(value) {
+        return Ok(port);
+    }
+    match value.rsplit_once(':') {
+        Some(("127.0.0.1" | "localhost", port)) => parse_port(port),
+        _ => Err(format!(
+            "Forwards listen only on client loopback; use --local <port> or --local 127.0.0.1:<port>, not {value}."
+        )),
+    }
+}
+
 fn validate_name(value: &str) -> Result<String, String> {
     if value.is_empty()
         || !value
@@ -252,6 +452,14 @@ fn validate_name(value: &str) -> Result<String, String> {
     Ok(value.to_string())
 }
 
+fn validate_agent_text(value: &str) -> Result<String, String> {
+    let text = value.trim();
+    if text.is_empty() || text.len() > 4096 || text.chars().any(char::is_control) {
+        return Err("Agent input must be one line of 1-4096 bytes.".to_string());
+    }
+    Ok(text.to_string())
+}
+
 fn validate_service_name(value: &str) -> Result<String, String> {
     if value.trim().is_empty() || value.len() > 48 || value.chars().any(char::is_control) {
         return Err("Service names must contain 1-48 printable characters.".to_string());
@@ -299,9 +507,28 @@ mod tests {
         assert!(parse(":pr create").is_err());
         assert!(parse(":pr merge").is_err());
         assert!(parse(":worktree remove --force").is_err());
+        assert!(parse(":worktree prune-merged --force").is_err());
         assert!(parse(":workspace rename new-name").is_err());
     }
 
+    #[test]
+    fn agent_spawn_takes_an_optional_worktree_and_base() {
+        assert_eq!(
+            parse(":agent spawn claude --worktree feat/auth --base main").unwrap(),
+            ClientCommand::AgentSpawnInWorktree {
+                provider: Provider::Claude,
+                branch: "feat/auth".to_owned(),
+                base: Some("main".to_owned()),
+            }
+        );
+        assert!(matches!(
+            parse(":agent spawn codex --worktree fix").unwrap(),
+            ClientCommand::AgentSpawnInWorktree { base: None, .. }
+        ));
+        assert!(parse(":agent spawn codex --worktree").is_err());
+        assert!(parse(":agent spawn codex --base main").is_err());
+    }
+
     #[test]
     fn parses_worktrunk_pr_url_as_one_argv() {
         let command = parse(":worktree open https://github.com/acme/app/pull/7").unwrap();
@@ -321,6 +548,47 @@ mod tests {
         assert!(parse(":service start 'line\nbreak'").is_err());
     }
 
+    #[test]
+    fn agent_send_takes_the_rest_of_the_line_verbatim() {
+        assert_eq!(
+            parse(":agent send don't stop, add \"tests\"  too ").unwrap(),
+            ClientCommand::AgentSend {
+                text: "don't stop, add \"tests\"  too".to_string(),
+            }
+        );
+        assert!(parse(":agent send").is_err());
+        assert!(parse(":agent send    ").is_err());
+        assert!(parse(":agent send first\u{1b}[Asecond").is_err());
+        assert!(parse(&format!(":agent send {}", "x".repeat(4097))).is_err());
+    }
+
+    #[test]
+    fn services_stop_restart_and_report_by_name() {
+        assert_eq!(
+            parse(":service stop web").unwrap(),
+            ClientCommand::ServiceStop {
+                name: "web".to_string(),
+            }
+        );
+        assert_eq!(
+            parse(":service restart 'api worker'").unwrap(),
+            ClientCommand::ServiceRestart {
+                name: "api worker".to_string(),
+            }
+        );
+        assert_eq!(
+            parse(":service status").unwrap(),
+            ClientCommand::ServiceStatus { name: None }
+        );
+        assert_eq!(
+            parse(":service status web").unwrap(),
+            ClientCommand::ServiceStatus {
+                name: Some("web".to_string()),
+            }
+        );
+        assert!(parse(":service stop").is_err());
+    }
+
     #[test]
     fn forward_selectors_support_exact_ipv4_and_ipv6_targets() {
         assert_eq!(
@@ -328,6 +596,7 @@ mod tests {
             ClientCommand::Forward {
                 remote_port: 3000,
                 bind_address: None,
+                local_port: None,
             }
         );
         assert_eq!(
@@ -335,6 +604,7 @@ mod tests {
             ClientCommand::Forward {
                 remote_port: 3000,
                 bind_address: Some("192.0.2.10".to_string()),
+                local_port: None,
             }
         );
         assert_eq!(
@@ -347,6 +617,86 @@ mod tests {
         assert!(parse(":forward ::1:3000").unwrap_err().contains("IPv6"));
     }
 
+    #[test]
+    fn forward_local_ports_stay_on_client_loopback() {
+        assert_eq!(
+            parse(":forward 3000 --local 13000").unwrap(),
+            ClientCommand::Forward {
+                remote_port: 3000,
+                bind_address: None,
+                local_port: Some(13000),
+            }
+        );
+        assert_eq!(
+            parse(":forward 10.0.0.5:3000 --local 127.0.0.1:13000").unwrap(),
+            ClientCommand::Forward {
+                remote_port: 3000,
+                bind_address: Some("10.0.0.5".to_string()),
+                local_port: Some(13000),
+            }
+        );
+        assert!(parse(":forward 3000 --local 0.0.0.0:13000")
+            .unwrap_err()
+            .contains("loopback"));
+        assert!(parse(":forward 3000 --local [::1]:13000")
+            .unwrap_err()
+            .contains("loopback"));
+        assert!(parse(":forward 3000 --local 0").is_err());
+    }
+
+    #[test]
+    fn reverse_forwards_name_client_and_host_ports() {
+        assert_eq!(
+            parse(":reverse 5432").unwrap(),
+            ClientCommand::Reverse {
+                local_port: 5432,
+                remote_port: None,
+            }
+        );
+        assert_eq!(
+            parse(":reverse 5432 --remote 15432").unwrap(),
+            ClientCommand::Reverse {
+                local_port: 5432,
+                remote_port: Some(15432),
+            }
+        );
+        assert_eq!(
+            parse(":reverse cancel 15432").unwrap(),
+            ClientCommand::ReverseCancel { remote_port: 15432 }
+        );
+        // The host side is always loopback; there is no address to choose.
+        assert!(parse(":reverse 5432 --remote 0.0.0.0:15432").is_err());
+        assert!(parse(":reverse 0").is_err());
+    }
+
+    #[test]
+    fn socket_forwards_use_the_unix_prefix_and_absolute_paths() {
+        assert_eq!(
+            parse(":forward unix:.s.PGSQL.5432").unwrap(),
+            ClientCommand::ForwardSocket {
+                selector: ".s.PGSQL.5432".to_owned(),
+                local_path: None,
+            }
+        );
+        assert_eq!(
+            parse(":forward unix:/var/run/docker.sock --local /tmp/devbox-docker.sock").unwrap(),
+            ClientCommand::ForwardSocket {
+                selector: "/var/run/docker.sock".to_owned(),
+                local_path: Some(PathBuf::from("/tmp/devbox-docker.sock")),
+            }
+        );
+        assert_eq!(
+            parse(":forward cancel unix:docker.sock").unwrap(),
+            ClientCommand::ForwardSocketCancel {
+                selector: "docker.sock".to_owned(),
+            }
+        );
+        assert!(parse(":forward unix:").is_err());
+        assert!(parse(":forward unix:run/docker.sock").is_err());
+        assert!(parse(":forward unix:docker.sock --local docker.sock").is_err());
+        assert!(parse(":forward unix:docker.sock --local /tmp/a:b").is_err());
+    }
+
     #[test]
     fn command_reference_includes_every_zero_argument_command() {
         let commands = HELP.iter().map(|(command, _)| *command).collect::<Vec<_>>();
@@ -354,7 +704,10 @@ mod tests {
             ":host import",
             ":workspace ungroup",
             ":worktree list",
+            ":worktree prs",
+            ":worktree prune-merged",
             ":status explain",
+            ":agents",
             ":approve",
             ":refresh",
             ":help",
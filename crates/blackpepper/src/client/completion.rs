@@ -10,9 +10,10 @@ mod values;
 
 use super::ClientState;
 use paths::command_paths;
+pub(super) use values::quote;
 use values::{
-    forward_cancels, forward_targets, hosts, prefixed, prefixed_workspaces, providers, quote,
-    services, themes, workspaces,
+    forward_cancels, forward_targets, hosts, prefixed, prefixed_workspaces, providers, services,
+    themes, workspaces,
 };
 
 pub const MAX_VISIBLE_CANDIDATES: usize = 8;
@@ -64,19 +65,33 @@ pub fn ghost(input: &str) -> Option<String> {
             ["workspace"] => "<add|switch|ungroup|terminate>",
             ["workspace", "add"] => "<path>",
             ["workspace", "switch"] => "<name|id>",
-            ["worktree"] => "<list|create|open|remove>",
+            ["worktree"] => "<list|prs|create|open|remove|prune-merged>",
             ["worktree", "create"] => "<branch>",
             ["worktree", "create", _] => "[--base <ref>] · Enter runs",
             ["worktree", "create", _, "--base"] => "<ref>",
             ["worktree", "open"] => "<branch|pr:123|url>",
-            ["agent"] => "<spawn>",
-            ["agent", "spawn"] => "<codex|claude|opencode>",
-            ["service"] => "<start>",
-            ["service", "start"] => "<name>",
+            ["agent"] => "<spawn|send>",
+            ["agent", "send"] => "<text> · Enter sends",
+            ["agent", "spawn"] => "<codex|claude|opencode|name>",
+            ["agent", "spawn", _] => "[--worktree <branch>] · Enter runs",
+            ["agent", "spawn", _, "--worktree"] => "<branch>",
+            ["agent", "spawn", _, "--worktree", _] => "[--base <ref>] · Enter runs",
+            ["agent", "spawn", _, "--worktree", _, "--base"] => "<ref>",
+            ["service"] => "<start|stop|restart|status>",
+            ["service", "start" | "stop" | "restart"] => "<name>",
+            ["service", "status"] => "[name] · Enter runs",
             ["theme"] => "<name>",
             ["ports"] => "[--all-host] · Enter runs",
             ["forward"] => "<port|address:port>",
             ["forward", "cancel"] => "<port|address:port>",
+            ["forward", selector] if selector.starts_with("unix:") => {
+                "[--local <path>] · Enter runs"
+            }
+            ["forward", selector, "--local"] if selector.starts_with("unix:") => "<path>",
+            ["reverse"] => "<local-port>",
+            ["reverse", "cancel"] => "<remote-port>",
+            ["reverse", _] => "[--remote <port>] · Enter runs",
+            ["reverse", _, "--remote"] => "<port>",
             ["status"] => "<explain>",
             _ => return None,
         }
@@ -101,12 +116,16 @@ pub fn candidates(state: &ClientState, input: &str) -> Vec<Candidate> {
         (["forward", "cancel", partial], false) => {
             Some(prefixed(forward_cancels(state), "forward cancel", partial))
         }
-        (["agent", "spawn"], true) => Some(providers()),
-        (["agent", "spawn", partial], false) => Some(prefixed(providers(), "agent spawn", partial)),
-        (["service", "start"], true) => Some(services(state)),
-        (["service", "start", partial], false) => {
-            Some(prefixed(services(state), "service start", partial))
+        (["agent", "spawn"], true) => Some(providers(state)),
+        (["agent", "spawn", partial], false) => {
+            Some(prefixed(providers(state), "agent spawn", partial))
+        }
+        (["service", verb @ ("start" | "stop" | "restart" | "status")], true) => {
+            Some(services(state, verb))
         }
+        (["service", verb @ ("start" | "stop" | "restart" | "status"), partial], false) => Some(
+            prefixed(services(state, verb), &format!("service {verb}"), partial),
+        ),
         (["host", verb @ ("connect" | "disconnect")], true) => Some(hosts(state, verb)),
         (["host", verb @ ("connect" | "disconnect"), partial], false) => Some(prefixed(
             hosts(state, verb),
@@ -123,6 +142,14 @@ pub fn candidates(state: &ClientState, input: &str) -> Vec<Candidate> {
             "ports --all-host",
             "include every listener on this host",
         )]),
+        (["agent", "spawn", provider], true) => Some(vec![Candidate::path(
+            format!("agent spawn {provider} --worktree"),
+            "in a new worktree",
+        )]),
+        (["agent", "spawn", provider, "--worktree", branch], true) => Some(vec![Candidate::path(
+            format!("agent spawn {provider} --worktree {} --base", quote(branch)),
+            "optional base ref",
+        )]),
         (["worktree", "create", branch], true) => Some(vec![Candidate::path(
             format!("worktree create {} --base", quote(branch)),
             "optional base ref",
@@ -141,8 +168,13 @@ pub fn candidates(state: &ClientState, input: &str) -> Vec<Candidate> {
 pub fn constraint(input: &str) -> Option<&'static str> {
     let words = input.split_whitespace().collect::<Vec<_>>();
     Some(match words.as_slice() {
+        ["forward", selector, ..] if selector.starts_with("unix:") => {
+            "client socket stays private to you"
+        }
         ["forward", ..] => "binds to client loopback only",
-        ["worktree", "create" | "remove", ..] => "previews first, then :approve",
+        ["reverse", ..] => "binds to host loopback only",
+        ["worktree", "create" | "remove" | "prune-merged", ..] => "previews first, then :approve",
+        ["agent", "spawn", _, "--worktree", ..] => "previews first, then :approve",
         ["workspace", "terminate", ..] => "keeps the folder",
         ["host", "add", ..] => "openssh alias, literal",
         _ => return None,
@@ -150,7 +182,17 @@ pub fn constraint(input: &str) -> Option<&'static str> {
 }
 
 pub(in crate::client) fn forward_target_count(state: &ClientState) -> usize {
-    forward_targets(state).len()
+    forward_targets(state)
+        .iter()
+        .filter(|candidate| !candidate.value.starts_with("forward unix:"))
+        .count()
+}
+
+pub(in crate::client) fn socket_target_count(state: &ClientState) -> usize {
+    forward_targets(state)
+        .iter()
+        .filter(|candidate| candidate.value.starts_with("forward unix:"))
+        .count()
 }
 
 /// Convert catalog syntax into the safe command text inserted by a click.
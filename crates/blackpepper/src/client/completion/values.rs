@@ -32,6 +32,41 @@ pub(super) fn forward_targets(state: &ClientState) -> Vec<Candidate> {
                 )
         })
         .collect::<Vec<_>>();
+    let sockets = snapshot
+        .sockets
+        .iter()
+        .filter(|socket| {
+            state.show_all_host_ports
+                || crate::client::runtime::ports::listener_matches_workspace(
+                    socket.workspace_path.as_deref(),
+                    &workspace.root_path,
+                )
+        })
+        .collect::<Vec<_>>();
+    // One socket held by several processes is still one target.
+    let mut seen = std::collections::BTreeSet::new();
+    let socket_candidates = sockets
+        .iter()
+        .filter(move |socket| seen.insert(&socket.path))
+        .map(|socket| {
+            // The file name is shorter, but only when it names one socket.
+            let same_name = sockets
+                .iter()
+                .filter(|other| other.name() == socket.name() && other.path != socket.path)
+                .count();
+            let selector = if same_name == 0 {
+                socket.name()
+            } else {
+                socket.path.as_str()
+            };
+            Candidate::complete(
+                format!("forward unix:{selector}"),
+                format!(
+                    "unix socket · {}",
+                    socket.process.as_deref().unwrap_or("unknown")
+                ),
+            )
+        });
     listeners
         .iter()
         .filter_map(|listener| {
@@ -56,12 +91,20 @@ pub(super) fn forward_targets(state: &ClientState) -> Vec<Candidate> {
             };
             Some(Candidate::complete(
                 format!("forward {selector}"),
-                format!(
-                    "discovered · {}",
-                    listener.process.as_deref().unwrap_or("unknown")
-                ),
+                match &listener.http {
+                    Some(http) => format!(
+                        "{} · {}",
+                        http.label(),
+                        listener.process.as_deref().unwrap_or("unknown")
+                    ),
+                    None => format!(
+                        "discovered · {}",
+                        listener.process.as_deref().unwrap_or("unknown")
+                    ),
+                },
             ))
         })
+        .chain(socket_candidates)
         .collect()
 }
 
@@ -103,15 +146,38 @@ pub(super) fn forward_cancels(state: &ClientState) -> Vec<Candidate> {
                 "active in this workspace",
             ))
         })
+        .chain(
+            state
+                .socket_forwards
+                .iter()
+                .filter(|forward| {
+                    Some(forward.workspace_id) == workspace
+                        && matches!(
+                            forward.status,
+                            ForwardStatus::Active | ForwardStatus::Direct
+                        )
+                })
+                .map(|forward| {
+                    Candidate::complete(
+                        format!("forward cancel unix:{}", forward.remote_path),
+                        "active in this workspace",
+                    )
+                }),
+        )
         .collect()
 }
 
-pub(super) fn providers() -> Vec<Candidate> {
+pub(super) fn providers(state: &ClientState) -> Vec<Candidate> {
     ["codex", "claude", "opencode"]
         .into_iter()
         .map(|provider| {
             Candidate::complete(format!("agent spawn {provider}"), "integrated provider")
         })
+        .chain(
+            state.config.providers.keys().map(|name| {
+                Candidate::complete(format!("agent spawn {name}"), "configured provider")
+            }),
+        )
         .collect()
 }
 
@@ -132,14 +198,14 @@ pub(super) fn themes(state: &ClientState) -> Vec<Candidate> {
         .collect()
 }
 
-pub(super) fn services(state: &ClientState) -> Vec<Candidate> {
+pub(super) fn services(state: &ClientState, verb: &str) -> Vec<Candidate> {
     state
         .config
         .startup
         .iter()
         .map(|entry| {
             Candidate::complete(
-                format!("service start {}", quote(&entry.name)),
+                format!("service {verb} {}", quote(&entry.name)),
                 if entry.auto_start {
                     "configured · auto-start"
                 } else {
@@ -247,7 +313,7 @@ pub(super) fn prefixed_workspaces(candidates: Vec<Candidate>, partial: &str) ->
         .collect()
 }
 
-pub(super) fn quote(value: &str) -> String {
+pub(in crate::client) fn quote(value: &str) -> String {
     if !value.is_empty()
         && value
             .chars()
@@ -154,12 +154,15 @@ fn apply_blocker_transition(
             rule_id,
             confidence,
             priority,
+            answers,
         } => Some(crate::agent_status::BlockerExplain {
             provider: transition.provider,
+            manifest: transition.manifest,
             manifest_version: transition.manifest_version,
             rule_id,
             confidence,
             priority,
+            answers,
         }),
         crate::status_monitor::BlockerChange::Cleared => None,
     };
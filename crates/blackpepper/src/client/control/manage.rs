@@ -6,7 +6,10 @@ use super::ClientRuntime;
 use crate::keymap::matches_chord;
 use termwiz::input::{KeyCode, KeyEvent, Modifiers};
 
-use modal::{handle_command_input, handle_help, handle_picker, open_command};
+use modal::{
+    handle_agents, handle_command_input, handle_help, handle_picker, handle_pull_requests,
+    handle_worktrees, open_command,
+};
 
 pub(super) use mouse::handle as handle_mouse;
 
@@ -14,8 +17,14 @@ pub(super) fn handle_key(state: &mut ClientState, runtime: &mut ClientRuntime, k
     let modifiers = key.modifiers.remove_positional_mods();
     // The picker and help both capture every key while open, so a filter
     // keystroke can never leak through to workspace navigation.
+    // A reply typed over the agents dashboard owns the keyboard until it is
+    // sent or dismissed.
     if handle_picker(state, runtime, &key, modifiers)
         || handle_help(state, &key, modifiers)
+        || (state.agents.is_some() && handle_command_input(state, runtime, &key, modifiers))
+        || handle_agents(state, runtime, &key, modifiers)
+        || handle_worktrees(state, runtime, &key, modifiers)
+        || handle_pull_requests(state, runtime, &key, modifiers)
         || handle_command_input(state, runtime, &key, modifiers)
         || handle_scrollable(state, &key, modifiers)
         || cancel_operation(state, runtime, &key, modifiers)
@@ -40,6 +49,7 @@ pub(super) fn handle_key(state: &mut ClientState, runtime: &mut ClientRuntime, k
         KeyCode::UpArrow => state.select_next(-1),
         KeyCode::DownArrow => state.select_next(1),
         KeyCode::Enter => attach_selected(state, runtime),
+        KeyCode::Char('g') if modifiers == Modifiers::NONE => go_to_status_agent(state, runtime),
         KeyCode::Escape if state.active_workspace.is_some() => {
             let workspace_id = state.active_workspace.expect("checked active workspace");
             state.mark_workspace_completions_seen(workspace_id);
@@ -49,6 +59,24 @@ pub(super) fn handle_key(state: &mut ClientState, runtime: &mut ClientRuntime, k
     }
 }
 
+/// Jump to the run behind the selected workspace's status, attaching first
+/// when the workspace is detached.
+fn go_to_status_agent(state: &mut ClientState, runtime: &mut ClientRuntime) {
+    let Some(workspace_id) = state.selected_workspace else {
+        state.set_output("Select a workspace first.");
+        return;
+    };
+    let Some(run_id) = state.status_agent(workspace_id) else {
+        state.set_output(
+            "No agent is reporting a status in this workspace; :agents lists every run.",
+        );
+        return;
+    };
+    if let Err(error) = actions::go_to_agent(state, runtime, workspace_id, run_id) {
+        state.set_output(error);
+    }
+}
+
 fn handle_scrollable(state: &mut ClientState, key: &KeyEvent, modifiers: Modifiers) -> bool {
     if modifiers != Modifiers::NONE {
         return false;
@@ -144,12 +172,16 @@ pub(in crate::client) fn attach_selected(state: &mut ClientState, runtime: &mut
         crate::client::runtime::HostOperationContext::Attach { workspace_id },
         state.event_tx.clone(),
         Box::new(move |runtime| {
+            // Read before attaching: once the PTY exists, its reader must
+            // start without waiting on more host round trips.
+            let declared_forwards = runtime.declared_forwards(workspace_id);
             runtime.attach_workspace(workspace_id, rows, cols).map(
                 |(process, provisional_clients)| {
                     crate::client::runtime::HostOperationValue::Attached {
                         workspace_id,
                         process,
                         provisional_clients,
+                        declared_forwards,
                     }
                 },
             )
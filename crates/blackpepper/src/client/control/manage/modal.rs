@@ -1,8 +1,9 @@
 //! Key handling for the surfaces that capture input while they are open.
 //!
-//! The picker, grouped help, and the command bar each swallow every key they
-//! see. Keeping them here means `handle_key` reads as a short list of who gets
-//! first refusal, rather than as one long match.
+//! The pickers, grouped help, the agents dashboard, the worktree browser, and
+//! the command bar each swallow every key they see. Keeping them here means
+//! `handle_key` reads as a short list of who gets first refusal, rather than
+//! as one long match.
 
 use super::attach_selected;
 use crate::client::runtime::ClientRuntime;
@@ -75,6 +76,148 @@ pub(super) fn handle_help(state: &mut ClientState, key: &KeyEvent, modifiers: Mo
     true
 }
 
+/// The agents dashboard: arrows pick a run, enter opens its tab, `r` opens a
+/// `:agent send` reply addressed to it, `1`-`4` send an asking run one of the
+/// answers its rule declares, and `s` flips between asks-first and
+/// most-recent order.
+pub(super) fn handle_agents(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    key: &KeyEvent,
+    modifiers: Modifiers,
+) -> bool {
+    let Some(view) = state.agents else {
+        return false;
+    };
+    if modifiers != Modifiers::NONE && modifiers != Modifiers::SHIFT {
+        return false;
+    }
+    match key.key {
+        KeyCode::Escape | KeyCode::Char('q') => state.agents = None,
+        KeyCode::UpArrow => state.move_agents(-1),
+        KeyCode::DownArrow => state.move_agents(1),
+        KeyCode::PageUp => state.move_agents(-10),
+        KeyCode::PageDown => state.move_agents(10),
+        KeyCode::Char('s') => {
+            // The cursor follows its run across the reorder rather than
+            // staying on a row index that now names a different agent.
+            let choice = state.agents_choice();
+            state.agents = Some(crate::client::state::AgentsView {
+                selected: 0,
+                order: view.order.toggled(),
+            });
+            let rows = state.agent_rows();
+            if let Some(agents) = state.agents.as_mut() {
+                agents.selected = choice
+                    .and_then(|(_, run_id)| rows.iter().position(|row| row.run_id == run_id))
+                    .unwrap_or(0);
+            }
+        }
+        KeyCode::Enter => {
+            if let Some((workspace_id, run_id)) = state.agents_choice() {
+                if let Err(error) = actions::go_to_agent(state, runtime, workspace_id, run_id) {
+                    state.set_output(error);
+                }
+            }
+        }
+        // The dashboard stays open under the prompt, so the next row is one
+        // arrow away once this reply is sent.
+        KeyCode::Char('r') => {
+            if let Some(choice) = state.agents_choice() {
+                prefill_command(state, ":agent send ");
+                state.agent_reply = Some(choice);
+            }
+        }
+        KeyCode::Char(digit @ '1'..='4') => {
+            if let Some((workspace_id, run_id)) = state.agents_choice() {
+                let index = usize::from(digit as u8 - b'1');
+                if let Err(error) =
+                    actions::answer_agent(state, runtime, workspace_id, run_id, index)
+                {
+                    state.set_output(error);
+                }
+            }
+        }
+        _ => {}
+    }
+    true
+}
+
+/// The worktree browser: arrows pick a row, enter opens it and `d` previews
+/// its removal, both through the Worktrunk approval flow.
+pub(super) fn handle_worktrees(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    key: &KeyEvent,
+    modifiers: Modifiers,
+) -> bool {
+    let Some(view) = state.worktrees.as_mut() else {
+        return false;
+    };
+    if modifiers != Modifiers::NONE && modifiers != Modifiers::SHIFT {
+        return false;
+    }
+    match key.key {
+        KeyCode::Escape | KeyCode::Char('q') => {
+            state.worktrees = None;
+            return true;
+        }
+        KeyCode::UpArrow => view.move_selection(-1),
+        KeyCode::DownArrow => view.move_selection(1),
+        KeyCode::PageUp => view.move_selection(-10),
+        KeyCode::PageDown => view.move_selection(10),
+        KeyCode::Enter => {
+            if let Err(error) = actions::open_selected_worktree(state, runtime) {
+                state.set_output(error);
+            }
+        }
+        KeyCode::Char('d') => {
+            if let Err(error) = actions::remove_selected_worktree(state, runtime) {
+                state.set_output(error);
+            }
+        }
+        _ => {}
+    }
+    true
+}
+
+/// The pull request picker: type to filter, enter previews opening the chosen
+/// pull request as a worktree.
+pub(super) fn handle_pull_requests(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    key: &KeyEvent,
+    modifiers: Modifiers,
+) -> bool {
+    let Some(view) = state.pull_requests.as_mut() else {
+        return false;
+    };
+    match key.key {
+        KeyCode::Escape => state.pull_requests = None,
+        KeyCode::Enter => {
+            if let Err(error) = actions::open_selected_pull_request(state, runtime) {
+                state.set_output(error);
+            }
+        }
+        KeyCode::UpArrow => view.move_selection(-1),
+        KeyCode::DownArrow => view.move_selection(1),
+        KeyCode::PageUp => view.move_selection(-10),
+        KeyCode::PageDown => view.move_selection(10),
+        KeyCode::Backspace => {
+            view.filter.pop();
+            view.selected = 0;
+        }
+        KeyCode::Char(character)
+            if modifiers == Modifiers::NONE || modifiers == Modifiers::SHIFT =>
+        {
+            view.filter.push(character);
+            view.selected = 0;
+        }
+        _ => {}
+    }
+    true
+}
+
 pub(super) fn handle_command_input(
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
@@ -93,7 +236,11 @@ pub(super) fn handle_command_input(
             let input = state.command_input.clone();
             match crate::client::parse_command(&input) {
                 Ok(command) => {
+                    // Closing the prompt forgets a reply's row; the command
+                    // it just submitted still needs it.
+                    let reply = state.agent_reply.take();
                     close_command(state);
+                    state.agent_reply = reply;
                     state.close_detail();
                     actions::execute_command(state, runtime, command);
                 }
@@ -148,6 +295,7 @@ pub(super) fn prefill_command(state: &mut ClientState, input: impl Into<String>)
 pub(super) fn close_command(state: &mut ClientState) {
     state.command_active = false;
     state.command_input.clear();
+    state.agent_reply = None;
     reset_command_feedback(state);
 }
 
@@ -53,6 +53,9 @@ fn click(state: &mut ClientState, runtime: &mut ClientRuntime, action: MouseActi
         MouseAction::ScrollSidebar
         | MouseAction::ScrollPicker
         | MouseAction::ScrollHelp
+        | MouseAction::ScrollAgents
+        | MouseAction::ScrollWorktrees
+        | MouseAction::ScrollPullRequests
         | MouseAction::ScrollDetail
         | MouseAction::ScrollApproval
         | MouseAction::ScrollPorts => {}
@@ -96,6 +99,9 @@ fn click(state: &mut ClientState, runtime: &mut ClientRuntime, action: MouseActi
         MouseAction::CloseCommand => modal::close_command(state),
         MouseAction::PrefillCommand(input) => {
             state.help = None;
+            state.agents = None;
+            state.worktrees = None;
+            state.pull_requests = None;
             state.picker = None;
             modal::prefill_command(state, input);
         }
@@ -108,6 +114,33 @@ fn click(state: &mut ClientState, runtime: &mut ClientRuntime, action: MouseActi
         }
         MouseAction::ClosePicker => state.picker = None,
         MouseAction::CloseHelp => state.help = None,
+        MouseAction::GoToAgent {
+            workspace_id,
+            run_id,
+        } => {
+            if let Err(error) = actions::go_to_agent(state, runtime, workspace_id, run_id) {
+                state.set_output(error);
+            }
+        }
+        MouseAction::CloseAgents => state.agents = None,
+        MouseAction::ChooseWorktree(index) => {
+            if let Some(view) = state.worktrees.as_mut() {
+                view.selected = index;
+            }
+            if let Err(error) = actions::open_selected_worktree(state, runtime) {
+                state.set_output(error);
+            }
+        }
+        MouseAction::CloseWorktrees => state.worktrees = None,
+        MouseAction::ChoosePullRequest(index) => {
+            if let Some(view) = state.pull_requests.as_mut() {
+                view.selected = index;
+            }
+            if let Err(error) = actions::open_selected_pull_request(state, runtime) {
+                state.set_output(error);
+            }
+        }
+        MouseAction::ClosePullRequests => state.pull_requests = None,
         MouseAction::CloseDetail => {
             state.close_detail();
         }
@@ -134,7 +167,7 @@ fn click(state: &mut ClientState, runtime: &mut ClientRuntime, action: MouseActi
             {
                 state.set_output(actions::existing_forward_message(forward));
             } else if let Err(error) =
-                actions::start_forward_target(state, runtime, workspace_id, target)
+                actions::start_forward_target(state, runtime, workspace_id, target, None)
             {
                 state.set_output(error);
             }
@@ -159,6 +192,17 @@ fn scroll(state: &mut ClientState, action: MouseAction, direction: i32) {
                 update(&mut help.scroll);
             }
         }
+        MouseAction::ScrollAgents => state.move_agents(direction * i32::from(amount)),
+        MouseAction::ScrollWorktrees => {
+            if let Some(view) = state.worktrees.as_mut() {
+                view.move_selection(direction * i32::from(amount));
+            }
+        }
+        MouseAction::ScrollPullRequests => {
+            if let Some(view) = state.pull_requests.as_mut() {
+                view.move_selection(direction * i32::from(amount));
+            }
+        }
         MouseAction::ScrollDetail => update(&mut state.detail_scroll),
         MouseAction::ScrollApproval => update(&mut state.approval_scroll),
         MouseAction::ScrollPorts => update(&mut state.ports_scroll),
@@ -169,6 +169,7 @@ fn escape_dismisses_an_approval_without_starting_a_mutation() {
             digest: "0".repeat(64),
         },
         review: "review".to_owned(),
+        removes: Vec::new(),
     });
     state.approval_scroll = 7;
 
@@ -190,6 +191,166 @@ fn escape_dismisses_an_approval_without_starting_a_mutation() {
     assert!(state.host_operations.is_empty());
 }
 
+#[test]
+fn the_worktree_browser_refuses_removals_it_cannot_review() {
+    let (root, mut runtime, mut state) = attached_fixture();
+    let workspace_id = state.selected_workspace.unwrap();
+    let list = crate::worktrunk::WorktreeList::parse(&format!(
+        r#"{{"schema":2,"repo":{{"default_branch":"main"}},"items":[
+          {{"branch":"main","worktree":{{"path":"{}","main":true}}}},
+          {{"branch":"feature","worktree":{{"path":"/elsewhere/feature"}}}}
+        ]}}"#,
+        root.path().join("workspace").display()
+    ))
+    .unwrap();
+    crate::client::actions::apply_worktree_list(&mut state, workspace_id, list);
+    assert_eq!(state.worktrees.as_ref().unwrap().selected, 0);
+    let press = |code| KeyEvent {
+        key: code,
+        modifiers: Modifiers::NONE,
+    };
+
+    handle_key(&mut state, &mut runtime, press(KeyCode::Char('d')));
+    assert_eq!(
+        state.output.as_deref(),
+        Some("The main worktree cannot be removed.")
+    );
+    handle_key(&mut state, &mut runtime, press(KeyCode::DownArrow));
+    handle_key(&mut state, &mut runtime, press(KeyCode::Char('d')));
+    assert!(state
+        .output
+        .as_deref()
+        .unwrap()
+        .contains("/elsewhere/feature is not a registered workspace"));
+    assert!(state.host_operations.is_empty());
+    assert!(state.worktrees.is_some());
+
+    handle_key(&mut state, &mut runtime, press(KeyCode::Escape));
+    assert!(state.worktrees.is_none());
+}
+
+#[test]
+fn dashboard_answers_only_the_rule_still_asking() {
+    let (_root, mut runtime, mut state) = attached_fixture();
+    let workspace_id = state.selected_workspace.unwrap();
+    let answer = |label: &str, key: &str| crate::agent_status::BlockerAnswer {
+        label: label.to_owned(),
+        key: key.to_owned().try_into().unwrap(),
+    };
+    let blocker = crate::agent_status::BlockerExplain {
+        provider: crate::agent_status::Provider::Claude,
+        manifest: crate::agent_status::BlockerManifestSource::Bundled,
+        manifest_version: "1".to_owned(),
+        rule_id: "permission_prompt".to_owned(),
+        confidence: crate::agent_status::BlockerConfidence::High,
+        priority: 900,
+        answers: vec![answer("yes", "1"), answer("no", "Esc")],
+    };
+    state.agent_runs.insert(
+        workspace_id,
+        vec![crate::client::state::AgentRunView {
+            run_id: crate::core::AgentRunId::new(),
+            pane_id: crate::core::PaneId::new(),
+            tab_id: 2,
+            provider: crate::agent_status::Provider::Claude,
+            zellij_pane_id: "terminal_2".to_owned(),
+            needs_input_capability: "exact".to_owned(),
+            snapshot: None,
+            explain: None,
+            snapshot_error: None,
+            seen_completion_revision: 0,
+            blocker: Some(blocker.clone()),
+            blocker_watcher_instance: None,
+            blocker_sequence: 1,
+            blocker_observed_at_ms: Some(1),
+            interrupted_after_sequence: None,
+            manifest_warning: None,
+        }],
+    );
+    state.agents = Some(crate::client::state::AgentsView::default());
+    let press = |code| KeyEvent {
+        key: code,
+        modifiers: Modifiers::NONE,
+    };
+
+    handle_key(&mut state, &mut runtime, press(KeyCode::Char('3')));
+    assert_eq!(
+        state.output.as_deref(),
+        Some("The permission_prompt prompt declares 2 answers.")
+    );
+
+    let run = &mut state.agent_runs.get_mut(&workspace_id).unwrap()[0];
+    run.blocker = Some(crate::agent_status::BlockerExplain {
+        rule_id: "connection_prompt".to_owned(),
+        answers: Vec::new(),
+        ..blocker
+    });
+    handle_key(&mut state, &mut runtime, press(KeyCode::Char('1')));
+    assert_eq!(
+        state.output.as_deref(),
+        Some("The connection_prompt prompt declares no answers; enter opens its tab.")
+    );
+
+    state.agent_runs.get_mut(&workspace_id).unwrap()[0].blocker = None;
+    handle_key(&mut state, &mut runtime, press(KeyCode::Char('1')));
+    assert!(state
+        .output
+        .as_deref()
+        .unwrap()
+        .contains("has no prompt on screen to answer"));
+    assert!(state.host_operations.is_empty());
+    assert!(state.agents.is_some());
+}
+
+#[test]
+fn typing_in_the_pull_request_picker_filters_by_number_or_text() {
+    let (_root, mut runtime, mut state) = attached_fixture();
+    let workspace_id = state.selected_workspace.unwrap();
+    let pull_request = |number, title: &str| crate::core::OpenPullRequest {
+        number,
+        title: title.to_owned(),
+        author: "ana".to_owned(),
+        draft: false,
+        branch: format!("branch-{number}"),
+    };
+    crate::client::actions::apply_pull_request_list(
+        &mut state,
+        workspace_id,
+        vec![
+            pull_request(120, "Add billing"),
+            pull_request(7, "Fix login"),
+        ],
+    );
+    let press = |code| KeyEvent {
+        key: code,
+        modifiers: Modifiers::NONE,
+    };
+    let selected = |state: &ClientState| {
+        state
+            .pull_requests
+            .as_ref()
+            .and_then(|view| view.selected_pull_request())
+            .map(|pull_request| pull_request.number)
+    };
+
+    handle_key(&mut state, &mut runtime, press(KeyCode::DownArrow));
+    assert_eq!(selected(&state), Some(7));
+    for character in "#12".chars() {
+        handle_key(&mut state, &mut runtime, press(KeyCode::Char(character)));
+    }
+    assert_eq!(selected(&state), Some(120));
+    handle_key(&mut state, &mut runtime, press(KeyCode::Char('x')));
+    handle_key(&mut state, &mut runtime, press(KeyCode::Enter));
+    assert_eq!(
+        state.output.as_deref(),
+        Some("No pull request matches that filter.")
+    );
+    assert!(state.host_operations.is_empty());
+
+    handle_key(&mut state, &mut runtime, press(KeyCode::Escape));
+    assert!(state.pull_requests.is_none());
+}
+
 #[test]
 fn clicking_the_manage_session_enters_work_mode() {
     let (_root, mut runtime, mut state) = attached_fixture();
@@ -8,9 +8,11 @@ mod control;
 mod focus;
 mod model;
 mod mouse;
+mod notify;
 mod render;
 mod runner;
 mod runtime;
+mod script;
 mod state;
 mod terminal;
 
@@ -20,6 +22,7 @@ pub use model::{
 };
 pub use render::render;
 pub use runner::run;
+pub use script::{run_script, SCRIPT_HELP};
 pub use state::{ClientMode, ClientState};
 pub use terminal::EmbeddedTerminal;
 
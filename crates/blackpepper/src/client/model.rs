@@ -63,7 +63,7 @@ impl DisplayStatus {
         }
     }
 
-    fn priority(self) -> u8 {
+    pub(in crate::client) fn priority(self) -> u8 {
         match self {
             Self::NeedsInput => 6,
             Self::Done => 5,
@@ -0,0 +1,183 @@
+//! Notifications for agents in workspaces you are not looking at.
+//!
+//! Periodic refresh hands over fresh `AgentSnapshot`s. Each run is compared
+//! against what this client last saw of it: an agent that keeps asking across
+//! ten refreshes raises one notification until it stops asking or a different
+//! blocker appears, and a completion this client has already marked seen
+//! raises none.
+
+use super::{ClientMode, ClientState, DisplayStatus};
+use crate::client_config::NotificationChannel;
+use crate::core::{AgentRunId, HostId};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What this client has already accounted for, per run.
+#[derive(Debug, Default)]
+pub struct NotificationLedger {
+    announced: BTreeMap<AgentRunId, Announced>,
+}
+
+#[derive(Debug, Clone)]
+struct Announced {
+    /// The run was displayed as asking for input.
+    asking: bool,
+    /// Rule of the blocker overlay the run was asking through, if any.
+    blocker_rule: Option<String>,
+    completion_revision: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Notice {
+    pub(super) title: String,
+    pub(super) body: String,
+}
+
+/// Notices owed for one host's freshly merged snapshots. The first sighting
+/// of a run only records its state: a client that starts up next to ten
+/// finished agents has nothing new to say about them.
+pub(super) fn collect(state: &mut ClientState, host_id: HostId) -> Vec<Notice> {
+    let watched = (state.mode == ClientMode::Work)
+        .then_some(state.active_workspace)
+        .flatten();
+    let places = state
+        .tree
+        .iter()
+        .filter(|host| host.id == host_id)
+        .flat_map(|host| {
+            host.repositories
+                .iter()
+                .flat_map(|repository| &repository.workspaces)
+                .map(|workspace| (workspace.id, (workspace.label.clone(), host.label.clone())))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let mut notices = Vec::new();
+    for (workspace_id, runs) in &state.agent_runs {
+        let Some((workspace, host)) = places.get(workspace_id) else {
+            continue;
+        };
+        for run in runs {
+            let Some(snapshot) = run.snapshot.as_ref() else {
+                continue;
+            };
+            let status = run.display_status();
+            let current = Announced {
+                asking: status == DisplayStatus::NeedsInput,
+                blocker_rule: run.blocker.as_ref().map(|blocker| blocker.rule_id.clone()),
+                completion_revision: snapshot.completion_revision,
+            };
+            let previous = state.notifications.announced.insert(run.run_id, current);
+            let Some(previous) = previous else {
+                continue;
+            };
+            if watched == Some(*workspace_id) || run.snapshot_error.is_some() {
+                continue;
+            }
+            let config = &state.config.notifications;
+            let blocker_rule = run.blocker.as_ref().map(|blocker| &blocker.rule_id);
+            let body = match status {
+                // A refresh that only bumps the revision of the same question
+                // stays quiet; a different blocker is a new question.
+                DisplayStatus::NeedsInput
+                    if (!previous.asking || previous.blocker_rule.as_ref() != blocker_rule)
+                        && config.wants_needs_input(run.provider) =>
+                {
+                    format!("{} asks for input on {host}", run.provider)
+                }
+                // `Done` is only displayed while the completion is newer than
+                // this client's seen cursor, so a seen completion stays quiet.
+                DisplayStatus::Done
+                    if snapshot.completion_revision > previous.completion_revision
+                        && config.wants_done(run.provider) =>
+                {
+                    format!("{} is done on {host}", run.provider)
+                }
+                _ => continue,
+            };
+            notices.push(Notice {
+                title: format!("[bp] {workspace}"),
+                body,
+            });
+        }
+    }
+    let live = state
+        .agent_runs
+        .values()
+        .flatten()
+        .map(|run| run.run_id)
+        .collect::<BTreeSet<_>>();
+    state
+        .notifications
+        .announced
+        .retain(|run_id, _| live.contains(run_id));
+    notices
+}
+
+/// Send notices through the configured channel. Returns a message when the
+/// outer terminal refused the write; desktop delivery is fire-and-forget.
+pub(super) fn deliver(channel: NotificationChannel, notices: Vec<Notice>) -> Option<String> {
+    deliver_with(
+        channel,
+        notices,
+        running_over_ssh(),
+        show_desktop,
+        super::terminal::write_outer_terminal,
+    )
+}
+
+fn deliver_with(
+    channel: NotificationChannel,
+    notices: Vec<Notice>,
+    over_ssh: bool,
+    mut desktop: impl FnMut(Notice),
+    mut write_outer: impl FnMut(&[u8]) -> Result<(), String>,
+) -> Option<String> {
+    let channel = match channel {
+        NotificationChannel::Auto if over_ssh => NotificationChannel::Osc777,
+        NotificationChannel::Auto => NotificationChannel::Desktop,
+        channel => channel,
+    };
+    let mut failure = None;
+    for notice in notices {
+        let sequence = match channel {
+            NotificationChannel::Desktop => {
+                desktop(notice);
+                continue;
+            }
+            NotificationChannel::Osc9 => crate::terminal::osc::osc9_notification(&format!(
+                "{}: {}",
+                notice.title, notice.body
+            )),
+            NotificationChannel::Osc777 => {
+                crate::terminal::osc::osc777_notification(&notice.title, &notice.body)
+            }
+            NotificationChannel::Auto | NotificationChannel::Off => continue,
+        };
+        if let Err(error) = write_outer(&sequence) {
+            failure = Some(format!("An agent notification could not be sent: {error}"));
+        }
+    }
+    failure
+}
+
+/// A desktop notification from a client running inside SSH would appear on
+/// the remote machine, not in front of the person using it.
+fn running_over_ssh() -> bool {
+    ["SSH_CONNECTION", "SSH_TTY"]
+        .iter()
+        .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty()))
+}
+
+/// D-Bus and the macOS notification centre can both block; neither may stall
+/// the event loop that is drawing the terminal.
+fn show_desktop(notice: Notice) {
+    std::thread::spawn(move || {
+        let _ = notify_rust::Notification::new()
+            .summary(&notice.title)
+            .body(&notice.body)
+            .show();
+    });
+}
+
+#[cfg(test)]
+#[path = "notify_tests.rs"]
+mod tests;
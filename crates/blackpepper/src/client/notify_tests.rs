@@ -0,0 +1,261 @@
+use super::*;
+use crate::agent_status::{
+    AgentSnapshot, AgentState, BlockerConfidence, BlockerExplain, IntegrationHealth,
+    NeedsInputCapability, Provider,
+};
+use crate::client::state::AgentRunView;
+use crate::core::{HostRecord, HostTransport, PaneId, RegistrySnapshot, WorkspaceRecord};
+
+fn watched_state() -> (ClientState, HostId, crate::core::WorkspaceId) {
+    let (event_tx, _event_rx) = std::sync::mpsc::channel();
+    let host = HostRecord::new("devbox", HostTransport::Local);
+    let mut workspace = WorkspaceRecord::new(host.id, "/workspace/blackpepper");
+    workspace.display_name = Some("blackpepper".to_owned());
+    let (host_id, workspace_id) = (host.id, workspace.id);
+    let mut state = ClientState::new(
+        crate::client_config::load_contents(None, None, None).unwrap(),
+        RegistrySnapshot {
+            hosts: vec![host],
+            workspaces: vec![workspace],
+            ..RegistrySnapshot::default()
+        },
+        event_tx,
+    );
+    state
+        .connections
+        .insert(host_id, crate::client::HostConnection::Local);
+    state.mode = ClientMode::Manage;
+    state.rebuild_tree();
+    (state, host_id, workspace_id)
+}
+
+fn advance(state: &mut ClientState, workspace_id: crate::core::WorkspaceId, next: AgentState) {
+    let run = &mut state.agent_runs.get_mut(&workspace_id).unwrap()[0];
+    let mut snapshot = run.snapshot.clone().unwrap();
+    snapshot.state = next;
+    snapshot.revision += 1;
+    snapshot.last_event_sequence = Some(snapshot.revision);
+    snapshot.completion_revision += u64::from(next == AgentState::Done);
+    run.apply_snapshot(snapshot);
+}
+
+fn run(provider: Provider) -> AgentRunView {
+    let run_id = AgentRunId::new();
+    AgentRunView {
+        run_id,
+        pane_id: PaneId::new(),
+        tab_id: 1,
+        provider,
+        zellij_pane_id: "terminal_1".to_owned(),
+        needs_input_capability: "exact".to_owned(),
+        snapshot: Some(AgentSnapshot {
+            run_id,
+            provider,
+            state: AgentState::Working,
+            revision: 1,
+            completion_revision: 0,
+            seen_completion_revision: 0,
+            last_event_sequence: Some(1),
+            last_event_at_ms: Some(1),
+            integration_health: IntegrationHealth::Healthy {
+                integration_version: Some(1),
+            },
+            needs_input_capability: NeedsInputCapability::ProviderEvents,
+            completion_suppressed: false,
+        }),
+        explain: None,
+        snapshot_error: None,
+        seen_completion_revision: 0,
+        blocker: None,
+        blocker_watcher_instance: None,
+        blocker_sequence: 0,
+        blocker_observed_at_ms: None,
+        interrupted_after_sequence: None,
+        manifest_warning: None,
+    }
+}
+
+fn blocker(rule_id: &str) -> BlockerExplain {
+    BlockerExplain {
+        provider: Provider::Claude,
+        manifest: Default::default(),
+        manifest_version: "1".to_owned(),
+        rule_id: rule_id.to_owned(),
+        confidence: BlockerConfidence::High,
+        priority: 0,
+        answers: Vec::new(),
+    }
+}
+
+#[test]
+fn a_question_is_announced_once_and_only_after_the_first_sighting() {
+    let (mut state, host_id, workspace_id) = watched_state();
+    state
+        .agent_runs
+        .insert(workspace_id, vec![run(Provider::Claude)]);
+    assert!(collect(&mut state, host_id).is_empty());
+
+    advance(&mut state, workspace_id, AgentState::NeedsInput);
+    assert_eq!(
+        collect(&mut state, host_id),
+        vec![Notice {
+            title: "[bp] blackpepper".to_owned(),
+            body: "claude asks for input on devbox".to_owned(),
+        }]
+    );
+    // The same question seen again on the next refresh stays quiet.
+    assert!(collect(&mut state, host_id).is_empty());
+}
+
+#[test]
+fn a_revision_bump_while_still_asking_stays_quiet_until_the_blocker_changes() {
+    let (mut state, host_id, workspace_id) = watched_state();
+    state
+        .agent_runs
+        .insert(workspace_id, vec![run(Provider::Claude)]);
+    collect(&mut state, host_id);
+
+    advance(&mut state, workspace_id, AgentState::NeedsInput);
+    assert_eq!(collect(&mut state, host_id).len(), 1);
+    advance(&mut state, workspace_id, AgentState::NeedsInput);
+    assert!(collect(&mut state, host_id).is_empty());
+
+    let run = &mut state.agent_runs.get_mut(&workspace_id).unwrap()[0];
+    run.blocker = Some(blocker("permission"));
+    assert_eq!(collect(&mut state, host_id).len(), 1);
+    advance(&mut state, workspace_id, AgentState::NeedsInput);
+    assert!(collect(&mut state, host_id).is_empty());
+
+    let run = &mut state.agent_runs.get_mut(&workspace_id).unwrap()[0];
+    run.blocker = Some(blocker("trust-folder"));
+    assert_eq!(collect(&mut state, host_id).len(), 1);
+
+    advance(&mut state, workspace_id, AgentState::Working);
+    let run = &mut state.agent_runs.get_mut(&workspace_id).unwrap()[0];
+    run.blocker = None;
+    assert!(collect(&mut state, host_id).is_empty());
+    advance(&mut state, workspace_id, AgentState::NeedsInput);
+    assert_eq!(collect(&mut state, host_id).len(), 1);
+}
+
+#[test]
+fn the_workspace_on_screen_and_opted_out_providers_stay_quiet() {
+    let (mut state, host_id, workspace_id) = watched_state();
+    state
+        .agent_runs
+        .insert(workspace_id, vec![run(Provider::Codex)]);
+    collect(&mut state, host_id);
+
+    state.mode = ClientMode::Work;
+    state.active_workspace = Some(workspace_id);
+    advance(&mut state, workspace_id, AgentState::NeedsInput);
+    assert!(collect(&mut state, host_id).is_empty());
+
+    state.mode = ClientMode::Manage;
+    state.config.notifications.providers.insert(
+        Provider::Codex,
+        crate::client_config::ProviderNotifications {
+            needs_input: None,
+            done: Some(false),
+        },
+    );
+    advance(&mut state, workspace_id, AgentState::Done);
+    assert!(collect(&mut state, host_id).is_empty());
+
+    state.config.notifications.providers.clear();
+    advance(&mut state, workspace_id, AgentState::Working);
+    advance(&mut state, workspace_id, AgentState::Done);
+    assert_eq!(
+        collect(&mut state, host_id)
+            .into_iter()
+            .map(|notice| notice.body)
+            .collect::<Vec<_>>(),
+        ["codex is done on devbox"]
+    );
+}
+
+fn notices() -> Vec<Notice> {
+    vec![Notice {
+        title: "[bp] api".to_owned(),
+        body: "codex is done on devbox".to_owned(),
+    }]
+}
+
+#[test]
+fn auto_delivery_writes_to_the_terminal_over_ssh_and_the_desktop_otherwise() {
+    let mut written = Vec::new();
+    let mut shown = Vec::new();
+    assert_eq!(
+        deliver_with(
+            NotificationChannel::Auto,
+            notices(),
+            true,
+            |notice| shown.push(notice),
+            |bytes| {
+                written.push(bytes.to_vec());
+                Ok(())
+            },
+        ),
+        None
+    );
+    assert_eq!(
+        written,
+        [b"\x1b]777;notify;[bp] api;codex is done on devbox\x07".to_vec()]
+    );
+    assert!(shown.is_empty());
+
+    written.clear();
+    deliver_with(
+        NotificationChannel::Auto,
+        notices(),
+        false,
+        |notice| shown.push(notice),
+        |bytes| {
+            written.push(bytes.to_vec());
+            Ok(())
+        },
+    );
+    assert!(written.is_empty());
+    assert_eq!(shown, notices());
+}
+
+#[test]
+fn explicit_channels_pick_their_own_encoding_or_stay_silent() {
+    let mut written = Vec::new();
+    deliver_with(
+        NotificationChannel::Osc9,
+        notices(),
+        false,
+        |_| panic!("OSC 9 must not raise a desktop notification"),
+        |bytes| {
+            written.push(bytes.to_vec());
+            Ok(())
+        },
+    );
+    assert_eq!(
+        written,
+        [b"\x1b]9;[bp] api: codex is done on devbox\x07".to_vec()]
+    );
+
+    assert_eq!(
+        deliver_with(
+            NotificationChannel::Off,
+            notices(),
+            true,
+            |_| panic!("off means off"),
+            |_| panic!("off means off"),
+        ),
+        None
+    );
+    assert_eq!(
+        deliver_with(
+            NotificationChannel::Osc777,
+            notices(),
+            false,
+            |_| {},
+            |_| Err("broken pipe".to_owned()),
+        )
+        .as_deref(),
+        Some("An agent notification could not be sent: broken pipe")
+    );
+}
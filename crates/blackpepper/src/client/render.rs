@@ -1,3 +1,4 @@
+mod agents;
 mod chord;
 mod chrome;
 mod command;
@@ -7,21 +8,26 @@ mod header;
 mod help;
 mod picker;
 mod ports;
+mod pull_requests;
 mod sidebar;
 mod style;
 mod terminal;
+mod worktrees;
 
 use super::{ClientMode, ClientState};
+use agents::render_agents;
 use footer::render_footer;
 use header::render_header;
 use help::render_help;
 use picker::render_picker;
 use ports::render_ports;
+use pull_requests::render_pull_requests;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::widgets::Block;
 use sidebar::render_sidebar;
 use style::ui_style;
 use terminal::render_terminal;
+use worktrees::render_worktrees;
 
 const WIDE_COLUMNS: u16 = 32 + 40 + 30;
 const MEDIUM_COLUMNS: u16 = 32 + 30;
@@ -89,6 +95,24 @@ fn render_manage_body(state: &mut ClientState, frame: &mut ratatui::Frame, area:
         render_help(state, frame, area);
         return;
     }
+    if state.agents.is_some() {
+        clear_ports(state);
+        state.terminal_area = None;
+        render_agents(state, frame, area);
+        return;
+    }
+    if state.worktrees.is_some() {
+        clear_ports(state);
+        state.terminal_area = None;
+        render_worktrees(state, frame, area);
+        return;
+    }
+    if state.pull_requests.is_some() {
+        clear_ports(state);
+        state.terminal_area = None;
+        render_pull_requests(state, frame, area);
+        return;
+    }
     let focused_view = state.mode == ClientMode::Authenticate
         || state.pending_approval.is_some()
         || state.detail.is_some();
@@ -0,0 +1,180 @@
+//! The `:agents` dashboard.
+//!
+//! Every agent run this client can see, across every host, in one list. The
+//! sidebar only shows the busiest run per workspace; this view is where a
+//! second waiting agent in the same workspace stops being invisible.
+
+use super::chrome;
+use super::glyph::Glyphs;
+use super::style::{
+    accent_style, mid_style, section_style, selected_style, status_style, status_text, ui_style,
+};
+use crate::client::state::{AgentRow, MouseAction, MouseTarget};
+use crate::client::ClientState;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+/// Gap between columns.
+const COLUMN_GAP: usize = 2;
+/// Status, provider, elapsed, workspace, host, tab, answers — see `row_cells`.
+const COLUMNS: usize = 7;
+
+pub(super) fn render_agents(state: &mut ClientState, frame: &mut ratatui::Frame, area: Rect) {
+    let glyphs = Glyphs::of(state);
+    let separator = glyphs.separator();
+    let Some(view) = state.agents else {
+        return;
+    };
+    let body = chrome::inner(area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(body);
+
+    let hint = format!(
+        "esc close {separator} {} select {separator} enter open {separator} r reply {separator} s sort",
+        glyphs.updown()
+    );
+    let hint_pad = usize::from(rows[0].width)
+        .saturating_sub(7 + Line::raw(&hint).width())
+        .max(2);
+    let heading = Line::from(vec![
+        Span::styled(":", accent_style(state)),
+        Span::raw("agents"),
+        Span::raw(" ".repeat(hint_pad)),
+        Span::styled(hint, section_style(state)),
+    ]);
+    frame.render_widget(Paragraph::new(heading).style(ui_style(state)), rows[0]);
+
+    let agents = state.agent_rows();
+    let selected = view.selected.min(agents.len().saturating_sub(1));
+    let mut lines = vec![
+        Line::raw(""),
+        Line::styled(
+            format!("AGENTS {separator} {}", view.order.label()),
+            section_style(state),
+        ),
+    ];
+    if agents.is_empty() {
+        lines.push(Line::styled(
+            "no agent runs yet; start one with :agent spawn <provider>",
+            section_style(state),
+        ));
+    }
+
+    let cells = agents
+        .iter()
+        .map(|row| row_cells(state, row, separator))
+        .collect::<Vec<_>>();
+    let widths = (0..COLUMNS)
+        .map(|column| {
+            cells
+                .iter()
+                .map(|cells| Line::raw(&cells[column]).width())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let width = usize::from(rows[1].width);
+
+    // Keep the cursor on screen: the two header rows never scroll away.
+    let visible = usize::from(rows[1].height).saturating_sub(2);
+    let offset = selected.saturating_sub(visible.saturating_sub(1));
+    let mut row_actions = Vec::new();
+    for (index, (row, cells)) in agents
+        .iter()
+        .zip(&cells)
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+    {
+        let line_index = lines.len();
+        let padded = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let padding = width.saturating_sub(Line::raw(cell).width());
+                format!("{cell}{}", " ".repeat(padding + COLUMN_GAP))
+            })
+            .collect::<Vec<_>>();
+        if index == selected {
+            let text = padded.concat();
+            let fill = width.saturating_sub(Line::raw(&text).width());
+            lines.push(Line::styled(
+                format!("{text}{}", " ".repeat(fill)),
+                selected_style(state),
+            ));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled(padded[0].clone(), status_style(state, row.status)),
+                Span::raw(padded[1].clone()),
+                Span::styled(padded[2].clone(), section_style(state)),
+                Span::styled(padded[3].clone(), mid_style(state)),
+                Span::styled(padded[4].clone(), section_style(state)),
+                Span::styled(padded[5].clone(), section_style(state)),
+                Span::styled(padded[6].clone(), accent_style(state)),
+            ]));
+        }
+        row_actions.push((
+            line_index,
+            MouseAction::GoToAgent {
+                workspace_id: row.workspace_id,
+                run_id: row.run_id,
+            },
+        ));
+    }
+
+    frame.render_widget(Paragraph::new(lines).style(ui_style(state)), rows[1]);
+    state.mouse_targets.push(MouseTarget {
+        area: rows[1],
+        action: MouseAction::ScrollAgents,
+    });
+    let close_x = 7 + hint_pad;
+    if close_x < usize::from(rows[0].width) {
+        state.mouse_targets.push(MouseTarget {
+            area: Rect::new(
+                rows[0].x.saturating_add(close_x as u16),
+                rows[0].y,
+                Line::raw("esc close")
+                    .width()
+                    .min(usize::from(rows[0].width) - close_x) as u16,
+                1,
+            ),
+            action: MouseAction::CloseAgents,
+        });
+    }
+    state
+        .mouse_targets
+        .extend(row_actions.into_iter().filter_map(|(line, action)| {
+            (line < usize::from(rows[1].height)).then_some(MouseTarget {
+                area: Rect::new(
+                    rows[1].x,
+                    rows[1].y.saturating_add(line as u16),
+                    rows[1].width,
+                    1,
+                ),
+                action,
+            })
+        }));
+}
+
+/// One row's cells in display order, measured together so every column lines
+/// up regardless of which host contributed the longest name. An asking row
+/// ends with its rule's answers, numbered by the key that sends each one.
+fn row_cells(state: &ClientState, row: &AgentRow, separator: &str) -> [String; COLUMNS] {
+    [
+        status_text(state, row.status, None),
+        row.provider.to_string(),
+        row.elapsed.clone().unwrap_or_default(),
+        row.workspace.clone(),
+        row.host.clone(),
+        format!("tab {}", row.tab_id),
+        row.answers
+            .iter()
+            .enumerate()
+            .map(|(index, label)| format!("{} {label}", index + 1))
+            .collect::<Vec<_>>()
+            .join(&format!(" {separator} ")),
+    ]
+}
@@ -300,13 +300,20 @@ pub(super) fn work_attention(state: &ClientState) -> Vec<String> {
         .is_some_and(|snapshot| {
             snapshot.warning.is_some() || snapshot.completeness != ProbeCompleteness::Full
         });
-    let forward_needs_attention = state.forwards.iter().any(|forward| {
-        forward.workspace_id == workspace_id
-            && !matches!(
-                forward.status,
-                ForwardStatus::Active | ForwardStatus::Direct
-            )
-    });
+    let unhealthy =
+        |status: &ForwardStatus| !matches!(status, ForwardStatus::Active | ForwardStatus::Direct);
+    let forward_needs_attention = state
+        .forwards
+        .iter()
+        .any(|forward| forward.workspace_id == workspace_id && unhealthy(&forward.status))
+        || state
+            .socket_forwards
+            .iter()
+            .any(|forward| forward.workspace_id == workspace_id && unhealthy(&forward.status))
+        || state
+            .reverse_forwards
+            .iter()
+            .any(|forward| forward.workspace_id == workspace_id && unhealthy(&forward.status));
     if port_probe_needs_attention || forward_needs_attention {
         attention.push("ports".to_owned());
     }
@@ -329,8 +336,15 @@ pub(super) fn default_footer_hint(state: &ClientState, width: u16) -> String {
         ClientMode::Manage => {
             // The row is truncated, not wrapped, so the `work` affordance is
             // dropped whole rather than clipping `q quit` off the end.
+            // `g agent` only appears while the selected row reports a run,
+            // so the hint never offers a jump that would refuse.
+            let agent = state
+                .selected_workspace
+                .and_then(|workspace_id| state.status_agent(workspace_id))
+                .map(|_| format!(" {separator} g agent"))
+                .unwrap_or_default();
             let full = format!(
-                "  {} select {separator} enter attach {separator} : command {separator} {toggle} work {separator} q quit",
+                "  {} select {separator} enter attach{agent} {separator} : command {separator} {toggle} work {separator} q quit",
                 glyphs.updown()
             );
             if Line::raw(&full).width() + 2 * usize::from(chrome::gutter(width))
@@ -12,12 +12,22 @@ pub(super) fn register_hint_targets(
     hint: &str,
 ) {
     let actions = if state.host_operations.is_empty() {
+        let agent = state.selected_workspace.and_then(|workspace_id| {
+            state
+                .status_agent(workspace_id)
+                .map(|run_id| MouseAction::GoToAgent {
+                    workspace_id,
+                    run_id,
+                })
+        });
         [
-            ("enter attach", MouseAction::AttachSelected),
-            (": command", MouseAction::OpenCommand),
-            ("q quit", MouseAction::Quit),
+            Some(("enter attach", MouseAction::AttachSelected)),
+            agent.map(|action| ("g agent", action)),
+            Some((": command", MouseAction::OpenCommand)),
+            Some(("q quit", MouseAction::Quit)),
         ]
         .into_iter()
+        .flatten()
         .collect::<Vec<_>>()
     } else {
         [
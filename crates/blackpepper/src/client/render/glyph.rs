@@ -8,8 +8,10 @@
 //! one column wide in both repertoires, so the fixed column arithmetic in the
 //! sidebar and ports panel is identical whichever set is active.
 
+use crate::client::state::ServiceHealth;
 use crate::client::{ClientState, DisplayStatus, HostConnection};
 use crate::client_config::GlyphSet;
+use crate::worktrunk::CiState;
 
 /// Braille spinner phases, in rotation order.
 const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -130,6 +132,26 @@ impl Glyphs {
         }
     }
 
+    /// The one-column marker for a branch's CI verdict.
+    pub(super) fn ci(self, ci: CiState) -> &'static str {
+        match ci {
+            CiState::Pending => self.transitional(),
+            CiState::Passing => self.done(),
+            CiState::Failing => self.exited(),
+        }
+    }
+
+    /// A configured service's health, in the same vocabulary as CI.
+    pub(super) fn service(self, health: ServiceHealth) -> &'static str {
+        match health {
+            ServiceHealth::Starting | ServiceHealth::Restarting => self.transitional(),
+            ServiceHealth::Ready => self.done(),
+            ServiceHealth::Crashed { .. } => self.exited(),
+            ServiceHealth::Stopped => self.idle(),
+            ServiceHealth::Blocked => self.attention(),
+        }
+    }
+
     /// The one-column marker for a host's connection state.
     pub(super) fn connection(self, connection: HostConnection) -> &'static str {
         match connection {
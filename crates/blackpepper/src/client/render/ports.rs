@@ -1,7 +1,7 @@
 use super::chrome;
 use super::glyph::Glyphs;
-use super::style::{danger_style, panel_style, section_style, warning_style};
-use crate::client::state::{MouseAction, MouseTarget};
+use super::style::{danger_style, panel_style, section_style, service_style, warning_style};
+use crate::client::state::{MouseAction, MouseTarget, ServiceHealth};
 use crate::client::ClientState;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
@@ -141,8 +141,10 @@ fn port_lines(state: &ClientState, width: u16) -> (Vec<Line<'static>>, Vec<RowAc
                         section_style(state),
                         forward_action.clone(),
                     ),
+                    // Name the taken port: with `--local` it is not the
+                    // listener's own number.
                     crate::ports::ForwardStatus::PortConflict => (
-                        "conflict".to_owned(),
+                        format!("conflict {}", forward.local_address.port()),
                         danger_style(state),
                         forward_action.clone(),
                     ),
@@ -195,6 +197,15 @@ fn port_lines(state: &ClientState, width: u16) -> (Vec<Line<'static>>, Vec<RowAc
             ));
             targets.push(None);
         }
+        listener_rows += socket_lines(
+            state,
+            glyphs,
+            snapshot,
+            active_workspace.zip(workspace_root),
+            width,
+            &mut lines,
+            &mut targets,
+        );
         // Unattributed listeners are a caveat on the list above, so they read
         // after it rather than pushing the ports themselves down.
         if let Some(warning) = &snapshot.warning {
@@ -232,18 +243,279 @@ fn port_lines(state: &ClientState, width: u16) -> (Vec<Line<'static>>, Vec<RowAc
             Some(MouseAction::PrefillCommand(":ports --all-host".to_owned())),
         ]);
     }
+    reverse_lines(state, glyphs, active_host, width, &mut lines, &mut targets);
+    service_lines(
+        state,
+        glyphs,
+        active_workspace,
+        width,
+        &mut lines,
+        &mut targets,
+    );
     debug_assert_eq!(lines.len(), targets.len());
     (lines, targets)
 }
 
+/// Unix sockets follow the TCP listeners in the same two-row shape, keyed by
+/// file name instead of port. A click prefills the `unix:` command rather
+/// than running it, since the client path is worth a look first.
+fn socket_lines(
+    state: &ClientState,
+    glyphs: Glyphs,
+    snapshot: &crate::ports::PortSnapshot,
+    workspace: Option<(crate::core::WorkspaceId, &str)>,
+    width: u16,
+    lines: &mut Vec<Line<'static>>,
+    targets: &mut Vec<RowAction>,
+) -> usize {
+    let pad = chrome::pad(width);
+    let inner = chrome::inner_width(width);
+    let mut rows = 0;
+    for socket in &snapshot.sockets {
+        let in_workspace = workspace.is_some_and(|(_, root)| {
+            crate::client::runtime::ports::listener_matches_workspace(
+                socket.workspace_path.as_deref(),
+                root,
+            )
+        });
+        if !state.show_all_host_ports && !in_workspace {
+            continue;
+        }
+        let forward = state.socket_forwards.iter().find(|forward| {
+            workspace.map(|(id, _)| id) == Some(forward.workspace_id)
+                && forward.remote_path == socket.path
+        });
+        let (action, action_style, command) = match forward {
+            None => (
+                ":forward · click".to_owned(),
+                section_style(state),
+                format!(":forward unix:{}", socket.path),
+            ),
+            Some(forward) => {
+                let (action, style) = match &forward.status {
+                    crate::ports::ForwardStatus::Direct | crate::ports::ForwardStatus::Active => (
+                        format!(
+                            "{} {}",
+                            glyphs.arrow(),
+                            forward.local_path.file_name().map_or_else(
+                                || forward.local_path.display().to_string(),
+                                |name| name.to_string_lossy().into_owned()
+                            )
+                        ),
+                        Style::default(),
+                    ),
+                    crate::ports::ForwardStatus::Reconnecting => {
+                        ("reconnecting".to_owned(), warning_style(state))
+                    }
+                    crate::ports::ForwardStatus::Cancelling => {
+                        ("cancelling".to_owned(), section_style(state))
+                    }
+                    crate::ports::ForwardStatus::PortConflict => {
+                        ("path in use".to_owned(), danger_style(state))
+                    }
+                    crate::ports::ForwardStatus::Failed(reason) => {
+                        (format!("failed: {reason}"), danger_style(state))
+                    }
+                };
+                (
+                    action,
+                    style,
+                    format!(":forward cancel unix:{}", socket.path),
+                )
+            }
+        };
+        // The name yields to the state: a forward's status matters more than
+        // the tail of a long socket name.
+        let action = fit(glyphs, &action, inner / 2);
+        let name = fit(
+            glyphs,
+            socket.name(),
+            inner.saturating_sub(Line::raw(&action).width() + 1),
+        );
+        let padding = inner
+            .saturating_sub(Line::raw(&name).width() + Line::raw(&action).width())
+            .max(1);
+        lines.push(Line::from(vec![
+            Span::raw(format!("{pad}{name}")),
+            Span::raw(" ".repeat(padding)),
+            Span::styled(action, action_style),
+            Span::raw(pad.clone()),
+        ]));
+        let process = socket.process.as_deref().unwrap_or("unknown");
+        lines.push(Line::styled(
+            format!(
+                "{pad}{}",
+                fit(
+                    glyphs,
+                    &format!("{process} {} unix {}", glyphs.separator(), socket.path),
+                    inner
+                )
+            ),
+            section_style(state),
+        ));
+        targets.extend([Some(MouseAction::PrefillCommand(command)), None]);
+        rows += 1;
+    }
+    rows
+}
+
+/// Reverse forwards belong to the host rather than one listener, so they
+/// follow the listener list with the same two-row shape: the host port and
+/// where it leads, then how to undo it.
+fn reverse_lines(
+    state: &ClientState,
+    glyphs: Glyphs,
+    host_id: Option<crate::core::HostId>,
+    width: u16,
+    lines: &mut Vec<Line<'static>>,
+    targets: &mut Vec<RowAction>,
+) {
+    let pad = chrome::pad(width);
+    let inner = chrome::inner_width(width);
+    let reverse = state
+        .reverse_forwards
+        .iter()
+        .filter(|forward| Some(forward.host_id) == host_id)
+        .collect::<Vec<_>>();
+    if reverse.is_empty() {
+        return;
+    }
+    lines.push(Line::raw(""));
+    targets.push(None);
+    for forward in reverse {
+        let (action, action_style) = match &forward.status {
+            crate::ports::ForwardStatus::Direct | crate::ports::ForwardStatus::Active => (
+                format!("{} client {}", glyphs.arrow(), forward.local_port),
+                Style::default(),
+            ),
+            crate::ports::ForwardStatus::Reconnecting
+            | crate::ports::ForwardStatus::PortConflict => {
+                ("reconnecting".to_owned(), warning_style(state))
+            }
+            crate::ports::ForwardStatus::Cancelling => {
+                ("cancelling".to_owned(), section_style(state))
+            }
+            crate::ports::ForwardStatus::Failed(reason) => {
+                (format!("failed: {reason}"), danger_style(state))
+            }
+        };
+        let port = forward.remote_port.to_string();
+        let action = fit(
+            glyphs,
+            &action,
+            inner.saturating_sub(port.chars().count() + 1),
+        );
+        let padding = inner
+            .saturating_sub(port.chars().count() + Line::raw(&action).width())
+            .max(1);
+        lines.push(Line::from(vec![
+            Span::raw(format!("{pad}{port}")),
+            Span::raw(" ".repeat(padding)),
+            Span::styled(action, action_style),
+            Span::raw(pad.clone()),
+        ]));
+        lines.push(Line::styled(
+            format!(
+                "{pad}{}",
+                fit(
+                    glyphs,
+                    &format!(
+                        "reverse {} host {} {} client {}",
+                        glyphs.separator(),
+                        forward.remote_endpoint(),
+                        glyphs.arrow(),
+                        forward.local_endpoint()
+                    ),
+                    inner,
+                )
+            ),
+            section_style(state),
+        ));
+        targets.extend([
+            Some(MouseAction::PrefillCommand(format!(
+                ":reverse cancel {}",
+                forward.remote_port
+            ))),
+            None,
+        ]);
+    }
+}
+
+/// Configured services of the workspace in view, one row each: the glyph and
+/// name, then the state on the right. A service that is down prefills the
+/// `:service start` that brings it back.
+fn service_lines(
+    state: &ClientState,
+    glyphs: Glyphs,
+    workspace_id: Option<crate::core::WorkspaceId>,
+    width: u16,
+    lines: &mut Vec<Line<'static>>,
+    targets: &mut Vec<RowAction>,
+) {
+    let pad = chrome::pad(width);
+    let inner = chrome::inner_width(width);
+    let Some(services) = workspace_id
+        .and_then(|workspace_id| state.services.get(&workspace_id))
+        .filter(|services| !services.is_empty())
+    else {
+        return;
+    };
+    lines.extend([
+        Line::raw(""),
+        Line::styled(format!("{pad}SERVICES"), section_style(state)),
+    ]);
+    targets.extend([None, None]);
+    for service in services {
+        let health = match service.health {
+            ServiceHealth::Crashed { code: Some(code) } => format!("crashed {code}"),
+            health => health.word().to_owned(),
+        };
+        let name = fit(
+            glyphs,
+            &service.name,
+            inner.saturating_sub(Line::raw(&health).width() + 3),
+        );
+        let padding = inner
+            .saturating_sub(Line::raw(&name).width() + Line::raw(&health).width() + 2)
+            .max(1);
+        let style = service_style(state, service.health);
+        lines.push(Line::from(vec![
+            Span::raw(pad.clone()),
+            Span::styled(glyphs.service(service.health), style),
+            Span::raw(format!(" {name}")),
+            Span::raw(" ".repeat(padding)),
+            Span::styled(health, style),
+            Span::raw(pad.clone()),
+        ]));
+        targets.push(
+            matches!(
+                service.health,
+                ServiceHealth::Crashed { .. } | ServiceHealth::Stopped | ServiceHealth::Blocked
+            )
+            .then(|| {
+                MouseAction::PrefillCommand(format!(
+                    ":service start {}",
+                    crate::client::completion::quote(&service.name)
+                ))
+            }),
+        );
+    }
+}
+
 /// The dim second row: who is listening and on which address. Two rows per
 /// listener keeps the port and its forward state on one scannable column.
+/// What the listener serves leads when known, since two `node` rows are
+/// otherwise told apart only by port.
 fn listener_detail(glyphs: Glyphs, listener: &crate::ports::PortListener, process: &str) -> String {
+    let separator = glyphs.separator();
+    let served = listener
+        .http
+        .as_ref()
+        .map(|http| format!("{} {separator} ", http.label()))
+        .unwrap_or_default();
     format!(
-        "{process} {} {}:{}",
-        glyphs.separator(),
-        listener.bind_address,
-        listener.port
+        "{served}{process} {separator} {}:{}",
+        listener.bind_address, listener.port
     )
 }
 
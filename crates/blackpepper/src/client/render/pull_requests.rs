@@ -0,0 +1,183 @@
+//! The `:worktree prs` picker.
+//!
+//! One row per open pull request: number, draft flag, author, head branch,
+//! and title last so a long one runs off the edge instead of pushing the
+//! other columns out of line. Enter hands the number to `:worktree open`.
+
+use super::chrome;
+use super::glyph::Glyphs;
+use super::style::{
+    accent_style, mid_style, section_style, selected_style, ui_style, warning_style,
+};
+use crate::client::state::{MouseAction, MouseTarget};
+use crate::client::ClientState;
+use crate::core::OpenPullRequest;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+const TITLE: &str = "worktree prs";
+/// Gap between columns.
+const COLUMN_GAP: usize = 2;
+/// Number, draft, author, branch, title — see `row_cells`.
+const COLUMNS: usize = 5;
+
+pub(super) fn render_pull_requests(
+    state: &mut ClientState,
+    frame: &mut ratatui::Frame,
+    area: Rect,
+) {
+    let glyphs = Glyphs::of(state);
+    let separator = glyphs.separator();
+    let Some(view) = state.pull_requests.clone() else {
+        return;
+    };
+    let body = chrome::inner(area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(body);
+
+    // The colon and one space of breathing room either side of the title.
+    let lead = TITLE.len() + 2;
+    let hint = format!(
+        "esc close {separator} type to filter {separator} {} select {separator} enter open",
+        glyphs.updown()
+    );
+    let hint_pad = usize::from(rows[0].width)
+        .saturating_sub(lead + Line::raw(&hint).width())
+        .max(2);
+    let heading = Line::from(vec![
+        Span::styled(":", accent_style(state)),
+        Span::raw(TITLE),
+        Span::raw(" ".repeat(hint_pad)),
+        Span::styled(hint, section_style(state)),
+    ]);
+    frame.render_widget(Paragraph::new(heading).style(ui_style(state)), rows[0]);
+
+    let matches = view.matches();
+    let selected = view.selected.min(matches.len().saturating_sub(1));
+    let mut lines = vec![
+        Line::raw(""),
+        Line::styled(
+            format!(
+                "OPEN PULL REQUESTS {separator} {} of {}",
+                matches.len(),
+                view.pull_requests.len()
+            ),
+            section_style(state),
+        ),
+        Line::from(vec![
+            Span::raw(view.filter.clone()),
+            Span::styled(" ", Style::default().add_modifier(Modifier::REVERSED)),
+        ]),
+    ];
+    if matches.is_empty() {
+        lines.push(Line::styled(
+            "no pull request matches that filter",
+            section_style(state),
+        ));
+    }
+
+    let cells = matches
+        .iter()
+        .map(|pull_request| row_cells(pull_request))
+        .collect::<Vec<_>>();
+    let widths = (0..COLUMNS)
+        .map(|column| {
+            cells
+                .iter()
+                .map(|cells| Line::raw(&cells[column]).width())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let width = usize::from(rows[1].width);
+
+    // Keep the cursor on screen: the three header rows never scroll away.
+    let visible = usize::from(rows[1].height).saturating_sub(3);
+    let offset = selected.saturating_sub(visible.saturating_sub(1));
+    let mut row_actions = Vec::new();
+    for (index, cells) in cells.iter().enumerate().skip(offset).take(visible) {
+        let line_index = lines.len();
+        let padded = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let padding = width.saturating_sub(Line::raw(cell).width());
+                format!("{cell}{}", " ".repeat(padding + COLUMN_GAP))
+            })
+            .collect::<Vec<_>>();
+        if index == selected {
+            let text = padded.concat();
+            let fill = width.saturating_sub(Line::raw(&text).width());
+            lines.push(Line::styled(
+                format!("{text}{}", " ".repeat(fill)),
+                selected_style(state),
+            ));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled(padded[0].clone(), mid_style(state)),
+                Span::styled(padded[1].clone(), warning_style(state)),
+                Span::styled(padded[2].clone(), section_style(state)),
+                Span::styled(padded[3].clone(), section_style(state)),
+                Span::raw(padded[4].clone()),
+            ]));
+        }
+        row_actions.push((line_index, MouseAction::ChoosePullRequest(index)));
+    }
+
+    frame.render_widget(Paragraph::new(lines).style(ui_style(state)), rows[1]);
+    state.mouse_targets.push(MouseTarget {
+        area: rows[1],
+        action: MouseAction::ScrollPullRequests,
+    });
+    let close_x = lead + hint_pad;
+    if close_x < usize::from(rows[0].width) {
+        state.mouse_targets.push(MouseTarget {
+            area: Rect::new(
+                rows[0].x.saturating_add(close_x as u16),
+                rows[0].y,
+                Line::raw("esc close")
+                    .width()
+                    .min(usize::from(rows[0].width) - close_x) as u16,
+                1,
+            ),
+            action: MouseAction::ClosePullRequests,
+        });
+    }
+    state
+        .mouse_targets
+        .extend(row_actions.into_iter().filter_map(|(line, action)| {
+            (line < usize::from(rows[1].height)).then_some(MouseTarget {
+                area: Rect::new(
+                    rows[1].x,
+                    rows[1].y.saturating_add(line as u16),
+                    rows[1].width,
+                    1,
+                ),
+                action,
+            })
+        }));
+}
+
+/// One row's cells in display order, measured together so the columns line
+/// up whatever the longest author or branch is.
+fn row_cells(pull_request: &OpenPullRequest) -> [String; COLUMNS] {
+    [
+        format!("#{}", pull_request.number),
+        if pull_request.draft {
+            "draft".to_owned()
+        } else {
+            String::new()
+        },
+        if pull_request.author.is_empty() {
+            "ghost".to_owned()
+        } else {
+            pull_request.author.clone()
+        },
+        pull_request.branch.clone(),
+        pull_request.title.clone(),
+    ]
+}
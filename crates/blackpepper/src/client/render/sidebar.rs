@@ -1,8 +1,8 @@
 use super::chrome;
 use super::glyph::Glyphs;
 use super::style::{
-    accent_style, connection_style, danger_style, list_status_span, list_status_text, mid_style,
-    panel_style, section_style, selected_style,
+    accent_style, ci_style, connection_style, danger_style, list_status_span, list_status_text,
+    mid_style, panel_style, section_style, selected_style,
 };
 use crate::client::state::{MouseAction, MouseTarget};
 use crate::client::{ClientState, HostConnection};
@@ -19,6 +19,7 @@ pub(super) fn render_sidebar(state: &mut ClientState, frame: &mut ratatui::Frame
     let inner = chrome::inner_width(area.width);
     let mut lines = vec![Line::styled(format!("{pad}HOSTS"), section_style(state))];
     let mut row_actions = Vec::new();
+    let mut status_actions = Vec::new();
     let mut selected_last_line = None;
     for (index, host) in state.tree.iter().enumerate() {
         // One blank row between hosts, so a long tree reads as groups rather
@@ -77,13 +78,30 @@ pub(super) fn render_sidebar(state: &mut ClientState, frame: &mut ratatui::Frame
                 let marker = if active { glyphs.connected() } else { " " };
                 let detail = state.status_elapsed(workspace.id, workspace.status);
                 let status = list_status_text(state, workspace.status, detail.as_deref());
-                let (label, padding) = aligned_label(glyphs, &workspace.label, &status, 3, inner);
+                // CI sits just before the agent status; only attached
+                // workspaces have an overview to report it.
+                let ci = state
+                    .overviews
+                    .get(&workspace.id)
+                    .and_then(|overview| overview.ci);
+                let ci_text = ci
+                    .map(|ci| format!("ci{} ", glyphs.ci(ci)))
+                    .unwrap_or_default();
+                let (label, padding) = aligned_label(
+                    glyphs,
+                    &workspace.label,
+                    &format!("{ci_text}{status}"),
+                    3,
+                    inner,
+                );
                 let workspace_row = lines.len();
+                let status_start =
+                    Line::raw(format!("{pad}{marker}  {label}{padding}{ci_text}")).width();
                 if selected {
                     // One span across the whole row: the design bleeds the
                     // selection through the gutter to both panel edges.
                     lines.push(Line::styled(
-                        format!("{pad}{marker}  {label}{padding}{status}{pad}"),
+                        format!("{pad}{marker}  {label}{padding}{ci_text}{status}{pad}"),
                         selected_style(state),
                     ));
                 } else {
@@ -96,11 +114,28 @@ pub(super) fn render_sidebar(state: &mut ClientState, frame: &mut ratatui::Frame
                         Span::raw(format!("{pad}{marker}  ")),
                         Span::styled(label, workspace_style),
                         Span::raw(padding),
+                        Span::styled(
+                            ci_text,
+                            ci.map(|ci| ci_style(state, ci)).unwrap_or_default(),
+                        ),
                         list_status_span(state, workspace.status, detail.as_deref()),
                         Span::raw(pad.clone()),
                     ]));
                 }
                 row_actions.push((workspace_row, MouseAction::SelectWorkspace(workspace.id)));
+                if let Some(run_id) = state.status_agent(workspace.id) {
+                    // The status text itself jumps to the run it reports,
+                    // while the rest of the row keeps plain selection.
+                    status_actions.push((
+                        workspace_row,
+                        status_start,
+                        Line::raw(&status).width(),
+                        MouseAction::GoToAgent {
+                            workspace_id: workspace.id,
+                            run_id,
+                        },
+                    ));
+                }
                 // Setup failure is durable workspace state, not an agent
                 // status. Give it a full semantic row so neither meaning is
                 // clipped inside the fixed 32-column navigation surface.
@@ -156,6 +191,25 @@ pub(super) fn render_sidebar(state: &mut ClientState, frame: &mut ratatui::Frame
                 action,
             })
         }));
+    state
+        .mouse_targets
+        .extend(
+            status_actions
+                .into_iter()
+                .filter_map(|(line, start, width, action)| {
+                    let visible = line.checked_sub(usize::from(scroll))?;
+                    (visible < usize::from(area.height) && start < usize::from(area.width))
+                        .then_some(MouseTarget {
+                            area: Rect::new(
+                                area.x.saturating_add(start as u16),
+                                area.y.saturating_add(visible as u16),
+                                width.min(usize::from(area.width) - start) as u16,
+                                1,
+                            ),
+                            action,
+                        })
+                }),
+        );
     frame.render_widget(
         Paragraph::new(lines)
             .style(panel_style(state))
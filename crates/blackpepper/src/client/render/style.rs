@@ -1,6 +1,8 @@
 use super::glyph::Glyphs;
+use crate::client::state::ServiceHealth;
 use crate::client::{ClientState, DisplayStatus, HostConnection};
 use crate::client_config::ColorTier;
+use crate::worktrunk::CiState;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Span;
 
@@ -249,6 +251,28 @@ pub(super) fn list_status_span(
     )
 }
 
+/// CI verdicts take the agent-status palette: pending reads like work in
+/// flight, passing like done, failing like an exit.
+pub(super) fn ci_style(state: &ClientState, ci: CiState) -> Style {
+    match ci {
+        CiState::Pending => semantic(state, theme(state).cyan, Color::Cyan),
+        CiState::Passing => semantic(state, theme(state).green, Color::Green),
+        CiState::Failing => semantic(state, theme(state).red, Color::Red),
+    }
+}
+
+pub(super) fn service_style(state: &ClientState, health: ServiceHealth) -> Style {
+    match health {
+        ServiceHealth::Starting | ServiceHealth::Restarting => {
+            semantic(state, theme(state).cyan, Color::Cyan)
+        }
+        ServiceHealth::Ready => semantic(state, theme(state).green, Color::Green),
+        ServiceHealth::Crashed { .. } => semantic(state, theme(state).red, Color::Red),
+        ServiceHealth::Stopped => section_style(state),
+        ServiceHealth::Blocked => semantic(state, theme(state).yellow, Color::Yellow),
+    }
+}
+
 /// Host connection colors reuse the agent-status palette, so green/yellow/red
 /// mean the same severity in both columns even though the vocabularies differ.
 pub(super) fn connection_style(state: &ClientState, connection: HostConnection) -> Style {
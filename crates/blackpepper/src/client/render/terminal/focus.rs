@@ -96,8 +96,10 @@ pub(in crate::client::render) fn render_approval(
 /// Style the review body: its field labels are dim, its values ink, so the
 /// exact command stands out from the scaffolding around it.
 fn review_lines(state: &ClientState, review: &str) -> Vec<Line<'static>> {
-    const LABELS: [&str; 4] = [
+    const LABELS: [&str; 6] = [
         "mutation",
+        "removals",
+        "after approval",
         "unapproved project hooks",
         "project hooks",
         "approval binds to this exact Worktrunk command and project hook plan.",
@@ -53,6 +53,7 @@ fn running_state() -> ClientState {
         blocker_sequence: 0,
         blocker_observed_at_ms: None,
         interrupted_after_sequence: None,
+        manifest_warning: None,
     };
     state.agent_runs.insert(workspace_id, vec![run]);
     state.refresh_workspace_status(workspace_id);
@@ -149,6 +150,7 @@ fn the_header_carries_branch_divergence_and_pull_request() {
             }),
             active_tab: Some(2),
             tab_count: Some(4),
+            ci: None,
         },
     );
     let terminal = draw(&mut state, 120, 24);
@@ -215,8 +217,10 @@ fn completion_offers_only_listeners_this_client_has_discovered() {
                 process: Some("node".to_owned()),
                 workspace_path: Some(workspace.root_path.clone().into()),
                 attribution: crate::ports::AttributionConfidence::ExactCwd,
+                http: None,
             }],
             completeness: crate::ports::ProbeCompleteness::Full,
+            sockets: Vec::new(),
             warning: None,
         },
     );
@@ -259,9 +263,11 @@ fn completion_uses_exact_addresses_when_a_port_has_multiple_listeners() {
                     process: Some("node".to_owned()),
                     workspace_path: Some(workspace.root_path.clone().into()),
                     attribution: crate::ports::AttributionConfidence::ExactCwd,
+                    http: None,
                 })
                 .collect(),
             completeness: crate::ports::ProbeCompleteness::Full,
+            sockets: Vec::new(),
             warning: None,
         },
     );
@@ -540,8 +546,10 @@ fn the_completion_list_overlays_rather_than_resizing_the_session() {
                 process: Some("node".to_owned()),
                 workspace_path: Some(workspace.root_path.clone().into()),
                 attribution: crate::ports::AttributionConfidence::ExactCwd,
+                http: None,
             }],
             completeness: crate::ports::ProbeCompleteness::Full,
+            sockets: Vec::new(),
             warning: None,
         },
     );
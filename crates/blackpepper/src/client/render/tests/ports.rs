@@ -18,9 +18,11 @@ fn state_with_ports(count: u16) -> crate::client::ClientState {
                     process: Some(format!("service-{offset}")),
                     workspace_path: Some(workspace.root_path.clone().into()),
                     attribution: AttributionConfidence::ExactCwd,
+                    http: None,
                 })
                 .collect(),
             completeness: ProbeCompleteness::Full,
+            sockets: Vec::new(),
             warning: None,
         },
     );
@@ -95,6 +97,130 @@ fn compact_ports_panel_scrolls_all_listeners_and_rebuilds_click_targets() {
     assert_eq!(targets[3].1.remote_port, 4_006);
 }
 
+#[test]
+fn a_forward_on_another_local_port_names_that_port() {
+    let mut state = state_with_ports(2);
+    let workspace = state.snapshot.workspaces[0].clone();
+    for (remote_port, local_port, status) in [
+        (4_000, 14_000, crate::ports::ForwardStatus::Active),
+        (4_001, 14_001, crate::ports::ForwardStatus::PortConflict),
+    ] {
+        state.forwards.push(crate::ports::ForwardState {
+            id: uuid::Uuid::new_v4(),
+            host_id: workspace.host_id,
+            workspace_id: workspace.id,
+            remote_host: "127.0.0.1".to_owned(),
+            remote_port,
+            requested_local_address: ([127, 0, 0, 1], local_port).into(),
+            local_address: ([127, 0, 0, 1], local_port).into(),
+            status,
+        });
+    }
+    let mut terminal = Terminal::new(TestBackend::new(30, 8)).unwrap();
+
+    terminal
+        .draw(|frame| super::super::ports::render_ports(&mut state, frame, Rect::new(0, 0, 30, 8)))
+        .unwrap();
+
+    let rendered = buffer_text(&terminal);
+    assert!(
+        rendered.contains("14000"),
+        "missing local port in:\n{rendered}"
+    );
+    assert!(
+        rendered.contains("conflict 14001"),
+        "missing conflicting port in:\n{rendered}"
+    );
+}
+
+#[test]
+fn an_http_label_leads_the_listener_detail_row() {
+    let mut state = state_with_ports(1);
+    let workspace = state.snapshot.workspaces[0].clone();
+    state.ports.get_mut(&workspace.host_id).unwrap().listeners[0].http =
+        Some(crate::ports::HttpLabel {
+            status: 200,
+            title: Some("Billing".to_owned()),
+            server: Some("Vite".to_owned()),
+        });
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+
+    terminal
+        .draw(|frame| super::super::ports::render_ports(&mut state, frame, Rect::new(0, 0, 40, 10)))
+        .unwrap();
+
+    let rendered = buffer_text(&terminal);
+    assert!(
+        rendered.contains("Billing · service-0"),
+        "missing HTTP label in:\n{rendered}"
+    );
+}
+
+#[test]
+fn workspace_sockets_list_by_name_and_prefill_their_unix_command() {
+    let mut state = state_with_ports(1);
+    let workspace = state.snapshot.workspaces[0].clone();
+    let path = format!("{}/tmp/.s.PGSQL.5432", workspace.root_path);
+    state
+        .ports
+        .get_mut(&workspace.host_id)
+        .unwrap()
+        .sockets
+        .push(crate::ports::SocketListener {
+            path: path.clone(),
+            pid: Some(9),
+            process: Some("postgres".to_owned()),
+            workspace_path: Some(workspace.root_path.clone().into()),
+            attribution: AttributionConfidence::ExactCwd,
+        });
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+
+    terminal
+        .draw(|frame| super::super::ports::render_ports(&mut state, frame, Rect::new(0, 0, 40, 10)))
+        .unwrap();
+
+    let rendered = buffer_text(&terminal);
+    assert!(
+        rendered.contains(".s.PGSQL.5432") && rendered.contains("postgres · unix"),
+        "missing socket row in:\n{rendered}"
+    );
+    let command = format!(":forward unix:{path}");
+    assert!(state.mouse_targets.iter().any(|target| matches!(
+        &target.action,
+        crate::client::state::MouseAction::PrefillCommand(prefill) if *prefill == command
+    )));
+}
+
+#[test]
+fn reverse_forwards_follow_the_listeners_with_their_cancel_command() {
+    let mut state = state_with_ports(1);
+    let workspace = state.snapshot.workspaces[0].clone();
+    state
+        .reverse_forwards
+        .push(crate::ports::ReverseForwardState::new(
+            workspace.host_id,
+            workspace.id,
+            5432,
+            15432,
+        ));
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+
+    terminal
+        .draw(|frame| super::super::ports::render_ports(&mut state, frame, Rect::new(0, 0, 40, 10)))
+        .unwrap();
+
+    let rendered = buffer_text(&terminal);
+    assert!(
+        rendered.contains("15432") && rendered.contains("→ client 5432"),
+        "missing reverse row in:\n{rendered}"
+    );
+    assert!(state.mouse_targets.iter().any(|target| matches!(
+        &target.action,
+        crate::client::state::MouseAction::PrefillCommand(command)
+            if command == ":reverse cancel 15432"
+    )));
+}
+
 #[test]
 fn manage_footer_keeps_enter_bound_to_workspace_attach() {
     let state = state_with_ports(1);
@@ -102,3 +228,46 @@ fn manage_footer_keeps_enter_bound_to_workspace_attach() {
     assert!(hint.contains("enter attach"));
     assert!(!hint.contains("enter forward"));
 }
+
+#[test]
+fn configured_services_show_their_health_and_a_down_one_offers_a_restart() {
+    let mut state = state_with_ports(0);
+    let workspace_id = state.snapshot.workspaces[0].id;
+    let mut crashed = crate::client::state::ServiceStatus::new("api worker");
+    crashed.health = crate::client::state::ServiceHealth::Crashed { code: Some(1) };
+    let mut ready = crate::client::state::ServiceStatus::new("web");
+    ready.health = crate::client::state::ServiceHealth::Ready;
+    state.services.insert(workspace_id, vec![ready, crashed]);
+    let mut terminal = Terminal::new(TestBackend::new(30, 10)).unwrap();
+
+    terminal
+        .draw(|frame| super::super::ports::render_ports(&mut state, frame, Rect::new(0, 0, 30, 10)))
+        .unwrap();
+
+    let rendered = buffer_text(&terminal);
+    assert!(
+        rendered.contains("SERVICES"),
+        "missing section in:\n{rendered}"
+    );
+    assert!(
+        rendered.contains("✓ web") && rendered.contains("ready"),
+        "missing ready row in:\n{rendered}"
+    );
+    assert!(
+        rendered.contains("× api worker") && rendered.contains("crashed 1"),
+        "missing crashed row in:\n{rendered}"
+    );
+    let prefills = state
+        .mouse_targets
+        .iter()
+        .filter_map(|target| match &target.action {
+            crate::client::state::MouseAction::PrefillCommand(command)
+                if command.starts_with(":service") =>
+            {
+                Some(command.as_str())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(prefills, [":service start 'api worker'"]);
+}
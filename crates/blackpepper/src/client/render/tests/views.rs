@@ -56,6 +56,7 @@ fn narrow_approval_shows_repository_exact_plan_hooks_and_approve() {
             digest: "0".repeat(64),
         },
         review: "mutation\nwt create feature/auth --base main --foreground --format=json\n\nunapproved project hooks\npost-create: ./scripts/bootstrap.sh\n\napproval binds to this exact Worktrunk command and project hook plan.\n:approve  run · esc dismiss · ↑↓ scroll".to_owned(),
+        removes: Vec::new(),
     });
 
     let rendered = buffer_text(&draw(&mut state, 40, 24));
@@ -144,3 +145,279 @@ fn narrow_detail_remains_visible_beside_the_workspace_selector() {
         crate::client::state::MouseAction::CloseDetail
     )));
 }
+
+fn agent_run(
+    provider: crate::agent_status::Provider,
+    state: crate::agent_status::AgentState,
+    tab_id: u64,
+    seconds_ago: u64,
+) -> crate::client::state::AgentRunView {
+    let run_id = crate::core::AgentRunId::new();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    crate::client::state::AgentRunView {
+        run_id,
+        pane_id: crate::core::PaneId::new(),
+        tab_id,
+        provider,
+        zellij_pane_id: tab_id.to_string(),
+        needs_input_capability: "exact".to_owned(),
+        snapshot: Some(crate::agent_status::AgentSnapshot {
+            run_id,
+            provider,
+            state,
+            revision: 1,
+            completion_revision: 0,
+            seen_completion_revision: 0,
+            last_event_sequence: Some(1),
+            last_event_at_ms: Some(now - seconds_ago * 1000),
+            integration_health: crate::agent_status::IntegrationHealth::Healthy {
+                integration_version: Some(1),
+            },
+            needs_input_capability: crate::agent_status::NeedsInputCapability::ProviderEvents,
+            completion_suppressed: false,
+        }),
+        explain: None,
+        snapshot_error: None,
+        seen_completion_revision: 0,
+        blocker: None,
+        blocker_watcher_instance: None,
+        blocker_sequence: 0,
+        blocker_observed_at_ms: None,
+        interrupted_after_sequence: None,
+        manifest_warning: None,
+    }
+}
+
+#[test]
+fn agents_dashboard_lists_waiting_runs_first_and_opens_the_row_clicked() {
+    use crate::agent_status::{AgentState, Provider};
+    let mut state = workspace_state();
+    let workspace_id = state.selected_workspace.unwrap();
+    let asking = agent_run(Provider::Claude, AgentState::NeedsInput, 4, 600);
+    let asking_id = asking.run_id;
+    state.agent_runs.insert(
+        workspace_id,
+        vec![
+            agent_run(Provider::Codex, AgentState::Working, 3, 5),
+            asking,
+        ],
+    );
+    state.agents = Some(crate::client::state::AgentsView::default());
+
+    let terminal = draw(&mut state, 100, 20);
+    let rendered = buffer_text(&terminal);
+    assert!(rendered.contains(":agents"));
+    assert!(rendered.contains("AGENTS · asks first"));
+    let claude = rendered.find("claude").expect("missing claude row");
+    let codex = rendered.find("codex").expect("missing codex row");
+    assert!(claude < codex, "asking run is not first in:\n{rendered}");
+    assert!(rendered.contains("tab 4") && rendered.contains("blackpepper"));
+    assert!(state.mouse_targets.iter().any(|target| matches!(
+        target.action,
+        crate::client::state::MouseAction::GoToAgent { run_id, .. } if run_id == asking_id
+    )));
+    assert_eq!(state.agents_choice(), Some((workspace_id, asking_id)));
+
+    state.agents = Some(crate::client::state::AgentsView {
+        selected: 0,
+        order: crate::client::state::AgentOrder::Recent,
+    });
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    assert!(rendered.find("codex").unwrap() < rendered.find("claude").unwrap());
+}
+
+#[test]
+fn an_asking_row_numbers_the_answers_its_rule_declares() {
+    use crate::agent_status::{AgentState, BlockerAnswer, Provider};
+    let mut state = workspace_state();
+    let workspace_id = state.selected_workspace.unwrap();
+    let mut asking = agent_run(Provider::Claude, AgentState::Working, 4, 5);
+    asking.blocker = Some(crate::agent_status::BlockerExplain {
+        provider: Provider::Claude,
+        manifest: crate::agent_status::BlockerManifestSource::Bundled,
+        manifest_version: "1".to_owned(),
+        rule_id: "permission_prompt".to_owned(),
+        confidence: crate::agent_status::BlockerConfidence::High,
+        priority: 900,
+        answers: vec![
+            BlockerAnswer {
+                label: "yes".to_owned(),
+                key: crate::agent_status::AnswerKey::Char(b'1'),
+            },
+            BlockerAnswer {
+                label: "no".to_owned(),
+                key: crate::agent_status::AnswerKey::Esc,
+            },
+        ],
+    });
+    state.agent_runs.insert(
+        workspace_id,
+        vec![
+            agent_run(Provider::Codex, AgentState::Working, 3, 5),
+            asking,
+        ],
+    );
+    state.agents = Some(crate::client::state::AgentsView::default());
+
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    assert!(
+        rendered.contains("tab 4  1 yes · 2 no"),
+        "missing answers in:\n{rendered}"
+    );
+    let after_codex = &rendered[rendered.find("tab 3").unwrap() + "tab 3".len()..];
+    assert!(after_codex.find("yes").is_none(), "{rendered}");
+}
+
+#[test]
+fn the_worktree_browser_lists_changes_commits_and_remote_branches() {
+    let mut state = workspace_state();
+    let workspace_id = state.selected_workspace.unwrap();
+    let list = crate::worktrunk::WorktreeList::parse(
+        r#"{"schema":2,"repo":{"default_branch":"main"},"items":[
+          {"branch":"feature","head":{"sha":"abc1234","short_sha":"abc1234","subject":"Add billing","committed_at":"2026-01-02T03:04:05Z"},"worktree":{"path":"/repo.feature","current":true,"changes":{"staged":true,"untracked":true}}},
+          {"branch":"review","remote":"origin","head":null}
+        ]}"#,
+    )
+    .unwrap();
+    state.worktrees = Some(crate::client::state::WorktreesView {
+        workspace_id,
+        list,
+        selected: 1,
+    });
+
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    assert!(rendered.contains(":worktree list"));
+    assert!(rendered.contains("WORKTREES · default main"));
+    for cell in [
+        "feature",
+        "current",
+        "+?",
+        "abc1234",
+        "2026-01-02",
+        "Add billing",
+    ] {
+        assert!(rendered.contains(cell), "missing {cell} in:\n{rendered}");
+    }
+    assert!(rendered.contains("review") && rendered.contains("origin only"));
+    assert!(state.mouse_targets.iter().any(|target| matches!(
+        target.action,
+        crate::client::state::MouseAction::ChooseWorktree(1)
+    )));
+}
+
+#[test]
+fn ci_shows_in_the_worktree_browser_and_the_sidebar_row() {
+    let mut state = workspace_state();
+    let workspace_id = state.selected_workspace.unwrap();
+    state.overviews.insert(
+        workspace_id,
+        crate::core::WorkspaceOverview {
+            ci: Some(crate::worktrunk::CiState::Failing),
+            ..Default::default()
+        },
+    );
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    assert!(
+        rendered.contains("ci×"),
+        "missing sidebar CI in:\n{rendered}"
+    );
+
+    let list = crate::worktrunk::WorktreeList::parse(
+        r#"{"schema":2,"repo":{"default_branch":"main"},"collected":{"ci":true},"items":[
+          {"branch":"feature","ci":{"status":"passed"}},
+          {"branch":"busy","ci":{"status":"running"}}
+        ]}"#,
+    )
+    .unwrap();
+    state.worktrees = Some(crate::client::state::WorktreesView {
+        workspace_id,
+        list,
+        selected: 0,
+    });
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    for cell in ["✓ ci passing", "◐ ci pending"] {
+        assert!(rendered.contains(cell), "missing {cell} in:\n{rendered}");
+    }
+}
+
+#[test]
+fn the_pull_request_picker_shows_number_author_draft_and_title() {
+    let mut state = workspace_state();
+    let workspace_id = state.selected_workspace.unwrap();
+    let pull_request = |number, title: &str, author: &str, draft| crate::core::OpenPullRequest {
+        number,
+        title: title.to_owned(),
+        author: author.to_owned(),
+        draft,
+        branch: format!("branch-{number}"),
+    };
+    state.pull_requests = Some(crate::client::state::PullRequestsView {
+        workspace_id,
+        pull_requests: vec![
+            pull_request(42, "Add billing", "ana", true),
+            pull_request(7, "Fix login", "", false),
+        ],
+        filter: String::new(),
+        selected: 1,
+    });
+
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    assert!(rendered.contains(":worktree prs"));
+    assert!(rendered.contains("OPEN PULL REQUESTS · 2 of 2"));
+    for cell in [
+        "#42",
+        "draft",
+        "ana",
+        "branch-42",
+        "Add billing",
+        "#7",
+        "ghost",
+    ] {
+        assert!(rendered.contains(cell), "missing {cell} in:\n{rendered}");
+    }
+    assert!(state.mouse_targets.iter().any(|target| matches!(
+        target.action,
+        crate::client::state::MouseAction::ChoosePullRequest(1)
+    )));
+
+    state.pull_requests.as_mut().unwrap().filter = "login".to_owned();
+    let rendered = buffer_text(&draw(&mut state, 100, 20));
+    assert!(rendered.contains("1 of 2") && !rendered.contains("Add billing"));
+}
+
+#[test]
+fn a_workspace_status_row_jumps_to_the_run_it_reports() {
+    use crate::agent_status::{AgentState, Provider};
+    let mut state = workspace_state();
+    let workspace_id = state.selected_workspace.unwrap();
+    let asking = agent_run(Provider::Claude, AgentState::NeedsInput, 4, 60);
+    let asking_id = asking.run_id;
+    state.agent_runs.insert(
+        workspace_id,
+        vec![
+            agent_run(Provider::Codex, AgentState::Working, 3, 5),
+            asking,
+        ],
+    );
+    state.refresh_workspace_status(workspace_id);
+    state.rebuild_tree();
+
+    let rendered = buffer_text(&draw(&mut state, 110, 24));
+    assert!(rendered.contains("g agent"), "missing hint in:\n{rendered}");
+    let status_target = state
+        .mouse_targets
+        .iter()
+        .find(|target| {
+            matches!(
+                target.action,
+                crate::client::state::MouseAction::GoToAgent { run_id, .. } if run_id == asking_id
+            ) && target.area.x < 32
+        })
+        .expect("status text is not clickable");
+    // Only the status text jumps; the label keeps plain selection.
+    assert!(status_target.area.x > 3);
+    assert!(status_target.area.width < 12);
+}
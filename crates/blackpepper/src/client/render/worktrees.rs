@@ -0,0 +1,243 @@
+//! The `:worktree list` browser.
+//!
+//! One row per Worktrunk item: branch, where it stands, uncommitted changes,
+//! CI when Worktrunk collected it, and its last commit. Rows are not actions by themselves; Enter and `d`
+//! hand the selected branch to the same reviewed mutations the commands use.
+
+use super::chrome;
+use super::glyph::Glyphs;
+use super::style::{
+    accent_style, ci_style, mid_style, section_style, selected_style, ui_style, warning_style,
+};
+use crate::client::state::{MouseAction, MouseTarget};
+use crate::client::ClientState;
+use crate::worktrunk::{Changes, Ci, WorktreeItem};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+/// Gap between columns.
+const COLUMN_GAP: usize = 2;
+/// Branch, tags, changes, CI, commit, date, subject — see `row_cells`.
+const COLUMNS: usize = 7;
+
+pub(super) fn render_worktrees(state: &mut ClientState, frame: &mut ratatui::Frame, area: Rect) {
+    let glyphs = Glyphs::of(state);
+    let separator = glyphs.separator();
+    let Some(view) = state.worktrees.clone() else {
+        return;
+    };
+    let body = chrome::inner(area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(body);
+
+    let hint = format!(
+        "esc close {separator} {} select {separator} enter open {separator} d remove",
+        glyphs.updown()
+    );
+    let hint_pad = usize::from(rows[0].width)
+        .saturating_sub(15 + Line::raw(&hint).width())
+        .max(2);
+    let heading = Line::from(vec![
+        Span::styled(":", accent_style(state)),
+        Span::raw("worktree list"),
+        Span::raw(" ".repeat(hint_pad)),
+        Span::styled(hint, section_style(state)),
+    ]);
+    frame.render_widget(Paragraph::new(heading).style(ui_style(state)), rows[0]);
+
+    let items = &view.list.items;
+    let selected = view.selected.min(items.len().saturating_sub(1));
+    let mut lines = vec![
+        Line::raw(""),
+        Line::styled(
+            format!(
+                "WORKTREES {separator} default {}",
+                view.list.repo.default_branch
+            ),
+            section_style(state),
+        ),
+    ];
+
+    let cells = items
+        .iter()
+        .map(|item| row_cells(state, view.workspace_id, item, glyphs))
+        .collect::<Vec<_>>();
+    let widths = (0..COLUMNS)
+        .map(|column| {
+            cells
+                .iter()
+                .map(|cells| Line::raw(&cells[column]).width())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let width = usize::from(rows[1].width);
+
+    // Keep the cursor on screen: the two header rows never scroll away.
+    let visible = usize::from(rows[1].height).saturating_sub(2);
+    let offset = selected.saturating_sub(visible.saturating_sub(1));
+    let mut row_actions = Vec::new();
+    for (index, cells) in cells.iter().enumerate().skip(offset).take(visible) {
+        let line_index = lines.len();
+        let padded = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                // A column no row uses, like CI when it was not collected,
+                // takes no gap either.
+                if *width == 0 {
+                    return String::new();
+                }
+                let padding = width.saturating_sub(Line::raw(cell).width());
+                format!("{cell}{}", " ".repeat(padding + COLUMN_GAP))
+            })
+            .collect::<Vec<_>>();
+        if index == selected {
+            let text = padded.concat();
+            let fill = width.saturating_sub(Line::raw(&text).width());
+            lines.push(Line::styled(
+                format!("{text}{}", " ".repeat(fill)),
+                selected_style(state),
+            ));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled(padded[0].clone(), mid_style(state)),
+                Span::styled(padded[1].clone(), section_style(state)),
+                Span::styled(padded[2].clone(), warning_style(state)),
+                Span::styled(
+                    padded[3].clone(),
+                    items[index]
+                        .ci
+                        .as_ref()
+                        .and_then(Ci::state)
+                        .map(|ci| ci_style(state, ci))
+                        .unwrap_or_default(),
+                ),
+                Span::styled(padded[4].clone(), section_style(state)),
+                Span::styled(padded[5].clone(), section_style(state)),
+                Span::raw(padded[6].clone()),
+            ]));
+        }
+        row_actions.push((line_index, MouseAction::ChooseWorktree(index)));
+    }
+
+    frame.render_widget(Paragraph::new(lines).style(ui_style(state)), rows[1]);
+    state.mouse_targets.push(MouseTarget {
+        area: rows[1],
+        action: MouseAction::ScrollWorktrees,
+    });
+    let close_x = 15 + hint_pad;
+    if close_x < usize::from(rows[0].width) {
+        state.mouse_targets.push(MouseTarget {
+            area: Rect::new(
+                rows[0].x.saturating_add(close_x as u16),
+                rows[0].y,
+                Line::raw("esc close")
+                    .width()
+                    .min(usize::from(rows[0].width) - close_x) as u16,
+                1,
+            ),
+            action: MouseAction::CloseWorktrees,
+        });
+    }
+    state
+        .mouse_targets
+        .extend(row_actions.into_iter().filter_map(|(line, action)| {
+            (line < usize::from(rows[1].height)).then_some(MouseTarget {
+                area: Rect::new(
+                    rows[1].x,
+                    rows[1].y.saturating_add(line as u16),
+                    rows[1].width,
+                    1,
+                ),
+                action,
+            })
+        }));
+}
+
+/// One row's cells in display order, measured together so every column lines
+/// up however long the longest branch name is.
+fn row_cells(
+    state: &ClientState,
+    listed: crate::core::WorkspaceId,
+    item: &WorktreeItem,
+    glyphs: Glyphs,
+) -> [String; COLUMNS] {
+    let separator = glyphs.separator();
+    let mut tags = Vec::new();
+    match &item.worktree {
+        Some(worktree) => {
+            for (set, tag) in [
+                (worktree.main, "main"),
+                (worktree.current, "current"),
+                (worktree.detached, "detached"),
+                (worktree.branch_mismatch, "branch mismatch"),
+            ] {
+                if set {
+                    tags.push(tag.to_owned());
+                }
+            }
+            if crate::client::actions::registered_worktree(state, listed, &worktree.path).is_some()
+            {
+                tags.push("registered".to_owned());
+            }
+        }
+        None => tags.push(match &item.remote {
+            Some(remote) => format!("{remote} only"),
+            None => "no worktree".to_owned(),
+        }),
+    }
+    let changes = item
+        .worktree
+        .as_ref()
+        .and_then(|worktree| worktree.changes.as_ref())
+        .map(change_markers)
+        .unwrap_or_default();
+    let ci = item
+        .ci
+        .as_ref()
+        .and_then(Ci::state)
+        .map(|ci| format!("{} ci {}", glyphs.ci(ci), ci.word()))
+        .unwrap_or_default();
+    let (sha, date, subject) = item
+        .head
+        .as_ref()
+        .map(|head| {
+            (
+                head.short_sha.clone(),
+                // RFC 3339; the day is enough to tell stale from fresh.
+                head.committed_at.chars().take(10).collect(),
+                head.subject.clone(),
+            )
+        })
+        .unwrap_or_default();
+    [
+        item.branch
+            .clone()
+            .unwrap_or_else(|| "(detached)".to_owned()),
+        tags.join(&format!(" {separator} ")),
+        changes,
+        ci,
+        sha,
+        date,
+        subject,
+    ]
+}
+
+/// Git-status letters, kept to ASCII so they sit outside the glyph budget.
+fn change_markers(changes: &Changes) -> String {
+    [
+        (changes.staged, '+'),
+        (changes.modified, '!'),
+        (changes.untracked, '?'),
+        (changes.renamed, 'r'),
+        (changes.deleted, 'd'),
+        (changes.conflicted, '='),
+    ]
+    .into_iter()
+    .filter_map(|(set, marker)| set.then_some(marker))
+    .collect()
+}
@@ -4,6 +4,7 @@ mod connection_restore;
 mod connection_update;
 pub(super) mod operations;
 mod periodic;
+mod saved_forwards;
 mod terminal_io;
 mod terminal_session;
 mod termination_signals;
@@ -33,6 +34,7 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     let (event_tx, event_rx) = mpsc::channel();
     let mut state = ClientState::new(config, snapshot, event_tx.clone());
     let startup_warnings = runtime.take_startup_warnings();
+    show_blocked_services(&mut state, &mut runtime);
     for host in &state.snapshot.hosts {
         state.connections.insert(
             host.id,
@@ -58,6 +60,19 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     if let Some(error) = agent_recovery_error {
         startup_messages.push(error);
     }
+    let (mut saved_forwards, previous_forwards) =
+        saved_forwards::SavedForwards::open(runtime.forwards_path());
+    match previous_forwards {
+        Ok(forwards) => {
+            let restored = saved_forwards::restore(&mut state, &mut runtime, forwards);
+            if restored > 0 {
+                state.set_output(format!(
+                    "Restored {restored} port forward(s) from the last session; remote ones return when their host connects."
+                ));
+            }
+        }
+        Err(error) => startup_messages.push(error),
+    }
     if !startup_messages.is_empty() {
         state.set_detail("Startup warnings", startup_messages.join("\n\n"));
         state.set_output(
@@ -79,6 +94,7 @@ pub fn run() -> Result<(), Box<dyn Error>> {
         &mut terminal_session,
         &mut state,
         &mut runtime,
+        &mut saved_forwards,
         event_rx,
         event_tx,
         termination_signals.pending(),
@@ -101,11 +117,13 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     terminal_session: &mut TerminalSessionGuard,
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
+    saved_forwards: &mut saved_forwards::SavedForwards,
     event_rx: mpsc::Receiver<ClientEvent>,
     event_tx: Sender<ClientEvent>,
     termination_signal: &AtomicUsize,
@@ -186,6 +204,13 @@ fn run_loop(
             if state.expire_transient_output() {
                 dirty = true;
             }
+            // Every forward mutation arrives as an event or connection update,
+            // both of which mark the frame dirty.
+            if dirty {
+                if let Err(error) = saved_forwards.record(state) {
+                    state.set_output(error);
+                }
+            }
             if last_draw.elapsed() >= FRAME_INTERVAL {
                 if dirty {
                     flush_input_modes(terminal, state)?;
@@ -214,6 +239,13 @@ fn run_loop(
     Ok(())
 }
 
+/// Services an attach or restore left unstarted, wherever that work ran.
+fn show_blocked_services(state: &mut ClientState, runtime: &mut ClientRuntime) {
+    for service in runtime.take_blocked_services() {
+        state.block_service(service.workspace_id, service.name, service.reason);
+    }
+}
+
 fn dispatch_event(
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
@@ -262,6 +294,7 @@ fn dispatch_event(
     // ownership even if the explicit operation finishes before its result is
     // eventually dequeued.
     periodic::invalidate_owned(state, runtime, periodic);
+    show_blocked_services(state, runtime);
     // Runner-handled connection/focus transitions bypass control's shared
     // epilogue, so synchronize modes here as the single dispatch boundary.
     state.update_input_modes();
@@ -1,5 +1,5 @@
 use super::{Coordinator, RestoreWorker};
-use crate::client::runtime::{ClientRuntime, ConnectionRestoreRuntime};
+use crate::client::runtime::{ClientRuntime, ConnectionRestoreRuntime, RestoredForwards};
 use crate::client::{ClientEvent, ClientState, HostConnection};
 use crate::core::HostId;
 use crate::transport::CommandCancellation;
@@ -39,6 +39,18 @@ impl Coordinator {
             .filter(|forward| forward.host_id == host_id)
             .cloned()
             .collect();
+        let socket_forwards = state
+            .socket_forwards
+            .iter()
+            .filter(|forward| forward.host_id == host_id)
+            .cloned()
+            .collect();
+        let reverse_forwards = state
+            .reverse_forwards
+            .iter()
+            .filter(|forward| forward.host_id == host_id)
+            .cloned()
+            .collect();
         let attached = state
             .terminals
             .keys()
@@ -52,7 +64,11 @@ impl Coordinator {
             if let Ok(restored) = payload_rx.recv() {
                 let outcome = restored.run(
                     token,
-                    forwards,
+                    RestoredForwards {
+                        forwards,
+                        socket_forwards,
+                        reverse_forwards,
+                    },
                     attached,
                     worker_cancellation,
                     event_tx.clone(),
@@ -18,13 +18,7 @@ pub(super) fn apply(state: &mut ClientState, update: ConnectionUpdate) {
             state.authentication_host = None;
             state.authentication_output.clear();
             state.mode = ClientMode::Manage;
-            for forward in state
-                .forwards
-                .iter_mut()
-                .filter(|forward| forward.host_id == host_id)
-            {
-                forward.mark_reconnecting();
-            }
+            state.mark_host_tunnels_reconnecting(host_id);
             let workspace_ids = state
                 .agent_runs
                 .keys()
@@ -43,13 +37,7 @@ pub(super) fn apply(state: &mut ClientState, update: ConnectionUpdate) {
             state.set_output("SSH connected; restoring workspaces, agent status, and tunnels…");
         }
         ConnectionUpdate::Failed { host_id, message } => {
-            for forward in state
-                .forwards
-                .iter_mut()
-                .filter(|forward| forward.host_id == host_id)
-            {
-                forward.mark_reconnecting();
-            }
+            state.mark_host_tunnels_reconnecting(host_id);
             let (connection, message) = classify_failure(&state.authentication_output, message);
             state.connections.insert(host_id, connection);
             state.authentication_host = None;
@@ -70,7 +58,19 @@ pub(super) fn apply_restored(state: &mut ClientState, report: ConnectionRestoreR
         Err(error) => errors.push(format!("Final registry snapshot: {error}")),
     }
     state.forwards.retain(|forward| forward.host_id != host_id);
-    state.forwards.extend(report.forwards);
+    state.forwards.extend(report.forwards.forwards);
+    state
+        .socket_forwards
+        .retain(|forward| forward.host_id != host_id);
+    state
+        .socket_forwards
+        .extend(report.forwards.socket_forwards);
+    state
+        .reverse_forwards
+        .retain(|forward| forward.host_id != host_id);
+    state
+        .reverse_forwards
+        .extend(report.forwards.reverse_forwards);
     if let Some(refresh) = &report.refresh {
         errors.extend(periodic::apply_connection_refresh(state, host_id, refresh));
     } else if !errors.is_empty() {
@@ -79,17 +79,27 @@ pub(super) fn apply_restored(state: &mut ClientState, report: ConnectionRestoreR
     errors.extend(report.watcher_errors);
     state.rebuild_tree();
 
+    let healthy = |status: &crate::ports::ForwardStatus| {
+        matches!(
+            status,
+            crate::ports::ForwardStatus::Active | crate::ports::ForwardStatus::Direct
+        )
+    };
     let failed_forwards = state
         .forwards
         .iter()
-        .filter(|forward| {
-            forward.host_id == host_id
-                && !matches!(
-                    forward.status,
-                    crate::ports::ForwardStatus::Active | crate::ports::ForwardStatus::Direct
-                )
-        })
-        .count();
+        .filter(|forward| forward.host_id == host_id && !healthy(&forward.status))
+        .count()
+        + state
+            .socket_forwards
+            .iter()
+            .filter(|forward| forward.host_id == host_id && !healthy(&forward.status))
+            .count()
+        + state
+            .reverse_forwards
+            .iter()
+            .filter(|forward| forward.host_id == host_id && !healthy(&forward.status))
+            .count();
     if errors.is_empty() && failed_forwards == 0 {
         let restored = report.restored_workspaces.unwrap_or_default();
         state.set_output(format!(
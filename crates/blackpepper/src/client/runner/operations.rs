@@ -4,7 +4,7 @@ use super::super::runtime::{
     ClientRuntime, DeferredHostAction, DeferredHostResult, DurableActionQueue,
     HostOperationContext, HostOperationValue, WorktreeChange,
 };
-use super::super::{actions, control, ClientMode, ClientState};
+use super::super::{actions, control, ClientCommand, ClientMode, ClientState};
 use crate::core::HostId;
 
 pub(super) fn progress(
@@ -62,6 +62,14 @@ pub(in crate::client) fn complete(
             HostOperationContext::InitialShellFocus { workspace_id } => Some(*workspace_id),
             _ => None,
         };
+        let agent_focus_workspace = match &completed.context {
+            HostOperationContext::AgentFocus { workspace_id } => Some(*workspace_id),
+            HostOperationContext::Attach { workspace_id } => {
+                clear_pending_agent_focus(state, *workspace_id);
+                None
+            }
+            _ => None,
+        };
         apply_deferred_results(state, completed.deferred_results);
         apply_deferred_results(
             state,
@@ -106,6 +114,11 @@ pub(in crate::client) fn complete(
             state.set_output(
                 "Initial workspace shell focus was cancelled by disconnect; no focus change was accepted.",
             );
+        } else if let Some(workspace_id) = agent_focus_workspace {
+            resume_attached_workspace(state, workspace_id);
+            state.set_output(
+                "Agent tab focus was cancelled by disconnect; no focus change was accepted.",
+            );
         }
         state.rebuild_tree();
         return;
@@ -247,9 +260,54 @@ fn apply_value(
                 "Service {name} is running in background Zellij tab {tab_id}."
             ));
         }
+        (
+            HostOperationContext::ServiceRestart { workspace_id, name },
+            HostOperationValue::ServiceStarted { tab_id },
+        ) => {
+            if let Some(status) = state.service_status_mut(workspace_id, &name) {
+                status.restarted(std::time::Instant::now());
+            }
+            state.set_output(format!(
+                "Service {name} crashed and was restarted in background Zellij tab {tab_id}."
+            ));
+        }
+        (
+            HostOperationContext::ServiceRerun { workspace_id, name },
+            HostOperationValue::ServiceStarted { tab_id },
+        ) => {
+            state.selected_workspace = Some(workspace_id);
+            state.set_output(format!(
+                "Service {name} was restarted in background Zellij tab {tab_id}."
+            ));
+        }
+        (
+            HostOperationContext::ServiceStop { workspace_id, name },
+            HostOperationValue::ServiceStopped(stopped),
+        ) => {
+            state.selected_workspace = Some(workspace_id);
+            state.set_output(if stopped {
+                format!("Service {name} was stopped and its tab closed.")
+            } else {
+                format!("Service {name} has no open tab; nothing was stopped.")
+            });
+        }
+        (
+            HostOperationContext::ServiceStatus { workspace_id },
+            HostOperationValue::ServiceStates(states),
+        ) => {
+            state.selected_workspace = Some(workspace_id);
+            actions::apply_service_states(state, workspace_id, states)
+        }
         (HostOperationContext::WorktreeList { workspace_id }, HostOperationValue::Worktrees(list)) => {
             state.selected_workspace = Some(workspace_id);
-            actions::apply_worktree_list(state, list)
+            actions::apply_worktree_list(state, workspace_id, list)
+        }
+        (
+            HostOperationContext::PullRequestList { workspace_id },
+            HostOperationValue::PullRequests(pull_requests),
+        ) => {
+            state.selected_workspace = Some(workspace_id);
+            actions::apply_pull_request_list(state, workspace_id, pull_requests)
         }
         (
             HostOperationContext::WorktreeMutation {
@@ -262,29 +320,77 @@ fn apply_value(
             if replaces_forwards {
                 state
                     .forwards
-                    .retain(|forward| forward.workspace_id != workspace_id);
+                    .retain(|forward| !result.removes.contains(&forward.workspace_id));
                 state
                     .forwards
                     .extend(result.forwards.take().unwrap_or_default());
+                state
+                    .socket_forwards
+                    .retain(|forward| !result.removes.contains(&forward.workspace_id));
+                state
+                    .socket_forwards
+                    .extend(result.socket_forwards.take().unwrap_or_default());
+                state
+                    .reverse_forwards
+                    .retain(|forward| !result.removes.contains(&forward.workspace_id));
+                state
+                    .reverse_forwards
+                    .extend(result.reverse_forwards.take().unwrap_or_default());
             }
             match result.change {
                 Ok(change) => {
-                    let removed = matches!(change, WorktreeChange::Removed);
+                    let removed = match &change {
+                        WorktreeChange::Removed => vec![workspace_id],
+                        WorktreeChange::Pruned { results } => results
+                            .iter()
+                            .filter(|result| result.error.is_none())
+                            .map(|result| result.workspace_id)
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    let registered = match &change {
+                        WorktreeChange::Registered { workspace_id, .. } => Some(*workspace_id),
+                        _ => None,
+                    };
+                    let provider = match &command {
+                        ClientCommand::AgentSpawnInWorktree { provider, .. } => Some(*provider),
+                        _ => None,
+                    };
                     actions::apply_worktree_change(
                         state,
                         change,
                         Some((workspace_id, command)),
                         result.session_error,
                     );
-                    if removed {
-                        if state.active_workspace == Some(workspace_id) {
+                    if let (Some(agent), Some(registered), Some(provider)) =
+                        (result.agent, registered, provider)
+                    {
+                        match agent {
+                            Ok(spawned) => {
+                                actions::apply_spawned(state, registered, provider, spawned)
+                            }
+                            Err(error) => state.set_output(format!(
+                                "Registered the worktree, but {provider} could not start: {error}"
+                            )),
+                        }
+                    }
+                    if !removed.is_empty() {
+                        if state
+                            .active_workspace
+                            .is_some_and(|active| removed.contains(&active))
+                        {
                             state.active_workspace = None;
                             state.mode = ClientMode::Manage;
                         }
-                        state.selected_workspace = state
-                            .workspace_ids()
-                            .into_iter()
-                            .find(|candidate| *candidate != workspace_id);
+                        if state
+                            .selected_workspace
+                            .is_none_or(|selected| removed.contains(&selected))
+                        {
+                            state.selected_workspace = state
+                                .workspace_ids()
+                                .into_iter()
+                                .find(|candidate| !removed.contains(candidate));
+                        }
                     }
                 }
                 Err(error) => state.set_output(format!("{label} failed: {error}")),
@@ -302,6 +408,10 @@ fn apply_value(
             HostOperationContext::ForwardStart { workspace_id },
             HostOperationValue::Forwarded(forward),
         ) if forward.workspace_id == workspace_id => actions::apply_forwarded(state, forward),
+        (
+            HostOperationContext::DeclaredForwards { workspace_id },
+            HostOperationValue::DeclaredForwards(results),
+        ) => actions::apply_declared_forwards(state, workspace_id, results),
         (
             HostOperationContext::ForwardCancel {
                 workspace_id,
@@ -311,24 +421,68 @@ fn apply_value(
         ) if forward.id == forward_id && forward.workspace_id == workspace_id => {
             actions::apply_cancelled(state, workspace_id, forward_id, forward)
         }
+        (
+            HostOperationContext::SocketForwardStart { workspace_id },
+            HostOperationValue::SocketForwarded(forward),
+        ) if forward.workspace_id == workspace_id => actions::apply_socket_forwarded(state, forward),
+        (
+            HostOperationContext::SocketForwardCancel {
+                workspace_id,
+                forward_id,
+            },
+            HostOperationValue::SocketForwardCancelled(forward),
+        ) if forward.id == forward_id && forward.workspace_id == workspace_id => {
+            actions::apply_socket_cancelled(state, forward_id, forward)
+        }
+        (
+            HostOperationContext::ReverseStart { workspace_id },
+            HostOperationValue::Reversed(forward),
+        ) if forward.workspace_id == workspace_id => actions::apply_reversed(state, forward),
+        (
+            HostOperationContext::ReverseCancel {
+                workspace_id,
+                forward_id,
+            },
+            HostOperationValue::ReverseCancelled(forward),
+        ) if forward.id == forward_id && forward.workspace_id == workspace_id => {
+            actions::apply_reverse_cancelled(state, forward_id, forward)
+        }
         (
             HostOperationContext::Attach { workspace_id },
             HostOperationValue::Attached {
                 workspace_id: returned,
                 process,
                 provisional_clients,
+                declared_forwards,
             },
         ) if workspace_id == returned => {
+            let agent_focus = state
+                .pending_agent_focus
+                .take_if(|(pending, _)| *pending == workspace_id);
             match control::apply_attachment(state, workspace_id, process, provisional_clients) {
-                Ok(()) => maybe_schedule_initial_shell_focus(
-                    state,
-                    runtime,
-                    host_id,
-                    workspace_id,
-                    provisional_clients,
-                ),
+                // The agent's tab replaces the initial shell as the focus
+                // target, so the first-attach correction has nothing to add.
+                Ok(()) => match agent_focus {
+                    Some((_, run_id)) => {
+                        if let Err(error) =
+                            actions::focus_agent(state, runtime, workspace_id, run_id)
+                        {
+                            state.set_output(format!(
+                                "Workspace attached, but its agent tab could not be opened: {error}"
+                            ));
+                        }
+                    }
+                    None => maybe_schedule_initial_shell_focus(
+                        state,
+                        runtime,
+                        host_id,
+                        workspace_id,
+                        provisional_clients,
+                    ),
+                },
                 Err(error) => state.set_output(format!("Workspace attach failed: {error}")),
             }
+            remember_declared_forwards(state, workspace_id, declared_forwards);
         }
         (
             HostOperationContext::RegisterAndAttach { host_id, path },
@@ -336,6 +490,7 @@ fn apply_value(
                 workspace_id,
                 path: returned_path,
                 attachment,
+                declared_forwards,
             },
         ) if path == returned_path => {
             state.selected_workspace = Some(workspace_id);
@@ -361,6 +516,7 @@ fn apply_value(
                     path.display()
                 )),
             }
+            remember_declared_forwards(state, workspace_id, declared_forwards);
         }
         (
             HostOperationContext::InitialShellFocus { workspace_id },
@@ -369,6 +525,35 @@ fn apply_value(
             resume_attached_workspace(state, workspace_id);
             state.clear_output();
         }
+        (
+            HostOperationContext::AgentFocus { workspace_id },
+            HostOperationValue::AgentFocused,
+        ) => {
+            state.mark_workspace_completions_seen(workspace_id);
+            resume_attached_workspace(state, workspace_id);
+            state.clear_output();
+        }
+        (
+            HostOperationContext::AgentInput {
+                workspace_id,
+                run_id,
+                provider,
+            },
+            HostOperationValue::AgentInputSent,
+        ) => {
+            state.mark_run_completion_seen(workspace_id, run_id);
+            state.set_output(format!("Sent to the {provider} agent."))
+        }
+        (
+            HostOperationContext::AgentAnswer {
+                provider,
+                rule_id,
+                answer,
+            },
+            HostOperationValue::AgentAnswered,
+        ) => state.set_output(format!(
+            "Answered {answer} to the {provider} agent's {rule_id} prompt."
+        )),
         (
             HostOperationContext::WorkspaceUngroup { workspace_id },
             HostOperationValue::WorkspaceUngrouped(workspace),
@@ -383,6 +568,21 @@ fn apply_value(
     }
 }
 
+/// Keep the workspace's `[[forward]]` declarations for periodic refresh. A
+/// config that cannot be read leaves the attachment alone and says why.
+fn remember_declared_forwards(
+    state: &mut ClientState,
+    workspace_id: crate::core::WorkspaceId,
+    declared: Result<Vec<crate::client_config::DeclaredForward>, String>,
+) {
+    match declared {
+        Ok(declared) => state.remember_declared_forwards(workspace_id, declared),
+        Err(error) => state.set_output(format!(
+            "Workspace attached, but its [[forward]] declarations could not be read: {error}"
+        )),
+    }
+}
+
 fn maybe_schedule_initial_shell_focus(
     state: &mut ClientState,
     runtime: &mut ClientRuntime,
@@ -439,14 +639,27 @@ fn resume_attached_workspace(state: &mut ClientState, workspace_id: crate::core:
     }
 }
 
+fn clear_pending_agent_focus(state: &mut ClientState, workspace_id: crate::core::WorkspaceId) {
+    state
+        .pending_agent_focus
+        .take_if(|(pending, _)| *pending == workspace_id);
+}
+
 fn fail_context(
     state: &mut ClientState,
     context: HostOperationContext,
     label: &str,
     error: String,
 ) {
-    if let HostOperationContext::InitialShellFocus { workspace_id } = &context {
-        resume_attached_workspace(state, *workspace_id);
+    match &context {
+        // A refused agent focus stays in Manage so the reason stays readable.
+        HostOperationContext::InitialShellFocus { workspace_id } => {
+            resume_attached_workspace(state, *workspace_id);
+        }
+        HostOperationContext::Attach { workspace_id } => {
+            clear_pending_agent_focus(state, *workspace_id);
+        }
+        _ => {}
     }
     if let HostOperationContext::WorktreeMutation {
         workspace_id,
@@ -463,6 +676,29 @@ fn fail_context(
                 "Worktrunk operation outcome unavailable: {error}"
             ));
         }
+        for forward in state
+            .socket_forwards
+            .iter_mut()
+            .filter(|forward| forward.workspace_id == *workspace_id)
+        {
+            forward.status = crate::ports::ForwardStatus::Failed(format!(
+                "Worktrunk operation outcome unavailable: {error}"
+            ));
+        }
+        for forward in state
+            .reverse_forwards
+            .iter_mut()
+            .filter(|forward| forward.workspace_id == *workspace_id)
+        {
+            forward.status = crate::ports::ForwardStatus::Failed(format!(
+                "Worktrunk operation outcome unavailable: {error}"
+            ));
+        }
+    }
+    if let HostOperationContext::ServiceRestart { workspace_id, name } = &context {
+        if let Some(status) = state.service_status_mut(*workspace_id, name) {
+            status.restart_failed(std::time::Instant::now());
+        }
     }
     if let HostOperationContext::ForwardCancel { forward_id, .. } = &context {
         if let Some(forward) = state
@@ -473,6 +709,24 @@ fn fail_context(
             forward.status = crate::ports::ForwardStatus::Failed(error.clone());
         }
     }
+    if let HostOperationContext::SocketForwardCancel { forward_id, .. } = &context {
+        if let Some(forward) = state
+            .socket_forwards
+            .iter_mut()
+            .find(|forward| forward.id == *forward_id)
+        {
+            forward.status = crate::ports::ForwardStatus::Failed(error.clone());
+        }
+    }
+    if let HostOperationContext::ReverseCancel { forward_id, .. } = &context {
+        if let Some(forward) = state
+            .reverse_forwards
+            .iter_mut()
+            .find(|forward| forward.id == *forward_id)
+        {
+            forward.status = crate::ports::ForwardStatus::Failed(error.clone());
+        }
+    }
     state.set_output(format!("{label} failed: {error}"));
 }
 
@@ -116,3 +116,78 @@ fn initial_focus_failure_is_visible_and_releases_terminal_input() {
         .as_deref()
         .is_some_and(|message| message.contains("client set changed; no focus change was sent")));
 }
+
+#[test]
+fn an_agent_spawned_with_a_worktree_lands_in_the_new_workspace_or_says_why_not() {
+    let (root, mut runtime, mut state, _events, host_id, workspace_id) = attached_fixture();
+    let worktree_root = root.path().join("workspace.feature");
+    std::fs::create_dir(&worktree_root).unwrap();
+    let worktree_id = runtime.register_workspace(host_id, &worktree_root).unwrap();
+    let command = ClientCommand::AgentSpawnInWorktree {
+        provider: crate::agent_status::Provider::Claude,
+        branch: "feature".to_owned(),
+        base: None,
+    };
+    let context = || HostOperationContext::WorktreeMutation {
+        workspace_id,
+        command: command.clone(),
+        replaces_forwards: false,
+    };
+    let value = |change, agent| {
+        HostOperationValue::WorktreeMutation(crate::client::runtime::WorktreeMutationResult {
+            change: Ok(change),
+            forwards: None,
+            socket_forwards: None,
+            reverse_forwards: None,
+            removes: Vec::new(),
+            session_error: None,
+            agent,
+        })
+    };
+
+    apply_value(
+        &mut state,
+        &mut runtime,
+        host_id,
+        context(),
+        value(
+            WorktreeChange::SetupFailed {
+                workspace_id: worktree_id,
+                path: worktree_root.clone(),
+                message: "post-create exited 1".to_owned(),
+            },
+            None,
+        ),
+        "Applying approved Worktrunk mutation",
+    );
+    assert_eq!(state.selected_workspace, Some(worktree_id));
+    assert!(!state.agent_runs.contains_key(&worktree_id));
+    assert!(state.output.as_deref().is_some_and(|message| {
+        message.contains("setup failed: post-create exited 1")
+            && message.contains("claude was not started")
+    }));
+
+    apply_value(
+        &mut state,
+        &mut runtime,
+        host_id,
+        context(),
+        value(
+            WorktreeChange::Registered {
+                workspace_id: worktree_id,
+                path: worktree_root,
+            },
+            Some(Ok(crate::client::runtime::SpawnedAgent {
+                run_id: crate::core::AgentRunId::new(),
+                pane_id: crate::core::PaneId::new(),
+                tab_id: 3,
+                zellij_pane_id: "terminal_4".to_owned(),
+                capability: "supported",
+            })),
+        ),
+        "Applying approved Worktrunk mutation",
+    );
+    assert_eq!(state.selected_workspace, Some(worktree_id));
+    assert_eq!(state.agent_runs[&worktree_id].len(), 1);
+    assert!(!state.agent_runs.contains_key(&workspace_id));
+}
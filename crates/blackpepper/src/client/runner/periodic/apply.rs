@@ -1,8 +1,9 @@
 use super::super::super::runtime::{ClientRuntime, ForwardCleanupBatch, ForwardCleanupOutcome};
-use super::super::super::{ClientState, HostConnection};
-use crate::core::{HostId, HostPeriodicRefresh};
-use std::collections::BTreeSet;
-use std::time::Duration;
+use super::super::super::state::ServiceStatus;
+use super::super::super::{actions, notify, ClientState, HostConnection};
+use crate::core::{HostId, HostPeriodicRefresh, WorkspaceId};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
 
 const BACKGROUND_NOTICE_DURATION: Duration = Duration::from_secs(5);
 
@@ -98,6 +99,27 @@ pub(super) fn refresh(
         }
     }
     let mut notices = merge_refresh_state(state, host_id, &refresh);
+    notices.extend(runtime.reconcile_reverse_forwards(
+        &mut state.reverse_forwards,
+        &state.snapshot,
+        host_id,
+    ));
+    notices.extend(runtime.reconcile_socket_forwards(
+        &mut state.socket_forwards,
+        &state.snapshot,
+        host_id,
+    ));
+    for (workspace_id, declared) in &refresh.declared_forwards {
+        state.remember_declared_forwards(*workspace_id, declared.clone());
+    }
+    notices.extend(actions::start_declared_forwards(state, runtime, host_id));
+    let due = update_services(state, host_id, &refresh);
+    actions::restart_services(state, runtime, host_id, due);
+    let agent_notices = notify::collect(state, host_id);
+    notices.extend(notify::deliver(
+        state.config.notifications.via,
+        agent_notices,
+    ));
     let watcher_errors = runtime.ensure_periodic_blocker_watchers(&refresh, state.event_tx.clone());
     notices.extend(watcher_errors);
     if !notices.is_empty() {
@@ -117,7 +139,11 @@ pub(super) fn merge_refresh_state(
     host_id: HostId,
     refresh: &HostPeriodicRefresh,
 ) -> Vec<String> {
-    state.ports.insert(host_id, refresh.ports.clone());
+    let mut ports = refresh.ports.clone();
+    if let Some(previous) = state.ports.get(&host_id) {
+        ports.keep_http_labels(previous);
+    }
+    state.ports.insert(host_id, ports);
     state.upsert_discovered_agent_runs(host_id, refresh.agent_runs.clone());
     for (run_id, snapshot) in &refresh.agent_snapshots {
         if let Some(run) = state
@@ -215,6 +241,60 @@ fn update_client_counts(state: &mut ClientState, host_id: HostId, refresh: &Host
     }
 }
 
+/// Fold this host's service observations into each service's health, keeping
+/// restart history for the names still reported. Returns the services whose
+/// restart policy wants a relaunch now.
+pub(super) fn update_services(
+    state: &mut ClientState,
+    host_id: HostId,
+    refresh: &HostPeriodicRefresh,
+) -> Vec<(WorkspaceId, String)> {
+    let now = Instant::now();
+    let mut previous = BTreeMap::new();
+    for workspace_id in state
+        .services
+        .keys()
+        .copied()
+        .filter(|workspace_id| state.host_for_workspace(*workspace_id) == Some(host_id))
+        .collect::<Vec<_>>()
+    {
+        if let Some(statuses) = state.services.remove(&workspace_id) {
+            previous.insert(workspace_id, statuses);
+        }
+    }
+    let mut due = Vec::new();
+    for (workspace_id, observed) in &refresh.services {
+        let mut known = previous.remove(workspace_id).unwrap_or_default();
+        let mut statuses = observed
+            .iter()
+            .map(|observation| {
+                let mut status = known
+                    .iter()
+                    .position(|status| status.name == observation.name)
+                    .map(|index| known.swap_remove(index))
+                    .unwrap_or_else(|| ServiceStatus::new(&observation.name));
+                if status.observe(observation, now) {
+                    due.push((*workspace_id, observation.name.clone()));
+                }
+                status
+            })
+            .collect::<Vec<_>>();
+        // A blocked service has no pane to report; it stays until started.
+        statuses.extend(known.into_iter().filter(ServiceStatus::is_blocked));
+        state.services.insert(*workspace_id, statuses);
+    }
+    for (workspace_id, known) in previous {
+        let blocked = known
+            .into_iter()
+            .filter(ServiceStatus::is_blocked)
+            .collect::<Vec<_>>();
+        if !blocked.is_empty() {
+            state.services.insert(workspace_id, blocked);
+        }
+    }
+    due
+}
+
 /// Replace this host's repository and tab context wholesale. An older helper
 /// reports no overviews at all, which clears this host's entries rather than
 /// leaving a branch on screen that nothing is confirming any more.
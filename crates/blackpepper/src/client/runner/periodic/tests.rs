@@ -238,6 +238,8 @@ fn refresh_started_before_explicit_operation_cannot_apply_or_clean_forwards_late
             client_count_errors: Default::default(),
             errors: vec!["stale refresh must not apply".to_owned()],
             overviews: Default::default(),
+            services: Default::default(),
+            declared_forwards: Default::default(),
         })),
     );
 
@@ -6,13 +6,15 @@ use crate::agent_status::{
     Provider,
 };
 use crate::client::runtime::ClientRuntime;
+use crate::client::state::ServiceHealth;
 use crate::client::{ClientState, DisplayStatus, HostConnection};
 use crate::core::{
     AgentRunBinding, AgentRunId, HostAgentRun, HostAgentSnapshot, HostId, HostPeriodicRefresh,
-    PaneId, RegistrySnapshot, SessionId, WorkspaceId, WorkspaceRecord,
+    PaneId, RegistrySnapshot, ServiceObservation, ServiceProcess, ServiceRestart, SessionId,
+    WorkspaceId, WorkspaceRecord,
 };
 
-use super::super::apply::{merge_refresh_state, refresh as apply_refresh};
+use super::super::apply::{merge_refresh_state, refresh as apply_refresh, update_services};
 
 #[test]
 fn background_refresh_error_temporarily_overlays_without_replacing_user_output() {
@@ -124,6 +126,8 @@ fn authoritative_exit_is_not_replaced_by_a_same_refresh_cleanup_error() {
             pane_id: None,
             snapshot: exited,
             explain,
+            manifest_warning: None,
+            blocker: None,
         },
     );
 
@@ -134,6 +138,60 @@ fn authoritative_exit_is_not_replaced_by_a_same_refresh_cleanup_error() {
     assert!(displayed.snapshot_error.is_none());
 }
 
+#[test]
+fn a_blocked_service_stays_listed_until_a_refresh_reports_its_pane() {
+    let root = tempfile::tempdir().unwrap();
+    let runtime = ClientRuntime::test_fixture(root.path());
+    let host_id = runtime.local_host_id();
+    let workspace = WorkspaceRecord::new(host_id, "/tmp/metadata-blocked-workspace");
+    runtime.registry.upsert_workspace(&workspace).unwrap();
+    let (events, _receiver) = mpsc::channel();
+    let mut state = ClientState::new(
+        crate::client_config::load_contents(None, None, None).unwrap(),
+        runtime.snapshot().unwrap(),
+        events,
+    );
+    state.block_service(
+        workspace.id,
+        "api".to_owned(),
+        "its dependency db is not running".to_owned(),
+    );
+    let live = |name: &str| ServiceObservation {
+        name: name.to_owned(),
+        process: ServiceProcess::Live,
+        ready: None,
+        restart: ServiceRestart::Never,
+    };
+    let health = |state: &ClientState| {
+        state.services[&workspace.id]
+            .iter()
+            .map(|status| (status.name.clone(), status.health))
+            .collect::<Vec<_>>()
+    };
+
+    let mut observed = refresh(host_id, Vec::new(), BTreeMap::new());
+    observed.services = BTreeMap::from([(workspace.id, vec![live("db")])]);
+    update_services(&mut state, host_id, &observed);
+    assert_eq!(
+        health(&state),
+        [
+            ("db".to_owned(), ServiceHealth::Ready),
+            ("api".to_owned(), ServiceHealth::Blocked)
+        ]
+    );
+    update_services(
+        &mut state,
+        host_id,
+        &refresh(host_id, Vec::new(), BTreeMap::new()),
+    );
+    assert_eq!(health(&state), [("api".to_owned(), ServiceHealth::Blocked)]);
+
+    observed.services = BTreeMap::from([(workspace.id, vec![live("api")])]);
+    update_services(&mut state, host_id, &observed);
+    assert_eq!(health(&state), [("api".to_owned(), ServiceHealth::Ready)]);
+    assert!(state.services[&workspace.id][0].blocked_by.is_none());
+}
+
 fn refresh(
     host_id: HostId,
     agent_runs: Vec<HostAgentRun>,
@@ -151,6 +209,8 @@ fn refresh(
         client_count_errors: BTreeMap::new(),
         errors: Vec::new(),
         overviews: BTreeMap::new(),
+        services: BTreeMap::new(),
+        declared_forwards: BTreeMap::new(),
     }
 }
 
@@ -0,0 +1,533 @@
+//! Port forwards carried across client restarts.
+//!
+//! Tunnels are owned by this client process, so the record is a private file
+//! in the client state directory and never reaches a host registry. Restored
+//! entries rejoin `ClientState` as if their host had just disconnected: the
+//! ordinary reconnect path then re-establishes each one on the exact local
+//! port, host port or socket path it used before, or reports the conflict.
+
+use crate::client::runtime::ClientRuntime;
+use crate::client::ClientState;
+use crate::core::{HostId, WorkspaceId};
+use crate::ports::{ForwardState, ForwardStatus, ReverseForwardState, SocketForwardState};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct SavedFile {
+    version: u32,
+    forwards: Vec<SavedForward>,
+    #[serde(default)]
+    socket_forwards: Vec<SavedSocketForward>,
+    #[serde(default)]
+    reverse_forwards: Vec<SavedReverseForward>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SavedForward {
+    host_id: HostId,
+    workspace_id: WorkspaceId,
+    remote_host: String,
+    remote_port: u16,
+    requested_local_address: SocketAddr,
+    local_address: SocketAddr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SavedSocketForward {
+    host_id: HostId,
+    workspace_id: WorkspaceId,
+    remote_path: String,
+    local_path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SavedReverseForward {
+    host_id: HostId,
+    workspace_id: WorkspaceId,
+    remote_port: u16,
+    local_port: u16,
+}
+
+impl SavedFile {
+    /// The forwards worth restoring, in a stable order so that a list that
+    /// was only reordered compares equal. A forward that is being cancelled
+    /// is already gone as far as the next start is concerned.
+    fn of(state: &ClientState) -> Self {
+        let live = |status: &ForwardStatus| *status != ForwardStatus::Cancelling;
+        let mut file = Self {
+            version: FORMAT_VERSION,
+            forwards: state
+                .forwards
+                .iter()
+                .filter(|forward| live(&forward.status))
+                .map(SavedForward::of)
+                .collect(),
+            socket_forwards: state
+                .socket_forwards
+                .iter()
+                .filter(|forward| live(&forward.status))
+                .map(SavedSocketForward::of)
+                .collect(),
+            reverse_forwards: state
+                .reverse_forwards
+                .iter()
+                .filter(|forward| live(&forward.status))
+                .map(SavedReverseForward::of)
+                .collect(),
+        };
+        file.forwards.sort();
+        file.socket_forwards.sort();
+        file.reverse_forwards.sort();
+        file
+    }
+}
+
+impl SavedForward {
+    fn of(forward: &ForwardState) -> Self {
+        Self {
+            host_id: forward.host_id,
+            workspace_id: forward.workspace_id,
+            remote_host: forward.remote_host.clone(),
+            remote_port: forward.remote_port,
+            requested_local_address: forward.requested_local_address,
+            local_address: forward.local_address,
+        }
+    }
+
+    fn into_forward(self) -> ForwardState {
+        ForwardState {
+            id: uuid::Uuid::new_v4(),
+            host_id: self.host_id,
+            workspace_id: self.workspace_id,
+            remote_host: self.remote_host,
+            remote_port: self.remote_port,
+            requested_local_address: self.requested_local_address,
+            local_address: self.local_address,
+            status: ForwardStatus::Reconnecting,
+        }
+    }
+}
+
+impl SavedSocketForward {
+    fn of(forward: &SocketForwardState) -> Self {
+        Self {
+            host_id: forward.host_id,
+            workspace_id: forward.workspace_id,
+            remote_path: forward.remote_path.clone(),
+            local_path: forward.local_path.clone(),
+        }
+    }
+
+    fn into_forward(self) -> SocketForwardState {
+        let mut forward = SocketForwardState::new(
+            self.host_id,
+            self.workspace_id,
+            self.remote_path,
+            self.local_path,
+        );
+        forward.status = ForwardStatus::Reconnecting;
+        forward
+    }
+}
+
+impl SavedReverseForward {
+    fn of(forward: &ReverseForwardState) -> Self {
+        Self {
+            host_id: forward.host_id,
+            workspace_id: forward.workspace_id,
+            remote_port: forward.remote_port,
+            local_port: forward.local_port,
+        }
+    }
+
+    fn into_forward(self) -> ReverseForwardState {
+        let mut forward = ReverseForwardState::new(
+            self.host_id,
+            self.workspace_id,
+            self.local_port,
+            self.remote_port,
+        );
+        forward.status = ForwardStatus::Reconnecting;
+        forward
+    }
+}
+
+/// The previous session's forwards, each waiting to reconnect.
+#[derive(Debug)]
+pub(super) struct Restorable {
+    forwards: Vec<ForwardState>,
+    socket_forwards: Vec<SocketForwardState>,
+    reverse_forwards: Vec<ReverseForwardState>,
+}
+
+impl From<SavedFile> for Restorable {
+    fn from(file: SavedFile) -> Self {
+        Self {
+            forwards: file
+                .forwards
+                .into_iter()
+                .map(SavedForward::into_forward)
+                .collect(),
+            socket_forwards: file
+                .socket_forwards
+                .into_iter()
+                .map(SavedSocketForward::into_forward)
+                .collect(),
+            reverse_forwards: file
+                .reverse_forwards
+                .into_iter()
+                .map(SavedReverseForward::into_forward)
+                .collect(),
+        }
+    }
+}
+
+pub(super) struct SavedForwards {
+    path: PathBuf,
+    written: SavedFile,
+}
+
+impl SavedForwards {
+    /// Read the previous session's record. A missing file is an empty record;
+    /// an unreadable one is reported and left on disk until the forward list
+    /// next changes.
+    pub(super) fn open(path: PathBuf) -> (Self, Result<Restorable, String>) {
+        let loaded = match std::fs::read(&path) {
+            Ok(contents) => serde_json::from_slice::<SavedFile>(&contents)
+                .map_err(|error| error.to_string())
+                .and_then(|file| {
+                    if file.version == FORMAT_VERSION {
+                        Ok(file)
+                    } else {
+                        Err(format!("unsupported format version {}", file.version))
+                    }
+                }),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(SavedFile {
+                version: FORMAT_VERSION,
+                ..SavedFile::default()
+            }),
+            Err(error) => Err(error.to_string()),
+        }
+        .map_err(|error| {
+            format!(
+                "Saved port forwards in {} could not be read ({error}); none were restored.",
+                path.display()
+            )
+        });
+        let written = loaded.as_ref().cloned().unwrap_or(SavedFile {
+            version: FORMAT_VERSION,
+            ..SavedFile::default()
+        });
+        (Self { path, written }, loaded.map(Restorable::from))
+    }
+
+    /// Rewrite the record when the set of forwards changed. Status changes
+    /// and reordering leave the file alone.
+    pub(super) fn record(&mut self, state: &ClientState) -> Result<(), String> {
+        let current = SavedFile::of(state);
+        if current == self.written {
+            return Ok(());
+        }
+        // Only one failure report per change: the next attempt waits for the
+        // list to change again rather than repeating on every frame.
+        let contents = serde_json::to_vec_pretty(&current).map_err(|error| error.to_string());
+        self.written = current;
+        contents
+            .and_then(|contents| self.replace(&contents))
+            .map_err(|error| {
+                format!("Port forwards could not be saved for the next start: {error}")
+            })
+    }
+
+    fn replace(&self, contents: &[u8]) -> Result<(), String> {
+        let directory = self
+            .path
+            .parent()
+            .ok_or_else(|| "the forward record has no parent directory".to_owned())?;
+        let mut temporary =
+            tempfile::NamedTempFile::new_in(directory).map_err(|error| error.to_string())?;
+        temporary
+            .write_all(contents)
+            .and_then(|()| temporary.as_file().sync_all())
+            .map_err(|error| error.to_string())?;
+        temporary
+            .persist(&self.path)
+            .map(drop)
+            .map_err(|error| error.error.to_string())
+    }
+}
+
+/// Put the previous session's forwards back. Local ones are re-established
+/// immediately; remote ones wait for their host's connection restore. An
+/// entry whose workspace is no longer registered is dropped, and with it
+/// from the next record. Returns how many were restored.
+pub(super) fn restore(
+    state: &mut ClientState,
+    runtime: &mut ClientRuntime,
+    restorable: Restorable,
+) -> usize {
+    let registered = |state: &ClientState, host_id: HostId, workspace_id: WorkspaceId| {
+        state
+            .snapshot
+            .workspaces
+            .iter()
+            .any(|workspace| workspace.id == workspace_id && workspace.host_id == host_id)
+    };
+    let local_host_id = runtime.local_host_id();
+    let mut restored = 0;
+    for mut forward in restorable.forwards {
+        if !registered(state, forward.host_id, forward.workspace_id) {
+            continue;
+        }
+        if forward.host_id == local_host_id {
+            runtime.reconnect_forward(&mut forward);
+        }
+        state.forwards.push(forward);
+        restored += 1;
+    }
+    for mut forward in restorable.socket_forwards {
+        if !registered(state, forward.host_id, forward.workspace_id) {
+            continue;
+        }
+        if forward.host_id == local_host_id {
+            // A local socket was never tunnelled; both paths coincide.
+            forward.status = ForwardStatus::Direct;
+            runtime.reconnect_socket_forward(&mut forward);
+        }
+        state.socket_forwards.push(forward);
+        restored += 1;
+    }
+    for mut forward in restorable.reverse_forwards {
+        if !registered(state, forward.host_id, forward.workspace_id) {
+            continue;
+        }
+        if forward.host_id == local_host_id {
+            // The loopback proxy died with the previous process; start it
+            // again rather than asking a transport the local host lacks.
+            forward = runtime
+                .reverse_workspace_port(
+                    forward.workspace_id,
+                    forward.local_port,
+                    forward.remote_port,
+                )
+                .unwrap_or_else(|error| {
+                    forward.status = ForwardStatus::Failed(error);
+                    forward
+                });
+        }
+        state.reverse_forwards.push(forward);
+        restored += 1;
+    }
+    restored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{HostRecord, HostTransport, WorkspaceRecord};
+    use crate::ports::RemotePortTarget;
+
+    fn forward(host_id: HostId, workspace_id: WorkspaceId, port: u16) -> ForwardState {
+        let mut forward = ForwardState::new(
+            host_id,
+            workspace_id,
+            RemotePortTarget::from_bind_address("127.0.0.1", port).unwrap(),
+        )
+        .unwrap();
+        forward.local_address = "127.0.0.1:41234".parse().unwrap();
+        forward
+    }
+
+    fn empty_state() -> ClientState {
+        let (event_tx, _event_rx) = std::sync::mpsc::channel();
+        ClientState::new(
+            crate::client_config::load_contents(None, None, None).unwrap(),
+            crate::core::RegistrySnapshot::default(),
+            event_tx,
+        )
+    }
+
+    #[test]
+    fn the_record_keeps_every_kind_and_drops_cancelling_forwards() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("forwards.json");
+        let (mut store, loaded) = SavedForwards::open(path.clone());
+        assert!(loaded.unwrap().forwards.is_empty());
+
+        let (host_id, workspace_id) = (HostId::new(), WorkspaceId::new());
+        let kept = forward(host_id, workspace_id, 3000);
+        let mut cancelling = forward(host_id, workspace_id, 4000);
+        cancelling.status = ForwardStatus::Cancelling;
+        let mut state = empty_state();
+        state.forwards = vec![kept.clone(), cancelling];
+        state.socket_forwards = vec![SocketForwardState::new(
+            host_id,
+            workspace_id,
+            "/workspace/run/db.sock".to_owned(),
+            PathBuf::from("/tmp/bp/db.sock"),
+        )];
+        state.reverse_forwards = vec![ReverseForwardState::new(host_id, workspace_id, 8080, 9090)];
+        store.record(&state).unwrap();
+
+        let (_, loaded) = SavedForwards::open(path.clone());
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.forwards.len(), 1);
+        assert_eq!(loaded.forwards[0].target(), kept.target());
+        assert_eq!(loaded.forwards[0].workspace_id, workspace_id);
+        assert_eq!(
+            loaded.forwards[0].requested_local_address,
+            kept.requested_local_address
+        );
+        assert_eq!(loaded.forwards[0].local_address, kept.local_address);
+        assert_eq!(loaded.forwards[0].status, ForwardStatus::Reconnecting);
+        assert_eq!(
+            loaded.socket_forwards[0].local_path,
+            PathBuf::from("/tmp/bp/db.sock")
+        );
+        assert_eq!(
+            loaded.socket_forwards[0].status,
+            ForwardStatus::Reconnecting
+        );
+        assert_eq!(
+            (
+                loaded.reverse_forwards[0].local_port,
+                loaded.reverse_forwards[0].remote_port
+            ),
+            (8080, 9090)
+        );
+
+        // Neither a status change nor a reordering rewrites the file, so a
+        // removed file stays removed.
+        std::fs::remove_file(&path).unwrap();
+        state.forwards.reverse();
+        state.forwards[1].status = ForwardStatus::Reconnecting;
+        store.record(&state).unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn a_record_without_socket_or_reverse_forwards_still_loads() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("forwards.json");
+        std::fs::write(&path, "{\"version\": 1, \"forwards\": []}").unwrap();
+
+        let (_, loaded) = SavedForwards::open(path);
+        let loaded = loaded.unwrap();
+        assert!(loaded.socket_forwards.is_empty());
+        assert!(loaded.reverse_forwards.is_empty());
+    }
+
+    #[test]
+    fn an_unreadable_record_is_reported_and_left_alone() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("forwards.json");
+        std::fs::write(&path, "{\"version\": 9, \"forwards\": []}").unwrap();
+
+        let (mut store, loaded) = SavedForwards::open(path.clone());
+        assert!(loaded.unwrap_err().contains("unsupported format version 9"));
+        store.record(&empty_state()).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "{\"version\": 9, \"forwards\": []}"
+        );
+    }
+
+    #[test]
+    fn local_forwards_return_at_once_and_remote_ones_wait_for_their_host() {
+        let root = tempfile::tempdir().unwrap();
+        let workspace_root = root.path().join("workspace");
+        std::fs::create_dir(&workspace_root).unwrap();
+        let mut runtime = ClientRuntime::test_fixture(root.path());
+        let local_id = runtime.local_host_id();
+        let local_workspace = runtime
+            .register_workspace(local_id, &workspace_root)
+            .unwrap();
+        let remote = HostRecord::new(
+            "lab",
+            HostTransport::Ssh {
+                destination: "homelab".to_owned(),
+            },
+        );
+        let remote_workspace = WorkspaceRecord::new(remote.id, "/srv/app");
+        let (event_tx, _event_rx) = std::sync::mpsc::channel();
+        let mut snapshot = runtime.snapshot().unwrap();
+        snapshot.hosts.push(remote.clone());
+        snapshot.workspaces.push(remote_workspace.clone());
+        let mut state = ClientState::new(
+            crate::client_config::load_contents(None, None, None).unwrap(),
+            snapshot,
+            event_tx,
+        );
+
+        let restored = restore(
+            &mut state,
+            &mut runtime,
+            Restorable {
+                forwards: vec![
+                    saved(forward(local_id, local_workspace, 3000)),
+                    saved(forward(remote.id, remote_workspace.id, 3001)),
+                    saved(forward(HostId::new(), WorkspaceId::new(), 3002)),
+                    // The host is still known but the workspace was removed.
+                    saved(forward(remote.id, WorkspaceId::new(), 3003)),
+                ],
+                socket_forwards: vec![
+                    SavedSocketForward::of(&SocketForwardState::new(
+                        local_id,
+                        local_workspace,
+                        "/tmp/app.sock".to_owned(),
+                        PathBuf::from("/tmp/app.sock"),
+                    ))
+                    .into_forward(),
+                    SavedSocketForward::of(&SocketForwardState::new(
+                        remote.id,
+                        WorkspaceId::new(),
+                        "/srv/app/db.sock".to_owned(),
+                        PathBuf::from("/tmp/db.sock"),
+                    ))
+                    .into_forward(),
+                ],
+                reverse_forwards: vec![SavedReverseForward::of(&ReverseForwardState::new(
+                    remote.id,
+                    remote_workspace.id,
+                    8080,
+                    8080,
+                ))
+                .into_forward()],
+            },
+        );
+
+        assert_eq!(restored, 4);
+        let statuses = state
+            .forwards
+            .iter()
+            .map(|forward| (forward.host_id, forward.status.clone()))
+            .collect::<Vec<_>>();
+        // A loopback service on the client's own machine needs no tunnel.
+        assert_eq!(statuses[0], (local_id, ForwardStatus::Direct));
+        assert_eq!(statuses[1].0, remote.id);
+        assert!(matches!(
+            statuses[1].1,
+            ForwardStatus::Reconnecting | ForwardStatus::PortConflict
+        ));
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(state.socket_forwards.len(), 1);
+        assert_eq!(state.socket_forwards[0].status, ForwardStatus::Direct);
+        assert_eq!(
+            state.reverse_forwards[0].status,
+            ForwardStatus::Reconnecting
+        );
+    }
+
+    /// What `open` hands back: a fresh identity waiting to reconnect.
+    fn saved(forward: ForwardState) -> ForwardState {
+        SavedForward::of(&forward).into_forward()
+    }
+}
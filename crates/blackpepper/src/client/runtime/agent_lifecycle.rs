@@ -19,7 +19,9 @@ impl ClientRuntime {
             RequestOperation::AgentSnapshot { run_id },
         )? {
             ResponsePayload::HostService { payload } => match *payload {
-                crate::core::HostServicePayload::AgentSnapshot { snapshot } => Ok(snapshot),
+                crate::core::HostServicePayload::AgentSnapshot { snapshot } => {
+                    Ok(snapshot.map(|snapshot| *snapshot))
+                }
                 _ => Err("bp-host returned an unexpected agent snapshot response.".to_owned()),
             },
             _ => Err("bp-host returned an unexpected agent snapshot response.".to_owned()),
@@ -23,6 +23,12 @@ pub(crate) struct SpawnedAgent {
     pub capability: &'static str,
 }
 
+/// Agent tabs are named after their run, so a reused numeric tab ID can be
+/// told apart from the tab this run created.
+pub(super) fn agent_tab_name(run_id: AgentRunId) -> String {
+    format!("agent-{run_id}")
+}
+
 impl ClientRuntime {
     pub(crate) fn spawn_agent(
         &mut self,
@@ -35,12 +41,33 @@ impl ClientRuntime {
             .workspace(workspace_id)
             .map_err(|error| error.to_string())?
             .ok_or_else(|| "The selected workspace no longer exists.".to_string())?;
+        let definition = match provider {
+            Provider::Custom(name) => Some(
+                self.workspace_config(&workspace)?
+                    .providers
+                    .remove(&name)
+                    .ok_or_else(|| {
+                        format!("No [providers.{name}] entry is configured for this workspace.")
+                    })?,
+            ),
+            Provider::Codex | Provider::Claude | Provider::OpenCode => None,
+        };
         let provider_kind = match provider {
             Provider::Codex => ProviderKind::Codex,
             Provider::Claude => ProviderKind::Claude,
             Provider::OpenCode => ProviderKind::OpenCode,
+            Provider::Custom(name) => ProviderKind::Custom {
+                name,
+                integration: definition
+                    .as_ref()
+                    .map_or_else(Default::default, |definition| definition.integration),
+            },
         };
-        let provider_binary = self.command_path(workspace.host_id, provider_kind.command())?;
+        let program = definition
+            .as_ref()
+            .and_then(|definition| definition.program.as_deref())
+            .unwrap_or(provider_kind.command());
+        let provider_binary = self.command_path(workspace.host_id, program)?;
         if provider_kind == ProviderKind::OpenCode {
             self.reserve_opencode_inline_config(workspace.host_id)?;
         }
@@ -57,6 +84,12 @@ impl ClientRuntime {
             &integration_dir,
         )?;
         launch.program = provider_binary;
+        if let Some(definition) = definition {
+            launch.args = definition.args;
+            for (key, value) in definition.env {
+                launch.env.entry(key).or_insert(value);
+            }
+        }
         if let Err(error) = self.install_assets(workspace.host_id, &launch.assets) {
             let cleanup = self.cleanup_assets_note(workspace.host_id, &launch.assets);
             return Err(format!("{error}{cleanup}"));
@@ -133,7 +166,7 @@ impl ClientRuntime {
         }
 
         let initial = initial_agent_command(&launch);
-        let name = format!("agent-{run_id}");
+        let name = agent_tab_name(run_id);
         let tab_result = self.transport_mut(workspace.host_id).and_then(|transport| {
             zellij
                 .ensure_tab(
@@ -230,9 +263,12 @@ impl ClientRuntime {
                 "Could not persist the exact Zellij agent binding: {error}.{tab}{abort}{assets}"
             ));
         }
-        if let Err(error) =
+        let health = if launch.reports_health() {
             self.wait_for_integration_health(workspace.host_id, run_id, tab_id, &launch)
-        {
+        } else {
+            Ok(())
+        };
+        if let Err(error) = health {
             // Codex hook trust can only be reviewed in the running TUI. Keep
             // that tab available for `/hooks`, but do not rediscover an
             // unhealthy status descriptor. The user closes it and retries
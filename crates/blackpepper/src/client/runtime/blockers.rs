@@ -254,6 +254,7 @@ mod tests {
             sequence: 1,
             observed_at_ms: 1,
             source: crate::status_monitor::BlockerSource::ZellijViewport,
+            manifest: crate::agent_status::BlockerManifestSource::Bundled,
             manifest_version: "test".to_owned(),
             state: crate::status_monitor::BlockerChange::Cleared,
         };
@@ -35,6 +35,7 @@ impl RuntimeFixture {
                 host_operation_generations: BTreeMap::new(),
                 deferred_host_actions: BTreeMap::new(),
                 startup_warnings: Vec::new(),
+                blocked_services: Vec::new(),
                 _singleton: Some(singleton),
             },
         }
@@ -9,6 +9,7 @@ use std::time::Duration;
 /// A client-owned loopback proxy for a local listener bound only to another
 /// interface. Existing loopback/wildcard listeners need no proxy.
 pub(super) struct LocalPortProxy {
+    target: SocketAddr,
     stop: Arc<AtomicBool>,
     accept_thread: Option<JoinHandle<()>>,
 }
@@ -38,11 +39,17 @@ impl LocalPortProxy {
             }
         });
         Ok(Self {
+            target,
             stop,
             accept_thread: Some(accept_thread),
         })
     }
 
+    /// Where accepted connections are relayed.
+    pub(super) fn target(&self) -> SocketAddr {
+        self.target
+    }
+
     fn stop(&mut self) {
         self.stop.store(true, Ordering::Release);
         if let Some(thread) = self.accept_thread.take() {
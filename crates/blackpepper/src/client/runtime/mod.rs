@@ -13,8 +13,10 @@ mod panes;
 mod periodic;
 pub(super) mod ports;
 mod restore;
+mod reverse_ports;
 mod services;
 mod session_lease;
+pub(super) mod socket_ports;
 mod startup;
 mod terminal_identity;
 #[cfg(test)]
@@ -41,7 +43,7 @@ pub(crate) use operation::{
     HostOperationValue, WorktreeMutationResult,
 };
 pub(crate) use periodic::PeriodicRefreshJob;
-pub(crate) use restore::{ConnectionRestoreReport, ConnectionRestoreRuntime};
+pub(crate) use restore::{ConnectionRestoreReport, ConnectionRestoreRuntime, RestoredForwards};
 pub(crate) use worktrunk::WorktreeChange;
 
 pub(crate) struct ClientRuntime {
@@ -59,6 +61,7 @@ pub(crate) struct ClientRuntime {
     host_operation_generations: BTreeMap<HostId, u64>,
     deferred_host_actions: BTreeMap<HostId, Vec<operation::DeferredHostAction>>,
     startup_warnings: Vec<String>,
+    blocked_services: Vec<services::BlockedService>,
     // Restore workers never acquire or release the interactive singleton.
     _singleton: Option<SingletonLock>,
 }
@@ -99,6 +102,31 @@ impl ClientRuntime {
         paths.prepare().map_err(|error| error.to_string())?;
         let singleton = SingletonLock::acquire(paths.singleton_lock_path())
             .map_err(|error| error.to_string())?;
+        let mut runtime = Self::open(paths, config, Some(singleton))?;
+        if cwd.is_dir() {
+            runtime.register_workspace(runtime.local_host_id, cwd)?;
+        }
+        if let Err(error) = runtime.restore_host_workspaces(runtime.local_host_id) {
+            runtime.startup_warnings.push(error);
+        }
+        let snapshot = runtime.snapshot()?;
+        Ok((runtime, snapshot))
+    }
+
+    /// Opens the shared registry for one scripted command. Scripts run beside
+    /// an interactive client, so they skip the singleton and workspace
+    /// restoration; host-side leases still serialize every session mutation.
+    pub(crate) fn initialize_scripted(config: &ClientConfig) -> Result<Self, String> {
+        let paths = CorePaths::discover().map_err(|error| error.to_string())?;
+        paths.prepare().map_err(|error| error.to_string())?;
+        Self::open(paths, config, None)
+    }
+
+    fn open(
+        paths: CorePaths,
+        config: &ClientConfig,
+        singleton: Option<SingletonLock>,
+    ) -> Result<Self, String> {
         let mut registry =
             HostRegistry::open(paths.registry_path()).map_err(|error| error.to_string())?;
         let local_host_id = registry
@@ -130,7 +158,7 @@ impl ClientRuntime {
             }
         }
 
-        let mut runtime = Self {
+        Ok(Self {
             paths,
             registry,
             local_host_id,
@@ -144,16 +172,9 @@ impl ClientRuntime {
             host_operation_generations: BTreeMap::new(),
             deferred_host_actions: BTreeMap::new(),
             startup_warnings: Vec::new(),
-            _singleton: Some(singleton),
-        };
-        if cwd.is_dir() {
-            runtime.register_workspace(local_host_id, cwd)?;
-        }
-        if let Err(error) = runtime.restore_host_workspaces(local_host_id) {
-            runtime.startup_warnings.push(error);
-        }
-        let snapshot = runtime.snapshot()?;
-        Ok((runtime, snapshot))
+            blocked_services: Vec::new(),
+            _singleton: singleton,
+        })
     }
 
     pub(crate) fn take_startup_warnings(&mut self) -> Vec<String> {
@@ -180,6 +201,10 @@ impl ClientRuntime {
         self.local_host_id
     }
 
+    pub(crate) fn forwards_path(&self) -> PathBuf {
+        self.paths.forwards_path()
+    }
+
     pub(crate) fn add_ssh_host(&mut self, name: &str, destination: &str) -> Result<HostId, String> {
         let hosts = self.snapshot()?.hosts;
         if let Some(host) = hosts.iter().find(|host| {
@@ -50,6 +50,7 @@ impl HostOperationRuntime {
                 host_operation_generations: BTreeMap::new(),
                 deferred_host_actions: BTreeMap::new(),
                 startup_warnings: Vec::new(),
+                blocked_services: Vec::new(),
                 _singleton: None,
             });
         }
@@ -136,6 +137,7 @@ impl ClientRuntime {
         }
         self.blocker_watchers
             .extend(std::mem::take(&mut runtime.blocker_watchers));
+        self.blocked_services.append(&mut runtime.blocked_services);
         Ok(())
     }
 
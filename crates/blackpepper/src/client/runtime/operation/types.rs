@@ -1,8 +1,9 @@
 use super::super::{ClientRuntime, SpawnedAgent, WorktreeChange};
 use crate::agent_status::Provider;
 use crate::client::ClientCommand;
+use crate::client_config::DeclaredForward;
 use crate::core::{HostId, WorkspaceId, WorkspaceRecord};
-use crate::ports::ForwardState;
+use crate::ports::{ForwardState, ReverseForwardState, SocketForwardState};
 use crate::transport::PtyProcess;
 use std::path::PathBuf;
 
@@ -20,9 +21,29 @@ pub(crate) enum HostOperationContext {
         workspace_id: WorkspaceId,
         name: String,
     },
+    /// An automatic relaunch under `restart = "on-failure"`.
+    ServiceRestart {
+        workspace_id: WorkspaceId,
+        name: String,
+    },
+    ServiceStop {
+        workspace_id: WorkspaceId,
+        name: String,
+    },
+    /// `:service restart`, which relaunches a service whatever its state.
+    ServiceRerun {
+        workspace_id: WorkspaceId,
+        name: String,
+    },
+    ServiceStatus {
+        workspace_id: WorkspaceId,
+    },
     WorktreeList {
         workspace_id: WorkspaceId,
     },
+    PullRequestList {
+        workspace_id: WorkspaceId,
+    },
     WorktreeMutation {
         workspace_id: WorkspaceId,
         command: ClientCommand,
@@ -38,10 +59,27 @@ pub(crate) enum HostOperationContext {
     ForwardStart {
         workspace_id: WorkspaceId,
     },
+    DeclaredForwards {
+        workspace_id: WorkspaceId,
+    },
     ForwardCancel {
         workspace_id: WorkspaceId,
         forward_id: uuid::Uuid,
     },
+    SocketForwardStart {
+        workspace_id: WorkspaceId,
+    },
+    SocketForwardCancel {
+        workspace_id: WorkspaceId,
+        forward_id: uuid::Uuid,
+    },
+    ReverseStart {
+        workspace_id: WorkspaceId,
+    },
+    ReverseCancel {
+        workspace_id: WorkspaceId,
+        forward_id: uuid::Uuid,
+    },
     Attach {
         workspace_id: WorkspaceId,
     },
@@ -52,6 +90,19 @@ pub(crate) enum HostOperationContext {
     InitialShellFocus {
         workspace_id: WorkspaceId,
     },
+    AgentFocus {
+        workspace_id: WorkspaceId,
+    },
+    AgentInput {
+        workspace_id: WorkspaceId,
+        run_id: crate::core::AgentRunId,
+        provider: crate::agent_status::Provider,
+    },
+    AgentAnswer {
+        provider: crate::agent_status::Provider,
+        rule_id: String,
+        answer: String,
+    },
     WorkspaceUngroup {
         workspace_id: WorkspaceId,
     },
@@ -67,7 +118,11 @@ pub(crate) enum HostOperationValue {
     ServiceStarted {
         tab_id: u64,
     },
+    /// False when no tab for the service was open.
+    ServiceStopped(bool),
+    ServiceStates(Vec<(String, crate::zellij::PaneProcessState)>),
     Worktrees(crate::worktrunk::WorktreeList),
+    PullRequests(Vec<crate::core::OpenPullRequest>),
     WorktreeMutation(WorktreeMutationResult),
     AgentDiagnostics {
         snapshots: Vec<(
@@ -79,18 +134,29 @@ pub(crate) enum HostOperationValue {
         snapshot: crate::ports::PortSnapshot,
     },
     Forwarded(ForwardState),
+    /// One result per declared forward, failures already naming their port.
+    DeclaredForwards(Vec<Result<ForwardState, String>>),
     ForwardCancelled(ForwardState),
+    SocketForwarded(SocketForwardState),
+    SocketForwardCancelled(SocketForwardState),
+    Reversed(ReverseForwardState),
+    ReverseCancelled(ReverseForwardState),
     Attached {
         workspace_id: WorkspaceId,
         process: PtyProcess,
         provisional_clients: usize,
+        declared_forwards: Result<Vec<DeclaredForward>, String>,
     },
     RegisteredAndAttached {
         workspace_id: WorkspaceId,
         path: PathBuf,
         attachment: Result<(PtyProcess, usize), String>,
+        declared_forwards: Result<Vec<DeclaredForward>, String>,
     },
     InitialShellFocused,
+    AgentFocused,
+    AgentInputSent,
+    AgentAnswered,
     WorkspaceUngrouped(WorkspaceRecord),
     Terminated,
 }
@@ -100,7 +166,14 @@ pub(crate) struct WorktreeMutationResult {
     /// Present only for approved removal. It is the authoritative remainder
     /// after attempting to stop every client-owned forward.
     pub forwards: Option<Vec<ForwardState>>,
+    /// The same remainders for socket and reverse forwards.
+    pub socket_forwards: Option<Vec<crate::ports::SocketForwardState>>,
+    pub reverse_forwards: Option<Vec<crate::ports::ReverseForwardState>>,
+    /// The workspaces whose forwards `forwards` replaces.
+    pub removes: Vec<WorkspaceId>,
     pub session_error: Option<String>,
+    /// The agent `:agent spawn --worktree` started in the new worktree.
+    pub agent: Option<Result<SpawnedAgent, String>>,
 }
 
 pub(crate) struct CompletedHostOperation {
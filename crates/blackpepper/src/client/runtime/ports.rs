@@ -1,6 +1,9 @@
 use super::{connection, ClientRuntime};
+use crate::client_config::DeclaredForward;
 use crate::core::{HostId, RegistrySnapshot, WorkspaceId, WorkspaceRecord};
-use crate::ports::{ForwardState, ForwardStatus, PortSnapshot, RemotePortTarget};
+use crate::ports::{
+    ForwardState, ForwardStatus, PortSnapshot, ProbeCompleteness, RemotePortTarget,
+};
 use crate::transport::{LocalForward, TransportError};
 use std::net::{IpAddr, Ipv4Addr};
 use std::path::Path;
@@ -30,6 +33,7 @@ impl ClientRuntime {
         &mut self,
         workspace_id: WorkspaceId,
         target: RemotePortTarget,
+        local_port: Option<u16>,
     ) -> Result<ForwardState, String> {
         // Reject a known recovery marker before starting another helper. The
         // host-side lease then closes the stale-client gap: it validates the
@@ -45,7 +49,9 @@ impl ClientRuntime {
         if workspace.host_id == self.local_host_id {
             let remote =
                 super::local_proxy::target_socket(&target.remote_host, target.remote_port)?;
-            if remote.ip().is_loopback() {
+            // A loopback service is reachable as-is unless the caller asked
+            // for a different local port, which needs the proxy.
+            if remote.ip().is_loopback() && local_port.is_none_or(|port| port == remote.port()) {
                 return Ok(ForwardState {
                     id: uuid::Uuid::new_v4(),
                     host_id: workspace.host_id,
@@ -57,21 +63,38 @@ impl ClientRuntime {
                     status: ForwardStatus::Direct,
                 });
             }
-            let state = ForwardState::new(workspace.host_id, workspace_id, target)
-                .map_err(|error| error.to_string())?;
+            let state =
+                ForwardState::with_local_port(workspace.host_id, workspace_id, target, local_port)
+                    .map_err(|error| error.to_string())?;
             let proxy = super::local_proxy::LocalPortProxy::start(state.local_address, remote)
                 .map_err(|error| format!("Could not start the local loopback proxy: {error}"))?;
             self.local_port_proxies.insert(state.local_address, proxy);
             return Ok(state);
         }
-        let state = ForwardState::new(workspace.host_id, workspace_id, target)
-            .map_err(|error| error.to_string())?;
+        let state =
+            ForwardState::with_local_port(workspace.host_id, workspace_id, target, local_port)
+                .map_err(|error| error.to_string())?;
         self.transport_mut(workspace.host_id)?
             .forward_local_port(local_forward(&state))
             .map_err(|error| error.to_string())?;
         Ok(state)
     }
 
+    /// The workspace's `[[forward]]` declarations, read from its own host the
+    /// same way its startup services are.
+    pub(crate) fn declared_forwards(
+        &mut self,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<DeclaredForward>, String> {
+        let workspace = self
+            .snapshot()?
+            .workspaces
+            .into_iter()
+            .find(|workspace| workspace.id == workspace_id)
+            .ok_or_else(|| "The selected workspace no longer exists.".to_owned())?;
+        Ok(self.workspace_config(&workspace)?.forwards)
+    }
+
     pub(crate) fn cancel_workspace_forward(
         &mut self,
         workspace_id: WorkspaceId,
@@ -211,7 +234,8 @@ impl ClientRuntime {
                     return;
                 }
             };
-            if target.ip().is_loopback() {
+            if target.ip().is_loopback() && forward.requested_local_address.port() == target.port()
+            {
                 forward.status = ForwardStatus::Direct;
                 return;
             }
@@ -248,7 +272,7 @@ impl ClientRuntime {
     }
 }
 
-fn forwardable_workspace(
+pub(super) fn forwardable_workspace(
     snapshot: &RegistrySnapshot,
     workspace_id: WorkspaceId,
 ) -> Result<WorkspaceRecord, String> {
@@ -266,16 +290,135 @@ fn forwardable_workspace(
         .ok_or_else(|| "The selected workspace no longer exists.".to_owned())
 }
 
+/// One workspace's `[[forward]]` declarations and which of them had a running
+/// listener in the previous port snapshot.
+#[derive(Debug, Default)]
+pub struct DeclaredForwards {
+    declared: Vec<DeclaredForward>,
+    present: Vec<DeclaredForward>,
+}
+
+impl DeclaredForwards {
+    pub(crate) fn new(declared: Vec<DeclaredForward>) -> Self {
+        Self {
+            declared,
+            present: Vec::new(),
+        }
+    }
+
+    /// Take an edited set of declarations. One that is still declared exactly
+    /// as before keeps its record of a running listener, wherever it moved in
+    /// the file, so the edit does not fire it again.
+    pub(crate) fn redeclare(&mut self, declared: Vec<DeclaredForward>) {
+        self.present.retain(|present| declared.contains(present));
+        self.declared = declared;
+    }
+
+    /// `auto` declarations whose listener appeared since the previous
+    /// snapshot, each resolved exactly as `:forward` would resolve it. A
+    /// declaration fires once per appearance, so cancelling its forward
+    /// sticks until the service itself restarts.
+    pub(crate) fn appeared(
+        &mut self,
+        snapshot: &PortSnapshot,
+        workspace_root: &str,
+    ) -> Vec<(DeclaredForward, Result<RemotePortTarget, String>)> {
+        // A failed probe says nothing about which services stopped.
+        if snapshot.completeness == ProbeCompleteness::Failed {
+            return Vec::new();
+        }
+        let mut appeared = Vec::new();
+        let mut present = Vec::new();
+        for declared in &self.declared {
+            // Config validation already rejected an unusable address.
+            let Ok(wanted) = declared
+                .bind
+                .as_deref()
+                .map(|address| RemotePortTarget::from_bind_address(address, declared.port))
+                .transpose()
+            else {
+                continue;
+            };
+            let running = snapshot.listeners.iter().any(|listener| {
+                listener.port == declared.port
+                    && listener_matches_workspace(
+                        listener.workspace_path.as_deref(),
+                        workspace_root,
+                    )
+                    && wanted
+                        .as_ref()
+                        .is_none_or(|wanted| listener.forward_target().as_ref() == Ok(wanted))
+            });
+            if !running {
+                continue;
+            }
+            present.push(declared.clone());
+            if declared.auto && !self.present.contains(declared) {
+                appeared.push((
+                    declared.clone(),
+                    resolve_workspace_target(
+                        snapshot,
+                        workspace_root,
+                        false,
+                        declared.port,
+                        declared.bind.as_deref(),
+                    ),
+                ));
+            }
+        }
+        self.present = present;
+        appeared
+    }
+
+    /// Re-arm a declaration whose forward could not be scheduled, so the next
+    /// snapshot tries it again.
+    pub(crate) fn retry(&mut self, declared: &DeclaredForward) {
+        self.present.retain(|present| present != declared);
+    }
+}
+
+/// Pick one exact listener for `port` among those attributed to the workspace
+/// (or every listener on the host), refusing any target that more than one
+/// socket could accept.
+pub(crate) fn resolve_workspace_target(
+    snapshot: &PortSnapshot,
+    workspace_root: &str,
+    all_host: bool,
+    remote_port: u16,
+    bind_address: Option<&str>,
+) -> Result<RemotePortTarget, String> {
+    let visible = snapshot.listeners.iter().filter(|listener| {
+        all_host || listener_matches_workspace(listener.workspace_path.as_deref(), workspace_root)
+    });
+    let target = crate::ports::resolve_forward_target(visible, remote_port, bind_address)?;
+    if crate::ports::target_is_ambiguous(&snapshot.listeners, &target) {
+        return Err(format!(
+            "Multiple processes can accept {}; TCP forwarding cannot select one process. Stop the overlapping listener or use another port.",
+            target.endpoint()
+        ));
+    }
+    Ok(target)
+}
+
 pub(super) fn forward_workspace_is_registered(
     snapshot: &RegistrySnapshot,
     forward: &ForwardState,
 ) -> bool {
-    !snapshot
-        .pending_worktree_removals
-        .contains(&forward.workspace_id)
-        && snapshot.workspaces.iter().any(|workspace| {
-            workspace.id == forward.workspace_id && workspace.host_id == forward.host_id
-        })
+    workspace_is_registered(snapshot, forward.host_id, forward.workspace_id)
+}
+
+/// Whether a tunnel's owning workspace is still registered on its host and
+/// not on its way out.
+pub(super) fn workspace_is_registered(
+    snapshot: &RegistrySnapshot,
+    host_id: HostId,
+    workspace_id: WorkspaceId,
+) -> bool {
+    !snapshot.pending_worktree_removals.contains(&workspace_id)
+        && snapshot
+            .workspaces
+            .iter()
+            .any(|workspace| workspace.id == workspace_id && workspace.host_id == host_id)
 }
 
 pub(super) fn local_forward(forward: &ForwardState) -> LocalForward {
@@ -25,6 +25,7 @@ fn test_runtime() -> (tempfile::TempDir, ClientRuntime) {
         host_operation_generations: std::collections::BTreeMap::new(),
         deferred_host_actions: std::collections::BTreeMap::new(),
         startup_warnings: Vec::new(),
+        blocked_services: Vec::new(),
         _singleton: Some(singleton),
     };
     (root, runtime)
@@ -163,6 +164,7 @@ fn pending_worktrunk_removal_refuses_a_new_forward() {
         .forward_workspace_port(
             workspace.id,
             RemotePortTarget::from_bind_address("127.0.0.1", 4321).unwrap(),
+            None,
         )
         .unwrap_err();
 
@@ -236,3 +238,138 @@ fn reconnect_refuses_a_removed_local_workspace() {
 
     assert!(matches!(forward.status, ForwardStatus::Failed(_)));
 }
+
+fn listening(port: u16, workspace_path: Option<&str>) -> crate::ports::PortListener {
+    crate::ports::PortListener {
+        bind_address: "127.0.0.1".to_owned(),
+        port,
+        pid: Some(42),
+        process: Some("node".to_owned()),
+        workspace_path: workspace_path.map(std::path::PathBuf::from),
+        attribution: crate::ports::AttributionConfidence::ExactCwd,
+        http: None,
+    }
+}
+
+fn probe(listeners: Vec<crate::ports::PortListener>) -> PortSnapshot {
+    PortSnapshot {
+        listeners,
+        completeness: ProbeCompleteness::Full,
+        sockets: Vec::new(),
+        warning: None,
+    }
+}
+
+#[test]
+fn a_declared_forward_fires_once_per_appearance_of_its_own_listener() {
+    let declared = |port, auto| DeclaredForward {
+        port,
+        bind: None,
+        local_port: None,
+        auto,
+    };
+    let mut forwards = DeclaredForwards::new(vec![declared(3000, true), declared(5432, false)]);
+    // Another workspace's service on the same port is not this one's.
+    assert!(forwards
+        .appeared(
+            &probe(vec![listening(3000, Some("/srv/other"))]),
+            "/srv/app"
+        )
+        .is_empty());
+
+    let running = probe(vec![
+        listening(3000, Some("/srv/app/web")),
+        listening(5432, Some("/srv/app")),
+    ]);
+    let appeared = forwards.appeared(&running, "/srv/app");
+    assert_eq!(appeared.len(), 1, "only auto declarations are forwarded");
+    assert_eq!(appeared[0].0.port, 3000);
+    assert_eq!(appeared[0].1.as_ref().unwrap().endpoint(), "127.0.0.1:3000");
+    assert!(forwards.appeared(&running, "/srv/app").is_empty());
+
+    // A failed probe neither stops nor restarts anything.
+    let failed = crate::ports::failed_probe("timed out".to_owned());
+    assert!(forwards.appeared(&failed, "/srv/app").is_empty());
+    assert!(forwards.appeared(&running, "/srv/app").is_empty());
+
+    // A restarted service is forwarded again, as is a re-armed declaration.
+    assert!(forwards.appeared(&probe(Vec::new()), "/srv/app").is_empty());
+    assert_eq!(forwards.appeared(&running, "/srv/app").len(), 1);
+    forwards.retry(&declared(3000, true));
+    assert_eq!(forwards.appeared(&running, "/srv/app").len(), 1);
+}
+
+#[test]
+fn rereading_the_same_declarations_keeps_what_already_fired() {
+    let declared = |port| DeclaredForward {
+        port,
+        bind: None,
+        local_port: None,
+        auto: true,
+    };
+    let (event_tx, _event_rx) = std::sync::mpsc::channel();
+    let mut state = crate::client::ClientState::new(
+        crate::client_config::load_contents(None, None, None).unwrap(),
+        crate::core::RegistrySnapshot::default(),
+        event_tx,
+    );
+    let workspace_id = WorkspaceId::new();
+    let running = probe(vec![
+        listening(3000, Some("/srv/app")),
+        listening(4000, Some("/srv/app")),
+    ]);
+    state.remember_declared_forwards(workspace_id, vec![declared(3000)]);
+    let forwards = state.declared_forwards.get_mut(&workspace_id).unwrap();
+    assert_eq!(forwards.appeared(&running, "/srv/app").len(), 1);
+
+    // A refresh that reads the same config must not forward the port again.
+    state.remember_declared_forwards(workspace_id, vec![declared(3000)]);
+    let forwards = state.declared_forwards.get_mut(&workspace_id).unwrap();
+    assert!(forwards.appeared(&running, "/srv/app").is_empty());
+
+    // An edited config is picked up without firing what was already up, and
+    // clearing it stops tracking.
+    state.remember_declared_forwards(workspace_id, vec![declared(4000), declared(3000)]);
+    let forwards = state.declared_forwards.get_mut(&workspace_id).unwrap();
+    let appeared = forwards.appeared(&running, "/srv/app");
+    assert_eq!(appeared.len(), 1);
+    assert_eq!(appeared[0].0.port, 4000);
+    state.remember_declared_forwards(workspace_id, Vec::new());
+    assert!(!state.declared_forwards.contains_key(&workspace_id));
+}
+
+#[test]
+fn editing_another_declaration_leaves_a_cancelled_forward_cancelled() {
+    let web = DeclaredForward {
+        port: 3000,
+        bind: None,
+        local_port: None,
+        auto: true,
+    };
+    let database = |local_port| DeclaredForward {
+        port: 5432,
+        bind: None,
+        local_port: Some(local_port),
+        auto: false,
+    };
+    let (event_tx, _event_rx) = std::sync::mpsc::channel();
+    let mut state = crate::client::ClientState::new(
+        crate::client_config::load_contents(None, None, None).unwrap(),
+        crate::core::RegistrySnapshot::default(),
+        event_tx,
+    );
+    let workspace_id = WorkspaceId::new();
+    let running = probe(vec![
+        listening(3000, Some("/srv/app")),
+        listening(5432, Some("/srv/app")),
+    ]);
+    state.remember_declared_forwards(workspace_id, vec![web.clone(), database(15432)]);
+    let forwards = state.declared_forwards.get_mut(&workspace_id).unwrap();
+    assert_eq!(forwards.appeared(&running, "/srv/app").len(), 1);
+
+    // The user cancels the web forward while its listener keeps running, then
+    // edits only the database entry.
+    state.remember_declared_forwards(workspace_id, vec![database(15433), web]);
+    let forwards = state.declared_forwards.get_mut(&workspace_id).unwrap();
+    assert!(forwards.appeared(&running, "/srv/app").is_empty());
+}
@@ -3,7 +3,7 @@ mod state;
 
 use super::{ClientRuntime, HostSlot};
 use crate::core::{HostId, HostPeriodicRefresh, HostRecord, RegistrySnapshot};
-use crate::ports::ForwardState;
+use crate::ports::{ForwardState, ReverseForwardState, SocketForwardState};
 use crate::transport::CommandCancellation;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -35,12 +35,40 @@ pub(super) struct PendingRestoreRuntime {
     watchers: BTreeMap<crate::core::AgentRunId, super::blockers::BlockerWatcher>,
 }
 
+/// The client-owned tunnels of one host, handed to its restoration and
+/// returned in its report.
+#[derive(Default)]
+pub(crate) struct RestoredForwards {
+    pub forwards: Vec<ForwardState>,
+    pub socket_forwards: Vec<SocketForwardState>,
+    pub reverse_forwards: Vec<ReverseForwardState>,
+}
+
+impl RestoredForwards {
+    fn rehost(&mut self, previous: HostId, host_id: HostId) {
+        let rehost = |forward_host: &mut HostId| {
+            if *forward_host == previous {
+                *forward_host = host_id;
+            }
+        };
+        self.forwards
+            .iter_mut()
+            .for_each(|forward| rehost(&mut forward.host_id));
+        self.socket_forwards
+            .iter_mut()
+            .for_each(|forward| rehost(&mut forward.host_id));
+        self.reverse_forwards
+            .iter_mut()
+            .for_each(|forward| rehost(&mut forward.host_id));
+    }
+}
+
 pub(crate) struct ConnectionRestoreReport {
     pub previous_host_id: HostId,
     pub host_id: HostId,
     pub snapshot: Result<RegistrySnapshot, String>,
     pub refresh: Option<HostPeriodicRefresh>,
-    pub forwards: Vec<ForwardState>,
+    pub forwards: RestoredForwards,
     pub errors: Vec<String>,
     pub restored_workspaces: Option<usize>,
     pub watcher_errors: Vec<String>,
@@ -209,6 +237,7 @@ fn move_host_entries(from: &mut ClientRuntime, to: &mut ClientRuntime, host_id:
     for (run_id, watcher) in std::mem::take(&mut from.blocker_watchers) {
         to.blocker_watchers.insert(run_id, watcher);
     }
+    to.blocked_services.append(&mut from.blocked_services);
 }
 
 fn ssh_destination(host: &HostRecord) -> Option<String> {
@@ -1,8 +1,7 @@
-use super::{ConnectionRestoreReport, ConnectionRestoreRuntime};
+use super::{ConnectionRestoreReport, ConnectionRestoreRuntime, RestoredForwards};
 use crate::client::runtime::{connection, HostSlot};
 use crate::client::ClientEvent;
 use crate::core::{HostId, HostPeriodicRefresh, WorkspaceId};
-use crate::ports::ForwardState;
 use crate::transport::{CommandCancellation, ConnectionState};
 use std::sync::mpsc::Sender;
 
@@ -10,7 +9,7 @@ impl ConnectionRestoreRuntime {
     pub(crate) fn run(
         mut self,
         token: uuid::Uuid,
-        mut forwards: Vec<ForwardState>,
+        mut forwards: RestoredForwards,
         attached_workspaces: Vec<WorkspaceId>,
         cancellation: CommandCancellation,
         events: Sender<ClientEvent>,
@@ -38,11 +37,7 @@ impl ConnectionRestoreRuntime {
                 );
             }
             let host_id = self.host_id;
-            for forward in &mut forwards {
-                if forward.host_id == connection_id {
-                    forward.host_id = host_id;
-                }
-            }
+            forwards.rehost(connection_id, host_id);
             progress(
                 &events,
                 token,
@@ -54,7 +49,7 @@ impl ConnectionRestoreRuntime {
                 Ok(snapshot) => {
                     let cleanup = self
                         .runtime_mut()
-                        .reconcile_forwards(&mut forwards, &snapshot);
+                        .reconcile_forwards(&mut forwards.forwards, &snapshot);
                     errors.extend(cleanup.failures);
                 }
                 Err(error) => errors.push(format!("Initial registry snapshot: {error}")),
@@ -83,12 +78,24 @@ impl ConnectionRestoreRuntime {
                     connection_id,
                     "Restoring client-owned tunnels…",
                 );
-                for forward in &mut forwards {
+                for forward in &mut forwards.forwards {
                     if cancellation.is_cancelled() {
                         break;
                     }
                     self.runtime_mut().reconnect_forward(forward);
                 }
+                for forward in &mut forwards.socket_forwards {
+                    if cancellation.is_cancelled() {
+                        break;
+                    }
+                    self.runtime_mut().reconnect_socket_forward(forward);
+                }
+                for forward in &mut forwards.reverse_forwards {
+                    if cancellation.is_cancelled() {
+                        break;
+                    }
+                    self.runtime_mut().reconnect_reverse_forward(forward);
+                }
             }
             let snapshot = if cancellation.is_cancelled() {
                 Err("Connection restoration was cancelled.".to_owned())
@@ -231,7 +238,7 @@ impl ConnectionRestoreRuntime {
 
     fn failed_report(
         &mut self,
-        forwards: Vec<ForwardState>,
+        forwards: RestoredForwards,
         cancelled: bool,
         error: String,
     ) -> ConnectionRestoreReport {
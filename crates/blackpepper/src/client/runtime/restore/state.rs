@@ -45,6 +45,7 @@ impl ConnectionRestoreRuntime {
             host_operation_generations: BTreeMap::new(),
             deferred_host_actions: BTreeMap::new(),
             startup_warnings: Vec::new(),
+            blocked_services: Vec::new(),
             _singleton: None,
         });
         Ok(())
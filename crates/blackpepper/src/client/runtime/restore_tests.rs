@@ -27,6 +27,7 @@ fn test_runtime() -> (tempfile::TempDir, ClientRuntime) {
         host_operation_generations: BTreeMap::new(),
         deferred_host_actions: BTreeMap::new(),
         startup_warnings: Vec::new(),
+        blocked_services: Vec::new(),
         _singleton: Some(singleton),
     };
     (root, runtime)
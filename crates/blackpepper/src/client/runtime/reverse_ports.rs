@@ -0,0 +1,297 @@
+//! Host-loopback ports carried back to services on the client.
+//!
+//! The SSH control master owns each `-R` listener, so a reverse forward lives
+//! exactly as long as the connection. A reconnect re-requests the same remote
+//! port; unlike a local forward there is no client port to keep free.
+
+use super::ClientRuntime;
+use crate::core::{HostId, RegistrySnapshot, WorkspaceId};
+use crate::ports::{ForwardStatus, ReverseForwardState};
+use crate::transport::{RemoteForward, TransportError};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+impl ClientRuntime {
+    pub(crate) fn reverse_workspace_port(
+        &mut self,
+        workspace_id: WorkspaceId,
+        local_port: u16,
+        remote_port: u16,
+    ) -> Result<ReverseForwardState, String> {
+        let workspace = self
+            .snapshot()?
+            .workspaces
+            .into_iter()
+            .find(|workspace| workspace.id == workspace_id)
+            .ok_or_else(|| "The selected workspace no longer exists.".to_owned())?;
+        let mut state =
+            ReverseForwardState::new(workspace.host_id, workspace_id, local_port, remote_port);
+        if workspace.host_id == self.local_host_id {
+            if local_port == remote_port {
+                state.status = ForwardStatus::Direct;
+                return Ok(state);
+            }
+            let proxy = super::local_proxy::LocalPortProxy::start(
+                loopback(remote_port),
+                loopback(local_port),
+            )
+            .map_err(|error| format!("Could not start the loopback proxy: {error}"))?;
+            self.local_port_proxies.insert(loopback(remote_port), proxy);
+            return Ok(state);
+        }
+        self.transport_mut(workspace.host_id)?
+            .forward_remote_port(remote_forward(&state))
+            .map_err(|error| error.to_string())?;
+        Ok(state)
+    }
+
+    pub(crate) fn cancel_reverse_forward(
+        &mut self,
+        forward: &ReverseForwardState,
+    ) -> Result<(), String> {
+        if forward.status == ForwardStatus::Direct {
+            return Ok(());
+        }
+        if forward.host_id == self.local_host_id {
+            // Local forwards key their proxies by the same loopback address,
+            // so only a proxy relaying to this forward's client port is ours.
+            let listen = loopback(forward.remote_port);
+            if self
+                .local_port_proxies
+                .get(&listen)
+                .is_some_and(|proxy| proxy.target() == loopback(forward.local_port))
+            {
+                self.local_port_proxies.remove(&listen);
+            }
+            return Ok(());
+        }
+        let Some(host) = self.hosts.get_mut(&forward.host_id) else {
+            // The control master that owned the listener is already gone.
+            return Ok(());
+        };
+        match host
+            .transport_mut()
+            .cancel_remote_forward(&remote_forward(forward))
+        {
+            Ok(()) | Err(TransportError::RemoteForwardNotOwned(_)) => Ok(()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    /// Stop a workspace's reverse forwards before a destructive removal. As
+    /// with local forwards, one that cannot be stopped stays tracked and
+    /// blocks the removal.
+    pub(crate) fn cancel_workspace_reverse_forwards(
+        &mut self,
+        forwards: &mut Vec<ReverseForwardState>,
+        workspace_id: WorkspaceId,
+    ) -> Result<usize, String> {
+        let mut cancelled = 0;
+        let mut failures = Vec::new();
+        let mut retained = Vec::with_capacity(forwards.len());
+        for mut forward in std::mem::take(forwards) {
+            if forward.workspace_id != workspace_id {
+                retained.push(forward);
+                continue;
+            }
+            match self.cancel_reverse_forward(&forward) {
+                Ok(()) => cancelled += 1,
+                Err(error) => {
+                    let message = format!(
+                        "Could not stop reverse forward {} before worktree removal: {error}",
+                        forward.remote_endpoint()
+                    );
+                    forward.status = ForwardStatus::Failed(message.clone());
+                    failures.push(message);
+                    retained.push(forward);
+                }
+            }
+        }
+        *forwards = retained;
+        if failures.is_empty() {
+            Ok(cancelled)
+        } else {
+            Err(format!(
+                "Worktree removal was blocked because {} reverse forward(s) could not be stopped: {}",
+                failures.len(),
+                failures.join(" | ")
+            ))
+        }
+    }
+
+    /// Stop and forget this host's reverse forwards whose workspace left the
+    /// registry; otherwise the control master keeps their host listener open
+    /// until the next reconnect. A failed cancellation stays visible and is
+    /// retried on the next refresh.
+    pub(crate) fn reconcile_reverse_forwards(
+        &mut self,
+        forwards: &mut Vec<ReverseForwardState>,
+        snapshot: &RegistrySnapshot,
+        host_id: HostId,
+    ) -> Vec<String> {
+        let mut failures = Vec::new();
+        let mut retained = Vec::with_capacity(forwards.len());
+        for mut forward in std::mem::take(forwards) {
+            if forward.host_id != host_id
+                || super::ports::workspace_is_registered(
+                    snapshot,
+                    forward.host_id,
+                    forward.workspace_id,
+                )
+            {
+                retained.push(forward);
+                continue;
+            }
+            if let Err(error) = self.cancel_reverse_forward(&forward) {
+                let message = format!(
+                    "Could not stop orphaned reverse forward {} for removed workspace {}: {error}",
+                    forward.remote_endpoint(),
+                    forward.workspace_id
+                );
+                forward.status = ForwardStatus::Failed(message.clone());
+                failures.push(message);
+                retained.push(forward);
+            }
+        }
+        *forwards = retained;
+        failures
+    }
+
+    /// Ask the fresh control master for the same host port again. A port
+    /// something else took in the meantime is reported, never moved.
+    pub(crate) fn reconnect_reverse_forward(&mut self, forward: &mut ReverseForwardState) {
+        let registered = self.snapshot().map(|snapshot| {
+            snapshot.workspaces.iter().any(|workspace| {
+                workspace.id == forward.workspace_id && workspace.host_id == forward.host_id
+            })
+        });
+        match registered {
+            Ok(true) => {}
+            Ok(false) => {
+                forward.status = ForwardStatus::Failed(
+                    "Owning workspace was removed; reverse forward was not restored.".to_owned(),
+                );
+                return;
+            }
+            Err(error) => {
+                forward.status = ForwardStatus::Failed(format!(
+                    "Could not verify the owning workspace; reverse forward was not restored: {error}"
+                ));
+                return;
+            }
+        }
+        if forward.status == ForwardStatus::Direct {
+            return;
+        }
+        let requested = remote_forward(forward);
+        forward.status = match self.transport_mut(forward.host_id) {
+            Ok(transport) => match transport.forward_remote_port(requested) {
+                Ok(_) => ForwardStatus::Active,
+                Err(error) => ForwardStatus::Failed(error.to_string()),
+            },
+            Err(error) => ForwardStatus::Failed(error),
+        };
+    }
+}
+
+fn remote_forward(forward: &ReverseForwardState) -> RemoteForward {
+    RemoteForward::loopback(forward.remote_port, forward.local_port)
+}
+
+fn loopback(port: u16) -> SocketAddr {
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_local_reverse_forward_is_direct_or_proxied_on_loopback() {
+        let root = tempfile::tempdir().unwrap();
+        let workspace_root = root.path().join("workspace");
+        std::fs::create_dir(&workspace_root).unwrap();
+        let mut runtime = ClientRuntime::test_fixture(root.path());
+        let workspace_id = runtime
+            .register_workspace(runtime.local_host_id(), &workspace_root)
+            .unwrap();
+
+        let same = runtime
+            .reverse_workspace_port(workspace_id, 5432, 5432)
+            .unwrap();
+        assert_eq!(same.status, ForwardStatus::Direct);
+
+        let service = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let local_port = service.local_addr().unwrap().port();
+        let free = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let remote_port = free.local_addr().unwrap().port();
+        drop(free);
+        let proxied = runtime
+            .reverse_workspace_port(workspace_id, local_port, remote_port)
+            .unwrap();
+        assert_eq!(proxied.status, ForwardStatus::Active);
+        std::net::TcpStream::connect(loopback(remote_port)).unwrap();
+        assert!(service.accept().is_ok());
+
+        // Neither a direct forward nor one relaying elsewhere owns the proxy
+        // listening on this port.
+        let mut direct = proxied.clone();
+        direct.status = ForwardStatus::Direct;
+        runtime.cancel_reverse_forward(&direct).unwrap();
+        let mut other = proxied.clone();
+        other.local_port = remote_port;
+        runtime.cancel_reverse_forward(&other).unwrap();
+        std::net::TcpStream::connect(loopback(remote_port)).unwrap();
+        assert!(service.accept().is_ok());
+
+        runtime.cancel_reverse_forward(&proxied).unwrap();
+        assert!(crate::ports::port_is_available(remote_port));
+    }
+
+    #[test]
+    fn removing_the_workspace_closes_its_reverse_listener() {
+        let root = tempfile::tempdir().unwrap();
+        let workspace_root = root.path().join("workspace");
+        std::fs::create_dir(&workspace_root).unwrap();
+        let mut runtime = ClientRuntime::test_fixture(root.path());
+        let host_id = runtime.local_host_id();
+        let workspace_id = runtime
+            .register_workspace(host_id, &workspace_root)
+            .unwrap();
+        let service = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let local_port = service.local_addr().unwrap().port();
+        let free = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let remote_port = free.local_addr().unwrap().port();
+        drop(free);
+        let mut forwards = vec![runtime
+            .reverse_workspace_port(workspace_id, local_port, remote_port)
+            .unwrap()];
+
+        let snapshot = runtime.snapshot().unwrap();
+        assert!(runtime
+            .reconcile_reverse_forwards(&mut forwards, &snapshot, host_id)
+            .is_empty());
+        assert_eq!(forwards.len(), 1);
+        assert!(!crate::ports::port_is_available(remote_port));
+
+        runtime.registry.remove_workspace(workspace_id).unwrap();
+        let snapshot = runtime.snapshot().unwrap();
+        assert!(runtime
+            .reconcile_reverse_forwards(&mut forwards, &snapshot, host_id)
+            .is_empty());
+        assert!(forwards.is_empty());
+        assert!(crate::ports::port_is_available(remote_port));
+    }
+
+    #[test]
+    fn reconnect_refuses_a_reverse_forward_whose_workspace_is_gone() {
+        let root = tempfile::tempdir().unwrap();
+        let mut runtime = ClientRuntime::test_fixture(root.path());
+        let mut forward = ReverseForwardState::new(HostId::new(), WorkspaceId::new(), 5432, 15432);
+        forward.mark_reconnecting();
+        runtime.reconnect_reverse_forward(&mut forward);
+        assert!(matches!(
+            forward.status,
+            ForwardStatus::Failed(ref reason) if reason.contains("was removed")
+        ));
+    }
+}
@@ -1,13 +1,32 @@
-use super::{text_path, ClientRuntime};
-use crate::client_config::{ClientConfig, StartupCommand};
-use crate::core::{SessionRecord, WorkspaceRecord};
+use super::{connection, text_path, ClientRuntime};
+use crate::client_config::{service_marker, ClientConfig, StartupCommand};
+use crate::core::{
+    HostServicePayload, RequestOperation, ResponsePayload, SessionRecord, WorkspaceId,
+    WorkspaceRecord,
+};
 use crate::transport::{HostCommand, HostTransport};
-use crate::zellij::{PaneProcessState, ZellijRuntime};
+use crate::zellij::{PaneProcessState, ZellijPane, ZellijRuntime};
 use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, Instant};
 
-const SERVICE_ID_ENV: &str = "BLACKPEPPER_SERVICE_ID";
+/// How long one dependency may take to become ready before its dependent
+/// gives up.
+const DEPENDENCY_TIMEOUT: Duration = Duration::from_secs(30);
+const DEPENDENCY_POLL: Duration = Duration::from_millis(500);
+
+/// An auto-start service attach did not launch because a dependency never
+/// became ready.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BlockedService {
+    pub workspace_id: WorkspaceId,
+    pub name: String,
+    pub reason: String,
+}
 
 impl ClientRuntime {
+    /// Launch the auto-start services in dependency order. A service whose
+    /// dependency never becomes ready is left unstarted and recorded as
+    /// blocked rather than failing the attach it runs under.
     pub(super) fn start_configured_services(
         &mut self,
         zellij: &ZellijRuntime,
@@ -15,12 +34,32 @@ impl ClientRuntime {
         workspace: &WorkspaceRecord,
     ) -> Result<(), String> {
         let config = self.workspace_config(workspace)?;
-        for service in config.startup.iter().filter(|service| service.auto_start) {
-            self.start_service(zellij, session, workspace, &config, service)?;
+        let wanted = config
+            .startup
+            .iter()
+            .filter(|service| service.auto_start)
+            .map(|service| service.name.as_str())
+            .collect::<Vec<_>>();
+        for service in launch_order(&config.startup, &wanted) {
+            match self.blocking_dependency(zellij, session, workspace, &config, service)? {
+                Some(reason) => self.blocked_services.push(BlockedService {
+                    workspace_id: workspace.id,
+                    name: service.name.clone(),
+                    reason,
+                }),
+                None => {
+                    self.start_service(zellij, session, workspace, &config, service)?;
+                }
+            }
         }
         Ok(())
     }
 
+    /// Services attach left unstarted since the last call.
+    pub(crate) fn take_blocked_services(&mut self) -> Vec<BlockedService> {
+        std::mem::take(&mut self.blocked_services)
+    }
+
     pub(crate) fn start_named_service(
         &mut self,
         workspace_id: crate::core::WorkspaceId,
@@ -29,16 +68,262 @@ impl ClientRuntime {
         let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
         let (zellij, session, _) = self.ensure_workspace_session_under_lease(&workspace)?;
         let config = self.workspace_config(&workspace)?;
-        let service = config
-            .startup
-            .iter()
-            .find(|service| service.name == name)
-            .ok_or_else(|| format!("No configured service is named '{name}'."))?;
-        let tab_id = self.start_service(&zellij, &session, &workspace, &config, service)?;
+        let service = configured_service(&config, name)?;
+        let tab_id =
+            self.start_with_dependencies(&zellij, &session, &workspace, &config, service)?;
         lease.release()?;
         Ok(tab_id)
     }
 
+    /// Close a configured service's tab. Returns false when no tab for it
+    /// is open; a missing session is not started just to find that out.
+    pub(crate) fn stop_named_service(
+        &mut self,
+        workspace_id: crate::core::WorkspaceId,
+        name: &str,
+    ) -> Result<bool, String> {
+        let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
+        let config = self.workspace_config(&workspace)?;
+        let service = configured_service(&config, name)?;
+        let stopped = match self.running_workspace_session_under_lease(&workspace)? {
+            Some((zellij, session)) => {
+                self.stop_service(&zellij, &session, &workspace, &service.name)?
+            }
+            None => false,
+        };
+        lease.release()?;
+        Ok(stopped)
+    }
+
+    /// Close the service's tab if one is open, then launch it again in the
+    /// same lease so a refresh never sees it half way.
+    pub(crate) fn restart_named_service(
+        &mut self,
+        workspace_id: crate::core::WorkspaceId,
+        name: &str,
+    ) -> Result<u64, String> {
+        let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
+        let config = self.workspace_config(&workspace)?;
+        let service = configured_service(&config, name)?;
+        if let Some((zellij, session)) = self.running_workspace_session_under_lease(&workspace)? {
+            self.stop_service(&zellij, &session, &workspace, &service.name)?;
+        }
+        let (zellij, session, _) = self.ensure_workspace_session_under_lease(&workspace)?;
+        let tab_id =
+            self.start_with_dependencies(&zellij, &session, &workspace, &config, service)?;
+        lease.release()?;
+        Ok(tab_id)
+    }
+
+    /// Each configured service, or just `name`, with the process state of
+    /// the pane carrying its launch marker.
+    pub(crate) fn service_states(
+        &mut self,
+        workspace_id: crate::core::WorkspaceId,
+        name: Option<&str>,
+    ) -> Result<Vec<(String, PaneProcessState)>, String> {
+        let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
+        let config = self.workspace_config(&workspace)?;
+        let services = match name {
+            Some(name) => vec![configured_service(&config, name)?],
+            None => config.startup.iter().collect(),
+        };
+        let session = self.running_workspace_session_under_lease(&workspace)?;
+        let mut states = Vec::with_capacity(services.len());
+        for service in services {
+            let state = match &session {
+                Some((zellij, session)) => self
+                    .service_pane(zellij, session, &workspace, &service.name)?
+                    .map_or(PaneProcessState::Missing, |pane| pane.process_state()),
+                None => PaneProcessState::Missing,
+            };
+            states.push((service.name.clone(), state));
+        }
+        lease.release()?;
+        Ok(states)
+    }
+
+    /// Launch whatever `service` needs that is not running yet, dependencies
+    /// first, then the service itself.
+    fn start_with_dependencies(
+        &mut self,
+        zellij: &ZellijRuntime,
+        session: &SessionRecord,
+        workspace: &WorkspaceRecord,
+        config: &ClientConfig,
+        service: &StartupCommand,
+    ) -> Result<u64, String> {
+        let mut tab_id = None;
+        for entry in launch_order(&config.startup, &[service.name.as_str()]) {
+            tab_id =
+                Some(self.start_after_dependencies(zellij, session, workspace, config, entry)?);
+        }
+        tab_id.ok_or_else(|| format!("No configured service is named '{}'.", service.name))
+    }
+
+    fn start_after_dependencies(
+        &mut self,
+        zellij: &ZellijRuntime,
+        session: &SessionRecord,
+        workspace: &WorkspaceRecord,
+        config: &ClientConfig,
+        service: &StartupCommand,
+    ) -> Result<u64, String> {
+        if let Some(reason) =
+            self.blocking_dependency(zellij, session, workspace, config, service)?
+        {
+            return Err(format!(
+                "Service {} was not started: {reason}.",
+                service.name
+            ));
+        }
+        self.start_service(zellij, session, workspace, config, service)
+    }
+
+    /// The first of `service`'s dependencies that does not become ready, as
+    /// the reason it stays unstarted.
+    fn blocking_dependency(
+        &mut self,
+        zellij: &ZellijRuntime,
+        session: &SessionRecord,
+        workspace: &WorkspaceRecord,
+        config: &ClientConfig,
+        service: &StartupCommand,
+    ) -> Result<Option<String>, String> {
+        for name in &service.depends_on {
+            let dependency = configured_service(config, name)?;
+            if let Some(reason) =
+                self.wait_for_dependency(zellij, session, workspace, dependency)?
+            {
+                return Ok(Some(reason));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Wait until `dependency` is ready: its process is alive and its
+    /// `ready` check, if it declares one, passes on the host. Returns why it
+    /// is not when it exits or never gets there.
+    fn wait_for_dependency(
+        &mut self,
+        zellij: &ZellijRuntime,
+        session: &SessionRecord,
+        workspace: &WorkspaceRecord,
+        dependency: &StartupCommand,
+    ) -> Result<Option<String>, String> {
+        let deadline = Instant::now() + DEPENDENCY_TIMEOUT;
+        loop {
+            let state = self
+                .service_pane(zellij, session, workspace, &dependency.name)?
+                .map_or(PaneProcessState::Missing, |pane| pane.process_state());
+            let waiting = match state {
+                PaneProcessState::Live => {
+                    let Some(ready) = &dependency.ready else {
+                        return Ok(None);
+                    };
+                    if self.service_ready(workspace, &dependency.name)? {
+                        return Ok(None);
+                    }
+                    match ready.port {
+                        Some(port) => format!("port {port} is not listening"),
+                        None => "its ready command does not pass".to_owned(),
+                    }
+                }
+                PaneProcessState::Exited { code } => {
+                    return Ok(Some(format!(
+                        "its dependency {} exited{}",
+                        dependency.name,
+                        code.map(|code| format!(" with status {code}"))
+                            .unwrap_or_default()
+                    )))
+                }
+                PaneProcessState::Missing | PaneProcessState::UnverifiedIdentity { .. } => {
+                    return Ok(Some(format!(
+                        "its dependency {} is not running",
+                        dependency.name
+                    )))
+                }
+            };
+            if Instant::now() >= deadline {
+                return Ok(Some(format!(
+                    "its dependency {} is running, but {waiting} after {} seconds",
+                    dependency.name,
+                    DEPENDENCY_TIMEOUT.as_secs()
+                )));
+            }
+            std::thread::sleep(DEPENDENCY_POLL);
+        }
+    }
+
+    /// One readiness check on the workspace host, the same one periodic
+    /// refresh runs.
+    fn service_ready(&mut self, workspace: &WorkspaceRecord, name: &str) -> Result<bool, String> {
+        match connection::registry_operation(
+            self,
+            workspace.host_id,
+            RequestOperation::ServiceReady {
+                workspace_id: workspace.id,
+                name: name.to_owned(),
+            },
+        )? {
+            ResponsePayload::HostService { payload } => match *payload {
+                HostServicePayload::ServiceReady { ready } => Ok(ready),
+                _ => Err("bp-host returned an unexpected readiness response.".to_owned()),
+            },
+            _ => Err("bp-host returned an unexpected readiness response.".to_owned()),
+        }
+    }
+
+    fn stop_service(
+        &mut self,
+        zellij: &ZellijRuntime,
+        session: &SessionRecord,
+        workspace: &WorkspaceRecord,
+        name: &str,
+    ) -> Result<bool, String> {
+        let Some(pane) = self.service_pane(zellij, session, workspace, name)? else {
+            return Ok(false);
+        };
+        let closed = zellij
+            .close_tab_if_pane_matches(
+                self.transport_mut(workspace.host_id)?,
+                &session.backend_session_id,
+                pane.tab_id,
+                &pane.tab_name,
+                &pane.selector(),
+                &service_marker(workspace.id, name),
+            )
+            .map_err(|error| format!("Could not close the service tab: {error}"))?;
+        if !closed {
+            return Err(format!(
+                "The tab of service '{name}' changed before it could be closed; it was left open."
+            ));
+        }
+        Ok(true)
+    }
+
+    /// The terminal pane in the service's own tab that still carries its
+    /// launch marker. A tab with the right name but another command is not it.
+    fn service_pane(
+        &mut self,
+        zellij: &ZellijRuntime,
+        session: &SessionRecord,
+        workspace: &WorkspaceRecord,
+        name: &str,
+    ) -> Result<Option<ZellijPane>, String> {
+        let tab_name = format!("service-{}", service_identity(workspace, name));
+        let marker = service_marker(workspace.id, name);
+        let panes = zellij
+            .list_panes(
+                self.transport_mut(workspace.host_id)?,
+                &session.backend_session_id,
+            )
+            .map_err(|error| error.to_string())?;
+        Ok(panes.into_iter().find(|pane| {
+            !pane.is_plugin && pane.tab_name == tab_name && pane.has_command_argument(&marker)
+        }))
+    }
+
     fn start_service(
         &mut self,
         zellij: &ZellijRuntime,
@@ -55,7 +340,7 @@ impl ClientRuntime {
             canonical_remote_service_cwd(self.transport_mut(workspace.host_id)?, root, &candidate)?
         };
         let identity = service_identity(workspace, &service.name);
-        let marker = format!("{SERVICE_ID_ENV}={identity}");
+        let marker = service_marker(workspace.id, &service.name);
         let mut arguments = config
             .workspace_env
             .iter()
@@ -120,8 +405,43 @@ impl ClientRuntime {
     }
 }
 
+/// `wanted` and everything it depends on, each dependency before its
+/// dependents and each service once. Config validation already rejected
+/// cycles and unknown names.
+fn launch_order<'a>(startup: &'a [StartupCommand], wanted: &[&str]) -> Vec<&'a StartupCommand> {
+    fn visit<'a>(startup: &'a [StartupCommand], name: &str, order: &mut Vec<&'a StartupCommand>) {
+        if order.iter().any(|service| service.name == name) {
+            return;
+        }
+        let Some(service) = startup.iter().find(|service| service.name == name) else {
+            return;
+        };
+        for dependency in &service.depends_on {
+            visit(startup, dependency, order);
+        }
+        order.push(service);
+    }
+
+    let mut order = Vec::new();
+    for name in wanted {
+        visit(startup, name, &mut order);
+    }
+    order
+}
+
+fn configured_service<'a>(
+    config: &'a ClientConfig,
+    name: &str,
+) -> Result<&'a StartupCommand, String> {
+    config
+        .startup
+        .iter()
+        .find(|service| service.name == name)
+        .ok_or_else(|| format!("No configured service is named '{name}'."))
+}
+
 fn service_identity(workspace: &WorkspaceRecord, name: &str) -> uuid::Uuid {
-    uuid::Uuid::new_v5(&workspace.id.as_uuid(), name.as_bytes())
+    crate::client_config::service_identity(workspace.id, name)
 }
 
 fn service_cwd(root: &Path, configured: Option<&Path>) -> Result<PathBuf, String> {
@@ -211,6 +531,40 @@ mod tests {
         assert_eq!(format!("service-{first}").len(), 44);
     }
 
+    fn service(name: &str, depends_on: &[&str]) -> StartupCommand {
+        StartupCommand {
+            name: name.to_owned(),
+            command: vec!["true".to_owned()],
+            cwd: None,
+            auto_start: false,
+            restart: crate::core::ServiceRestart::Never,
+            ready: None,
+            depends_on: depends_on.iter().map(|name| (*name).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    fn dependencies_launch_first_and_each_service_once() {
+        let startup = [
+            service("web", &["api", "cache"]),
+            service("api", &["db"]),
+            service("worker", &["db"]),
+            service("cache", &[]),
+            service("db", &[]),
+        ];
+        let names = |wanted: &[&str]| {
+            launch_order(&startup, wanted)
+                .into_iter()
+                .map(|service| service.name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&["web", "worker"]),
+            ["db", "api", "cache", "web", "worker"]
+        );
+        assert_eq!(names(&["api"]), ["db", "api"]);
+    }
+
     #[test]
     fn service_cwd_cannot_escape_the_workspace() {
         assert_eq!(
@@ -0,0 +1,401 @@
+//! Unix sockets on the workspace host reached through client socket paths.
+//!
+//! The control master owns each `-L path:path` listener. Its client end lives
+//! under the runtime directory unless `--local` names another path, and a
+//! reconnect binds the same path again so existing configuration keeps
+//! working.
+
+use super::ClientRuntime;
+use crate::core::{HostId, RegistrySnapshot, WorkspaceId};
+use crate::ports::{ForwardStatus, PortSnapshot, SocketForwardState};
+use crate::transport::{SocketForward, TransportError};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// `sun_path` holds 104 bytes on macOS and 108 on Linux, including the NUL.
+const SOCKET_PATH_MAX_BYTES: usize = 103;
+
+impl ClientRuntime {
+    pub(crate) fn forward_workspace_socket(
+        &mut self,
+        workspace_id: WorkspaceId,
+        remote_path: String,
+        local_path: Option<PathBuf>,
+    ) -> Result<SocketForwardState, String> {
+        super::ports::forwardable_workspace(&self.snapshot()?, workspace_id)?;
+        let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
+        let workspace = super::ports::forwardable_workspace(&self.snapshot()?, workspace.id)?;
+        lease.release()?;
+        if workspace.host_id == self.local_host_id {
+            if local_path
+                .as_deref()
+                .is_some_and(|path| path != Path::new(&remote_path))
+            {
+                return Err(format!(
+                    "This workspace runs on this machine; connect to {remote_path} directly."
+                ));
+            }
+            let mut state = SocketForwardState::new(
+                workspace.host_id,
+                workspace_id,
+                remote_path.clone(),
+                PathBuf::from(remote_path),
+            );
+            state.status = ForwardStatus::Direct;
+            return Ok(state);
+        }
+        let local_path = match local_path {
+            Some(path) => path,
+            None => self.default_socket_path(workspace.host_id, workspace_id, &remote_path),
+        };
+        if local_path.as_os_str().len() > SOCKET_PATH_MAX_BYTES {
+            return Err(format!(
+                "{} is too long for a Unix socket; choose a shorter one with --local <path>.",
+                local_path.display()
+            ));
+        }
+        let state =
+            SocketForwardState::new(workspace.host_id, workspace_id, remote_path, local_path);
+        clear_stale_socket(&state.local_path)?;
+        self.transport_mut(workspace.host_id)?
+            .forward_socket(socket_forward(&state))
+            .map_err(|error| error.to_string())?;
+        Ok(state)
+    }
+
+    pub(crate) fn cancel_socket_forward(
+        &mut self,
+        forward: &SocketForwardState,
+    ) -> Result<(), String> {
+        if forward.status == ForwardStatus::Direct {
+            return Ok(());
+        }
+        if let Some(host) = self.hosts.get_mut(&forward.host_id) {
+            match host
+                .transport_mut()
+                .cancel_socket_forward(&socket_forward(forward))
+            {
+                Ok(()) | Err(TransportError::SocketForwardNotOwned(_)) => {}
+                Err(error) => return Err(error.to_string()),
+            }
+        }
+        // The master stops accepting but may leave the file behind.
+        clear_stale_socket(&forward.local_path)
+    }
+
+    /// Stop a workspace's socket forwards before a destructive removal. As
+    /// with local forwards, one that cannot be stopped stays tracked and
+    /// blocks the removal.
+    pub(crate) fn cancel_workspace_socket_forwards(
+        &mut self,
+        forwards: &mut Vec<SocketForwardState>,
+        workspace_id: WorkspaceId,
+    ) -> Result<usize, String> {
+        let mut cancelled = 0;
+        let mut failures = Vec::new();
+        let mut retained = Vec::with_capacity(forwards.len());
+        for mut forward in std::mem::take(forwards) {
+            if forward.workspace_id != workspace_id {
+                retained.push(forward);
+                continue;
+            }
+            match self.cancel_socket_forward(&forward) {
+                Ok(()) => cancelled += 1,
+                Err(error) => {
+                    let message = format!(
+                        "Could not stop socket forward {} before worktree removal: {error}",
+                        forward.remote_path
+                    );
+                    forward.status = ForwardStatus::Failed(message.clone());
+                    failures.push(message);
+                    retained.push(forward);
+                }
+            }
+        }
+        *forwards = retained;
+        if failures.is_empty() {
+            Ok(cancelled)
+        } else {
+            Err(format!(
+                "Worktree removal was blocked because {} socket forward(s) could not be stopped: {}",
+                failures.len(),
+                failures.join(" | ")
+            ))
+        }
+    }
+
+    /// Stop and forget this host's socket forwards whose workspace left the
+    /// registry, with their client socket files. A failed cancellation stays
+    /// visible and is retried on the next refresh.
+    pub(crate) fn reconcile_socket_forwards(
+        &mut self,
+        forwards: &mut Vec<SocketForwardState>,
+        snapshot: &RegistrySnapshot,
+        host_id: HostId,
+    ) -> Vec<String> {
+        let mut failures = Vec::new();
+        let mut retained = Vec::with_capacity(forwards.len());
+        for mut forward in std::mem::take(forwards) {
+            if forward.host_id != host_id
+                || super::ports::workspace_is_registered(
+                    snapshot,
+                    forward.host_id,
+                    forward.workspace_id,
+                )
+            {
+                retained.push(forward);
+                continue;
+            }
+            if let Err(error) = self.cancel_socket_forward(&forward) {
+                let message = format!(
+                    "Could not stop orphaned socket forward {} for removed workspace {}: {error}",
+                    forward.remote_path, forward.workspace_id
+                );
+                forward.status = ForwardStatus::Failed(message.clone());
+                failures.push(message);
+                retained.push(forward);
+            }
+        }
+        *forwards = retained;
+        failures
+    }
+
+    /// Bind the same client path on the fresh control master. A path some
+    /// other process now serves is reported rather than taken over.
+    pub(crate) fn reconnect_socket_forward(&mut self, forward: &mut SocketForwardState) {
+        let registered = self.snapshot().map(|snapshot| {
+            snapshot.workspaces.iter().any(|workspace| {
+                workspace.id == forward.workspace_id && workspace.host_id == forward.host_id
+            })
+        });
+        match registered {
+            Ok(true) => {}
+            Ok(false) => {
+                forward.status = ForwardStatus::Failed(
+                    "Owning workspace was removed; socket forward was not restored.".to_owned(),
+                );
+                return;
+            }
+            Err(error) => {
+                forward.status = ForwardStatus::Failed(format!(
+                    "Could not verify the owning workspace; socket forward was not restored: {error}"
+                ));
+                return;
+            }
+        }
+        if forward.status == ForwardStatus::Direct {
+            return;
+        }
+        if clear_stale_socket(&forward.local_path).is_err() {
+            forward.status = ForwardStatus::PortConflict;
+            return;
+        }
+        let requested = socket_forward(forward);
+        forward.status = match self.transport_mut(forward.host_id) {
+            Ok(transport) => match transport.forward_socket(requested) {
+                Ok(_) => ForwardStatus::Active,
+                Err(error) => ForwardStatus::Failed(error.to_string()),
+            },
+            Err(error) => ForwardStatus::Failed(error),
+        };
+    }
+
+    /// `<runtime>/sockets/<host>-<workspace>-<name>`: the prefixes keep the
+    /// same socket name from two hosts, or two workspaces on one host, apart.
+    fn default_socket_path(
+        &self,
+        host_id: HostId,
+        workspace_id: WorkspaceId,
+        remote_path: &str,
+    ) -> PathBuf {
+        let name = remote_path.rsplit('/').next().unwrap_or(remote_path);
+        let (host, workspace) = (host_id.to_string(), workspace_id.to_string());
+        self.paths.socket_forward_dir().join(format!(
+            "{}-{}-{name}",
+            &host[..8.min(host.len())],
+            &workspace[..8.min(workspace.len())]
+        ))
+    }
+}
+
+/// Pick one discovered socket by full path or by file name among those
+/// attributed to the workspace (or every socket on the host).
+pub(crate) fn resolve_workspace_socket(
+    snapshot: &PortSnapshot,
+    workspace_root: &str,
+    all_host: bool,
+    selector: &str,
+) -> Result<String, String> {
+    let matches = snapshot
+        .sockets
+        .iter()
+        .filter(|socket| {
+            all_host
+                || super::ports::listener_matches_workspace(
+                    socket.workspace_path.as_deref(),
+                    workspace_root,
+                )
+        })
+        .filter(|socket| {
+            if selector.starts_with('/') {
+                socket.path == selector
+            } else {
+                socket.name() == selector
+            }
+        })
+        .map(|socket| socket.path.clone())
+        .collect::<BTreeSet<_>>();
+    let mut paths = matches.into_iter();
+    match (paths.next(), paths.next()) {
+        (Some(path), None) => Ok(path),
+        (None, _) => Err(format!(
+            "No discovered Unix socket matches {selector}. Run :ports first (or :ports --all-host for unattributed services)."
+        )),
+        (Some(first), Some(second)) => Err(format!(
+            "Several sockets are named {selector} ({first}, {second}, …); use :forward unix:<full path>."
+        )),
+    }
+}
+
+fn socket_forward(forward: &SocketForwardState) -> SocketForward {
+    SocketForward {
+        local_path: forward.local_path.clone(),
+        remote_path: forward.remote_path.clone(),
+    }
+}
+
+/// Remove a socket file nothing accepts on any more. Regular files and live
+/// sockets are never touched.
+fn clear_stale_socket(path: &Path) -> Result<(), String> {
+    use std::os::unix::fs::FileTypeExt;
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(format!("Could not inspect {}: {error}", path.display())),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(format!(
+            "{} already exists and is not a socket.",
+            path.display()
+        ));
+    }
+    if std::os::unix::net::UnixStream::connect(path).is_ok() {
+        return Err(format!("{} is already in use.", path.display()));
+    }
+    std::fs::remove_file(path)
+        .map_err(|error| format!("Could not remove {}: {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::{AttributionConfidence, ProbeCompleteness, SocketListener};
+
+    fn socket(path: &str, workspace: Option<&str>) -> SocketListener {
+        SocketListener {
+            path: path.to_owned(),
+            pid: Some(7),
+            process: Some("postgres".to_owned()),
+            workspace_path: workspace.map(PathBuf::from),
+            attribution: AttributionConfidence::ExactCwd,
+        }
+    }
+
+    #[test]
+    fn a_socket_is_selected_by_exact_path_or_unique_name() {
+        let snapshot = PortSnapshot {
+            listeners: Vec::new(),
+            sockets: vec![
+                socket("/srv/app/tmp/pg/.s.PGSQL.5432", Some("/srv/app")),
+                socket("/srv/other/tmp/.s.PGSQL.5432", Some("/srv/other")),
+                socket("/run/docker.sock", None),
+            ],
+            completeness: ProbeCompleteness::Full,
+            warning: None,
+        };
+
+        assert_eq!(
+            resolve_workspace_socket(&snapshot, "/srv/app", false, ".s.PGSQL.5432").unwrap(),
+            "/srv/app/tmp/pg/.s.PGSQL.5432"
+        );
+        assert!(resolve_workspace_socket(&snapshot, "/srv/app", false, "docker.sock").is_err());
+        assert!(
+            resolve_workspace_socket(&snapshot, "/srv/app", true, ".s.PGSQL.5432")
+                .unwrap_err()
+                .contains("full path")
+        );
+        assert_eq!(
+            resolve_workspace_socket(&snapshot, "/srv/app", true, "/run/docker.sock").unwrap(),
+            "/run/docker.sock"
+        );
+    }
+
+    #[test]
+    fn the_same_socket_in_two_workspaces_gets_two_client_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let runtime = ClientRuntime::test_fixture(root.path());
+        let host_id = HostId::new();
+        let first =
+            runtime.default_socket_path(host_id, WorkspaceId::new(), "/srv/a/tmp/.s.PGSQL.5432");
+        let second =
+            runtime.default_socket_path(host_id, WorkspaceId::new(), "/srv/b/tmp/.s.PGSQL.5432");
+        assert_ne!(first, second);
+        assert!(first.to_string_lossy().ends_with("-.s.PGSQL.5432"));
+    }
+
+    #[test]
+    fn removing_the_workspace_clears_its_client_socket() {
+        let root = tempfile::tempdir().unwrap();
+        let mut runtime = ClientRuntime::test_fixture(root.path());
+        let remote = crate::core::HostRecord::new(
+            "remote",
+            crate::core::HostTransport::Ssh {
+                destination: "remote.example".to_owned(),
+            },
+        );
+        runtime.registry.upsert_host(&remote).unwrap();
+        let workspace = crate::core::WorkspaceRecord::new(remote.id, "/srv/app");
+        runtime.registry.upsert_workspace(&workspace).unwrap();
+        let local_path = root.path().join("app.sock");
+        drop(std::os::unix::net::UnixListener::bind(&local_path).unwrap());
+        let mut forwards = vec![SocketForwardState::new(
+            remote.id,
+            workspace.id,
+            "/srv/app/tmp/.s.PGSQL.5432".to_owned(),
+            local_path.clone(),
+        )];
+
+        let snapshot = runtime.snapshot().unwrap();
+        assert!(runtime
+            .reconcile_socket_forwards(&mut forwards, &snapshot, remote.id)
+            .is_empty());
+        assert_eq!(forwards.len(), 1);
+        assert!(local_path.exists());
+
+        runtime.registry.remove_workspace(workspace.id).unwrap();
+        let snapshot = runtime.snapshot().unwrap();
+        assert!(runtime
+            .reconcile_socket_forwards(&mut forwards, &snapshot, remote.id)
+            .is_empty());
+        assert!(forwards.is_empty());
+        assert!(!local_path.exists());
+    }
+
+    #[test]
+    fn only_a_dead_socket_file_is_cleared() {
+        let root = tempfile::tempdir().unwrap();
+        let live = root.path().join("live.sock");
+        let _listener = std::os::unix::net::UnixListener::bind(&live).unwrap();
+        assert!(clear_stale_socket(&live).unwrap_err().contains("in use"));
+
+        let dead = root.path().join("dead.sock");
+        drop(std::os::unix::net::UnixListener::bind(&dead).unwrap());
+        clear_stale_socket(&dead).unwrap();
+        assert!(!dead.exists());
+
+        let file = root.path().join("notes.txt");
+        std::fs::write(&file, "keep").unwrap();
+        assert!(clear_stale_socket(&file).is_err());
+        assert!(file.exists());
+    }
+}
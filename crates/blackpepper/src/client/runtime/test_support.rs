@@ -24,6 +24,7 @@ impl ClientRuntime {
             host_operation_generations: BTreeMap::new(),
             deferred_host_actions: BTreeMap::new(),
             startup_warnings: Vec::new(),
+            blocked_services: Vec::new(),
             _singleton: None,
         }
     }
@@ -1,7 +1,11 @@
-use super::super::{session_lease::SessionInitializationLease, ClientRuntime};
-use crate::core::{SessionBackend, SessionRecord, SessionState, WorkspaceId, WorkspaceRecord};
+use super::super::agents::agent_tab_name;
+use super::super::{session_lease::SessionInitializationLease, text_path, ClientRuntime};
+use crate::core::{
+    AgentRunId, SessionBackend, SessionRecord, SessionState, WorkspaceId, WorkspaceRecord,
+};
+use crate::providers::runtime::AGENT_RUN_ID_ENV;
 use crate::transport::{is_blackpepper_zellij_version, sha256_bytes, PtyProcess};
-use crate::zellij::ZellijRuntime;
+use crate::zellij::{ZellijError, ZellijRuntime};
 use portable_pty::PtySize;
 use std::path::Path;
 
@@ -114,6 +118,116 @@ impl ClientRuntime {
         }
     }
 
+    /// Bring one recorded agent tab to the front of the attached session.
+    /// The lease serializes this with attach and background mutations, and
+    /// the Zellij layer re-verifies the run's launch marker before focusing.
+    pub(crate) fn focus_agent_tab(
+        &mut self,
+        workspace_id: WorkspaceId,
+        run_id: AgentRunId,
+        tab_id: u64,
+        zellij_pane_id: &str,
+    ) -> Result<(), String> {
+        let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
+        let result = (|| {
+            let sessions = self
+                .registry
+                .sessions_for_workspace(workspace.id)
+                .map_err(|error| error.to_string())?;
+            let session = latest_non_exited_zellij_session(&sessions).ok_or_else(|| {
+                "The workspace has no live Zellij session holding this agent.".to_owned()
+            })?;
+            let binary =
+                self.exact_binary(workspace.host_id, "zellij", &session.backend_version)?;
+            let zellij = ZellijRuntime::for_version(binary, &session.backend_version)
+                .map_err(|error| error.to_string())?;
+            let (zellij, _) = zellij
+                .resolve_session_namespace(
+                    self.transport_mut(workspace.host_id)?,
+                    &session.backend_session_id,
+                )
+                .map_err(|error| error.to_string())?;
+            let focused = zellij
+                .focus_agent_tab(
+                    self.transport_mut(workspace.host_id)?,
+                    &session.backend_session_id,
+                    tab_id,
+                    &agent_tab_name(run_id),
+                    zellij_pane_id,
+                    &format!("{AGENT_RUN_ID_ENV}={run_id}"),
+                )
+                .map_err(|error| agent_focus_refusal(error, tab_id))?;
+            if focused {
+                Ok(())
+            } else {
+                Err(
+                    "The agent's tab no longer holds its original pane; no focus change was sent."
+                        .to_owned(),
+                )
+            }
+        })();
+        let release = lease.release();
+        match (result, release) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(error), Ok(())) => Err(error),
+            (Ok(()), Err(error)) => Err(error),
+            (Err(operation), Err(release)) => Err(format!(
+                "{operation}; the workspace lifecycle lease also failed to release: {release}"
+            )),
+        }
+    }
+
+    /// Type into an agent's pane without attaching or moving any client's
+    /// focus. Nothing is written unless the pane still carries the run's
+    /// launch identity and its process is live.
+    pub(crate) fn write_to_agent_pane(
+        &mut self,
+        workspace_id: WorkspaceId,
+        run_id: AgentRunId,
+        tab_id: u64,
+        zellij_pane_id: &str,
+        text: &str,
+        bytes: &[u8],
+    ) -> Result<(), String> {
+        let (lease, workspace) = self.acquire_workspace_session_lease(workspace_id)?;
+        let result = (|| {
+            let (zellij, session) = self
+                .running_workspace_session_under_lease(&workspace)?
+                .ok_or_else(|| {
+                    "The workspace has no running Zellij session holding this agent.".to_owned()
+                })?;
+            let written = zellij
+                .write_to_pane_if_matches(
+                    self.transport_mut(workspace.host_id)?,
+                    &session.backend_session_id,
+                    tab_id,
+                    &agent_tab_name(run_id),
+                    zellij_pane_id,
+                    &format!("{AGENT_RUN_ID_ENV}={run_id}"),
+                    text,
+                    bytes,
+                )
+                .map_err(|error| error.to_string())?;
+            if written {
+                Ok(())
+            } else {
+                Err(
+                    "The agent's tab no longer holds its original live pane; nothing was sent."
+                        .to_owned(),
+                )
+            }
+        })();
+        let release = lease.release();
+        match (result, release) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(error), Ok(())) => Err(error),
+            (Ok(()), Err(error)) => Err(error),
+            (Err(operation), Err(release)) => Err(format!(
+                "{operation}; the workspace lifecycle lease also failed to release: {release}"
+            )),
+        }
+    }
+
     pub(in crate::client::runtime) fn ensure_workspace_session(
         &mut self,
         workspace: &WorkspaceRecord,
@@ -230,6 +344,23 @@ impl ClientRuntime {
         let created = if session_exists {
             false
         } else {
+            // A project layout shapes only the session it is created in, so it
+            // is parsed here rather than in the check above: a broken layout
+            // must not stop anyone reattaching to a session that already runs.
+            let zellij = match &config.workspace_layout {
+                Some(layout) => {
+                    let path = text_path(&Path::new(&workspace.root_path).join(layout))?;
+                    let zellij = zellij
+                        .clone()
+                        .with_layout_file(path)
+                        .map_err(|error| error.to_string())?;
+                    zellij
+                        .check_configuration(self.transport_mut(workspace.host_id)?)
+                        .map_err(|error| error.to_string())?;
+                    zellij
+                }
+                None => zellij.clone(),
+            };
             zellij
                 .ensure_session_with_env(
                     self.transport_mut(workspace.host_id)?,
@@ -325,6 +456,31 @@ impl ClientRuntime {
         lease.release()
     }
 
+    /// The workspace's running Zellij session, if any, without creating one.
+    /// Callers hold the workspace session lease.
+    pub(in crate::client::runtime) fn running_workspace_session_under_lease(
+        &mut self,
+        workspace: &WorkspaceRecord,
+    ) -> Result<Option<(ZellijRuntime, SessionRecord)>, String> {
+        let sessions = self
+            .registry
+            .sessions_for_workspace(workspace.id)
+            .map_err(|error| error.to_string())?;
+        let Some(session) = latest_non_exited_zellij_session(&sessions).cloned() else {
+            return Ok(None);
+        };
+        let binary = self.exact_binary(workspace.host_id, "zellij", &session.backend_version)?;
+        let zellij = ZellijRuntime::for_version(binary, &session.backend_version)
+            .map_err(|error| error.to_string())?;
+        let (zellij, session_exists) = zellij
+            .resolve_session_namespace(
+                self.transport_mut(workspace.host_id)?,
+                &session.backend_session_id,
+            )
+            .map_err(|error| error.to_string())?;
+        Ok(session_exists.then_some((zellij, session)))
+    }
+
     pub(in crate::client::runtime) fn current_or_new_session(
         &self,
         workspace: &WorkspaceRecord,
@@ -362,6 +518,19 @@ impl ClientRuntime {
     }
 }
 
+/// Zellij resolves out-of-band focus against its last-active client, so with a
+/// second client attached the jump could land on someone else's screen. Say
+/// that in words rather than as the raw safety-rule name.
+pub(super) fn agent_focus_refusal(error: ZellijError, tab_id: u64) -> String {
+    match error {
+        ZellijError::ClientConflict { clients, .. } => format!(
+            "{} Zellij clients are attached to this session, and a focus change could move another client's view; no focus change was sent. Select tab {tab_id} from that client, or detach it and retry.",
+            clients.len()
+        ),
+        error => error.to_string(),
+    }
+}
+
 pub(super) fn latest_non_exited_zellij_session(
     sessions: &[SessionRecord],
 ) -> Option<&SessionRecord> {
@@ -1,6 +1,6 @@
 use super::{
     provisional_attachment_count,
-    session::{latest_non_exited_zellij_session, zellij_session_name},
+    session::{agent_focus_refusal, latest_non_exited_zellij_session, zellij_session_name},
     ClientRuntime,
 };
 use crate::client::runtime::HostSlot;
@@ -167,6 +167,27 @@ fn branded_zellij_generations_get_distinct_stable_session_names() {
     assert!(next.len() <= 64);
 }
 
+#[test]
+fn agent_focus_refusal_names_the_other_client_instead_of_the_rule() {
+    let client = |client_id| crate::zellij::ZellijClient {
+        client_id,
+        pane_id: "terminal_1".to_owned(),
+        running_command: "zellij attach repo-main".to_owned(),
+    };
+    let message = agent_focus_refusal(
+        crate::zellij::ZellijError::ClientConflict {
+            operation: crate::zellij::ClientOperation::FocusChange,
+            clients: vec![client(1), client(2)],
+        },
+        7,
+    );
+
+    assert!(message.starts_with("2 Zellij clients are attached"));
+    assert!(message.contains("no focus change was sent"));
+    assert!(message.contains("tab 7"));
+    assert!(!message.contains("FocusChange"));
+}
+
 fn local_runtime() -> (tempfile::TempDir, ClientRuntime) {
     let root = tempfile::tempdir().unwrap();
     let paths = CorePaths::from_roots(root.path().join("state"), root.path().join("run"));
@@ -188,6 +209,7 @@ fn local_runtime() -> (tempfile::TempDir, ClientRuntime) {
         host_operation_generations: BTreeMap::new(),
         deferred_host_actions: BTreeMap::new(),
         startup_warnings: Vec::new(),
+        blocked_services: Vec::new(),
         _singleton: Some(singleton),
     };
     (root, runtime)
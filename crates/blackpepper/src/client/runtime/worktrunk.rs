@@ -1,7 +1,7 @@
 use super::{connection, ClientRuntime};
 use crate::core::{
-    HostServicePayload, RequestOperation, ResponsePayload, WorkspaceId, WorkspaceSetup,
-    WorktrunkMutationOutcome,
+    HostServicePayload, OpenPullRequest, RequestOperation, ResponsePayload, WorkspaceId,
+    WorkspaceSetup, WorktrunkMutationOutcome, WorktrunkPruneItem, WorktrunkPruneResult,
 };
 use crate::transport::WORKTRUNK_VERSION;
 use crate::worktrunk::{WorktreeList, WorktrunkApprovalToken, WorktrunkProjectCommand};
@@ -24,6 +24,14 @@ pub(crate) enum WorktreeChange {
         message: String,
     },
     Removed,
+    PruneApprovalRequired {
+        items: Vec<WorktrunkPruneItem>,
+        approval: WorktrunkApprovalToken,
+        unapproved_project_commands: Vec<WorktrunkProjectCommand>,
+    },
+    Pruned {
+        results: Vec<WorktrunkPruneResult>,
+    },
     UnknownAfterDisconnect,
 }
 
@@ -51,6 +59,24 @@ impl ClientRuntime {
         Ok(list)
     }
 
+    /// Open pull requests for the workspace's repository, from the host's
+    /// `gh`. Nothing here needs Worktrunk until one of them is opened.
+    pub(crate) fn list_pull_requests(
+        &mut self,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<OpenPullRequest>, String> {
+        let workspace = self.workspace_record(workspace_id)?;
+        match self.host_service(
+            workspace.host_id,
+            RequestOperation::ListPullRequests {
+                workspace_id: workspace.id,
+            },
+        )? {
+            HostServicePayload::PullRequests { pull_requests } => Ok(pull_requests),
+            _ => Err("bp-host returned an unexpected pull request list response.".to_string()),
+        }
+    }
+
     pub(crate) fn create_worktree(
         &mut self,
         workspace_id: WorkspaceId,
@@ -151,6 +177,62 @@ impl ClientRuntime {
         }
     }
 
+    /// Preview, or run, the host's prune plan for the workspace's repository.
+    /// `removes` are the workspaces the reviewed plan listed; an approved run
+    /// terminates them first, exactly as `remove_worktree` does for one.
+    pub(crate) fn prune_merged_worktrees(
+        &mut self,
+        workspace_id: WorkspaceId,
+        removes: &[WorkspaceId],
+        approval: Option<WorktrunkApprovalToken>,
+    ) -> Result<WorktreeChange, String> {
+        let workspace = self.workspace_record(workspace_id)?;
+        self.ensure_worktrunk(workspace.host_id)?;
+        if approval.is_some() {
+            for target in removes {
+                self.terminate_workspace(*target)?;
+            }
+        }
+        let payload = match connection::registry_operation_tracked(
+            self,
+            workspace.host_id,
+            RequestOperation::WorktrunkPruneMerged {
+                workspace_id: workspace.id,
+                repository_path: workspace.root_path,
+                approval,
+            },
+        ) {
+            Ok(ResponsePayload::HostService { payload }) => *payload,
+            Ok(_) => return Err("bp-host returned an unexpected Worktrunk response.".to_string()),
+            Err(error) if error.is_unknown_after_send() => {
+                return Ok(WorktreeChange::UnknownAfterDisconnect)
+            }
+            Err(error) => return Err(error.to_string()),
+        };
+        match payload {
+            HostServicePayload::WorktrunkPruneApprovalRequired {
+                items,
+                approval,
+                unapproved_project_commands,
+            } => Ok(WorktreeChange::PruneApprovalRequired {
+                items,
+                approval,
+                unapproved_project_commands,
+            }),
+            HostServicePayload::WorktrunkMutation {
+                outcome: WorktrunkMutationOutcome::Pruned { results },
+            } => {
+                for result in results.iter().filter(|result| result.error.is_none()) {
+                    self.registry
+                        .remove_workspace(result.workspace_id)
+                        .map_err(|error| error.to_string())?;
+                }
+                Ok(WorktreeChange::Pruned { results })
+            }
+            _ => Err("bp-host returned an unexpected Worktrunk prune response.".to_string()),
+        }
+    }
+
     fn switch_result(
         &mut self,
         host_id: crate::core::HostId,
@@ -0,0 +1,436 @@
+//! Non-interactive `bp <command>` entry point for shell scripts and CI.
+//!
+//! Scripted commands share the palette parser and the runtime's host
+//! operations, but never render: each one prints a single result (JSON with
+//! `--json`) and exits. They act on this machine's workspaces only, because
+//! SSH hosts need the interactive client to relay authentication prompts.
+
+use super::command::{self, ClientCommand};
+use super::runtime::ClientRuntime;
+use crate::core::WorkspaceRecord;
+use crate::ports::{ForwardState, ForwardStatus, PortSnapshot};
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::mpsc;
+
+pub const SCRIPT_HELP: &[(&str, &str)] = &[
+    ("workspace list", "List registered workspaces"),
+    ("workspace add <path>", "Register a folder as a workspace"),
+    (
+        "agent spawn <codex|claude|opencode|name>",
+        "Start an integrated or [providers.<name>] agent tab",
+    ),
+    ("ports [--all-host]", "Discover listening ports"),
+    (
+        "forward <port|address:port> [--local <port>]",
+        "Forward one listener until interrupted",
+    ),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+struct Invocation {
+    request: Request,
+    json: bool,
+    workspace: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Request {
+    WorkspaceList,
+    Command(ClientCommand),
+}
+
+/// Runs one scripted command against the shared registry and exits.
+/// `program` names this binary in warnings, as it does in errors.
+pub fn run_script(program: &str, arguments: Vec<String>) -> Result<(), Box<dyn Error>> {
+    let invocation = parse_arguments(arguments)?;
+    let cwd = std::env::current_dir()?;
+    let config = crate::client_config::load(&cwd)?;
+    let mut runtime = ClientRuntime::initialize_scripted(&config)?;
+    let mut stdout = io::stdout().lock();
+    let held = execute(&mut runtime, program, &cwd, &invocation, &mut stdout)?;
+    drop(stdout);
+    if let Some(forward) = held {
+        hold_forward(&mut runtime, &forward)?;
+    }
+    Ok(())
+}
+
+fn parse_arguments(arguments: impl IntoIterator<Item = String>) -> Result<Invocation, String> {
+    let mut json = false;
+    let mut workspace = None;
+    let mut words = Vec::new();
+    let mut arguments = arguments.into_iter();
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--json" => json = true,
+            "--workspace" => {
+                let selector = arguments
+                    .next()
+                    .filter(|value| !value.is_empty() && !value.starts_with("--"))
+                    .ok_or_else(|| "--workspace needs a workspace name or ID.".to_owned())?;
+                workspace = Some(selector);
+            }
+            _ => words.push(argument),
+        }
+    }
+    let request = if words == ["workspace", "list"] {
+        Request::WorkspaceList
+    } else {
+        let input = format!(":{}", shell_words::join(&words));
+        match command::parse(&input)? {
+            command @ (ClientCommand::WorkspaceRegister { .. }
+            | ClientCommand::AgentSpawn { .. }
+            | ClientCommand::Ports { .. }
+            | ClientCommand::Forward { .. }) => Request::Command(command),
+            _ => {
+                return Err(format!(
+                    "{input} needs the interactive client; run bp without arguments."
+                ))
+            }
+        }
+    };
+    if workspace.is_some()
+        && matches!(
+            request,
+            Request::WorkspaceList | Request::Command(ClientCommand::WorkspaceRegister { .. })
+        )
+    {
+        return Err("--workspace only applies to agent, ports, and forward commands.".to_owned());
+    }
+    Ok(Invocation {
+        request,
+        json,
+        workspace,
+    })
+}
+
+/// Returns a forward this process must keep alive until it is interrupted.
+fn execute(
+    runtime: &mut ClientRuntime,
+    program: &str,
+    cwd: &Path,
+    invocation: &Invocation,
+    out: &mut impl Write,
+) -> Result<Option<ForwardState>, String> {
+    let selector = invocation.workspace.as_deref();
+    match &invocation.request {
+        Request::WorkspaceList => {
+            let snapshot = runtime.snapshot()?;
+            if invocation.json {
+                return emit_json(out, &snapshot.workspaces).map(|()| None);
+            }
+            for workspace in &snapshot.workspaces {
+                let host = snapshot
+                    .hosts
+                    .iter()
+                    .find(|host| host.id == workspace.host_id)
+                    .map_or("unknown host", |host| host.display_name.as_str());
+                emit_line(
+                    out,
+                    format!("{}  {host}  {}", workspace.id, workspace.root_path),
+                )?;
+            }
+            Ok(None)
+        }
+        Request::Command(ClientCommand::WorkspaceRegister { path }) => {
+            let workspace_id = runtime.register_workspace(runtime.local_host_id(), path)?;
+            let workspace = runtime.find_workspace(&workspace_id.to_string())?;
+            if invocation.json {
+                return emit_json(out, &workspace).map(|()| None);
+            }
+            emit_line(
+                out,
+                format!("Registered {} as {}.", workspace.root_path, workspace.id),
+            )
+            .map(|()| None)
+        }
+        Request::Command(ClientCommand::AgentSpawn { provider }) => {
+            let workspace = resolve_workspace(runtime, cwd, selector)?;
+            // Blocker transitions are only rendered by the interactive client;
+            // it rediscovers this run from the registry when it next starts.
+            let (sender, _transitions) = mpsc::channel();
+            let spawned = runtime.spawn_agent(workspace.id, *provider, sender)?;
+            if invocation.json {
+                return emit_json(
+                    out,
+                    &serde_json::json!({
+                        "workspace_id": workspace.id,
+                        "run_id": spawned.run_id,
+                        "pane_id": spawned.pane_id,
+                        "provider": provider,
+                        "tab_id": spawned.tab_id,
+                        "zellij_pane_id": spawned.zellij_pane_id,
+                        "needs_input_capability": spawned.capability,
+                    }),
+                )
+                .map(|()| None);
+            }
+            emit_line(
+                out,
+                format!(
+                    "Started {provider} in {} as run {} (Zellij tab {}).",
+                    workspace.root_path, spawned.run_id, spawned.tab_id
+                ),
+            )
+            .map(|()| None)
+        }
+        Request::Command(ClientCommand::Ports { all_host }) => {
+            let workspace = if *all_host {
+                None
+            } else {
+                Some(resolve_workspace(runtime, cwd, selector)?)
+            };
+            let mut snapshot = runtime.discover_ports(runtime.local_host_id())?;
+            if let Some(workspace) = &workspace {
+                snapshot.listeners.retain(|listener| {
+                    super::runtime::ports::listener_matches_workspace(
+                        listener.workspace_path.as_deref(),
+                        &workspace.root_path,
+                    )
+                });
+                snapshot.sockets.retain(|socket| {
+                    super::runtime::ports::listener_matches_workspace(
+                        socket.workspace_path.as_deref(),
+                        &workspace.root_path,
+                    )
+                });
+            }
+            if invocation.json {
+                return emit_json(out, &snapshot).map(|()| None);
+            }
+            for listener in &snapshot.listeners {
+                emit_line(
+                    out,
+                    format!(
+                        "{}  {}{}",
+                        listener.bind_endpoint(),
+                        listener.process.as_deref().unwrap_or("unknown process"),
+                        listener
+                            .http
+                            .as_ref()
+                            .map(|http| format!("  {}", http.label()))
+                            .unwrap_or_default()
+                    ),
+                )?;
+            }
+            for socket in &snapshot.sockets {
+                emit_line(
+                    out,
+                    format!(
+                        "unix:{}  {}",
+                        socket.path,
+                        socket.process.as_deref().unwrap_or("unknown process")
+                    ),
+                )?;
+            }
+            if let Some(warning) = &snapshot.warning {
+                eprintln!("{program}: {warning}");
+            }
+            Ok(None)
+        }
+        Request::Command(ClientCommand::Forward {
+            remote_port,
+            bind_address,
+            local_port,
+        }) => {
+            let workspace = resolve_workspace(runtime, cwd, selector)?;
+            let snapshot = runtime.discover_ports(workspace.host_id)?;
+            let target = forward_target(&snapshot, &workspace, *remote_port, bind_address)?;
+            let forward = runtime.forward_workspace_port(workspace.id, target, *local_port)?;
+            if invocation.json {
+                emit_json(out, &forward)?;
+            } else if forward.status == ForwardStatus::Direct {
+                emit_line(
+                    out,
+                    format!(
+                        "Local service is already available at http://{}; no tunnel was created.",
+                        forward.local_address
+                    ),
+                )?;
+            } else {
+                emit_line(
+                    out,
+                    format!(
+                        "Forward active: http://{} → {}. Interrupt to stop it.",
+                        forward.local_address,
+                        forward.remote_endpoint()
+                    ),
+                )?;
+            }
+            Ok((forward.status != ForwardStatus::Direct).then_some(forward))
+        }
+        Request::Command(_) => unreachable!("parse_arguments accepts only scripted commands"),
+    }
+}
+
+/// The selector wins; otherwise the innermost registered workspace that
+/// contains the working directory is used, mirroring how `bp` opens a folder.
+fn resolve_workspace(
+    runtime: &ClientRuntime,
+    cwd: &Path,
+    selector: Option<&str>,
+) -> Result<WorkspaceRecord, String> {
+    let local_host_id = runtime.local_host_id();
+    let workspace = match selector {
+        Some(selector) => runtime.find_workspace(selector)?,
+        None => {
+            let cwd = std::fs::canonicalize(cwd)
+                .map_err(|error| format!("Could not open {}: {error}", cwd.display()))?;
+            runtime
+                .snapshot()?
+                .workspaces
+                .into_iter()
+                .filter(|workspace| {
+                    workspace.host_id == local_host_id
+                        && cwd.starts_with(Path::new(&workspace.root_path))
+                })
+                .max_by_key(|workspace| workspace.root_path.len())
+                .ok_or_else(|| {
+                    format!(
+                        "No registered workspace contains {}; pass --workspace <name|id> or run bp workspace add .",
+                        cwd.display()
+                    )
+                })?
+        }
+    };
+    if workspace.host_id != local_host_id {
+        return Err(format!(
+            "Workspace {} is on an SSH host; scripted commands act on this machine only.",
+            workspace.root_path
+        ));
+    }
+    Ok(workspace)
+}
+
+fn forward_target(
+    snapshot: &PortSnapshot,
+    workspace: &WorkspaceRecord,
+    remote_port: u16,
+    bind_address: &Option<String>,
+) -> Result<crate::ports::RemotePortTarget, String> {
+    super::runtime::ports::resolve_workspace_target(
+        snapshot,
+        &workspace.root_path,
+        false,
+        remote_port,
+        bind_address.as_deref(),
+    )
+}
+
+/// Client-owned tunnels live in this process, so the command stays in the
+/// foreground until a termination signal and then cancels its own forward.
+fn hold_forward(runtime: &mut ClientRuntime, forward: &ForwardState) -> Result<(), String> {
+    use signal_hook::consts::signal::{SIGHUP, SIGINT, SIGTERM};
+    let mut signals = signal_hook::iterator::Signals::new([SIGHUP, SIGINT, SIGTERM])
+        .map_err(|error| format!("Could not watch for interruption: {error}"))?;
+    signals.forever().next();
+    runtime.cancel_workspace_forward(forward.workspace_id, forward)
+}
+
+fn emit_json(out: &mut impl Write, value: &impl serde::Serialize) -> Result<(), String> {
+    serde_json::to_writer(&mut *out, value).map_err(|error| error.to_string())?;
+    emit_line(out, String::new())
+}
+
+fn emit_line(out: &mut impl Write, line: String) -> Result<(), String> {
+    writeln!(out, "{line}").map_err(|error| format!("Could not write output: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent_status::Provider;
+
+    fn arguments(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[test]
+    fn scripted_arguments_reuse_the_palette_parser() {
+        assert_eq!(
+            parse_arguments(arguments(&[
+                "agent",
+                "spawn",
+                "codex",
+                "--workspace",
+                "api"
+            ])),
+            Ok(Invocation {
+                request: Request::Command(ClientCommand::AgentSpawn {
+                    provider: Provider::Codex
+                }),
+                json: false,
+                workspace: Some("api".to_owned()),
+            })
+        );
+        assert_eq!(
+            parse_arguments(arguments(&["--json", "ports", "--all-host"])),
+            Ok(Invocation {
+                request: Request::Command(ClientCommand::Ports { all_host: true }),
+                json: true,
+                workspace: None,
+            })
+        );
+        assert_eq!(
+            parse_arguments(arguments(&["workspace", "add", "/srv/my project"]))
+                .map(|invocation| invocation.request),
+            Ok(Request::Command(ClientCommand::WorkspaceRegister {
+                path: "/srv/my project".into()
+            }))
+        );
+    }
+
+    #[test]
+    fn interactive_only_commands_and_misplaced_flags_are_refused() {
+        assert!(parse_arguments(arguments(&["quit"]))
+            .unwrap_err()
+            .contains("interactive client"));
+        assert!(parse_arguments(arguments(&["approve"]))
+            .unwrap_err()
+            .contains("interactive client"));
+        assert_eq!(
+            parse_arguments(arguments(&["agent", "spawn"])).unwrap_err(),
+            "Usage: :agent spawn <codex|claude|opencode|name> | send <text>"
+        );
+        assert!(parse_arguments(arguments(&["ports", "--workspace"])).is_err());
+        assert!(parse_arguments(arguments(&["workspace", "list", "--workspace", "api"])).is_err());
+    }
+
+    #[test]
+    fn workspace_commands_print_registry_records() {
+        let root = tempfile::tempdir().unwrap();
+        let folder = root.path().join("project");
+        std::fs::create_dir(&folder).unwrap();
+        let mut runtime = ClientRuntime::test_fixture(root.path());
+
+        let mut added = Vec::new();
+        let invocation = parse_arguments(arguments(&[
+            "workspace",
+            "add",
+            folder.to_str().unwrap(),
+            "--json",
+        ]))
+        .unwrap();
+        assert_eq!(
+            execute(&mut runtime, "bp", root.path(), &invocation, &mut added),
+            Ok(None)
+        );
+        let added: WorkspaceRecord = serde_json::from_slice(&added).unwrap();
+
+        let mut listed = Vec::new();
+        let invocation = parse_arguments(arguments(&["workspace", "list", "--json"])).unwrap();
+        execute(&mut runtime, "bp", root.path(), &invocation, &mut listed).unwrap();
+        let listed: Vec<WorkspaceRecord> = serde_json::from_slice(&listed).unwrap();
+        assert_eq!(listed, vec![added.clone()]);
+
+        assert_eq!(
+            resolve_workspace(&runtime, &folder.join("."), None).map(|found| found.id),
+            Ok(added.id)
+        );
+        assert!(resolve_workspace(&runtime, root.path(), None)
+            .unwrap_err()
+            .contains("No registered workspace contains"));
+    }
+}
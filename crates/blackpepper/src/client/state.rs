@@ -3,24 +3,28 @@ use super::{
     HostNode,
 };
 use crate::client_config::ClientConfig;
+mod agent_dashboard;
 mod agent_run;
 mod input_modes;
+mod services;
 mod view;
 
-use crate::core::{HostAgentRun, HostId, RegistrySnapshot, WorkspaceId};
+use crate::core::{AgentRunId, HostAgentRun, HostId, RegistrySnapshot, WorkspaceId};
 use crate::input::InputDecoder;
 use crate::keymap::{parse_key_chord, KeyChord};
-use crate::ports::{ForwardState, PortSnapshot};
+use crate::ports::{ForwardState, PortSnapshot, ReverseForwardState, SocketForwardState};
 use crate::terminal::InputModes;
 use ratatui::layout::Rect;
 use std::collections::{BTreeMap, HashMap};
 use std::sync::mpsc::Sender;
 use std::time::{Duration, Instant};
 
+pub use agent_dashboard::AgentRow;
 pub use agent_run::AgentRunView;
+pub use services::{ServiceHealth, ServiceStatus};
 pub use view::{
-    ClientMode, DetailView, HelpView, MouseAction, MouseTarget, PendingWorktrunkApproval,
-    WorkspacePicker,
+    AgentOrder, AgentsView, ClientMode, DetailView, HelpView, MouseAction, MouseTarget,
+    PendingWorktrunkApproval, PullRequestsView, WorkspacePicker, WorktreesView,
 };
 
 pub struct ClientState {
@@ -38,6 +42,13 @@ pub struct ClientState {
     pub ports: BTreeMap<HostId, PortSnapshot>,
     pub show_all_host_ports: bool,
     pub forwards: Vec<ForwardState>,
+    /// Host Unix sockets this client reaches through its own socket paths.
+    pub socket_forwards: Vec<SocketForwardState>,
+    /// Host-loopback ports this client carries back to its own services.
+    pub reverse_forwards: Vec<ReverseForwardState>,
+    /// `[[forward]]` declarations of attached workspaces, applied as their
+    /// listeners appear in periodic port snapshots.
+    pub declared_forwards: BTreeMap<WorkspaceId, super::runtime::ports::DeclaredForwards>,
     /// Scroll offset for the compact ports panel. The full `:ports` detail
     /// remains independently scrollable.
     pub ports_scroll: u16,
@@ -47,6 +58,9 @@ pub struct ClientState {
     pub connected_clients: BTreeMap<WorkspaceId, usize>,
     /// Host-computed repository and tab context, keyed by workspace.
     pub overviews: BTreeMap<WorkspaceId, crate::core::WorkspaceOverview>,
+    /// Configured services with an open tab, per attached workspace, in
+    /// config order.
+    pub services: BTreeMap<WorkspaceId, Vec<ServiceStatus>>,
     /// Explicit host work remains visible even if terminal output replaces
     /// the transient footer message while its worker is running.
     pub host_operations: BTreeMap<HostId, (uuid::Uuid, String)>,
@@ -65,6 +79,18 @@ pub struct ClientState {
     pub picker: Option<WorkspacePicker>,
     /// Open grouped help, if any.
     pub help: Option<HelpView>,
+    /// Open cross-host agent dashboard, if any.
+    pub agents: Option<AgentsView>,
+    /// Open `:worktree list` browser, if any.
+    pub worktrees: Option<WorktreesView>,
+    /// Open `:worktree prs` picker, if any.
+    pub pull_requests: Option<PullRequestsView>,
+    /// An agent tab to focus once its workspace finishes attaching.
+    pub pending_agent_focus: Option<(WorkspaceId, crate::core::AgentRunId)>,
+    /// The dashboard row a reply typed as `:agent send` is addressed to.
+    pub agent_reply: Option<(WorkspaceId, crate::core::AgentRunId)>,
+    /// Agent revisions already announced by a desktop or terminal notification.
+    pub notifications: super::notify::NotificationLedger,
     pub pending_approval: Option<PendingWorktrunkApproval>,
     pub approval_scroll: u16,
     pub detail: Option<DetailView>,
@@ -137,11 +163,15 @@ impl ClientState {
             ports: BTreeMap::new(),
             show_all_host_ports: false,
             forwards: Vec::new(),
+            socket_forwards: Vec::new(),
+            reverse_forwards: Vec::new(),
+            declared_forwards: BTreeMap::new(),
             ports_scroll: 0,
             ports_area: None,
             mouse_targets: Vec::new(),
             connected_clients: BTreeMap::new(),
             overviews: BTreeMap::new(),
+            services: BTreeMap::new(),
             host_operations: BTreeMap::new(),
             authentication_host: None,
             authentication_output: Vec::new(),
@@ -151,6 +181,12 @@ impl ClientState {
             command_error: None,
             picker: None,
             help: None,
+            agents: None,
+            worktrees: None,
+            pull_requests: None,
+            pending_agent_focus: None,
+            agent_reply: None,
+            notifications: Default::default(),
             pending_approval: None,
             approval_scroll: 0,
             detail: None,
@@ -232,6 +268,53 @@ impl ClientState {
             .map(|workspace| workspace.host_id)
     }
 
+    /// Every tunnel on `host_id` went down with its connection; the next
+    /// restore brings each back on the ports it used before.
+    pub(in crate::client) fn mark_host_tunnels_reconnecting(&mut self, host_id: HostId) {
+        for forward in self
+            .forwards
+            .iter_mut()
+            .filter(|forward| forward.host_id == host_id)
+        {
+            forward.mark_reconnecting();
+        }
+        for forward in self
+            .socket_forwards
+            .iter_mut()
+            .filter(|forward| forward.host_id == host_id)
+        {
+            forward.mark_reconnecting();
+        }
+        for forward in self
+            .reverse_forwards
+            .iter_mut()
+            .filter(|forward| forward.host_id == host_id)
+        {
+            forward.mark_reconnecting();
+        }
+    }
+
+    /// Track a workspace's `[[forward]]` declarations. Unchanged ones keep
+    /// their record of which listeners were already up, even when another
+    /// entry is edited, so rereading the config on every refresh does not
+    /// fire them again.
+    pub(in crate::client) fn remember_declared_forwards(
+        &mut self,
+        workspace_id: WorkspaceId,
+        declared: Vec<crate::client_config::DeclaredForward>,
+    ) {
+        if declared.is_empty() {
+            self.declared_forwards.remove(&workspace_id);
+        } else if let Some(current) = self.declared_forwards.get_mut(&workspace_id) {
+            current.redeclare(declared);
+        } else {
+            self.declared_forwards.insert(
+                workspace_id,
+                super::runtime::ports::DeclaredForwards::new(declared),
+            );
+        }
+    }
+
     pub fn set_output(&mut self, message: impl Into<String>) {
         self.transient_output = None;
         self.output = Some(message.into());
@@ -308,6 +391,34 @@ impl ClientState {
         changed
     }
 
+    /// Replying to one run marks only that run's completion seen; the
+    /// workspace's other agents keep their badges.
+    pub fn mark_run_completion_seen(
+        &mut self,
+        workspace_id: WorkspaceId,
+        run_id: AgentRunId,
+    ) -> bool {
+        let Some(run) = self
+            .agent_runs
+            .get_mut(&workspace_id)
+            .and_then(|runs| runs.iter_mut().find(|run| run.run_id == run_id))
+        else {
+            return false;
+        };
+        let Some(revision) = run
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.completion_revision)
+            .filter(|revision| run.seen_completion_revision < *revision)
+        else {
+            return false;
+        };
+        run.seen_completion_revision = revision;
+        self.refresh_workspace_status(workspace_id);
+        self.rebuild_tree();
+        true
+    }
+
     /// Workspaces matching the open picker's filter, in sidebar order, each
     /// with the host it lives on. Filtering is a plain case-insensitive
     /// substring match over the label so what you type is what you get.
@@ -575,6 +686,7 @@ mod tests {
             blocker_sequence: 0,
             blocker_observed_at_ms: None,
             interrupted_after_sequence: None,
+            manifest_warning: None,
         }
     }
 
@@ -627,6 +739,30 @@ mod tests {
         assert!(!state.mark_workspace_completions_seen(workspace_id));
     }
 
+    #[test]
+    fn replying_to_one_run_leaves_the_others_unseen() {
+        let (event_tx, _event_rx) = std::sync::mpsc::channel();
+        let mut state = ClientState::new(
+            crate::client_config::load_contents(None, None, None).unwrap(),
+            RegistrySnapshot::default(),
+            event_tx,
+        );
+        let workspace_id = WorkspaceId::new();
+        let runs = [11, 12].map(|revision| {
+            let mut run = run_view();
+            run.apply_snapshot(snapshot(run.run_id, AgentState::Done, revision));
+            run
+        });
+        let replied = runs[0].run_id;
+        state.agent_runs.insert(workspace_id, runs.to_vec());
+
+        assert!(state.mark_run_completion_seen(workspace_id, replied));
+        let runs = &state.agent_runs[&workspace_id];
+        assert_eq!(runs[0].display_status(), DisplayStatus::Ready);
+        assert_eq!(runs[1].display_status(), DisplayStatus::Done);
+        assert!(!state.mark_run_completion_seen(workspace_id, replied));
+    }
+
     #[test]
     fn authoritative_activity_clears_interrupted_state() {
         let mut run = run_view();
@@ -642,10 +778,12 @@ mod tests {
         let mut run = run_view();
         run.blocker = Some(BlockerExplain {
             provider: Provider::Codex,
+            manifest: crate::agent_status::BlockerManifestSource::Bundled,
             manifest_version: "test".to_owned(),
             rule_id: "approval".to_owned(),
             confidence: BlockerConfidence::High,
             priority: 10,
+            answers: Vec::new(),
         });
         run.blocker_observed_at_ms = Some(10);
 
@@ -676,10 +814,12 @@ mod tests {
         run.provider = Provider::OpenCode;
         run.blocker = Some(BlockerExplain {
             provider: Provider::OpenCode,
+            manifest: crate::agent_status::BlockerManifestSource::Bundled,
             manifest_version: "test".to_owned(),
             rule_id: "approval".to_owned(),
             confidence: BlockerConfidence::High,
             priority: 10,
+            answers: Vec::new(),
         });
         run.blocker_observed_at_ms = Some(9);
         let mut recovered = snapshot(run.run_id, AgentState::Working, 10);
@@ -0,0 +1,129 @@
+//! Agent navigation: rows for the cross-host dashboard, and the run behind a
+//! workspace's sidebar status.
+
+use super::{elapsed_label, AgentOrder, ClientState};
+use crate::agent_status::Provider;
+use crate::client::DisplayStatus;
+use crate::core::{AgentRunId, HostId, WorkspaceId};
+use std::cmp::Reverse;
+
+/// One agent run as the dashboard lists it, already joined with the names of
+/// the workspace and host it lives on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentRow {
+    pub host_id: HostId,
+    pub workspace_id: WorkspaceId,
+    pub run_id: AgentRunId,
+    pub provider: Provider,
+    pub status: DisplayStatus,
+    /// Age of the last provider event, in the sidebar's vocabulary.
+    pub elapsed: Option<String>,
+    pub workspace: String,
+    pub host: String,
+    pub tab_id: u64,
+    /// Labels of the answers the asking rule declares, in key order.
+    pub answers: Vec<String>,
+    last_event_at_ms: Option<u64>,
+}
+
+impl ClientState {
+    /// Every run this client knows about, across hosts, in the dashboard's
+    /// current order. Runs whose workspace has left the tree are skipped: the
+    /// dashboard cannot open what the sidebar cannot show.
+    pub fn agent_rows(&self) -> Vec<AgentRow> {
+        let order = self.agents.map(|view| view.order).unwrap_or_default();
+        let mut rows = Vec::new();
+        for host in &self.tree {
+            for workspace in host
+                .repositories
+                .iter()
+                .flat_map(|repository| &repository.workspaces)
+            {
+                for run in self.agent_runs.get(&workspace.id).into_iter().flatten() {
+                    let last_event_at_ms = run
+                        .snapshot
+                        .as_ref()
+                        .and_then(|snapshot| snapshot.last_event_at_ms);
+                    let status = run.display_status();
+                    let answers = run
+                        .blocker
+                        .as_ref()
+                        .filter(|_| status == DisplayStatus::NeedsInput)
+                        .map(|blocker| {
+                            blocker
+                                .answers
+                                .iter()
+                                .map(|answer| answer.label.clone())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    rows.push(AgentRow {
+                        host_id: host.id,
+                        workspace_id: workspace.id,
+                        run_id: run.run_id,
+                        provider: run.provider,
+                        status,
+                        elapsed: last_event_at_ms.and_then(elapsed_label),
+                        workspace: workspace.label.clone(),
+                        host: host.label.clone(),
+                        tab_id: run.tab_id,
+                        answers,
+                        last_event_at_ms,
+                    });
+                }
+            }
+        }
+        // Both orders are stable over the tree order, so rows with equal keys
+        // keep their sidebar position instead of shuffling between frames.
+        match order {
+            AgentOrder::AsksFirst => rows.sort_by_key(|row| {
+                (
+                    Reverse(row.status.priority()),
+                    Reverse(row.last_event_at_ms),
+                )
+            }),
+            AgentOrder::Recent => rows.sort_by_key(|row| Reverse(row.last_event_at_ms)),
+        }
+        rows
+    }
+
+    /// Move the dashboard cursor, clamping rather than wrapping.
+    pub fn move_agents(&mut self, direction: i32) {
+        let count = self.agent_rows().len();
+        let Some(view) = self.agents.as_mut() else {
+            return;
+        };
+        view.selected = if count == 0 {
+            0
+        } else {
+            (view.selected as i32 + direction).clamp(0, count as i32 - 1) as usize
+        };
+    }
+
+    /// The run a workspace's status row is reporting: of the runs showing
+    /// that status, the one whose provider spoke last. `None` while idle,
+    /// since an idle row points at nothing worth jumping to.
+    pub fn status_agent(&self, workspace_id: WorkspaceId) -> Option<AgentRunId> {
+        let status = self.statuses.get(&workspace_id).copied()?;
+        if status == DisplayStatus::Idle {
+            return None;
+        }
+        self.agent_runs
+            .get(&workspace_id)?
+            .iter()
+            .filter(|run| run.display_status() == status)
+            .max_by_key(|run| {
+                run.snapshot
+                    .as_ref()
+                    .and_then(|snapshot| snapshot.last_event_at_ms)
+            })
+            .map(|run| run.run_id)
+    }
+
+    pub fn agents_choice(&self) -> Option<(WorkspaceId, AgentRunId)> {
+        let view = self.agents?;
+        self.agent_rows()
+            .get(view.selected)
+            .map(|row| (row.workspace_id, row.run_id))
+    }
+}
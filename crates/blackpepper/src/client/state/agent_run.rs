@@ -23,6 +23,9 @@ pub struct AgentRunView {
     /// session. Codex and Claude cannot distinguish an interrupted turn from a
     /// normal stop, so a later completion event must not manufacture `done`.
     pub interrupted_after_sequence: Option<u64>,
+    /// Why the host's watcher runs without the user's blocker manifest, as of
+    /// the last `:status explain`.
+    pub manifest_warning: Option<String>,
 }
 
 impl AgentRunView {
@@ -45,6 +48,7 @@ impl AgentRunView {
             blocker_sequence: 0,
             blocker_observed_at_ms: None,
             interrupted_after_sequence: None,
+            manifest_warning: None,
         }
     }
 
@@ -155,6 +159,7 @@ impl AgentRunView {
 
     pub fn apply_host_snapshot(&mut self, snapshot: crate::core::HostAgentSnapshot) {
         self.explain = Some(snapshot.explain);
+        self.manifest_warning = snapshot.manifest_warning;
         self.apply_snapshot(snapshot.snapshot);
     }
 
@@ -181,6 +186,7 @@ fn displayed_needs_input_capability(
         }
         crate::agent_status::Provider::OpenCode
         | crate::agent_status::Provider::Codex
-        | crate::agent_status::Provider::Claude => "partial",
+        | crate::agent_status::Provider::Claude
+        | crate::agent_status::Provider::Custom(_) => "partial",
     }
 }
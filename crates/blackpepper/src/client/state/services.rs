@@ -0,0 +1,248 @@
+//! Configured services as periodic refresh reports them, and the
+//! `restart = "on-failure"` backoff.
+//!
+//! The host only observes; deciding to restart happens here so a relaunch
+//! runs as ordinary host work with the same session lease `:service start`
+//! takes, never from inside a refresh.
+
+use crate::core::{ServiceObservation, ServiceProcess, ServiceRestart};
+use std::time::{Duration, Instant};
+
+/// The first retry waits a second and each later one twice as long.
+const BACKOFF_CAP: Duration = Duration::from_secs(60);
+/// A relaunched service that stays up this long is healthy again, so its
+/// next crash starts the backoff over.
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+/// A restart whose result never arrived (its worker was discarded on
+/// disconnect) stops blocking the next attempt after this long.
+const RESTART_GRACE: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServiceHealth {
+    /// Running, but its readiness check has not passed.
+    Starting,
+    Ready,
+    /// Exited unsuccessfully; `code` is `None` when a signal ended it.
+    Crashed {
+        code: Option<i32>,
+    },
+    /// Exited with status 0, which the restart policy leaves alone.
+    Stopped,
+    Restarting,
+    /// Not started on attach because a dependency never became ready.
+    Blocked,
+}
+
+impl ServiceHealth {
+    pub const fn word(self) -> &'static str {
+        match self {
+            Self::Starting => "starting",
+            Self::Ready => "ready",
+            Self::Crashed { .. } => "crashed",
+            Self::Stopped => "stopped",
+            Self::Restarting => "restarting",
+            Self::Blocked => "blocked",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ServiceStatus {
+    pub name: String,
+    pub health: ServiceHealth,
+    /// Consecutive automatic restarts, which set the backoff.
+    pub restarts: u32,
+    /// Why attach left the service unstarted, while it is [`ServiceHealth::Blocked`].
+    pub blocked_by: Option<String>,
+    crashed_at: Option<Instant>,
+    restarting_since: Option<Instant>,
+    live_since: Option<Instant>,
+}
+
+impl ServiceStatus {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            health: ServiceHealth::Starting,
+            restarts: 0,
+            blocked_by: None,
+            crashed_at: None,
+            restarting_since: None,
+            live_since: None,
+        }
+    }
+
+    /// Fold in one observation. Returns true when the policy wants a restart
+    /// now; the caller confirms with [`Self::begin_restart`] once one starts.
+    pub fn observe(&mut self, observation: &ServiceObservation, now: Instant) -> bool {
+        // A reported pane means the service was started after all.
+        self.blocked_by = None;
+        match observation.process {
+            ServiceProcess::Live => {
+                if self.live_since.is_none() {
+                    self.live_since = Some(now);
+                }
+                if self
+                    .live_since
+                    .is_some_and(|since| now.duration_since(since) >= STABLE_AFTER)
+                {
+                    self.restarts = 0;
+                }
+                self.crashed_at = None;
+                self.restarting_since = None;
+                self.health = if observation.ready == Some(false) {
+                    ServiceHealth::Starting
+                } else {
+                    ServiceHealth::Ready
+                };
+                false
+            }
+            ServiceProcess::Exited { code: Some(0) } => {
+                self.live_since = None;
+                self.health = ServiceHealth::Stopped;
+                false
+            }
+            ServiceProcess::Exited { code } => {
+                self.live_since = None;
+                // The old pane stays visible until the restart replaces it.
+                if self
+                    .restarting_since
+                    .is_some_and(|since| now.duration_since(since) < RESTART_GRACE)
+                {
+                    return false;
+                }
+                self.restarting_since = None;
+                let crashed_at = *self.crashed_at.get_or_insert(now);
+                self.health = ServiceHealth::Crashed { code };
+                observation.restart == ServiceRestart::OnFailure
+                    && now.duration_since(crashed_at) >= self.backoff()
+            }
+        }
+    }
+
+    pub fn is_blocked(&self) -> bool {
+        self.health == ServiceHealth::Blocked
+    }
+
+    pub fn begin_restart(&mut self, now: Instant) {
+        self.restarts = self.restarts.saturating_add(1);
+        self.restarting_since = Some(now);
+        self.health = ServiceHealth::Restarting;
+    }
+
+    /// The relaunched tab is running; readiness is up to the next refresh.
+    pub fn restarted(&mut self, now: Instant) {
+        self.restarting_since = None;
+        self.crashed_at = None;
+        self.live_since = Some(now);
+        self.health = ServiceHealth::Starting;
+    }
+
+    /// A relaunch that failed counts as another crash and waits out the next,
+    /// longer backoff.
+    pub fn restart_failed(&mut self, now: Instant) {
+        self.restarting_since = None;
+        self.crashed_at = Some(now);
+        self.health = ServiceHealth::Crashed { code: None };
+    }
+
+    fn backoff(&self) -> Duration {
+        Duration::from_secs(1u64 << self.restarts.min(6)).min(BACKOFF_CAP)
+    }
+}
+
+impl super::ClientState {
+    /// Show a service attach left unstarted. Refresh reports only services
+    /// with a pane, so the entry stays until one is started.
+    pub fn block_service(
+        &mut self,
+        workspace_id: crate::core::WorkspaceId,
+        name: String,
+        reason: String,
+    ) {
+        let statuses = self.services.entry(workspace_id).or_default();
+        let status = match statuses.iter().position(|status| status.name == name) {
+            Some(index) => &mut statuses[index],
+            None => {
+                statuses.push(ServiceStatus::new(name));
+                statuses.last_mut().expect("status was just pushed")
+            }
+        };
+        status.health = ServiceHealth::Blocked;
+        status.blocked_by = Some(reason);
+    }
+
+    pub fn service_status_mut(
+        &mut self,
+        workspace_id: crate::core::WorkspaceId,
+        name: &str,
+    ) -> Option<&mut ServiceStatus> {
+        self.services
+            .get_mut(&workspace_id)?
+            .iter_mut()
+            .find(|status| status.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seen(process: ServiceProcess, ready: Option<bool>) -> ServiceObservation {
+        ServiceObservation {
+            name: "web".to_owned(),
+            process,
+            ready,
+            restart: ServiceRestart::OnFailure,
+        }
+    }
+
+    #[test]
+    fn a_crash_restarts_after_a_backoff_that_doubles_until_the_service_is_stable() {
+        let crashed = seen(ServiceProcess::Exited { code: Some(1) }, None);
+        let start = Instant::now();
+        let mut status = ServiceStatus::new("web");
+
+        assert!(!status.observe(&crashed, start));
+        assert_eq!(status.health, ServiceHealth::Crashed { code: Some(1) });
+        assert!(status.observe(&crashed, start + Duration::from_secs(1)));
+        status.begin_restart(start + Duration::from_secs(1));
+        // The exited pane is still listed until the relaunch replaces it.
+        assert!(!status.observe(&crashed, start + Duration::from_secs(2)));
+        assert_eq!(status.health, ServiceHealth::Restarting);
+
+        status.restarted(start + Duration::from_secs(2));
+        assert!(!status.observe(&crashed, start + Duration::from_secs(3)));
+        assert!(!status.observe(&crashed, start + Duration::from_secs(4)));
+        assert!(status.observe(&crashed, start + Duration::from_secs(5)));
+
+        status.begin_restart(start + Duration::from_secs(5));
+        let live = seen(ServiceProcess::Live, None);
+        assert!(!status.observe(&live, start + Duration::from_secs(6)));
+        assert_eq!(status.restarts, 2);
+        assert!(!status.observe(&live, start + Duration::from_secs(70)));
+        assert_eq!(status.restarts, 0);
+    }
+
+    #[test]
+    fn readiness_and_clean_exit_never_trigger_a_restart() {
+        let now = Instant::now();
+        let mut status = ServiceStatus::new("web");
+        assert!(!status.observe(&seen(ServiceProcess::Live, Some(false)), now));
+        assert_eq!(status.health, ServiceHealth::Starting);
+        assert!(!status.observe(&seen(ServiceProcess::Live, Some(true)), now));
+        assert_eq!(status.health, ServiceHealth::Ready);
+        let clean = seen(ServiceProcess::Exited { code: Some(0) }, None);
+        assert!(!status.observe(&clean, now + Duration::from_secs(120)));
+        assert_eq!(status.health, ServiceHealth::Stopped);
+
+        let mut never = ServiceStatus::new("web");
+        let crashed = ServiceObservation {
+            restart: ServiceRestart::Never,
+            ..seen(ServiceProcess::Exited { code: None }, None)
+        };
+        assert!(!never.observe(&crashed, now));
+        assert!(!never.observe(&crashed, now + Duration::from_secs(120)));
+        assert_eq!(never.health, ServiceHealth::Crashed { code: None });
+    }
+}
@@ -8,6 +8,9 @@ pub struct PendingWorktrunkApproval {
     pub command: ClientCommand,
     pub approval: crate::worktrunk::WorktrunkApprovalToken,
     pub review: String,
+    /// Workspaces the approved command removes; their sessions and forwards
+    /// end before it runs.
+    pub removes: Vec<WorkspaceId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -33,6 +36,114 @@ pub struct HelpView {
     pub scroll: u16,
 }
 
+/// The cross-host agent dashboard. Its rows are rebuilt from every host's
+/// agent runs on each frame, so the cursor is an index into that sorted list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AgentsView {
+    pub selected: usize,
+    pub order: AgentOrder,
+}
+
+/// The `:worktree list` browser. It keeps the whole Worktrunk list rather
+/// than a rendering of it, so a row can still name its branch and path when
+/// Enter or `d` hands it to the approval flow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreesView {
+    /// The workspace whose repository was listed; mutations run against it.
+    pub workspace_id: WorkspaceId,
+    pub list: crate::worktrunk::WorktreeList,
+    pub selected: usize,
+}
+
+impl WorktreesView {
+    pub fn selected_item(&self) -> Option<&crate::worktrunk::WorktreeItem> {
+        self.list.items.get(self.selected)
+    }
+
+    /// Move the cursor, clamping rather than wrapping.
+    pub fn move_selection(&mut self, direction: i32) {
+        let count = self.list.items.len();
+        self.selected = if count == 0 {
+            0
+        } else {
+            (self.selected as i32 + direction).clamp(0, count as i32 - 1) as usize
+        };
+    }
+}
+
+/// The `:worktree prs` picker over one repository's open pull requests.
+/// Typing filters by number, title, author, or branch; Enter hands `pr:N` to
+/// the same reviewed `:worktree open` flow as typing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestsView {
+    /// The workspace whose repository was listed; the worktree opens from it.
+    pub workspace_id: WorkspaceId,
+    pub pull_requests: Vec<crate::core::OpenPullRequest>,
+    pub filter: String,
+    /// Index into the filtered list, as in the workspace picker.
+    pub selected: usize,
+}
+
+impl PullRequestsView {
+    pub fn matches(&self) -> Vec<&crate::core::OpenPullRequest> {
+        let filter = self.filter.to_lowercase();
+        let number = filter.trim_start_matches('#');
+        self.pull_requests
+            .iter()
+            .filter(|pull_request| {
+                filter.is_empty()
+                    || pull_request.number.to_string().starts_with(number)
+                    || [
+                        &pull_request.title,
+                        &pull_request.author,
+                        &pull_request.branch,
+                    ]
+                    .iter()
+                    .any(|text| text.to_lowercase().contains(&filter))
+            })
+            .collect()
+    }
+
+    pub fn selected_pull_request(&self) -> Option<&crate::core::OpenPullRequest> {
+        self.matches().get(self.selected).copied()
+    }
+
+    /// Move the cursor, clamping rather than wrapping.
+    pub fn move_selection(&mut self, direction: i32) {
+        let count = self.matches().len();
+        self.selected = if count == 0 {
+            0
+        } else {
+            (self.selected as i32 + direction).clamp(0, count as i32 - 1) as usize
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AgentOrder {
+    /// Agents waiting on a person first, then finished, running, and idle.
+    #[default]
+    AsksFirst,
+    /// Most recent provider event first, whatever the state.
+    Recent,
+}
+
+impl AgentOrder {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::AsksFirst => "asks first",
+            Self::Recent => "most recent",
+        }
+    }
+
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::AsksFirst => Self::Recent,
+            Self::Recent => Self::AsksFirst,
+        }
+    }
+}
+
 /// A Blackpepper-owned action associated with a visible screen region.
 ///
 /// These targets are rebuilt during every render. That keeps hit testing tied
@@ -54,6 +165,15 @@ pub enum MouseAction {
     ChoosePicker(WorkspaceId),
     ClosePicker,
     CloseHelp,
+    GoToAgent {
+        workspace_id: WorkspaceId,
+        run_id: crate::core::AgentRunId,
+    },
+    CloseAgents,
+    ChooseWorktree(usize),
+    CloseWorktrees,
+    ChoosePullRequest(usize),
+    ClosePullRequests,
     CloseDetail,
     Approve,
     DismissApproval,
@@ -66,6 +186,9 @@ pub enum MouseAction {
     ScrollSidebar,
     ScrollPicker,
     ScrollHelp,
+    ScrollAgents,
+    ScrollWorktrees,
+    ScrollPullRequests,
     ScrollDetail,
     ScrollApproval,
     ScrollPorts,
@@ -78,6 +201,9 @@ impl MouseAction {
             Self::ScrollSidebar
                 | Self::ScrollPicker
                 | Self::ScrollHelp
+                | Self::ScrollAgents
+                | Self::ScrollWorktrees
+                | Self::ScrollPullRequests
                 | Self::ScrollDetail
                 | Self::ScrollApproval
                 | Self::ScrollPorts
@@ -227,7 +227,7 @@ fn set_native_clipboard(_target: ClipboardTarget, text: &str) -> Result<(), Stri
         .map_err(|error| format!("could not write the system clipboard: {error}"))
 }
 
-fn write_outer_terminal(sequence: &[u8]) -> Result<(), String> {
+pub(super) fn write_outer_terminal(sequence: &[u8]) -> Result<(), String> {
     let stdout = io::stdout();
     let mut output = stdout.lock();
     output
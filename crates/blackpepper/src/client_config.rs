@@ -4,8 +4,12 @@
 mod raw;
 pub mod theme;
 
+use crate::agent_status::{Provider, ProviderName};
+pub use crate::core::DeclaredForward;
+use crate::core::ServiceRestart;
+pub use crate::providers::runtime::ProviderIntegration;
 use raw::{parse_hex_color, parse_optional_contents, read_optional, RawConfig};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -20,8 +24,16 @@ pub struct ClientConfig {
     pub keymap: KeymapConfig,
     pub hosts: BTreeMap<String, SshHostConfig>,
     pub startup: Vec<StartupCommand>,
+    pub forwards: Vec<DeclaredForward>,
     pub workspace_env: BTreeMap<String, String>,
+    /// A Zellij layout a new session opens with, relative to the workspace
+    /// root. Only the workspace layers can name one.
+    pub workspace_layout: Option<PathBuf>,
+    /// `[providers.<name>]` entries, merged by name with the innermost layer
+    /// winning.
+    pub providers: BTreeMap<ProviderName, ProviderDefinition>,
     pub ui: UiConfig,
+    pub notifications: NotificationConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -46,7 +58,7 @@ impl SshHostConfig {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct StartupCommand {
     pub name: String,
@@ -55,6 +67,127 @@ pub struct StartupCommand {
     pub cwd: Option<PathBuf>,
     #[serde(default)]
     pub auto_start: bool,
+    #[serde(default)]
+    pub restart: ServiceRestart,
+    /// How the service shows it is serving. Without one, a running process
+    /// counts as ready.
+    #[serde(default)]
+    pub ready: Option<ServiceReadiness>,
+    /// Services that must be ready before this one launches. Starting this
+    /// one starts them too.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+/// An agent provider Blackpepper has no bundled integration for.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProviderDefinition {
+    /// The program to run; the provider's name when unset.
+    #[serde(default)]
+    pub program: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Added to the agent's environment. Blackpepper's own variables win.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// A blocker manifest for the provider's prompts, read on the workspace
+    /// host. A relative path is relative to the workspace root.
+    #[serde(default)]
+    pub blocker_manifest: Option<PathBuf>,
+    #[serde(default)]
+    pub integration: ProviderIntegration,
+}
+
+/// The argument every service pane carries, which is how the client and the
+/// host's periodic refresh both recognise a service tab by content rather than
+/// by a tab name or pane ID that Zellij may reuse.
+pub(crate) fn service_marker(workspace_id: crate::core::WorkspaceId, name: &str) -> String {
+    format!("{SERVICE_ID_ENV}={}", service_identity(workspace_id, name))
+}
+
+pub(crate) fn service_identity(workspace_id: crate::core::WorkspaceId, name: &str) -> uuid::Uuid {
+    uuid::Uuid::new_v5(&workspace_id.as_uuid(), name.as_bytes())
+}
+
+const SERVICE_ID_ENV: &str = "BLACKPEPPER_SERVICE_ID";
+
+/// A `[[startup]]` entry's readiness check; exactly one field is set.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceReadiness {
+    /// A host loopback port that accepts connections once the service is up.
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Argv run in the service's cwd; ready once it exits 0.
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+}
+
+/// Which agent transitions raise a notification, and where it is delivered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationConfig {
+    pub via: NotificationChannel,
+    pub needs_input: bool,
+    pub done: bool,
+    /// Per-provider switches. A field left unset inherits the global one.
+    pub providers: BTreeMap<Provider, ProviderNotifications>,
+}
+
+impl NotificationConfig {
+    pub fn wants_needs_input(&self, provider: Provider) -> bool {
+        self.via != NotificationChannel::Off
+            && self
+                .providers
+                .get(&provider)
+                .and_then(|overrides| overrides.needs_input)
+                .unwrap_or(self.needs_input)
+    }
+
+    pub fn wants_done(&self, provider: Provider) -> bool {
+        self.via != NotificationChannel::Off
+            && self
+                .providers
+                .get(&provider)
+                .and_then(|overrides| overrides.done)
+                .unwrap_or(self.done)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProviderNotifications {
+    #[serde(default)]
+    pub needs_input: Option<bool>,
+    #[serde(default)]
+    pub done: Option<bool>,
+}
+
+/// Where an agent notification goes. `Auto` picks the desktop when the client
+/// runs on the machine in front of you and the outer terminal (OSC 777) when
+/// the client itself runs inside an SSH session, where a desktop notification
+/// would pop up on a screen nobody is looking at.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NotificationChannel {
+    #[default]
+    Auto,
+    Desktop,
+    Osc9,
+    Osc777,
+    Off,
+}
+
+impl NotificationChannel {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "auto" => Some(Self::Auto),
+            "desktop" => Some(Self::Desktop),
+            "osc9" => Some(Self::Osc9),
+            "osc777" => Some(Self::Osc777),
+            "off" => Some(Self::Off),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -244,6 +377,9 @@ pub fn load(root: &Path) -> Result<ClientConfig, ConfigError> {
     let local_path = root.join(".blackpepper").join("config.local.toml");
 
     let user = read_optional(user_path.as_deref())?;
+    if let Some(path) = &user_path {
+        check_user_layer(path, user.as_ref())?;
+    }
     let project = read_optional(Some(&project_path))?;
     let local = read_optional(Some(&local_path))?;
     Ok(merge(user, project, local))
@@ -254,12 +390,28 @@ pub(crate) fn load_contents(
     project: Option<(PathBuf, String)>,
     local: Option<(PathBuf, String)>,
 ) -> Result<ClientConfig, ConfigError> {
+    let user_path = user.as_ref().map(|(path, _)| path.clone());
     let user = parse_optional_contents(user)?;
+    if let Some(path) = &user_path {
+        check_user_layer(path, user.as_ref())?;
+    }
     let project = parse_optional_contents(project)?;
     let local = parse_optional_contents(local)?;
     Ok(merge(user, project, local))
 }
 
+/// A layout is a file inside one checkout, so the user layer, which every
+/// workspace shares, cannot name one.
+fn check_user_layer(path: &Path, user: Option<&RawConfig>) -> Result<(), ConfigError> {
+    if user.is_some_and(|raw| raw.workspace.layout.is_some()) {
+        return Err(ConfigError::Invalid {
+            path: path.to_path_buf(),
+            message: "workspace.layout names a file in one checkout; set it in the project's .blackpepper/config.toml or config.local.toml instead".to_owned(),
+        });
+    }
+    Ok(())
+}
+
 pub fn user_config_path() -> Option<PathBuf> {
     dirs::config_dir().map(|root| root.join("blackpepper").join("config.toml"))
 }
@@ -291,10 +443,50 @@ fn merge(
                 .map(|config| config.startup.clone())
         })
         .unwrap_or_default();
+    // Declared forwards describe a project's own services, so only the
+    // workspace layers can name them.
+    let forwards = [&project, &local]
+        .iter()
+        .rev()
+        .find_map(|layer| {
+            layer
+                .as_ref()
+                .filter(|config| !config.forward.is_empty())
+                .map(|config| config.forward.clone())
+        })
+        .unwrap_or_default();
+    let workspace_layout = [&project, &local]
+        .iter()
+        .rev()
+        .find_map(|layer| layer.as_ref().and_then(|raw| raw.workspace.layout.clone()));
     let hosts = user
         .as_ref()
         .map(|raw| raw.hosts.clone())
         .unwrap_or_default();
+    let mut providers = BTreeMap::new();
+    for layer in layers.iter().filter_map(|layer| layer.as_ref()) {
+        for (name, definition) in &layer.providers {
+            // Names were validated with the layer.
+            if let Some(name) = ProviderName::new(name) {
+                providers.insert(name, definition.clone());
+            }
+        }
+    }
+    let mut notification_providers = BTreeMap::<Provider, ProviderNotifications>::new();
+    for layer in layers.iter().filter_map(|layer| layer.as_ref()) {
+        for (provider, overrides) in &layer.notifications.provider {
+            let merged = notification_providers.entry(*provider).or_default();
+            merged.needs_input = overrides.needs_input.or(merged.needs_input);
+            merged.done = overrides.done.or(merged.done);
+        }
+    }
+    let notification_switch = |getter: fn(&RawConfig) -> Option<bool>| {
+        layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.as_ref().and_then(getter))
+            .unwrap_or(true)
+    };
     let theme = layers
         .iter()
         .rev()
@@ -315,7 +507,10 @@ fn merge(
         },
         hosts,
         startup,
+        forwards,
         workspace_env: env,
+        workspace_layout,
+        providers,
         ui: UiConfig {
             // Surfaces come from the theme; an explicit background or
             // foreground still wins, so a user who has already tuned those
@@ -348,6 +543,21 @@ fn merge(
                 })
                 .unwrap_or_default(),
         },
+        notifications: NotificationConfig {
+            via: layers
+                .iter()
+                .rev()
+                .find_map(|layer| {
+                    layer
+                        .as_ref()
+                        .and_then(|raw| raw.notifications.via.as_deref())
+                })
+                .and_then(NotificationChannel::parse)
+                .unwrap_or_default(),
+            needs_input: notification_switch(|raw| raw.notifications.needs_input),
+            done: notification_switch(|raw| raw.notifications.done),
+            providers: notification_providers,
+        },
     }
 }
 
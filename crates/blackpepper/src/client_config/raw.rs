@@ -1,8 +1,12 @@
-use super::{ConfigError, SshHostConfig, StartupCommand};
+use super::{
+    ConfigError, DeclaredForward, NotificationChannel, ProviderDefinition, ProviderNotifications,
+    SshHostConfig, StartupCommand,
+};
+use crate::agent_status::Provider;
 use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 #[derive(Debug, Default, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -14,9 +18,15 @@ pub(super) struct RawConfig {
     #[serde(default)]
     pub(super) startup: Vec<StartupCommand>,
     #[serde(default)]
+    pub(super) providers: BTreeMap<String, ProviderDefinition>,
+    #[serde(default)]
+    pub(super) forward: Vec<DeclaredForward>,
+    #[serde(default)]
     pub(super) workspace: RawWorkspace,
     #[serde(default)]
     pub(super) ui: RawUi,
+    #[serde(default)]
+    pub(super) notifications: RawNotifications,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -32,6 +42,8 @@ pub(super) struct RawKeymap {
 pub(super) struct RawWorkspace {
     #[serde(default)]
     pub(super) env: BTreeMap<String, String>,
+    /// A Zellij KDL layout, relative to the workspace root.
+    pub(super) layout: Option<PathBuf>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -48,6 +60,16 @@ pub(super) struct RawUi {
     pub(super) theme: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct RawNotifications {
+    pub(super) via: Option<String>,
+    pub(super) needs_input: Option<bool>,
+    pub(super) done: Option<bool>,
+    #[serde(default)]
+    pub(super) provider: BTreeMap<Provider, ProviderNotifications>,
+}
+
 pub(super) fn read_optional(path: Option<&Path>) -> Result<Option<RawConfig>, ConfigError> {
     let Some(path) = path else {
         return Ok(None);
@@ -130,6 +152,34 @@ fn validate_raw(path: &Path, raw: &RawConfig) -> Result<(), ConfigError> {
                 .to_string(),
         });
     }
+    if raw
+        .notifications
+        .via
+        .as_deref()
+        .is_some_and(|value| NotificationChannel::parse(value).is_none())
+    {
+        return Err(ConfigError::Invalid {
+            path: path.to_path_buf(),
+            message:
+                "notifications.via must be \"auto\", \"desktop\", \"osc9\", \"osc777\", or \"off\""
+                    .to_string(),
+        });
+    }
+    // Configured providers notify with the defaults; per-provider overrides
+    // stay limited to the integrated agents whose lifecycle events are known.
+    if let Some(provider) = raw
+        .notifications
+        .provider
+        .keys()
+        .find(|provider| matches!(provider, Provider::Custom(_)))
+    {
+        return Err(ConfigError::Invalid {
+            path: path.to_path_buf(),
+            message: format!(
+                "notifications.provider.{provider} must be \"codex\", \"claude\", or \"opencode\""
+            ),
+        });
+    }
     if let Some(name) = raw.ui.theme.as_deref() {
         if super::theme::by_name(name).is_none() {
             return Err(ConfigError::Invalid {
@@ -160,11 +210,7 @@ fn validate_raw(path: &Path, raw: &RawConfig) -> Result<(), ConfigError> {
         }
     }
     for (key, value) in &raw.workspace.env {
-        let mut characters = key.chars();
-        let valid_key = key.len() <= 128
-            && matches!(characters.next(), Some('_' | 'a'..='z' | 'A'..='Z'))
-            && characters.all(|character| character == '_' || character.is_ascii_alphanumeric());
-        if !valid_key || value.contains('\0') || value.len() > 16 * 1024 {
+        if !valid_env_key(key) || value.contains('\0') || value.len() > 16 * 1024 {
             return Err(ConfigError::Invalid {
                 path: path.to_path_buf(),
                 message: format!(
@@ -173,6 +219,57 @@ fn validate_raw(path: &Path, raw: &RawConfig) -> Result<(), ConfigError> {
             });
         }
     }
+    if let Some(layout) = &raw.workspace.layout {
+        let inside = layout
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if !inside
+            || layout
+                .extension()
+                .is_none_or(|extension| extension != "kdl")
+            || layout
+                .to_str()
+                .is_none_or(|text| text.contains(['\0', '\n', '\r']))
+        {
+            return Err(ConfigError::Invalid {
+                path: path.to_path_buf(),
+                message: format!(
+                    "workspace.layout {} must be a .kdl file inside the workspace, given relative to its root",
+                    layout.display()
+                ),
+            });
+        }
+    }
+    for (name, provider) in &raw.providers {
+        if crate::agent_status::ProviderName::new(name).is_none() {
+            return Err(ConfigError::Invalid {
+                path: path.to_path_buf(),
+                message: format!(
+                    "providers.{name} needs a name of lowercase letters, digits, and '-', up to 32 characters, that is not a built-in provider"
+                ),
+            });
+        }
+        let mut words = provider
+            .program
+            .iter()
+            .chain(&provider.args)
+            .chain(provider.env.values());
+        if provider.program.as_deref().is_some_and(str::is_empty)
+            || words.any(|word| word.contains('\0'))
+            || !provider.env.keys().all(|key| valid_env_key(key))
+            || provider
+                .blocker_manifest
+                .as_deref()
+                .is_some_and(|path| path.as_os_str().is_empty())
+        {
+            return Err(ConfigError::Invalid {
+                path: path.to_path_buf(),
+                message: format!(
+                    "providers.{name} needs a non-empty program, valid environment names, and no NUL bytes"
+                ),
+            });
+        }
+    }
     let mut startup_names = std::collections::BTreeSet::new();
     for startup in &raw.startup {
         if startup.name.trim().is_empty()
@@ -197,10 +294,124 @@ fn validate_raw(path: &Path, raw: &RawConfig) -> Result<(), ConfigError> {
                 message: format!("startup {} contains a NUL byte", startup.name),
             });
         }
+        if let Some(ready) = &startup.ready {
+            let valid = match (ready.port, &ready.command) {
+                (Some(port), None) => port != 0,
+                (None, Some(command)) => {
+                    !command.is_empty() && command.iter().all(|word| !word.contains('\0'))
+                }
+                _ => false,
+            };
+            if !valid {
+                return Err(ConfigError::Invalid {
+                    path: path.to_path_buf(),
+                    message: format!(
+                        "startup {} ready needs exactly one of a non-zero port or a non-empty command array",
+                        startup.name
+                    ),
+                });
+            }
+        }
+    }
+    for startup in &raw.startup {
+        if let Some(missing) = startup
+            .depends_on
+            .iter()
+            .find(|name| !startup_names.contains(name))
+        {
+            return Err(ConfigError::Invalid {
+                path: path.to_path_buf(),
+                message: format!(
+                    "startup {} depends on {missing}, which is not a [[startup]] name",
+                    startup.name
+                ),
+            });
+        }
+    }
+    if let Some(cycle) = startup_cycle(&raw.startup) {
+        return Err(ConfigError::Invalid {
+            path: path.to_path_buf(),
+            message: format!("startup depends_on forms a cycle: {}", cycle.join(" -> ")),
+        });
+    }
+    let mut declared = std::collections::BTreeSet::new();
+    let mut local_ports = std::collections::BTreeSet::new();
+    for forward in &raw.forward {
+        let target = crate::ports::RemotePortTarget::from_bind_address(
+            forward.bind.as_deref().unwrap_or("127.0.0.1"),
+            forward.port,
+        )
+        .map_err(|error| ConfigError::Invalid {
+            path: path.to_path_buf(),
+            message: format!("[[forward]] port {}: {error}", forward.port),
+        })?;
+        if !declared.insert((
+            forward.bind.is_some().then_some(target.remote_host),
+            forward.port,
+        )) {
+            return Err(ConfigError::Invalid {
+                path: path.to_path_buf(),
+                message: format!("duplicate [[forward]] for port {}", forward.port),
+            });
+        }
+        if let Some(local_port) = forward.local_port {
+            if local_port == 0 || !local_ports.insert(local_port) {
+                return Err(ConfigError::Invalid {
+                    path: path.to_path_buf(),
+                    message: format!(
+                        "[[forward]] local_port {local_port} must be non-zero and used once"
+                    ),
+                });
+            }
+        }
     }
     Ok(())
 }
 
+fn valid_env_key(key: &str) -> bool {
+    let mut characters = key.chars();
+    key.len() <= 128
+        && matches!(characters.next(), Some('_' | 'a'..='z' | 'A'..='Z'))
+        && characters.all(|character| character == '_' || character.is_ascii_alphanumeric())
+}
+
+/// The first dependency cycle in declaration order, closed by repeating its
+/// first name. Every `depends_on` name is already known to exist.
+fn startup_cycle(startup: &[StartupCommand]) -> Option<Vec<String>> {
+    fn visit<'a>(
+        startup: &'a [StartupCommand],
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut std::collections::BTreeSet<&'a str>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = path.iter().position(|entry| *entry == name) {
+            let mut cycle = path[start..]
+                .iter()
+                .map(|entry| (*entry).to_owned())
+                .collect::<Vec<_>>();
+            cycle.push(name.to_owned());
+            return Some(cycle);
+        }
+        if !done.insert(name) {
+            return None;
+        }
+        path.push(name);
+        let service = startup.iter().find(|service| service.name == name)?;
+        for dependency in &service.depends_on {
+            if let Some(cycle) = visit(startup, dependency, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        None
+    }
+
+    let mut done = std::collections::BTreeSet::new();
+    startup
+        .iter()
+        .find_map(|service| visit(startup, &service.name, &mut Vec::new(), &mut done))
+}
+
 pub(super) fn parse_hex_color(value: &str) -> Option<(u8, u8, u8)> {
     let hex = value.trim().strip_prefix('#').unwrap_or(value.trim());
     if hex.len() != 6 {
@@ -1,6 +1,10 @@
-use super::{load, load_contents, ColorTier, ConfigError};
+use super::{
+    load, load_contents, ColorTier, ConfigError, NotificationChannel, ProviderIntegration,
+};
+use crate::agent_status::Provider;
 use crate::test_utils::env_lock;
 use std::fs;
+use std::path::Path;
 use tempfile::TempDir;
 
 #[test]
@@ -100,6 +104,107 @@ fn v2_palette_is_the_default_and_custom_colors_still_override_it() {
     assert_eq!(custom.ui.foreground, (0xdd, 0xee, 0xff));
 }
 
+#[test]
+fn notification_switches_merge_per_provider_across_layers() {
+    let defaults = load_contents(None, None, None).unwrap().notifications;
+    assert_eq!(defaults.via, NotificationChannel::Auto);
+    assert!(defaults.wants_needs_input(Provider::Codex) && defaults.wants_done(Provider::Codex));
+
+    let config = load_contents(
+        Some((
+            "user.toml".into(),
+            "[notifications]\ndone = false\n\n[notifications.provider.claude]\ndone = true\nneeds_input = false\n".to_owned(),
+        )),
+        Some((
+            "project.toml".into(),
+            "[notifications.provider.claude]\nneeds_input = true\n".to_owned(),
+        )),
+        None,
+    )
+    .unwrap()
+    .notifications;
+    assert!(!config.wants_done(Provider::Codex));
+    assert!(config.wants_done(Provider::Claude));
+    assert!(config.wants_needs_input(Provider::Claude));
+
+    let off = load_contents(
+        Some((
+            "user.toml".into(),
+            "[notifications]\nvia = \"off\"\n".to_owned(),
+        )),
+        None,
+        None,
+    )
+    .unwrap()
+    .notifications;
+    assert!(!off.wants_needs_input(Provider::Codex));
+
+    for invalid in [
+        "[notifications]\nvia = \"bell\"\n",
+        "[notifications.provider.gemini]\ndone = true\n",
+    ] {
+        assert!(matches!(
+            load_contents(Some(("user.toml".into(), invalid.to_owned())), None, None),
+            Err(ConfigError::Invalid { .. })
+        ));
+    }
+}
+
+#[test]
+fn declared_forwards_come_from_the_innermost_workspace_layer() {
+    let project = "[[forward]]\nport = 3000\nauto = true\n\n[[forward]]\nport = 8080\nbind = \"0.0.0.0\"\nlocal_port = 18080\n";
+    let config = load_contents(
+        Some(("user.toml".into(), "[[forward]]\nport = 9999\n".to_owned())),
+        Some(("project.toml".into(), project.to_owned())),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        config
+            .forwards
+            .iter()
+            .map(|forward| (
+                forward.port,
+                forward.bind.as_deref(),
+                forward.local_port,
+                forward.auto
+            ))
+            .collect::<Vec<_>>(),
+        [
+            (3000, None, None, true),
+            (8080, Some("0.0.0.0"), Some(18080), false)
+        ]
+    );
+
+    let local = load_contents(
+        None,
+        Some(("project.toml".into(), project.to_owned())),
+        Some((
+            "local.toml".into(),
+            "[[forward]]\nport = 5173\nauto = true\n".to_owned(),
+        )),
+    )
+    .unwrap();
+    assert_eq!(local.forwards.len(), 1);
+    assert_eq!(local.forwards[0].port, 5173);
+
+    for invalid in [
+        "[[forward]]\nport = 0\n",
+        "[[forward]]\nport = 3000\nbind = \"example.com\"\n",
+        "[[forward]]\nport = 3000\n\n[[forward]]\nport = 3000\n",
+        "[[forward]]\nport = 3000\nlocal_port = 4000\n\n[[forward]]\nport = 3001\nlocal_port = 4000\n",
+        "[[forward]]\nport = 3000\nautostart = true\n",
+    ] {
+        assert!(
+            matches!(
+                load_contents(None, Some(("project.toml".into(), invalid.to_owned())), None),
+                Err(ConfigError::Invalid { .. })
+            ),
+            "accepted {invalid:?}"
+        );
+    }
+}
+
 #[test]
 fn terminal_color_tiers_have_deterministic_precedence() {
     let _guard = env_lock();
@@ -139,3 +244,193 @@ fn terminal_color_tiers_have_deterministic_precedence() {
         }
     }
 }
+
+#[test]
+fn services_declare_a_restart_policy_and_one_readiness_check() {
+    let config = load_contents(
+        None,
+        Some((
+            "project.toml".into(),
+            "[[startup]]\nname = \"web\"\ncommand = [\"npm\", \"start\"]\nrestart = \"on-failure\"\nready = { port = 3000 }\n\n[[startup]]\nname = \"api\"\ncommand = [\"cargo\", \"run\"]\nready = { command = [\"curl\", \"-fs\", \"localhost:8080/health\"] }\n".to_owned(),
+        )),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        config.startup[0].restart,
+        crate::core::ServiceRestart::OnFailure
+    );
+    assert_eq!(config.startup[0].ready.as_ref().unwrap().port, Some(3000));
+    assert_eq!(
+        config.startup[1].restart,
+        crate::core::ServiceRestart::Never
+    );
+    assert_eq!(
+        config.startup[1].ready.as_ref().unwrap().command.as_deref(),
+        Some(
+            &[
+                "curl".to_owned(),
+                "-fs".to_owned(),
+                "localhost:8080/health".to_owned()
+            ][..]
+        )
+    );
+
+    for invalid in [
+        "restart = \"always\"\n",
+        "ready = { port = 0 }\n",
+        "ready = { command = [] }\n",
+        "ready = {}\n",
+        "ready = { port = 3000, command = [\"true\"] }\n",
+    ] {
+        let contents = format!("[[startup]]\nname = \"web\"\ncommand = [\"npm\"]\n{invalid}");
+        assert!(
+            matches!(
+                load_contents(None, Some(("project.toml".into(), contents)), None),
+                Err(ConfigError::Invalid { .. })
+            ),
+            "accepted {invalid:?}"
+        );
+    }
+}
+
+#[test]
+fn configured_providers_merge_by_name_with_the_innermost_layer_winning() {
+    let config = load_contents(
+        Some((
+            "user.toml".into(),
+            "[providers.aider]\nargs = [\"--no-auto-commits\"]\n\n[providers.goose]\nprogram = \"goose\"\nargs = [\"session\"]\n".to_owned(),
+        )),
+        Some((
+            "project.toml".into(),
+            "[providers.goose]\nprogram = \"/opt/goose/bin/goose\"\nenv = { GOOSE_MODE = \"approve\" }\nblocker_manifest = \".blackpepper/goose.toml\"\nintegration = \"hooks\"\n".to_owned(),
+        )),
+        None,
+    )
+    .unwrap();
+    let name = |name| crate::agent_status::ProviderName::new(name).unwrap();
+    let aider = &config.providers[&name("aider")];
+    assert_eq!(aider.program, None);
+    assert_eq!(aider.integration, ProviderIntegration::None);
+    let goose = &config.providers[&name("goose")];
+    assert_eq!(goose.program.as_deref(), Some("/opt/goose/bin/goose"));
+    assert!(goose.args.is_empty());
+    assert_eq!(goose.env["GOOSE_MODE"], "approve");
+    assert_eq!(
+        goose.blocker_manifest.as_deref(),
+        Some(Path::new(".blackpepper/goose.toml"))
+    );
+    assert_eq!(goose.integration, ProviderIntegration::Hooks);
+
+    for invalid in [
+        "[providers.codex]\n",
+        "[providers.Gemini]\n",
+        "[providers.gemini]\nprogram = \"\"\n",
+        "[providers.gemini]\nenv = { \"1BAD\" = \"x\" }\n",
+        "[providers.gemini]\nintegration = \"plugin\"\n",
+    ] {
+        assert!(
+            matches!(
+                load_contents(
+                    None,
+                    Some(("project.toml".into(), invalid.to_owned())),
+                    None
+                ),
+                Err(ConfigError::Invalid { .. })
+            ),
+            "accepted {invalid:?}"
+        );
+    }
+}
+
+#[test]
+fn service_dependencies_must_name_services_and_never_loop() {
+    let config = load_contents(
+        None,
+        Some((
+            "project.toml".into(),
+            "[[startup]]\nname = \"web\"\ncommand = [\"npm\"]\ndepends_on = [\"api\"]\n\n[[startup]]\nname = \"api\"\ncommand = [\"cargo\"]\ndepends_on = [\"db\"]\n\n[[startup]]\nname = \"db\"\ncommand = [\"postgres\"]\n".to_owned(),
+        )),
+        None,
+    )
+    .unwrap();
+    assert_eq!(config.startup[0].depends_on, ["api"]);
+    assert!(config.startup[2].depends_on.is_empty());
+
+    for (invalid, message) in [
+        (
+            "[[startup]]\nname = \"web\"\ncommand = [\"npm\"]\ndepends_on = [\"db\"]\n",
+            "startup web depends on db, which is not a [[startup]] name",
+        ),
+        (
+            "[[startup]]\nname = \"web\"\ncommand = [\"npm\"]\ndepends_on = [\"web\"]\n",
+            "startup depends_on forms a cycle: web -> web",
+        ),
+        (
+            "[[startup]]\nname = \"web\"\ncommand = [\"npm\"]\ndepends_on = [\"api\"]\n\n[[startup]]\nname = \"api\"\ncommand = [\"cargo\"]\ndepends_on = [\"db\"]\n\n[[startup]]\nname = \"db\"\ncommand = [\"postgres\"]\ndepends_on = [\"api\"]\n",
+            "startup depends_on forms a cycle: api -> db -> api",
+        ),
+    ] {
+        let error = load_contents(None, Some(("project.toml".into(), invalid.to_owned())), None)
+            .unwrap_err();
+        assert!(
+            matches!(&error, ConfigError::Invalid { message: found, .. } if found == message),
+            "{error:?}"
+        );
+    }
+}
+
+#[test]
+fn a_workspace_layout_is_a_kdl_file_inside_the_workspace() {
+    let config = load_contents(
+        None,
+        Some((
+            "project.toml".into(),
+            "[workspace]\nlayout = \".blackpepper/layout.kdl\"\n".to_owned(),
+        )),
+        Some((
+            "local.toml".into(),
+            "[workspace]\nenv = { A = \"1\" }\n".to_owned(),
+        )),
+    )
+    .unwrap();
+    assert_eq!(
+        config.workspace_layout.as_deref(),
+        Some(Path::new(".blackpepper/layout.kdl"))
+    );
+
+    // The user layer applies to every workspace, so it cannot name one.
+    let error = load_contents(
+        Some((
+            "user.toml".into(),
+            "[workspace]\nlayout = \"mine.kdl\"\n".to_owned(),
+        )),
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(&error, ConfigError::Invalid { path, message }
+            if path == Path::new("user.toml") && message.contains("workspace.layout")),
+        "{error}"
+    );
+
+    for invalid in [
+        "[workspace]\nlayout = \"\"\n",
+        "[workspace]\nlayout = \"../layout.kdl\"\n",
+        "[workspace]\nlayout = \"/etc/layout.kdl\"\n",
+        "[workspace]\nlayout = \"layout.toml\"\n",
+    ] {
+        assert!(
+            matches!(
+                load_contents(
+                    None,
+                    Some(("project.toml".into(), invalid.to_owned())),
+                    None
+                ),
+                Err(ConfigError::Invalid { .. })
+            ),
+            "accepted {invalid:?}"
+        );
+    }
+}
@@ -12,10 +12,12 @@ pub use ids::{AgentRunId, HostId, PaneId, RepositoryId, SessionId, WorkspaceId};
 pub use paths::{CorePaths, PathError};
 pub use protocol::{
     serve_json_lines, serve_json_lines_with_extension, AgentProcessObservation, AgentRunBinding,
-    FailureCode, HelperRequest, HelperResponse, HostAgentRun, HostAgentSnapshot, HostAgentUpdate,
-    HostPeriodicRefresh, HostServicePayload, ProtocolError, ProtocolExtension, ProtocolFailure,
-    PullRequestState, PullRequestSummary, RepositoryInspection, RequestOperation, ResponsePayload,
-    ResponseResult, WorkspaceOverview, WorktrunkMutationOutcome, PROTOCOL_VERSION,
+    DeclaredForward, FailureCode, HelperRequest, HelperResponse, HostAgentRun, HostAgentSnapshot,
+    HostAgentUpdate, HostBlockerState, HostPeriodicRefresh, HostServicePayload, OpenPullRequest,
+    ProtocolError, ProtocolExtension, ProtocolFailure, PullRequestState, PullRequestSummary,
+    RepositoryInspection, RequestOperation, ResponsePayload, ResponseResult, ServiceObservation,
+    ServiceProcess, ServiceRestart, WorkspaceOverview, WorktrunkMutationOutcome,
+    WorktrunkPruneItem, WorktrunkPruneReason, WorktrunkPruneResult, PROTOCOL_VERSION,
 };
 pub use records::{
     HostRecord, HostTransport, RegistrySnapshot, SessionBackend, SessionRecord, SessionState,
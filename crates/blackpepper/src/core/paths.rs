@@ -90,6 +90,40 @@ impl CorePaths {
         })
     }
 
+    pub fn forwards_path(&self) -> PathBuf {
+        self.forwards_path_for(client_channel())
+    }
+
+    fn forwards_path_for(&self, channel: ClientChannel) -> PathBuf {
+        // Tunnels belong to one client process, so their restart record is
+        // per channel: a development client must not race the installed one
+        // for the same local ports on the next start.
+        self.state_dir.join(match channel {
+            ClientChannel::Production => "forwards.json",
+            ClientChannel::Development => "forwards-dev.json",
+            ClientChannel::SourceWatch => "forwards-watch.json",
+        })
+    }
+
+    /// What the host's periodic refresh keeps between helper runs: parsed
+    /// workspace config and service readiness checks, one file per
+    /// workspace. Safe to lose.
+    pub fn service_checks_dir(&self) -> PathBuf {
+        self.runtime_dir.join("service-checks")
+    }
+
+    /// The latest blocker each running host watcher reported, one file per
+    /// agent run. A watcher removes its own file when it stops.
+    pub fn agent_blockers_dir(&self) -> PathBuf {
+        self.runtime_dir.join("agent-blockers")
+    }
+
+    /// Client ends of forwarded Unix sockets. The runtime tree keeps the
+    /// paths short enough for `sun_path`.
+    pub fn socket_forward_dir(&self) -> PathBuf {
+        self.runtime_dir.join("sockets")
+    }
+
     pub fn repository_lock_dir(&self) -> PathBuf {
         // These coordination locks must remain identical when one client has
         // XDG_RUNTIME_DIR (desktop) and another does not (SSH/browser). They
@@ -104,6 +138,7 @@ impl CorePaths {
     pub fn prepare(&self) -> Result<(), PathError> {
         create_private_dir(&self.state_dir)?;
         create_private_dir(&self.runtime_dir)?;
+        create_private_dir(&self.socket_forward_dir())?;
         create_private_dir(&self.state_dir.join("run"))?;
         create_private_dir(&self.repository_lock_dir())?;
         create_private_dir(&self.session_lock_dir())?;
@@ -279,6 +314,14 @@ mod tests {
         assert_ne!(event_paths[0], event_paths[1]);
         assert_ne!(event_paths[0], event_paths[2]);
         assert_ne!(event_paths[1], event_paths[2]);
+        let forward_paths = [
+            paths.forwards_path_for(ClientChannel::Production),
+            paths.forwards_path_for(ClientChannel::Development),
+            paths.forwards_path_for(ClientChannel::SourceWatch),
+        ];
+        assert_ne!(forward_paths[0], forward_paths[1]);
+        assert_ne!(forward_paths[0], forward_paths[2]);
+        assert_ne!(forward_paths[1], forward_paths[2]);
         let production_lock = SingletonLock::acquire(&production).unwrap();
         let development_lock = SingletonLock::acquire(&development).unwrap();
         let source_watch_lock = SingletonLock::acquire(&source_watch).unwrap();
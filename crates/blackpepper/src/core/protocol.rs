@@ -4,9 +4,11 @@ mod wire;
 
 pub use server::{serve_json_lines, serve_json_lines_with_extension, ProtocolExtension};
 pub use service_types::{
-    AgentProcessObservation, AgentRunBinding, HostAgentRun, HostAgentSnapshot, HostAgentUpdate,
-    HostPeriodicRefresh, HostServicePayload, PullRequestState, PullRequestSummary,
-    RepositoryInspection, WorkspaceOverview, WorktrunkMutationOutcome,
+    AgentProcessObservation, AgentRunBinding, DeclaredForward, HostAgentRun, HostAgentSnapshot,
+    HostAgentUpdate, HostBlockerState, HostPeriodicRefresh, HostServicePayload, OpenPullRequest,
+    PullRequestState, PullRequestSummary, RepositoryInspection, ServiceObservation, ServiceProcess,
+    ServiceRestart, WorkspaceOverview, WorktrunkMutationOutcome, WorktrunkPruneItem,
+    WorktrunkPruneReason, WorktrunkPruneResult,
 };
 pub use wire::ProtocolError;
 
@@ -54,6 +56,13 @@ pub enum RequestOperation {
         session_id: SessionId,
     },
     DiscoverPorts,
+    /// Whether one configured service passes its readiness check right now:
+    /// the port connect or `ready.command` periodic refresh runs, read from
+    /// the host's copy of the config.
+    ServiceReady {
+        workspace_id: WorkspaceId,
+        name: String,
+    },
     /// Read-only, host-scoped observations used by the client's periodic UI
     /// refresh. Keeping these observations in one transient helper invocation
     /// lets the client wait for the entire bounded operation off its render
@@ -110,6 +119,9 @@ pub enum RequestOperation {
         workspace_id: WorkspaceId,
         repository_path: String,
     },
+    ListPullRequests {
+        workspace_id: WorkspaceId,
+    },
     WorktrunkCreate {
         repository_path: String,
         branch: String,
@@ -126,6 +138,11 @@ pub enum RequestOperation {
         target_path: String,
         approval: Option<WorktrunkApprovalToken>,
     },
+    WorktrunkPruneMerged {
+        workspace_id: WorkspaceId,
+        repository_path: String,
+        approval: Option<WorktrunkApprovalToken>,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -1,7 +1,7 @@
 use crate::agent_status::{AgentExplain, AgentSnapshot, StoredAgentUpdate};
 use crate::ports::PortSnapshot;
 use crate::worktrunk::{
-    SwitchResult, WorktreeList, WorktrunkApprovalToken, WorktrunkProjectCommand,
+    CiState, SwitchResult, WorktreeList, WorktrunkApprovalToken, WorktrunkProjectCommand,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -30,6 +30,23 @@ pub struct HostAgentSnapshot {
     pub pane_id: Option<PaneId>,
     pub snapshot: AgentSnapshot,
     pub explain: AgentExplain,
+    /// Why the blocker watcher runs without the user's manifest for this
+    /// provider, such as a file that does not parse.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest_warning: Option<String>,
+    /// What the run's host watcher last saw on screen. Only a request for one
+    /// run's snapshot reads it; `None` when no watcher is running for the run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocker: Option<HostBlockerState>,
+}
+
+/// The latest blocker transition a host watcher emitted for its run.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HostBlockerState {
+    pub sequence: u64,
+    /// The matched rule, or `None` once the prompt has cleared.
+    pub rule_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -64,6 +81,66 @@ pub struct HostPeriodicRefresh {
     /// built against this field still reads a refresh from an older helper.
     #[serde(default)]
     pub overviews: BTreeMap<WorkspaceId, WorkspaceOverview>,
+    /// Configured `[[startup]]` services whose tab is open, per attached
+    /// workspace. A service with no tab is simply absent.
+    #[serde(default)]
+    pub services: BTreeMap<WorkspaceId, Vec<ServiceObservation>>,
+    /// `[[forward]]` declarations per attached workspace whose config parsed,
+    /// so an edited config takes effect without reattaching.
+    #[serde(default)]
+    pub declared_forwards: BTreeMap<WorkspaceId, Vec<DeclaredForward>>,
+}
+
+/// One configured service as the host last saw it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceObservation {
+    pub name: String,
+    pub process: ServiceProcess,
+    /// Whether the configured readiness check passed; `None` when the service
+    /// declares none, or its process is not running to be checked.
+    #[serde(default)]
+    pub ready: Option<bool>,
+    /// Carried from the host's copy of the config so the client can apply the
+    /// policy without reading it again.
+    #[serde(default)]
+    pub restart: ServiceRestart,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceProcess {
+    Live,
+    Exited { code: Option<i32> },
+}
+
+/// What happens when a service's process exits.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ServiceRestart {
+    #[default]
+    Never,
+    /// Relaunch after a non-zero exit, with backoff.
+    OnFailure,
+}
+
+/// A `[[forward]]` entry: a workspace port that should be forwarded whenever
+/// its listener is running.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeclaredForward {
+    pub port: u16,
+    /// Exact listener address, as `:forward <address>:<port>` takes it. Left
+    /// unset, the port alone must identify one listener.
+    #[serde(default)]
+    pub bind: Option<String>,
+    /// Exact client port to listen on instead of `port` or a free one. The
+    /// forward fails rather than moving when this port is taken.
+    #[serde(default)]
+    pub local_port: Option<u16>,
+    /// Forward as soon as the listener appears in the workspace's ports.
+    #[serde(default)]
+    pub auto: bool,
 }
 
 /// Repository and session context for one workspace, gathered host-side
@@ -87,6 +164,10 @@ pub struct WorkspaceOverview {
     pub active_tab: Option<u32>,
     #[serde(default)]
     pub tab_count: Option<u32>,
+    /// Worktrunk's CI verdict for the checked-out branch, where the forge
+    /// reports one.
+    #[serde(default)]
+    pub ci: Option<CiState>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -116,6 +197,19 @@ impl PullRequestState {
     }
 }
 
+/// One open pull request offered by the `:worktree prs` picker.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpenPullRequest {
+    pub number: u32,
+    pub title: String,
+    /// GitHub login; empty when `gh` reports no author (a deleted account).
+    pub author: String,
+    pub draft: bool,
+    /// Head branch, which becomes the worktree branch once opened.
+    pub branch: String,
+}
+
 /// Exact Zellij identity recorded after an agent tab and its terminal pane
 /// have both been observed. Rehydration must match every field before it can
 /// treat a process as the original run.
@@ -163,6 +257,36 @@ pub enum WorktrunkMutationOutcome {
     Switched { result: SwitchResult },
     Removed { path: PathBuf },
     SetupFailed { path: PathBuf, message: String },
+    Pruned { results: Vec<WorktrunkPruneResult> },
+}
+
+/// One registered worktree in a `:worktree prune-merged` plan.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorktrunkPruneItem {
+    pub workspace_id: WorkspaceId,
+    pub path: PathBuf,
+    pub branch: String,
+    pub reason: WorktrunkPruneReason,
+    pub command: String,
+}
+
+/// Why a clean branch no longer needs its worktree.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "kind")]
+pub enum WorktrunkPruneReason {
+    Merged { number: u32 },
+    UpstreamGone { upstream: String },
+}
+
+/// What happened to one planned removal. Later items still run after an
+/// earlier one fails.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorktrunkPruneResult {
+    pub workspace_id: WorkspaceId,
+    pub path: PathBuf,
+    pub error: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -174,6 +298,9 @@ pub enum HostServicePayload {
     PeriodicRefresh {
         refresh: Box<HostPeriodicRefresh>,
     },
+    ServiceReady {
+        ready: bool,
+    },
     RepositoryInspected {
         repository: Option<RepositoryInspection>,
     },
@@ -181,7 +308,7 @@ pub enum HostServicePayload {
         workspace: WorkspaceRecord,
     },
     AgentSnapshot {
-        snapshot: Option<HostAgentSnapshot>,
+        snapshot: Option<Box<HostAgentSnapshot>>,
     },
     AgentUpdates {
         updates: Vec<HostAgentUpdate>,
@@ -195,11 +322,20 @@ pub enum HostServicePayload {
     Worktrees {
         list: WorktreeList,
     },
+    PullRequests {
+        pull_requests: Vec<OpenPullRequest>,
+    },
     WorktrunkApprovalRequired {
         command: String,
         approval: WorktrunkApprovalToken,
         unapproved_project_commands: Vec<WorktrunkProjectCommand>,
     },
+    /// The whole prune plan under one approval token.
+    WorktrunkPruneApprovalRequired {
+        items: Vec<WorktrunkPruneItem>,
+        approval: WorktrunkApprovalToken,
+        unapproved_project_commands: Vec<WorktrunkProjectCommand>,
+    },
     WorktrunkMutation {
         outcome: WorktrunkMutationOutcome,
     },
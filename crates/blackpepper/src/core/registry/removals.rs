@@ -6,7 +6,7 @@ use std::{path::Path, str::FromStr, time::SystemTime};
 mod validation;
 use validation::{
     build_intent, removal_from_connection, row_to_removal, validate_current_intent,
-    validate_current_target, validation, workspace_from_connection,
+    validate_current_target, validation, workspace_from_connection, Survivor,
 };
 
 /// Durable evidence that a Worktrunk removal was dispatched but may not yet
@@ -15,7 +15,9 @@ use validation::{
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct WorktrunkRemovalIntent {
     pub(crate) workspace_id: WorkspaceId,
-    pub(crate) surviving_workspace_id: WorkspaceId,
+    /// `None` when Worktrunk runs from the repository's main worktree, which
+    /// need not be registered; `surviving_path` is then that worktree.
+    pub(crate) surviving_workspace_id: Option<WorkspaceId>,
     pub(crate) host_id: HostId,
     pub(crate) repository_id: RepositoryId,
     pub(crate) repository_key: String,
@@ -58,7 +60,39 @@ impl HostRegistry {
         build_intent(
             self.local_host_id()?,
             target,
-            surviving,
+            Survivor::Registered(surviving),
+            expected_target_path,
+            repository_key,
+        )
+    }
+
+    /// Like `plan_worktrunk_removal`, but Worktrunk runs from the main
+    /// worktree at `main_path`. The registry cannot vouch for an unregistered
+    /// folder, so the caller must have derived it from `repository_key`.
+    pub(crate) fn plan_worktrunk_removal_from_main(
+        &self,
+        workspace_id: WorkspaceId,
+        main_path: &str,
+        expected_target_path: &str,
+        repository_key: String,
+    ) -> Result<WorktrunkRemovalIntent, RegistryError> {
+        if !Path::new(&repository_key).is_absolute() {
+            return Err(validation(
+                "Worktrunk repository identity must be an absolute path.",
+            ));
+        }
+        if self.worktrunk_removal(workspace_id)?.is_some() {
+            return Err(validation(
+                "A previous Worktrunk removal has an unknown result; run :worktree list before trying again.",
+            ));
+        }
+        let target = self
+            .workspace(workspace_id)?
+            .ok_or_else(|| validation("The Worktrunk target workspace is not registered."))?;
+        build_intent(
+            self.local_host_id()?,
+            target,
+            Survivor::MainWorktree(main_path.to_owned()),
             expected_target_path,
             repository_key,
         )
@@ -86,7 +120,7 @@ impl HostRegistry {
              VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
             (
                 intent.workspace_id.to_string(),
-                intent.surviving_workspace_id.to_string(),
+                intent.surviving_workspace_id.map(|id| id.to_string()),
                 intent.host_id.to_string(),
                 intent.repository_id.to_string(),
                 &intent.repository_key,
@@ -4,14 +4,21 @@ use std::str::FromStr;
 
 use super::WorktrunkRemovalIntent;
 
+/// Where Worktrunk runs while it removes the target.
+pub(super) enum Survivor {
+    Registered(WorkspaceRecord),
+    /// The repository's main worktree, by path only.
+    MainWorktree(String),
+}
+
 pub(super) fn build_intent(
     local_host_id: HostId,
     target: WorkspaceRecord,
-    surviving: WorkspaceRecord,
+    surviving: Survivor,
     expected_target_path: &str,
     repository_key: String,
 ) -> Result<WorktrunkRemovalIntent, RegistryError> {
-    if target.host_id != local_host_id || surviving.host_id != local_host_id {
+    if target.host_id != local_host_id {
         return Err(validation(
             "Worktrunk removal workspaces must belong to this host.",
         ));
@@ -26,24 +33,44 @@ pub(super) fn build_intent(
         .as_ref()
         .ok_or_else(|| validation("Worktrunk target has no registered Git repository identity."))?
         .repository_id();
-    let surviving_repository = surviving
-        .repository
-        .as_ref()
-        .ok_or_else(|| validation("Surviving Worktrunk workspace has no Git repository identity."))?
-        .repository_id();
-    if target_repository != surviving_repository {
-        return Err(validation(
-            "Worktrunk target and surviving workspace have different repository identities.",
-        ));
-    }
+    let (surviving_workspace_id, surviving_path) = match surviving {
+        Survivor::Registered(surviving) => {
+            if surviving.host_id != local_host_id {
+                return Err(validation(
+                    "Worktrunk removal workspaces must belong to this host.",
+                ));
+            }
+            let surviving_repository = surviving
+                .repository
+                .as_ref()
+                .ok_or_else(|| {
+                    validation("Surviving Worktrunk workspace has no Git repository identity.")
+                })?
+                .repository_id();
+            if target_repository != surviving_repository {
+                return Err(validation(
+                    "Worktrunk target and surviving workspace have different repository identities.",
+                ));
+            }
+            (Some(surviving.id), surviving.root_path)
+        }
+        Survivor::MainWorktree(path) => {
+            if !std::path::Path::new(&path).is_absolute() || path == target.root_path {
+                return Err(validation(
+                    "Worktrunk removal requires a different, absolute main worktree.",
+                ));
+            }
+            (None, path)
+        }
+    };
     Ok(WorktrunkRemovalIntent {
         workspace_id: target.id,
-        surviving_workspace_id: surviving.id,
+        surviving_workspace_id,
         host_id: local_host_id,
         repository_id: target_repository,
         repository_key,
         target_path: target.root_path,
-        surviving_path: surviving.root_path,
+        surviving_path,
     })
 }
 
@@ -53,8 +80,14 @@ pub(super) fn validate_current_intent(
 ) -> Result<(), RegistryError> {
     let target = workspace_from_connection(connection, intent.workspace_id)?
         .ok_or_else(|| validation("The Worktrunk target workspace is no longer registered."))?;
-    let surviving = workspace_from_connection(connection, intent.surviving_workspace_id)?
-        .ok_or_else(|| validation("The surviving Worktrunk workspace is no longer registered."))?;
+    let surviving = match intent.surviving_workspace_id {
+        Some(id) => {
+            Survivor::Registered(workspace_from_connection(connection, id)?.ok_or_else(|| {
+                validation("The surviving Worktrunk workspace is no longer registered.")
+            })?)
+        }
+        None => Survivor::MainWorktree(intent.surviving_path.clone()),
+    };
     let current = build_intent(
         intent.host_id,
         target,
@@ -121,7 +154,10 @@ pub(super) fn removal_from_connection(
 pub(super) fn row_to_removal(row: &rusqlite::Row<'_>) -> rusqlite::Result<WorktrunkRemovalIntent> {
     Ok(WorktrunkRemovalIntent {
         workspace_id: parse_id(row.get::<_, String>(0)?, 0)?,
-        surviving_workspace_id: parse_id(row.get::<_, String>(1)?, 1)?,
+        surviving_workspace_id: row
+            .get::<_, Option<String>>(1)?
+            .map(|value| parse_id(value, 1))
+            .transpose()?,
         host_id: parse_id(row.get::<_, String>(2)?, 2)?,
         repository_id: parse_id(row.get::<_, String>(3)?, 3)?,
         repository_key: row.get(4)?,
@@ -3,7 +3,7 @@ use crate::core::{HostRecord, SessionRecord, WorkspaceRecord};
 use rusqlite::{types::Type, Connection, Row};
 use std::{error::Error, str::FromStr};
 
-const SCHEMA_VERSION: u32 = 5;
+const SCHEMA_VERSION: u32 = 6;
 
 pub(super) fn initialize_schema(connection: &Connection) -> Result<(), RegistryError> {
     connection.execute_batch("BEGIN IMMEDIATE")?;
@@ -68,7 +68,7 @@ fn initialize_schema_locked(connection: &Connection) -> Result<(), RegistryError
              );
              CREATE TABLE worktrunk_removal_intents (
                workspace_id TEXT PRIMARY KEY NOT NULL,
-               surviving_workspace_id TEXT NOT NULL,
+               surviving_workspace_id TEXT,
                host_id TEXT NOT NULL,
                repository_id TEXT NOT NULL,
                repository_key TEXT NOT NULL,
@@ -78,7 +78,7 @@ fn initialize_schema_locked(connection: &Connection) -> Result<(), RegistryError
              );
              CREATE INDEX worktrunk_removal_repository_key
                ON worktrunk_removal_intents(repository_key);
-             PRAGMA user_version = 5;",
+             PRAGMA user_version = 6;",
         )?;
     } else if version == 1 {
         connection.execute_batch(
@@ -103,7 +103,7 @@ fn initialize_schema_locked(connection: &Connection) -> Result<(), RegistryError
         connection.execute_batch(
             "CREATE TABLE worktrunk_removal_intents (
                workspace_id TEXT PRIMARY KEY NOT NULL,
-               surviving_workspace_id TEXT NOT NULL,
+               surviving_workspace_id TEXT,
                host_id TEXT NOT NULL,
                repository_id TEXT NOT NULL,
                repository_key TEXT NOT NULL,
@@ -113,7 +113,31 @@ fn initialize_schema_locked(connection: &Connection) -> Result<(), RegistryError
              );
              CREATE INDEX worktrunk_removal_repository_key
                ON worktrunk_removal_intents(repository_key);
-             PRAGMA user_version = 5;",
+             PRAGMA user_version = 6;",
+        )?;
+    } else if version == 5 {
+        // An unregistered main worktree can now be the survivor, so its
+        // workspace ID became optional. SQLite cannot relax NOT NULL in place.
+        connection.execute_batch(
+            "ALTER TABLE worktrunk_removal_intents RENAME TO worktrunk_removal_intents_v5;
+             CREATE TABLE worktrunk_removal_intents (
+               workspace_id TEXT PRIMARY KEY NOT NULL,
+               surviving_workspace_id TEXT,
+               host_id TEXT NOT NULL,
+               repository_id TEXT NOT NULL,
+               repository_key TEXT NOT NULL,
+               target_path TEXT NOT NULL,
+               surviving_path TEXT NOT NULL,
+               created_at_ms INTEGER NOT NULL
+             );
+             INSERT INTO worktrunk_removal_intents
+               SELECT workspace_id, surviving_workspace_id, host_id, repository_id,
+                      repository_key, target_path, surviving_path, created_at_ms
+               FROM worktrunk_removal_intents_v5;
+             DROP TABLE worktrunk_removal_intents_v5;
+             CREATE INDEX worktrunk_removal_repository_key
+               ON worktrunk_removal_intents(repository_key);
+             PRAGMA user_version = 6;",
         )?;
     }
     Ok(())
@@ -242,7 +266,7 @@ mod tests {
                 |row| row.get(0),
             )
             .unwrap();
-        assert_eq!(version, 5);
+        assert_eq!(version, 6);
         assert_eq!(default_value, "'unknown'");
         assert_eq!(setup_default, "'{\"status\":\"ready\"}'");
     }
@@ -265,7 +289,54 @@ mod tests {
                 |row| row.get(0),
             )
             .unwrap();
-        assert_eq!(version, 5);
+        assert_eq!(version, 6);
         assert_eq!(table, "worktrunk_removal_intents");
     }
+
+    #[test]
+    fn migrates_version_five_removal_markers_to_an_optional_survivor() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE worktrunk_removal_intents (
+                   workspace_id TEXT PRIMARY KEY NOT NULL,
+                   surviving_workspace_id TEXT NOT NULL,
+                   host_id TEXT NOT NULL,
+                   repository_id TEXT NOT NULL,
+                   repository_key TEXT NOT NULL,
+                   target_path TEXT NOT NULL,
+                   surviving_path TEXT NOT NULL,
+                   created_at_ms INTEGER NOT NULL
+                 );
+                 CREATE INDEX worktrunk_removal_repository_key
+                   ON worktrunk_removal_intents(repository_key);
+                 INSERT INTO worktrunk_removal_intents
+                   VALUES ('target', 'survivor', 'host', 'repo', '/srv/app/.git',
+                           '/srv/app-feature', '/srv/app', 1);
+                 PRAGMA user_version = 5;",
+            )
+            .unwrap();
+        initialize_schema(&connection).unwrap();
+
+        let version: u32 = connection
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        let survivor: String = connection
+            .query_row(
+                "SELECT surviving_workspace_id FROM worktrunk_removal_intents WHERE workspace_id = 'target'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        let required: bool = connection
+            .query_row(
+                "SELECT \"notnull\" FROM pragma_table_info('worktrunk_removal_intents') WHERE name = 'surviving_workspace_id'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(version, 6);
+        assert_eq!(survivor, "survivor");
+        assert!(!required);
+    }
 }
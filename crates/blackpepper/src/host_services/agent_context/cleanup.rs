@@ -58,7 +58,7 @@ impl AgentContextStore {
                 continue;
             };
             let file = match provider {
-                Provider::Codex => None,
+                Provider::Codex | Provider::Custom(_) => None,
                 Provider::Claude => Some(integration_dir.join(format!("claude-{run_id}.json"))),
                 Provider::OpenCode => Some(integration_dir.join(format!("opencode-{run_id}.js"))),
             };
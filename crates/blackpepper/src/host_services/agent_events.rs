@@ -2,13 +2,14 @@ pub(super) use super::agent_context::AgentRunContext;
 use super::agent_context::{AgentContextStore, StoredAgentRunContext};
 use crate::agent_status::{
     AgentEventDraft, AgentEventKind, AgentEventSource, AgentEventStore, IntegrationHealth,
-    NeedsInputCapability, Provider,
+    NeedsInputCapability, Provider, ProviderName,
 };
+use crate::client_config::ProviderIntegration;
 use crate::core::{
     AgentProcessObservation, AgentRunBinding, AgentRunId, CorePaths, HostAgentRun, HostAgentUpdate,
     HostRegistry, WorkspaceId,
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 mod host_run;
@@ -23,6 +24,7 @@ pub(super) struct HostAgentEvents {
     store: AgentEventStore,
     context: AgentContextStore,
     path: PathBuf,
+    registry_path: PathBuf,
 }
 
 impl HostAgentEvents {
@@ -34,6 +36,7 @@ impl HostAgentEvents {
             store,
             context,
             path,
+            registry_path: paths.registry_path(),
         })
     }
 
@@ -216,7 +219,7 @@ impl HostAgentEvents {
             .collect::<Vec<_>>();
         let updates = self
             .store
-            .append_transient_batch(&drafts, provider_capability(context.provider))
+            .append_transient_batch(&drafts, self.provider_capability(&context))
             .map_err(|error| error.to_string())?;
         if updates.iter().any(|update| {
             update.event.host_id != context.host_id
@@ -239,12 +242,42 @@ impl HostAgentEvents {
     fn active_context(&self, run_id: AgentRunId) -> Result<Option<AgentRunContext>, String> {
         self.context.active(run_id)
     }
-}
 
-fn provider_capability(provider: Provider) -> NeedsInputCapability {
-    match provider {
-        Provider::OpenCode => NeedsInputCapability::ProviderEvents,
-        Provider::Codex | Provider::Claude => NeedsInputCapability::ProviderEventsWithOverlay,
+    /// The capability a run's tracker starts with. A configured provider
+    /// reports its own state only through hooks; without them the screen
+    /// overlay is its only way to ask, and it is never reported idle. Its
+    /// config is read only until the run's first event.
+    fn provider_capability(&self, context: &AgentRunContext) -> NeedsInputCapability {
+        match context.provider {
+            Provider::OpenCode => NeedsInputCapability::ProviderEvents,
+            Provider::Codex | Provider::Claude => NeedsInputCapability::ProviderEventsWithOverlay,
+            Provider::Custom(name) => {
+                if let Ok(Some(snapshot)) = self.store.snapshot(context.run_id) {
+                    return snapshot.needs_input_capability;
+                }
+                if self.declares_hooks(context.workspace_id, name) {
+                    NeedsInputCapability::ProviderEventsWithOverlay
+                } else {
+                    NeedsInputCapability::BlockerOverlay
+                }
+            }
+        }
+    }
+
+    /// An unreadable registry or config counts as no hooks: the overlay is
+    /// the baseline every provider has.
+    fn declares_hooks(&self, workspace_id: WorkspaceId, name: ProviderName) -> bool {
+        let Ok(Some(workspace)) = HostRegistry::open(&self.registry_path)
+            .and_then(|registry| registry.workspace(workspace_id))
+        else {
+            return false;
+        };
+        crate::client_config::load(Path::new(&workspace.root_path)).is_ok_and(|config| {
+            config
+                .providers
+                .get(&name)
+                .is_some_and(|provider| provider.integration == ProviderIntegration::Hooks)
+        })
     }
 }
 
@@ -30,7 +30,7 @@ pub(super) fn cleanup_managed_asset(
     record: &StoredAgentRunContext,
 ) -> Result<(), String> {
     let name = match record.context.provider {
-        Provider::Codex => return Ok(()),
+        Provider::Codex | Provider::Custom(_) => return Ok(()),
         Provider::Claude => format!("claude-{}.json", record.context.run_id),
         Provider::OpenCode => format!("opencode-{}.js", record.context.run_id),
     };
@@ -1,4 +1,4 @@
-use super::{host_run_from, provider_capability, AgentRunContext, HostAgentEvents};
+use super::{host_run_from, AgentRunContext, HostAgentEvents};
 use crate::agent_status::{AgentEventDraft, AgentEventKind, AgentEventSource, AgentStatusTracker};
 use crate::core::{AgentRunId, HostAgentRun, HostAgentUpdate};
 
@@ -56,7 +56,7 @@ impl HostAgentEvents {
         };
         let update = self
             .store
-            .append_transient(draft, provider_capability(context.provider))
+            .append_transient(draft, self.provider_capability(&context))
             .map_err(|error| error.to_string())?;
         Ok(HostAgentUpdate {
             host_id: context.host_id,
@@ -78,7 +78,7 @@ impl HostAgentEvents {
                 AgentStatusTracker::new(
                     record.context.run_id,
                     record.context.provider,
-                    provider_capability(record.context.provider),
+                    self.provider_capability(&record.context),
                 )
                 .snapshot()
             });
@@ -43,6 +43,8 @@ impl HostAgentEvents {
                 pane_id: context.pane_id,
                 snapshot,
                 explain,
+                manifest_warning: None,
+                blocker: None,
             })),
             (None, None) => Ok(None),
             _ => Err("Agent status snapshot and diagnostics were inconsistent.".to_string()),
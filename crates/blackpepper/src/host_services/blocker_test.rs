@@ -0,0 +1,152 @@
+use super::blocker_watch::with_user_manifest;
+use crate::agent_status::{BlockerInput, BlockerOverlay, Provider, RuleDiagnosis, RuleOutcome};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockerTestArgs {
+    pub provider: Provider,
+    pub manifest: Option<PathBuf>,
+    pub terminal_title: Option<String>,
+    pub viewport: PathBuf,
+}
+
+impl BlockerTestArgs {
+    pub fn parse(arguments: impl IntoIterator<Item = String>) -> Option<Self> {
+        let mut provider = None;
+        let mut manifest = None;
+        let mut terminal_title = None;
+        let mut viewport = None;
+        let mut arguments = arguments.into_iter();
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "--provider" if provider.is_none() => provider = arguments.next()?.parse().ok(),
+                "--manifest" if manifest.is_none() => {
+                    manifest = arguments.next().map(PathBuf::from)
+                }
+                "--title" if terminal_title.is_none() => terminal_title = arguments.next(),
+                path if viewport.is_none() && !path.starts_with("--") => {
+                    viewport = Some(PathBuf::from(path))
+                }
+                _ => return None,
+            }
+        }
+        Some(Self {
+            provider: provider?,
+            manifest,
+            terminal_title,
+            viewport: viewport?,
+        })
+    }
+}
+
+/// Matches a captured screen offline and reports every rule that matched or
+/// nearly did. Nothing here touches Zellij, the registry, or a running agent.
+pub fn blocker_test(arguments: &BlockerTestArgs, mut writer: impl Write) -> Result<(), String> {
+    let overlay = test_overlay(arguments)?;
+    let viewport = std::fs::read_to_string(&arguments.viewport).map_err(|error| {
+        format!(
+            "Could not read the viewport {}: {error}",
+            arguments.viewport.display()
+        )
+    })?;
+    let input = BlockerInput {
+        viewport: &viewport,
+        terminal_title: arguments.terminal_title.as_deref(),
+    };
+    report(&overlay, input, &mut writer).map_err(|error| error.to_string())
+}
+
+/// The rules the live watcher would use, with `--manifest` standing in for
+/// the user manifest of an integrated provider.
+fn test_overlay(arguments: &BlockerTestArgs) -> Result<BlockerOverlay, String> {
+    let provider = arguments.provider;
+    let Some(path) = &arguments.manifest else {
+        if let Provider::Custom(name) = provider {
+            return Err(format!(
+                "{name} has no bundled manifest; pass --manifest with its [providers.{name}] blocker_manifest."
+            ));
+        }
+        let bundled = BlockerOverlay::bundled(provider).map_err(|error| error.to_string())?;
+        return with_user_manifest(bundled);
+    };
+    let input = read_manifest(path)?;
+    let overlay = match provider {
+        Provider::Custom(_) => BlockerOverlay::configured(provider, &input),
+        provider => {
+            BlockerOverlay::bundled(provider).and_then(|bundled| bundled.with_user_manifest(&input))
+        }
+    };
+    overlay.map_err(|error| format!("The manifest {} is invalid: {error}", path.display()))
+}
+
+fn read_manifest(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|error| format!("Could not read the manifest {}: {error}", path.display()))
+}
+
+fn report(
+    overlay: &BlockerOverlay,
+    input: BlockerInput<'_>,
+    writer: &mut impl Write,
+) -> std::io::Result<()> {
+    let provider = overlay.provider();
+    let winner = overlay.evaluate(input).map(|blocker| blocker.rule_id);
+    let diagnoses = overlay.diagnose(input);
+    match diagnoses
+        .iter()
+        .find(|diagnosis| Some(&diagnosis.rule_id) == winner.as_ref())
+    {
+        Some(matched) => writeln!(
+            writer,
+            "{provider}: rule {} matched ({})",
+            matched.rule_id,
+            describe(matched)
+        )?,
+        None => writeln!(writer, "{provider}: no rule matched")?,
+    }
+    let mut unmatched = 0;
+    for diagnosis in &diagnoses {
+        if Some(&diagnosis.rule_id) == winner.as_ref() {
+            continue;
+        }
+        let id = &diagnosis.rule_id;
+        let rule = describe(diagnosis);
+        match &diagnosis.outcome {
+            RuleOutcome::Matched => writeln!(writer, "  outranked {id} ({rule})")?,
+            RuleOutcome::NotContains(needle) => writeln!(
+                writer,
+                "  near miss {id} ({rule}): not_contains {needle:?} is present"
+            )?,
+            RuleOutcome::NotRegex(pattern) => writeln!(
+                writer,
+                "  near miss {id} ({rule}): not_regex {pattern:?} matched"
+            )?,
+            RuleOutcome::OutsideRegion => writeln!(
+                writer,
+                "  near miss {id} ({rule}): matches the viewport but not its {}",
+                diagnosis.region
+            )?,
+            RuleOutcome::Unmatched => unmatched += 1,
+        }
+    }
+    if unmatched > 0 {
+        writeln!(writer, "  {unmatched} other rule(s) did not match")?;
+    }
+    Ok(())
+}
+
+fn describe(diagnosis: &RuleDiagnosis) -> String {
+    format!(
+        "{}, {} confidence, priority {}, {} manifest {}",
+        diagnosis.region,
+        diagnosis.confidence.as_str(),
+        diagnosis.priority,
+        diagnosis.manifest,
+        diagnosis.manifest_version
+    )
+}
+
+#[cfg(test)]
+#[path = "blocker_test_tests.rs"]
+mod tests;
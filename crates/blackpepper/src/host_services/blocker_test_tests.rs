@@ -0,0 +1,125 @@
+use super::*;
+
+const CLAUDE_BLOCKED: &str = include_str!("../agent_status/fixtures/claude_blocked.txt");
+
+const AUTHORED: &str = r#"
+schema_version = 1
+provider = "claude"
+version = "4"
+
+[[blockers]]
+id = "bash_approval"
+confidence = "high"
+priority = 1200
+region = { kind = "top_lines", lines = 1 }
+contains_all = ["Do you want to proceed?"]
+
+[[blockers]]
+id = "amend_only"
+confidence = "medium"
+priority = 1100
+contains_all = ["Tab to amend"]
+not_contains = ["cargo test"]
+
+[[blockers]]
+id = "never_seen"
+confidence = "medium"
+contains_all = ["this text is not on screen"]
+"#;
+
+fn arguments(root: &Path, manifest: Option<&str>) -> BlockerTestArgs {
+    let viewport = root.join("viewport.txt");
+    std::fs::write(&viewport, CLAUDE_BLOCKED).unwrap();
+    let manifest = manifest.map(|contents| {
+        let path = root.join("rules.toml");
+        std::fs::write(&path, contents).unwrap();
+        path
+    });
+    BlockerTestArgs {
+        provider: Provider::Claude,
+        manifest,
+        terminal_title: None,
+        viewport,
+    }
+}
+
+#[test]
+fn reports_the_winning_rule_and_the_rules_that_narrowly_failed() {
+    let root = tempfile::tempdir().unwrap();
+    let mut output = Vec::new();
+
+    blocker_test(&arguments(root.path(), Some(AUTHORED)), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with(
+        "claude: rule permission_prompt matched (bottom_lines 18, high confidence, priority 900, bundled manifest "
+    ));
+    assert_eq!(
+        lines[1],
+        "  near miss bash_approval (top_lines 1, high confidence, priority 1200, user manifest 4): matches the viewport but not its top_lines 1"
+    );
+    assert_eq!(
+        lines[2],
+        "  near miss amend_only (viewport, medium confidence, priority 1100, user manifest 4): not_contains \"cargo test\" is present"
+    );
+    assert!(lines
+        .last()
+        .unwrap()
+        .ends_with("other rule(s) did not match"));
+    assert!(!output.contains("never_seen"));
+}
+
+#[test]
+fn a_lower_priority_match_is_reported_as_outranked() {
+    let root = tempfile::tempdir().unwrap();
+    let authored = AUTHORED.replace("top_lines\", lines = 1", "bottom_lines\", lines = 6");
+    let mut output = Vec::new();
+
+    blocker_test(&arguments(root.path(), Some(&authored)), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("claude: rule bash_approval matched (bottom_lines 6, high"));
+    assert!(output.contains("\n  outranked permission_prompt (bottom_lines 18,"));
+}
+
+#[test]
+fn manifests_are_validated_and_configured_providers_need_one() {
+    let root = tempfile::tempdir().unwrap();
+    let invalid = AUTHORED.replace("\"claude\"", "\"codex\"");
+    let error = blocker_test(&arguments(root.path(), Some(&invalid)), Vec::new()).unwrap_err();
+    assert!(error.contains("expected claude blocker manifest, found codex"));
+
+    let mut custom = arguments(root.path(), None);
+    custom.provider = "gemini".parse().unwrap();
+    let error = blocker_test(&custom, Vec::new()).unwrap_err();
+    assert!(error.contains("pass --manifest"));
+}
+
+#[test]
+fn arguments_need_a_provider_and_one_viewport() {
+    let parse = |arguments: &[&str]| {
+        BlockerTestArgs::parse(arguments.iter().map(|argument| (*argument).to_owned()))
+    };
+    assert_eq!(
+        parse(&[
+            "--provider",
+            "claude",
+            "--manifest",
+            "rules.toml",
+            "--title",
+            "Action Required",
+            "screen.txt",
+        ]),
+        Some(BlockerTestArgs {
+            provider: Provider::Claude,
+            manifest: Some(PathBuf::from("rules.toml")),
+            terminal_title: Some("Action Required".to_owned()),
+            viewport: PathBuf::from("screen.txt"),
+        })
+    );
+    assert_eq!(parse(&["screen.txt"]), None);
+    assert_eq!(parse(&["--provider", "claude"]), None);
+    assert_eq!(parse(&["--provider", "claude", "a.txt", "b.txt"]), None);
+    assert_eq!(parse(&["--provider", "claude", "--verbose", "a.txt"]), None);
+}
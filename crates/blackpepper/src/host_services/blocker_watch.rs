@@ -1,17 +1,21 @@
 use super::agent_events::HostAgentEvents;
 use super::tool_runtime::{discover_zellij_binary, validate_exact_binary};
-use crate::agent_status::{IntegrationHealth, Provider};
-use crate::core::{AgentRunId, CorePaths, HostRegistry, PaneId, WorkspaceId};
+use crate::agent_status::{BlockerOverlay, IntegrationHealth, Provider, ProviderName};
+use crate::core::{AgentRunId, CorePaths, HostBlockerState, HostRegistry, PaneId, WorkspaceId};
 use crate::status_monitor::{
     run_host_local_subscription_cancellable_with_health, run_host_local_subscription_fallible,
-    HostSubscriptionError, MonitorContext, ViewportBlockerMonitor,
+    BlockerChange, HostSubscriptionError, MonitorContext, ViewportBlockerMonitor,
 };
 use crate::zellij::ZellijRuntime;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::Receiver;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+mod latest;
+pub(super) use latest::latest_blocker;
+use latest::LatestBlocker;
+
 const HEALTH_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -71,6 +75,94 @@ impl BlockerWatchArgs {
     }
 }
 
+/// A configured provider's manifest comes from the same workspace config the
+/// client launched it with. Without one its screen never reports a blocker.
+fn configured_overlay(
+    registry: &HostRegistry,
+    workspace_id: WorkspaceId,
+    name: ProviderName,
+) -> Result<BlockerOverlay, String> {
+    let workspace = registry
+        .workspace(workspace_id)
+        .map_err(|error| error.to_string())?
+        .ok_or_else(|| "The agent's workspace is no longer registered.".to_owned())?;
+    let root = Path::new(&workspace.root_path);
+    let config = crate::client_config::load(root).map_err(|error| error.to_string())?;
+    let definition = config
+        .providers
+        .get(&name)
+        .ok_or_else(|| format!("No [providers.{name}] entry is configured for this workspace."))?;
+    let provider = Provider::Custom(name);
+    let Some(manifest) = &definition.blocker_manifest else {
+        return Ok(BlockerOverlay::without_rules(provider));
+    };
+    let manifest = root.join(manifest);
+    let input = std::fs::read_to_string(&manifest).map_err(|error| {
+        format!(
+            "Could not read the {name} blocker manifest {}: {error}",
+            manifest.display()
+        )
+    })?;
+    BlockerOverlay::configured(provider, &input).map_err(|error| {
+        format!(
+            "The {name} blocker manifest {} is invalid: {error}",
+            manifest.display()
+        )
+    })
+}
+
+/// Where the host looks for a user's override of a provider's manifest,
+/// under the same config home as the user config.
+pub(crate) fn user_manifest_path(provider: Provider) -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|home| !home.to_string_lossy().trim().is_empty())
+        .map(PathBuf::from)
+        .or_else(dirs::config_dir)?;
+    Some(
+        config_home
+            .join("blackpepper")
+            .join("agent-status")
+            .join(format!("{provider}.toml")),
+    )
+}
+
+/// Layers the user's manifest for this provider, when there is one, over the
+/// bundled or configured rules it extends.
+pub(super) fn with_user_manifest(mut overlay: BlockerOverlay) -> Result<BlockerOverlay, String> {
+    add_user_manifest(&mut overlay)?;
+    Ok(overlay)
+}
+
+/// [`with_user_manifest`] in place: a user manifest that does not load
+/// leaves `overlay` with its own rules.
+fn add_user_manifest(overlay: &mut BlockerOverlay) -> Result<(), String> {
+    let Some(path) = user_manifest_path(overlay.provider()) else {
+        return Ok(());
+    };
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => {
+            return Err(format!(
+                "Could not read the user blocker manifest {}: {error}",
+                path.display()
+            ))
+        }
+    };
+    overlay.add_user_manifest(&input).map_err(|error| {
+        format!(
+            "The user blocker manifest {} is invalid: {error}",
+            path.display()
+        )
+    })
+}
+
+/// Why a watcher for `provider` would run without the user's manifest.
+/// The manifest only adds rules, so it is checked on its own.
+pub(super) fn user_manifest_warning(provider: Provider) -> Option<String> {
+    add_user_manifest(&mut BlockerOverlay::without_rules(provider)).err()
+}
+
 fn valid_version(value: &str) -> bool {
     !value.is_empty()
         && value.len() <= 64
@@ -102,14 +194,14 @@ pub fn watch_blockers_cancellable(
     cancelled: Receiver<()>,
 ) -> Result<(), String> {
     let binary = discover_zellij_binary(&arguments.zellij_version)?;
-    let (monitor, runtime) = prepare_monitor(paths, registry, arguments, &binary)?;
+    let (monitor, runtime, latest) = prepare_monitor(paths, registry, arguments, &binary)?;
     let mut events = HostAgentEvents::open(paths)?;
     run_host_local_subscription_cancellable_with_health(
         &runtime,
         &arguments.session,
         monitor,
         now_millis,
-        transition_writer(writer),
+        transition_writer(writer, latest),
         cancelled,
         || {
             events
@@ -131,13 +223,13 @@ fn watch_blockers_with_binary(
     mut writer: impl Write,
     binary: &Path,
 ) -> Result<(), String> {
-    let (mut monitor, runtime) = prepare_monitor(paths, registry, arguments, binary)?;
+    let (mut monitor, runtime, latest) = prepare_monitor(paths, registry, arguments, binary)?;
     let result = run_host_local_subscription_fallible(
         &runtime,
         &arguments.session,
         &mut monitor,
         now_millis,
-        transition_writer(&mut writer),
+        transition_writer(&mut writer, latest),
     );
     match result {
         Ok(_) => Ok(()),
@@ -153,7 +245,7 @@ fn prepare_monitor(
     registry: &HostRegistry,
     arguments: &BlockerWatchArgs,
     binary: &Path,
-) -> Result<(ViewportBlockerMonitor, ZellijRuntime), String> {
+) -> Result<(ViewportBlockerMonitor, ZellijRuntime, LatestBlocker), String> {
     let host_id = registry
         .local_host_id()
         .map_err(|error| error.to_string())?;
@@ -181,12 +273,19 @@ fn prepare_monitor(
         provider: arguments.provider,
         integration_health,
     };
-    let monitor = ViewportBlockerMonitor::bundled_after(
+    let mut overlay = match arguments.provider {
+        Provider::Custom(name) => configured_overlay(registry, arguments.workspace_id, name)?,
+        provider => BlockerOverlay::bundled(provider).map_err(|error| error.to_string())?,
+    };
+    // A broken user manifest must not take the base rules down with it;
+    // `:status explain` reports it from the run's snapshot instead.
+    let _ = add_user_manifest(&mut overlay);
+    let monitor = ViewportBlockerMonitor::with_overlay_after(
         monitor_context,
         &arguments.zellij_pane_id,
+        overlay,
         arguments.after_sequence,
-    )
-    .map_err(|error| error.to_string())?;
+    );
     let binary = validate_exact_binary(binary.to_path_buf(), "Zellij", &arguments.zellij_version)?;
     let binary = binary
         .to_str()
@@ -197,13 +296,26 @@ fn prepare_monitor(
     let (runtime, _) = runtime
         .resolve_session_namespace(&mut transport, &arguments.session)
         .map_err(|error| error.to_string())?;
-    Ok((monitor, runtime))
+    let latest = LatestBlocker::claim(paths, arguments.run_id, arguments.after_sequence)?;
+    Ok((monitor, runtime, latest))
 }
 
+/// Records each transition on the host before the client can act on it.
 fn transition_writer(
     mut writer: impl Write,
+    latest: LatestBlocker,
 ) -> impl FnMut(crate::status_monitor::BlockerTransition) -> io::Result<()> {
     move |transition| {
+        let rule_id = match &transition.state {
+            BlockerChange::NeedsInput { rule_id, .. } => Some(rule_id.clone()),
+            BlockerChange::Cleared => None,
+        };
+        latest
+            .record(HostBlockerState {
+                sequence: transition.sequence,
+                rule_id,
+            })
+            .map_err(io::Error::other)?;
         serde_json::to_writer(&mut writer, &transition).map_err(io::Error::other)?;
         writer.write_all(b"\n")?;
         writer.flush()
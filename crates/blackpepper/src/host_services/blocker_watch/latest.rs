@@ -0,0 +1,118 @@
+use crate::core::{AgentRunId, CorePaths, HostBlockerState};
+use crate::providers::runtime::write_private_atomic;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize, Serialize)]
+struct Record {
+    watcher: uuid::Uuid,
+    pid: u32,
+    state: HostBlockerState,
+}
+
+/// One watcher's record of what it last emitted for its run, so a write into
+/// the pane is checked against the host's screen rather than the client's
+/// copy of it. Dropping the watcher's claim removes the record unless a newer
+/// watcher for the same run has replaced it; a watcher that dies without
+/// dropping it leaves a record whose process is gone, which readers ignore.
+pub(super) struct LatestBlocker {
+    path: PathBuf,
+    watcher: uuid::Uuid,
+    pid: u32,
+}
+
+impl LatestBlocker {
+    /// Start a run's record with no blocker at the sequence the watcher
+    /// resumes after.
+    pub(super) fn claim(
+        paths: &CorePaths,
+        run_id: AgentRunId,
+        sequence: u64,
+    ) -> Result<Self, String> {
+        let latest = Self {
+            path: record_path(paths, run_id),
+            watcher: uuid::Uuid::new_v4(),
+            pid: std::process::id(),
+        };
+        latest.record(HostBlockerState {
+            sequence,
+            rule_id: None,
+        })?;
+        Ok(latest)
+    }
+
+    pub(super) fn record(&self, state: HostBlockerState) -> Result<(), String> {
+        let record = Record {
+            watcher: self.watcher,
+            pid: self.pid,
+            state,
+        };
+        let contents = serde_json::to_vec(&record).map_err(|error| error.to_string())?;
+        write_private_atomic(&self.path, &contents)
+    }
+}
+
+impl Drop for LatestBlocker {
+    fn drop(&mut self) {
+        if read(&self.path).is_some_and(|record| record.watcher == self.watcher) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// The run's latest blocker, when a watcher is running for it.
+pub(in crate::host_services) fn latest_blocker(
+    paths: &CorePaths,
+    run_id: AgentRunId,
+) -> Option<HostBlockerState> {
+    read(&record_path(paths, run_id))
+        .filter(|record| process_exists(record.pid))
+        .map(|record| record.state)
+}
+
+/// Whether the watcher that wrote a record still runs. `EPERM` means the
+/// process exists but belongs to someone else.
+fn process_exists(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // SAFETY: signal 0 only checks that the process exists.
+    let signalled = unsafe { libc::kill(pid, 0) } == 0;
+    signalled || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+fn read(path: &Path) -> Option<Record> {
+    serde_json::from_slice(&std::fs::read(path).ok()?).ok()
+}
+
+fn record_path(paths: &CorePaths, run_id: AgentRunId) -> PathBuf {
+    paths.agent_blockers_dir().join(format!("{run_id}.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_killed_watchers_blocker_is_not_reported() {
+        let root = tempfile::tempdir().unwrap();
+        let paths = CorePaths::from_roots(root.path().join("state"), root.path().join("run"));
+        let run_id = AgentRunId::new();
+        let mut exited = std::process::Command::new("true").spawn().unwrap();
+        let dead_pid = exited.id();
+        exited.wait().unwrap();
+
+        let mut watcher = LatestBlocker::claim(&paths, run_id, 0).unwrap();
+        watcher.pid = dead_pid;
+        watcher
+            .record(HostBlockerState {
+                sequence: 1,
+                rule_id: Some("approval".to_owned()),
+            })
+            .unwrap();
+        // A signal skips the drop that would have removed the record.
+        std::mem::forget(watcher);
+
+        assert_eq!(latest_blocker(&paths, run_id), None);
+    }
+}
@@ -8,6 +8,7 @@ use crate::core::{HostRegistry, WorkspaceRecord};
 fn host_watcher_emits_redacted_transitions_only() {
     use std::os::unix::fs::PermissionsExt;
 
+    let _guard = crate::test_utils::env_lock();
     let root = tempfile::tempdir().unwrap();
     let paths = CorePaths::from_roots(root.path().join("state"), root.path().join("run"));
     paths.prepare().unwrap();
@@ -63,7 +64,72 @@ fn host_watcher_emits_redacted_transitions_only() {
     assert_eq!(transition.sequence, 9);
     assert_eq!(transition.run_id, arguments.run_id);
     assert!(!output.contains("sensitive-command-text"));
+    // The host's record lives only as long as the watcher that wrote it.
+    assert_eq!(latest_blocker(&paths, arguments.run_id), None);
     assert!(!output.contains("\"viewport\":"));
+    assert_eq!(
+        transition.manifest,
+        crate::agent_status::BlockerManifestSource::Bundled
+    );
+
+    // A user manifest for the provider is layered over the bundled rules.
+    let user_config = root.path().join("config");
+    std::fs::create_dir_all(user_config.join("blackpepper").join("agent-status")).unwrap();
+    std::env::set_var("XDG_CONFIG_HOME", &user_config);
+    let manifest = user_manifest_path(Provider::Codex).unwrap();
+    assert!(manifest.starts_with(&user_config));
+    std::fs::write(
+        &manifest,
+        "schema_version = 1\nprovider = \"codex\"\nversion = \"3\"\n\n[[blockers]]\nid = \"local_rule\"\nconfidence = \"high\"\npriority = 5000\nregex_any = [\".\"]\n",
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    let overridden =
+        watch_blockers_with_binary(&paths, &registry, &arguments, &mut output, &binary);
+    // An invalid one leaves the bundled rules watching and becomes a warning.
+    std::fs::write(&manifest, "schema_version = 1\nprovider = \"claude\"\n").unwrap();
+    let mut fallback = Vec::new();
+    let invalid = watch_blockers_with_binary(&paths, &registry, &arguments, &mut fallback, &binary);
+    let warning = user_manifest_warning(Provider::Codex);
+    std::env::remove_var("XDG_CONFIG_HOME");
+    overridden.unwrap();
+    let transition: crate::status_monitor::BlockerTransition =
+        serde_json::from_slice(output.trim_ascii()).unwrap();
+    assert_eq!(
+        transition.manifest,
+        crate::agent_status::BlockerManifestSource::User
+    );
+    assert_eq!(transition.manifest_version, "3");
+    invalid.unwrap();
+    let transition: crate::status_monitor::BlockerTransition =
+        serde_json::from_slice(fallback.trim_ascii()).unwrap();
+    assert_eq!(
+        transition.manifest,
+        crate::agent_status::BlockerManifestSource::Bundled
+    );
+    assert!(warning.unwrap().starts_with("The user blocker manifest"));
+    assert_eq!(user_manifest_warning(Provider::Codex), None);
+}
+
+#[test]
+fn a_stopped_watcher_leaves_a_newer_watchers_blocker_in_place() {
+    let root = tempfile::tempdir().unwrap();
+    let paths = CorePaths::from_roots(root.path().join("state"), root.path().join("run"));
+    let run_id = AgentRunId::new();
+    let asking = |sequence| HostBlockerState {
+        sequence,
+        rule_id: Some("approval".to_owned()),
+    };
+
+    let first = LatestBlocker::claim(&paths, run_id, 0).unwrap();
+    first.record(asking(1)).unwrap();
+    assert_eq!(latest_blocker(&paths, run_id), Some(asking(1)));
+    let second = LatestBlocker::claim(&paths, run_id, 1).unwrap();
+    second.record(asking(2)).unwrap();
+    drop(first);
+    assert_eq!(latest_blocker(&paths, run_id), Some(asking(2)));
+    drop(second);
+    assert_eq!(latest_blocker(&paths, run_id), None);
 }
 
 #[test]
@@ -2,6 +2,7 @@
 
 mod agent_context;
 mod agent_events;
+mod blocker_test;
 mod blocker_watch;
 mod periodic;
 mod ports;
@@ -15,6 +16,7 @@ mod worktrunk_approval;
 mod worktrunk_exec;
 mod worktrunk_lock;
 
+pub use blocker_test::{blocker_test, BlockerTestArgs};
 pub use blocker_watch::{watch_blockers, watch_blockers_cancellable, BlockerWatchArgs};
 pub use provider_hook::{record_provider_hook, ProviderHookArgs};
 pub use session_lease::{hold_session_lease, SessionLeaseArgs, SESSION_LEASE_READY};
@@ -24,6 +26,7 @@ use crate::core::{
     RequestOperation, ResponsePayload, ResponseResult,
 };
 use agent_events::{AgentRunContext, HostAgentEvents};
+use std::path::Path;
 use worktrunk_exec::WorktrunkExecutor;
 
 pub struct HostServices {
@@ -52,15 +55,19 @@ impl ProtocolExtension for HostServices {
     fn execute(&mut self, registry: &HostRegistry, operation: RequestOperation) -> ResponseResult {
         let result = match operation {
             RequestOperation::DiscoverPorts => Ok(HostServicePayload::Ports {
-                snapshot: ports::discover(registry),
+                snapshot: ports::discover(registry, true),
             }),
+            RequestOperation::ServiceReady { workspace_id, name } => {
+                local_workspace_root(registry, workspace_id)
+                    .and_then(|root_path| periodic::service_ready(Path::new(&root_path), &name))
+                    .map(|ready| HostServicePayload::ServiceReady { ready })
+            }
             RequestOperation::PeriodicRefresh {
                 attached_workspaces,
-            } => periodic::refresh(&self.paths, registry, attached_workspaces).map(|refresh| {
-                HostServicePayload::PeriodicRefresh {
+            } => periodic::refresh(&self.paths, &self.worktrunk, registry, attached_workspaces)
+                .map(|refresh| HostServicePayload::PeriodicRefresh {
                     refresh: Box::new(refresh),
-                }
-            }),
+                }),
             RequestOperation::InspectRepository { root_path } => {
                 repository::inspect(registry, &root_path)
                     .map(|repository| HostServicePayload::RepositoryInspected { repository })
@@ -73,7 +80,14 @@ impl ProtocolExtension for HostServices {
             RequestOperation::AgentSnapshot { run_id } => self
                 .agent_events()
                 .and_then(|mut events| events.snapshot(run_id))
-                .map(|snapshot| HostServicePayload::AgentSnapshot { snapshot }),
+                .map(|snapshot| HostServicePayload::AgentSnapshot {
+                    snapshot: snapshot.map(|mut snapshot| {
+                        snapshot.manifest_warning =
+                            blocker_watch::user_manifest_warning(snapshot.snapshot.provider);
+                        snapshot.blocker = blocker_watch::latest_blocker(&self.paths, run_id);
+                        Box::new(snapshot)
+                    }),
+                }),
             RequestOperation::AgentFollow {
                 run_id,
                 after_sequence,
@@ -156,6 +170,11 @@ impl ProtocolExtension for HostServices {
             } => self
                 .worktrunk
                 .list(registry, workspace_id, &repository_path),
+            RequestOperation::ListPullRequests { workspace_id } => {
+                local_workspace_root(registry, workspace_id)
+                    .and_then(|root_path| repo_status::open_pull_requests(&root_path))
+                    .map(|pull_requests| HostServicePayload::PullRequests { pull_requests })
+            }
             RequestOperation::WorktrunkCreate {
                 repository_path,
                 branch,
@@ -181,6 +200,16 @@ impl ProtocolExtension for HostServices {
             } => self
                 .worktrunk
                 .remove(registry, workspace_id, &target_path, approval.as_ref()),
+            RequestOperation::WorktrunkPruneMerged {
+                workspace_id,
+                repository_path,
+                approval,
+            } => self.worktrunk.prune_merged(
+                registry,
+                workspace_id,
+                &repository_path,
+                approval.as_ref(),
+            ),
             _ => {
                 return ResponseResult::Error {
                     error: ProtocolFailure {
@@ -255,5 +284,22 @@ fn validate_agent_workspace(
     Ok(())
 }
 
+fn local_workspace_root(
+    registry: &HostRegistry,
+    workspace_id: crate::core::WorkspaceId,
+) -> Result<String, String> {
+    let host_id = registry
+        .local_host_id()
+        .map_err(|error| error.to_string())?;
+    let workspace = registry
+        .workspace(workspace_id)
+        .map_err(|error| error.to_string())?
+        .ok_or_else(|| "The selected workspace is not registered.".to_owned())?;
+    if workspace.host_id != host_id {
+        return Err("The selected workspace belongs to another host.".to_owned());
+    }
+    Ok(workspace.root_path)
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,16 +1,22 @@
 use super::agent_events::{AgentRunContext, HostAgentEvents};
+use super::worktrunk_exec::WorktrunkExecutor;
 use crate::core::{
     AgentProcessObservation, CorePaths, HostAgentRun, HostPeriodicRefresh, HostRegistry,
     WorkspaceId,
 };
+use service_state::ServiceState;
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
 
 mod observation;
+mod service_state;
+mod services;
 
 const MAX_ATTACHED_WORKSPACES: usize = 256;
 
 pub(super) fn refresh(
     paths: &CorePaths,
+    worktrunk: &WorktrunkExecutor,
     registry: &HostRegistry,
     attached_workspaces: Vec<WorkspaceId>,
 ) -> Result<HostPeriodicRefresh, String> {
@@ -19,15 +25,46 @@ pub(super) fn refresh(
     let host_id = registry
         .local_host_id()
         .map_err(|error| error.to_string())?;
-    let ports = super::ports::discover(registry);
+    let ports = super::ports::discover(registry, false);
     let mut events = HostAgentEvents::open(paths)?;
     let listed_runs = events.list_runs(None)?;
-    let groups = observation::session_groups(&registry_snapshot, listed_runs, &attached_workspaces);
+    // The host reads the same layered config the client does, so service
+    // policy is observed where the service runs. A config that does not parse
+    // reports no services; starting one already surfaces that error.
+    let state_dir = paths.service_checks_dir();
+    let mut service_state = ServiceState::load(&state_dir, &attached_workspaces);
+    let now_ms = service_state::now_millis();
+    let configs = attached_workspaces
+        .iter()
+        .filter_map(|workspace_id| {
+            let record = registry_snapshot
+                .workspaces
+                .iter()
+                .find(|record| record.id == *workspace_id)?;
+            let config =
+                service_state.config(*workspace_id, Path::new(&record.root_path), now_ms)?;
+            Some((*workspace_id, config))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let service_names = configs
+        .iter()
+        .map(|(workspace_id, config)| {
+            let names = config.startup.iter().map(|service| service.name.clone());
+            (*workspace_id, names.collect())
+        })
+        .collect();
+    let groups = observation::session_groups(
+        &registry_snapshot,
+        listed_runs,
+        &attached_workspaces,
+        &service_names,
+    );
 
     let mut observations = Vec::new();
     let mut connected_clients = BTreeMap::new();
     let mut client_count_errors = BTreeMap::new();
     let mut session_tabs = BTreeMap::new();
+    let mut service_panes = BTreeMap::<WorkspaceId, Vec<_>>::new();
     let handles = groups
         .into_values()
         .map(|group| std::thread::spawn(move || observation::observe_session(group)))
@@ -39,11 +76,22 @@ pub(super) fn refresh(
                 connected_clients.extend(observed.clients);
                 client_count_errors.extend(observed.client_errors);
                 session_tabs.extend(observed.tabs);
+                for (workspace_id, name, state) in observed.services {
+                    service_panes
+                        .entry(workspace_id)
+                        .or_default()
+                        .push((name, state));
+                }
             }
             Err(_) => return Err("A Zellij observation worker panicked.".to_owned()),
         }
     }
     let mut overviews = BTreeMap::new();
+    let mut services = BTreeMap::new();
+    let declared_forwards = configs
+        .iter()
+        .map(|(workspace_id, config)| (*workspace_id, config.forwards.clone()))
+        .collect();
     for workspace_id in attached_workspaces {
         if !client_count_errors.contains_key(&workspace_id) {
             connected_clients.entry(workspace_id).or_insert(0);
@@ -58,13 +106,33 @@ pub(super) fn refresh(
             continue;
         };
         let mut overview = super::repo_status::overview(&record.root_path);
+        overview.ci = overview
+            .head
+            .as_deref()
+            .filter(|head| *head != "detached")
+            .and_then(|head| worktrunk.ci_state(&record.root_path, head));
         if let Some((active, count)) = session_tabs.get(&workspace_id).copied() {
             overview.active_tab = Some(active);
             overview.tab_count = Some(count);
         }
         overviews.insert(workspace_id, overview);
+        if let Some(config) = configs.get(&workspace_id) {
+            let observed = services::observe(
+                Path::new(&record.root_path),
+                &config.startup,
+                &config.env,
+                service_panes.remove(&workspace_id).unwrap_or_default(),
+                service_state.checks(workspace_id),
+                now_ms,
+            );
+            if !observed.is_empty() {
+                services.insert(workspace_id, observed);
+            }
+        }
     }
 
+    service_state.save(&state_dir);
+
     let mut agent_runs = Vec::new();
     let mut agent_snapshots = BTreeMap::new();
     let mut agent_observation_errors = BTreeMap::new();
@@ -139,9 +207,23 @@ pub(super) fn refresh(
         client_count_errors,
         errors,
         overviews,
+        services,
+        declared_forwards,
     })
 }
 
+/// Check one configured service now, for a dependent waiting to start. The
+/// config is read fresh: the dependent was just launched from it.
+pub(super) fn service_ready(root: &Path, name: &str) -> Result<bool, String> {
+    let config = crate::client_config::load(root).map_err(|error| error.to_string())?;
+    let service = config
+        .startup
+        .iter()
+        .find(|service| service.name == name)
+        .ok_or_else(|| format!("No configured service is named '{name}'."))?;
+    Ok(services::ready_now(root, service, &config.workspace_env))
+}
+
 fn validate_attached(workspaces: &[WorkspaceId]) -> Result<(), String> {
     if workspaces.len() > MAX_ATTACHED_WORKSPACES {
         return Err(format!(
@@ -175,4 +257,27 @@ mod tests {
             .unwrap_err()
             .contains("duplicate"));
     }
+
+    #[test]
+    fn a_dependency_check_connects_to_its_port_without_probing_http() {
+        let root = tempfile::tempdir().unwrap();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::fs::create_dir(root.path().join(".blackpepper")).unwrap();
+        std::fs::write(
+            root.path().join(".blackpepper/config.toml"),
+            format!(
+                "[[startup]]\nname = \"db\"\ncommand = [\"true\"]\nready = {{ port = {port} }}\n\n\
+                 [[startup]]\nname = \"seed\"\ncommand = [\"true\"]\nready = {{ command = [\"false\"] }}\n\n\
+                 [[startup]]\nname = \"web\"\ncommand = [\"true\"]\n"
+            ),
+        )
+        .unwrap();
+        assert_eq!(service_ready(root.path(), "db"), Ok(true));
+        assert_eq!(service_ready(root.path(), "seed"), Ok(false));
+        assert_eq!(service_ready(root.path(), "web"), Ok(true));
+        assert!(service_ready(root.path(), "api").is_err());
+        drop(listener);
+        assert_eq!(service_ready(root.path(), "db"), Ok(false));
+    }
 }
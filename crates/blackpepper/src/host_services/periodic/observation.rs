@@ -1,4 +1,5 @@
 use super::super::tool_runtime::discover_zellij_binary;
+use crate::client_config::service_marker;
 use crate::core::{
     AgentProcessObservation, HostAgentRun, RegistrySnapshot, SessionBackend, SessionState,
     WorkspaceId,
@@ -14,6 +15,8 @@ pub(super) struct SessionGroup {
     session: String,
     runs: Vec<HostAgentRun>,
     attached_workspaces: Vec<WorkspaceId>,
+    /// Configured service names of the attached workspaces in this session.
+    services: Vec<(WorkspaceId, String)>,
 }
 
 #[derive(Default)]
@@ -23,6 +26,8 @@ pub(super) struct SessionObservation {
     pub(super) client_errors: BTreeMap<WorkspaceId, String>,
     /// One-based focused tab and total tab count, per attached workspace.
     pub(super) tabs: BTreeMap<WorkspaceId, (u32, u32)>,
+    /// Services whose pane was found, with its process state.
+    pub(super) services: Vec<(WorkspaceId, String, PaneProcessState)>,
 }
 
 #[derive(Default)]
@@ -36,6 +41,7 @@ pub(super) fn session_groups(
     snapshot: &RegistrySnapshot,
     runs: Vec<HostAgentRun>,
     attached: &[WorkspaceId],
+    services: &BTreeMap<WorkspaceId, Vec<String>>,
 ) -> BTreeMap<(String, String), SessionGroup> {
     let mut groups = BTreeMap::new();
     for run in runs {
@@ -69,6 +75,13 @@ pub(super) fn session_groups(
         group.version.clone_from(&session.backend_version);
         group.session.clone_from(&session.backend_session_id);
         group.attached_workspaces.push(*workspace_id);
+        group.services.extend(
+            services
+                .get(workspace_id)
+                .into_iter()
+                .flatten()
+                .map(|name| (*workspace_id, name.clone())),
+        );
     }
     groups
 }
@@ -103,7 +116,7 @@ pub(super) fn observe_session(group: SessionGroup) -> SessionObservation {
     // Agent lifecycle and attached-client overview are independent reads.
     // One unavailable screen snapshot must not discard valid evidence from
     // the other, especially a terminal pane exit that must be persisted.
-    let panes = if group.runs.is_empty() {
+    let panes = if group.runs.is_empty() && group.services.is_empty() {
         Ok(Vec::new())
     } else {
         zellij
@@ -148,14 +161,14 @@ fn combine_observations(
     overview: Result<SessionOverview, String>,
 ) -> SessionObservation {
     let mut result = SessionObservation::default();
-    match panes {
+    match &panes {
         Ok(panes) => {
             for run in group.runs {
                 let observation = if panes.is_empty() {
                     AgentProcessObservation::Missing
                 } else {
                     process_observation(classify_pane_process(
-                        &panes,
+                        panes,
                         run.binding.tab_id,
                         &run.binding.tab_name,
                         &run.binding.zellij_pane_id,
@@ -169,6 +182,23 @@ fn combine_observations(
             .runs
             .extend(group.runs.into_iter().map(|run| (run, Err(error.clone())))),
     }
+    // A service is found by its launch marker alone: its tab is recreated on
+    // every start, so no recorded tab or pane ID exists to compare against.
+    // Without a pane listing there is no evidence either way, so nothing is
+    // reported rather than a guess.
+    if let Ok(panes) = &panes {
+        for (workspace_id, name) in group.services {
+            let marker = service_marker(workspace_id, &name);
+            if let Some(pane) = panes
+                .iter()
+                .find(|pane| !pane.is_plugin && pane.has_command_argument(&marker))
+            {
+                result
+                    .services
+                    .push((workspace_id, name, pane.process_state()));
+            }
+        }
+    }
 
     match overview {
         Ok(overview) => {
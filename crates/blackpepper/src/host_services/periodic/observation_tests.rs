@@ -85,6 +85,47 @@ fn valid_overview_survives_pane_failure() {
     assert!(observed.client_errors.is_empty());
 }
 
+#[test]
+fn services_are_found_by_their_launch_marker_wherever_their_tab_is() {
+    let workspace = WorkspaceId::new();
+    let pane = |id: u32, name: &str, exited: bool| ZellijPane {
+        id,
+        is_plugin: false,
+        tab_id: u64::from(id),
+        tab_name: "renamed by hand".to_owned(),
+        exited,
+        exit_status: exited.then_some(2),
+        is_held: false,
+        terminal_command: Some(format!("env {} npm start", service_marker(workspace, name))),
+        pane_command: None,
+    };
+    let group = SessionGroup {
+        attached_workspaces: vec![workspace],
+        services: ["web", "worker", "docs"]
+            .map(|name| (workspace, name.to_owned()))
+            .to_vec(),
+        ..SessionGroup::default()
+    };
+
+    let observed = combine_observations(
+        group,
+        Ok(vec![pane(3, "web", false), pane(5, "worker", true)]),
+        Ok(SessionOverview::default()),
+    );
+
+    assert_eq!(
+        observed.services,
+        [
+            (workspace, "web".to_owned(), PaneProcessState::Live),
+            (
+                workspace,
+                "worker".to_owned(),
+                PaneProcessState::Exited { code: Some(2) }
+            ),
+        ]
+    );
+}
+
 fn run(zellij_pane_id: &str) -> HostAgentRun {
     let run_id = AgentRunId::new();
     HostAgentRun {
@@ -0,0 +1,276 @@
+//! What periodic refresh remembers between `bp-host` runs.
+//!
+//! Every refresh is a fresh helper process, so the parsed workspace config
+//! and each service's last readiness check live in private files under the
+//! runtime directory. Each workspace has its own file, so clients attached to
+//! different workspaces on one host never overwrite each other's state.
+//! Losing a file costs one config read and one round of checks; it never
+//! changes what a service is.
+
+use crate::client_config::{DeclaredForward, StartupCommand};
+use crate::core::WorkspaceId;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long a parsed config is reused. Refreshes run every few seconds; an
+/// edited config takes effect within this long.
+const CONFIG_TTL: Duration = Duration::from_secs(10);
+/// The first recheck of a readiness result, and the delay after it changes.
+const CHECK_BACKOFF_MIN: Duration = Duration::from_secs(2);
+/// A service that is still starting is checked at least this often.
+const CHECK_BACKOFF_STARTING: Duration = Duration::from_secs(8);
+/// A ready service is rechecked at least this often.
+const CHECK_BACKOFF_READY: Duration = Duration::from_secs(30);
+/// A workspace file no refresh has written for this long belongs to a
+/// workspace no client has attached any more.
+const STATE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// The parts of a workspace's layered config periodic refresh uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct WorkspaceConfig {
+    pub(super) startup: Vec<StartupCommand>,
+    pub(super) env: BTreeMap<String, String>,
+    pub(super) forwards: Vec<DeclaredForward>,
+}
+
+/// One service's last readiness result and when to check it again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct ReadyCheck {
+    pub(super) ready: bool,
+    checked_at_ms: u64,
+    backoff_ms: u64,
+}
+
+impl ReadyCheck {
+    pub(super) fn is_due(&self, now_ms: u64) -> bool {
+        now_ms >= self.checked_at_ms.saturating_add(self.backoff_ms) || now_ms < self.checked_at_ms
+    }
+
+    /// Record a fresh result. An unchanged result doubles the delay up to
+    /// its cap; a change starts over, so a service that stops answering is
+    /// confirmed quickly either way.
+    pub(super) fn next(previous: Option<&Self>, ready: bool, now_ms: u64) -> Self {
+        let cap = if ready {
+            CHECK_BACKOFF_READY
+        } else {
+            CHECK_BACKOFF_STARTING
+        };
+        let backoff_ms = match previous {
+            Some(previous) if previous.ready == ready => previous.backoff_ms.saturating_mul(2),
+            _ => CHECK_BACKOFF_MIN.as_millis() as u64,
+        }
+        .min(cap.as_millis() as u64);
+        Self {
+            ready,
+            checked_at_ms: now_ms,
+            backoff_ms,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WorkspaceState {
+    #[serde(default)]
+    config: Option<(u64, WorkspaceConfig)>,
+    #[serde(default)]
+    checks: BTreeMap<String, ReadyCheck>,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct ServiceState {
+    workspaces: BTreeMap<WorkspaceId, WorkspaceState>,
+}
+
+impl ServiceState {
+    /// The attached workspaces' files. An unreadable file is an empty one.
+    pub(super) fn load(directory: &Path, attached: &[WorkspaceId]) -> Self {
+        let workspaces = attached
+            .iter()
+            .map(|workspace_id| {
+                let state = std::fs::read(workspace_path(directory, *workspace_id))
+                    .ok()
+                    .and_then(|contents| serde_json::from_slice(&contents).ok())
+                    .unwrap_or_default();
+                (*workspace_id, state)
+            })
+            .collect();
+        Self { workspaces }
+    }
+
+    /// Best effort: a refresh that cannot save only repeats work next time.
+    /// Files for workspaces this refresh did not load are left to their own
+    /// clients and removed only once they expire.
+    pub(super) fn save(&self, directory: &Path) {
+        for (workspace_id, state) in &self.workspaces {
+            if let Ok(contents) = serde_json::to_vec(state) {
+                let _ = crate::providers::runtime::write_private_atomic(
+                    &workspace_path(directory, *workspace_id),
+                    &contents,
+                );
+            }
+        }
+        expire(directory, SystemTime::now());
+    }
+
+    /// The workspace's config, read again once the cached copy is older than
+    /// [`CONFIG_TTL`]. A config that does not parse is not cached, so the
+    /// next refresh tries again.
+    pub(super) fn config(
+        &mut self,
+        workspace_id: WorkspaceId,
+        root: &Path,
+        now_ms: u64,
+    ) -> Option<WorkspaceConfig> {
+        let workspace = self.workspaces.entry(workspace_id).or_default();
+        if let Some((read_at_ms, config)) = &workspace.config {
+            if now_ms >= *read_at_ms && now_ms - read_at_ms < CONFIG_TTL.as_millis() as u64 {
+                return Some(config.clone());
+            }
+        }
+        workspace.config = None;
+        let config = crate::client_config::load(root).ok()?;
+        let config = WorkspaceConfig {
+            startup: config.startup,
+            env: config.workspace_env,
+            forwards: config.forwards,
+        };
+        workspace.config = Some((now_ms, config.clone()));
+        Some(config)
+    }
+
+    pub(super) fn checks(
+        &mut self,
+        workspace_id: WorkspaceId,
+    ) -> &mut BTreeMap<String, ReadyCheck> {
+        &mut self.workspaces.entry(workspace_id).or_default().checks
+    }
+}
+
+/// Remove workspace files not written within [`STATE_TTL`] of `now`.
+fn expire(directory: &Path, now: SystemTime) {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+        let written = entry.metadata().and_then(|metadata| metadata.modified());
+        if written.is_ok_and(|written| {
+            now.duration_since(written)
+                .is_ok_and(|age| age >= STATE_TTL)
+        }) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn workspace_path(directory: &Path, workspace_id: WorkspaceId) -> PathBuf {
+    directory.join(format!("{workspace_id}.json"))
+}
+
+pub(super) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_back_off_while_the_result_holds_and_start_over_when_it_changes() {
+        let first = ReadyCheck::next(None, false, 1_000);
+        assert!(!first.is_due(2_999));
+        assert!(first.is_due(3_000));
+        let second = ReadyCheck::next(Some(&first), false, 3_000);
+        let third = ReadyCheck::next(Some(&second), false, 7_000);
+        let fourth = ReadyCheck::next(Some(&third), false, 15_000);
+        assert_eq!(
+            [first, second, third, fourth].map(|check| check.backoff_ms),
+            [2_000, 4_000, 8_000, 8_000]
+        );
+
+        let ready = ReadyCheck::next(Some(&fourth), true, 23_000);
+        assert_eq!(ready.backoff_ms, 2_000);
+        let mut check = ready;
+        for now in 0..6 {
+            check = ReadyCheck::next(Some(&check), true, 25_000 + now);
+        }
+        assert_eq!(check.backoff_ms, 30_000);
+        // A clock that went backwards never postpones a check.
+        assert!(check.is_due(1_000));
+    }
+
+    #[test]
+    fn a_parsed_config_is_reused_until_it_expires() {
+        let root = tempfile::tempdir().unwrap();
+        let config = root.path().join(".blackpepper");
+        std::fs::create_dir(&config).unwrap();
+        let write = |name: &str| {
+            std::fs::write(
+                config.join("config.toml"),
+                format!("[[startup]]\nname = \"{name}\"\ncommand = [\"true\"]\n"),
+            )
+            .unwrap();
+        };
+        write("web");
+        let workspace_id = WorkspaceId::new();
+        let mut state = ServiceState::default();
+        let names = |state: &mut ServiceState, now| {
+            state
+                .config(workspace_id, root.path(), now)
+                .unwrap()
+                .startup
+                .into_iter()
+                .map(|service| service.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&mut state, 0), ["web"]);
+        write("api");
+        assert_eq!(names(&mut state, 9_999), ["web"]);
+        assert_eq!(names(&mut state, 10_000), ["api"]);
+
+        let directory = root.path().join("service-checks");
+        state.save(&directory);
+        let mut loaded = ServiceState::load(&directory, &[workspace_id]);
+        std::fs::remove_file(config.join("config.toml")).unwrap();
+        assert_eq!(names(&mut loaded, 10_001), ["api"]);
+    }
+
+    #[test]
+    fn refreshes_for_other_workspaces_keep_a_workspace_until_it_expires() {
+        let root = tempfile::tempdir().unwrap();
+        let directory = root.path().join("service-checks");
+        let first = WorkspaceId::new();
+        let second = WorkspaceId::new();
+
+        let mut state = ServiceState::load(&directory, &[first]);
+        state
+            .checks(first)
+            .insert("web".to_owned(), ReadyCheck::next(None, true, 1_000));
+        state.save(&directory);
+        // Another client's refresh, attached only to its own workspace.
+        let mut other = ServiceState::load(&directory, &[second]);
+        other
+            .checks(second)
+            .insert("api".to_owned(), ReadyCheck::next(None, false, 1_000));
+        other.save(&directory);
+
+        let mut loaded = ServiceState::load(&directory, &[first, second]);
+        assert!(loaded.checks(first).contains_key("web"));
+        assert!(loaded.checks(second).contains_key("api"));
+
+        expire(&directory, SystemTime::now() + STATE_TTL);
+        let mut loaded = ServiceState::load(&directory, &[first, second]);
+        assert!(loaded.checks(first).is_empty());
+        assert!(loaded.checks(second).is_empty());
+    }
+}
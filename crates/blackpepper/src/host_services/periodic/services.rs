@@ -0,0 +1,240 @@
+//! Readiness checks for the configured services periodic refresh found.
+//!
+//! Checks run host-side because that is where the service listens and where
+//! its checkout lives. A service is checked again on its own backoff rather
+//! than on every refresh, so a service that stops answering drops back to
+//! starting within one backoff instead of staying ready on old evidence.
+
+use super::super::process::run_bounded_timeout;
+use super::service_state::ReadyCheck;
+use crate::client_config::{ServiceReadiness, StartupCommand};
+use crate::core::{ServiceObservation, ServiceProcess};
+use crate::zellij::PaneProcessState;
+use std::collections::BTreeMap;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Per check, well inside the refresh deadline; checks run concurrently.
+const READY_TIMEOUT: Duration = Duration::from_secs(2);
+const PORT_TIMEOUT: Duration = Duration::from_millis(300);
+const READY_EXEC: &str = "cd -- \"$1\" || exit 125\nshift\nexec \"$@\"\n";
+
+/// One workspace's observed service panes, paired with the policy its config
+/// declares. Live services whose check is due are checked; the others report
+/// their last result from `checks`, which is updated in place. Services the
+/// config no longer names are left out: nothing would restart or check them.
+pub(super) fn observe(
+    root: &Path,
+    startup: &[StartupCommand],
+    env: &BTreeMap<String, String>,
+    panes: Vec<(String, PaneProcessState)>,
+    checks: &mut BTreeMap<String, ReadyCheck>,
+    now_ms: u64,
+) -> Vec<ServiceObservation> {
+    let observed = panes
+        .into_iter()
+        .filter_map(|(name, state)| {
+            let service = startup.iter().find(|service| service.name == name)?;
+            let process = match state {
+                PaneProcessState::Live => ServiceProcess::Live,
+                PaneProcessState::Exited { code } => ServiceProcess::Exited { code },
+                PaneProcessState::Missing | PaneProcessState::UnverifiedIdentity { .. } => {
+                    return None
+                }
+            };
+            let ready = (process == ServiceProcess::Live)
+                .then(|| service.ready.clone())
+                .flatten();
+            let check = ready.map(|ready| match checks.get(&name) {
+                Some(previous) if !previous.is_due(now_ms) => Err(previous.ready),
+                _ => {
+                    let cwd = service_cwd(root, service);
+                    let env = env.clone();
+                    Ok(std::thread::spawn(move || is_ready(&ready, &cwd, &env)))
+                }
+            });
+            Some((name, process, service.restart, check))
+        })
+        .collect::<Vec<_>>();
+    // A stopped service starts over with a quick first check when it returns.
+    let mut kept = BTreeMap::new();
+    let observed = observed
+        .into_iter()
+        .map(|(name, process, restart, check)| {
+            let ready = check.map(|check| match check {
+                Err(cached) => {
+                    kept.insert(name.clone(), checks[&name]);
+                    cached
+                }
+                Ok(handle) => {
+                    // A panicked check proves nothing about the service.
+                    let ready = handle.join().unwrap_or(false);
+                    kept.insert(
+                        name.clone(),
+                        ReadyCheck::next(checks.get(&name), ready, now_ms),
+                    );
+                    ready
+                }
+            });
+            ServiceObservation {
+                name,
+                process,
+                ready,
+                restart,
+            }
+        })
+        .collect();
+    *checks = kept;
+    observed
+}
+
+/// One check of `service` now, outside its backoff. A service that declares
+/// no check is ready once it runs.
+pub(super) fn ready_now(
+    root: &Path,
+    service: &StartupCommand,
+    env: &BTreeMap<String, String>,
+) -> bool {
+    service
+        .ready
+        .as_ref()
+        .is_none_or(|ready| is_ready(ready, &service_cwd(root, service), env))
+}
+
+fn service_cwd(root: &Path, service: &StartupCommand) -> PathBuf {
+    service
+        .cwd
+        .as_deref()
+        .map_or_else(|| root.to_path_buf(), |cwd| root.join(cwd))
+}
+
+fn is_ready(ready: &ServiceReadiness, cwd: &Path, env: &BTreeMap<String, String>) -> bool {
+    if let Some(port) = ready.port {
+        return [
+            SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+            SocketAddr::from((Ipv6Addr::LOCALHOST, port)),
+        ]
+        .iter()
+        .any(|address| TcpStream::connect_timeout(address, PORT_TIMEOUT).is_ok());
+    }
+    let Some(command) = &ready.command else {
+        return false;
+    };
+    let mut arguments = vec![
+        "-c".into(),
+        READY_EXEC.into(),
+        "bp-service-ready".into(),
+        cwd.as_os_str().to_owned(),
+        "env".into(),
+    ];
+    arguments.extend(
+        env.iter()
+            .map(|(key, value)| format!("{key}={value}").into()),
+    );
+    arguments.extend(command.iter().map(Into::into));
+    run_bounded_timeout("/bin/sh".as_ref(), &arguments, READY_TIMEOUT)
+        .is_ok_and(|output| output.status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ServiceRestart;
+
+    fn service(name: &str, ready: Option<ServiceReadiness>) -> StartupCommand {
+        StartupCommand {
+            name: name.to_owned(),
+            command: vec!["true".to_owned()],
+            cwd: None,
+            auto_start: false,
+            restart: ServiceRestart::OnFailure,
+            ready,
+            depends_on: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn live_services_are_checked_and_exited_ones_are_not() {
+        let listener = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("marker"), "").unwrap();
+        let startup = [
+            service(
+                "web",
+                Some(ServiceReadiness {
+                    port: Some(port),
+                    command: None,
+                }),
+            ),
+            service(
+                "api",
+                Some(ServiceReadiness {
+                    port: None,
+                    command: Some(vec!["test".into(), "-f".into(), "marker".into()]),
+                }),
+            ),
+            service(
+                "worker",
+                Some(ServiceReadiness {
+                    port: None,
+                    command: Some(vec!["false".into()]),
+                }),
+            ),
+            service("plain", None),
+        ];
+        let panes = || {
+            vec![
+                ("web".to_owned(), PaneProcessState::Live),
+                ("api".to_owned(), PaneProcessState::Live),
+                (
+                    "worker".to_owned(),
+                    PaneProcessState::Exited { code: Some(1) },
+                ),
+                ("plain".to_owned(), PaneProcessState::Live),
+                ("removed".to_owned(), PaneProcessState::Live),
+            ]
+        };
+        let mut checks = BTreeMap::new();
+        let observed = observe(
+            root.path(),
+            &startup,
+            &BTreeMap::new(),
+            panes(),
+            &mut checks,
+            1_000,
+        );
+        let ready = observed
+            .iter()
+            .map(|service| (service.name.as_str(), service.process, service.ready))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ready,
+            [
+                ("web", ServiceProcess::Live, Some(true)),
+                ("api", ServiceProcess::Live, Some(true)),
+                ("worker", ServiceProcess::Exited { code: Some(1) }, None),
+                ("plain", ServiceProcess::Live, None),
+            ]
+        );
+        assert!(observed
+            .iter()
+            .all(|service| service.restart == ServiceRestart::OnFailure));
+        assert_eq!(checks.keys().collect::<Vec<_>>(), ["api", "web"]);
+
+        // The next refresh reuses the result until the check is due again.
+        std::fs::remove_file(root.path().join("marker")).unwrap();
+        let ready = |observed: Vec<ServiceObservation>| {
+            observed
+                .into_iter()
+                .find(|service| service.name == "api")
+                .and_then(|service| service.ready)
+        };
+        let env = BTreeMap::new();
+        let again = observe(root.path(), &startup, &env, panes(), &mut checks, 2_000);
+        assert_eq!(ready(again), Some(true));
+        let due = observe(root.path(), &startup, &env, panes(), &mut checks, 3_000);
+        assert_eq!(ready(due), Some(false));
+    }
+}
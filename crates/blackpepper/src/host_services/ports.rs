@@ -1,8 +1,12 @@
+mod http;
+
 use super::process::run_bounded;
 use crate::core::HostRegistry;
 use crate::ports::{
-    attribute_linux_cwds, failed_probe, parse_linux_ss, parse_macos_lsof, platform_probe,
-    AttributionConfidence, PortSnapshot, ProbeCompleteness,
+    add_linux_unix_sockets, add_macos_unix_sockets, attribute_linux_cwds, failed_probe,
+    macos_listening_socket_paths, parse_linux_ss, parse_macos_lsof, platform_probe,
+    platform_socket_probe, platform_socket_state_probe, AttributionConfidence, PortSnapshot,
+    ProbeCommand, ProbeCompleteness,
 };
 use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsStr;
@@ -10,7 +14,10 @@ use std::path::PathBuf;
 
 const MAX_MACOS_CWD_PROBES: usize = 512;
 
-pub(super) fn discover(registry: &HostRegistry) -> PortSnapshot {
+/// `http_labels` asks each attributed loopback listener what it serves. Only
+/// an explicit `:ports` does; periodic refreshes must not hit dev servers
+/// every few seconds.
+pub(super) fn discover(registry: &HostRegistry, http_labels: bool) -> PortSnapshot {
     let probe = match platform_probe() {
         Ok(probe) => probe,
         Err(message) => return failed_probe(message),
@@ -40,6 +47,7 @@ pub(super) fn discover(registry: &HostRegistry) -> PortSnapshot {
             "Listener output exceeded the safe capture limit.",
         );
     }
+    discover_sockets(&mut snapshot);
     let workspaces = registry
         .snapshot()
         .map(|snapshot| {
@@ -62,9 +70,72 @@ pub(super) fn discover(registry: &HostRegistry) -> PortSnapshot {
     } else if cfg!(target_os = "macos") {
         attribute_macos_cwds(&mut snapshot, &workspaces);
     }
+    if http_labels {
+        http::label_listeners(&mut snapshot);
+    }
     snapshot
 }
 
+/// Unix sockets only add to the TCP result; a failed socket probe leaves the
+/// listeners usable and says why the socket list is missing.
+fn discover_sockets(snapshot: &mut PortSnapshot) {
+    let Ok(probe) = platform_socket_probe() else {
+        return;
+    };
+    let Some(stdout) = run_socket_probe(snapshot, &probe) else {
+        return;
+    };
+    if cfg!(target_os = "linux") {
+        add_linux_unix_sockets(snapshot, &stdout);
+        return;
+    }
+    let Some(states) =
+        platform_socket_state_probe().and_then(|probe| run_socket_probe(snapshot, &probe))
+    else {
+        return;
+    };
+    add_macos_unix_sockets(snapshot, &stdout, &macos_listening_socket_paths(&states));
+}
+
+fn run_socket_probe(snapshot: &mut PortSnapshot, probe: &ProbeCommand) -> Option<String> {
+    let output = match run_bounded(OsStr::new(probe.program), probe.args) {
+        // lsof exits 1 when it finds nothing to list.
+        Ok(output)
+            if output.status.success()
+                || (output.stdout.is_empty() && output.stderr.is_empty()) =>
+        {
+            output
+        }
+        Ok(output) => {
+            snapshot.completeness = ProbeCompleteness::Partial;
+            append_warning(
+                snapshot,
+                &format!(
+                    "Unix socket discovery failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            );
+            return None;
+        }
+        Err(error) => {
+            snapshot.completeness = ProbeCompleteness::Partial;
+            append_warning(
+                snapshot,
+                &format!("Could not run {} for Unix sockets: {error}", probe.program),
+            );
+            return None;
+        }
+    };
+    if output.truncated {
+        snapshot.completeness = ProbeCompleteness::Partial;
+        append_warning(
+            snapshot,
+            "Unix socket output exceeded the safe capture limit.",
+        );
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 fn attribute_macos_cwds(snapshot: &mut PortSnapshot, workspaces: &[PathBuf]) {
     if workspaces.is_empty() {
         return;
@@ -72,7 +143,9 @@ fn attribute_macos_cwds(snapshot: &mut PortSnapshot, workspaces: &[PathBuf]) {
     let pids = snapshot
         .listeners
         .iter()
-        .filter_map(|listener| listener.pid)
+        .map(|listener| listener.pid)
+        .chain(snapshot.sockets.iter().map(|socket| socket.pid))
+        .flatten()
         .collect::<BTreeSet<_>>();
     if pids.len() > MAX_MACOS_CWD_PROBES {
         snapshot.completeness = ProbeCompleteness::Partial;
@@ -128,6 +201,16 @@ fn attribute_macos_cwds(snapshot: &mut PortSnapshot, workspaces: &[PathBuf]) {
                 listener.attribution = AttributionConfidence::ExactCwd;
             }
         }
+        for socket in snapshot
+            .sockets
+            .iter_mut()
+            .filter(|socket| socket.pid == Some(pid))
+        {
+            if let Some(workspace) = workspace {
+                socket.workspace_path = Some(workspace.clone());
+                socket.attribution = AttributionConfidence::ExactCwd;
+            }
+        }
     }
     if !output.status.success() || output.truncated {
         snapshot.completeness = ProbeCompleteness::Partial;
@@ -0,0 +1,203 @@
+//! Bounded HTTP identification of workspace listeners.
+//!
+//! Only loopback listeners attributed to a registered workspace are asked,
+//! each with one `GET /` that has a hard deadline and a capped response. A
+//! listener that is not HTTP simply stays unlabelled.
+
+use crate::ports::{AttributionConfidence, HttpLabel, PortSnapshot};
+use std::io::{Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+const MAX_PROBES: usize = 16;
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(300);
+const RESPONSE_DEADLINE: Duration = Duration::from_secs(1);
+const MAX_RESPONSE_BYTES: usize = 32 * 1024;
+const MAX_LABEL_CHARS: usize = 80;
+
+pub(super) fn label_listeners(snapshot: &mut PortSnapshot) {
+    let mut candidates = Vec::<(SocketAddr, Vec<usize>)>::new();
+    for (index, listener) in snapshot.listeners.iter().enumerate() {
+        if listener.attribution != AttributionConfidence::ExactCwd {
+            continue;
+        }
+        let Some(address) = listener.forward_target().ok().and_then(|target| {
+            let ip = target.remote_host.parse::<IpAddr>().ok()?;
+            ip.is_loopback()
+                .then(|| SocketAddr::new(ip, target.remote_port))
+        }) else {
+            continue;
+        };
+        // Several processes sharing one socket get one request between them.
+        match candidates.iter().position(|(known, _)| *known == address) {
+            Some(known) => candidates[known].1.push(index),
+            None if candidates.len() < MAX_PROBES => candidates.push((address, vec![index])),
+            None => {}
+        }
+    }
+    let labels = std::thread::scope(|scope| {
+        candidates
+            .iter()
+            .map(|(address, _)| scope.spawn(move || probe(*address)))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().ok().flatten())
+            .collect::<Vec<_>>()
+    });
+    for ((_, indices), label) in candidates.into_iter().zip(labels) {
+        for index in indices {
+            snapshot.listeners[index].http = label.clone();
+        }
+    }
+}
+
+fn probe(address: SocketAddr) -> Option<HttpLabel> {
+    let deadline = Instant::now() + RESPONSE_DEADLINE;
+    let mut stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT).ok()?;
+    stream.set_write_timeout(Some(CONNECT_TIMEOUT)).ok()?;
+    stream
+        .write_all(
+            format!(
+                "GET / HTTP/1.0\r\nHost: {address}\r\nUser-Agent: blackpepper\r\nAccept: text/html\r\nConnection: close\r\n\r\n"
+            )
+            .as_bytes(),
+        )
+        .ok()?;
+    let mut response = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    while response.len() < MAX_RESPONSE_BYTES {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() || stream.set_read_timeout(Some(remaining)).is_err() {
+            break;
+        }
+        match stream.read(&mut chunk) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => response.extend_from_slice(&chunk[..read]),
+        }
+        // The title is near the top; there is no need to wait for the rest.
+        if response
+            .windows(8)
+            .any(|window| window.eq_ignore_ascii_case(b"</title>"))
+        {
+            break;
+        }
+    }
+    parse_response(&response)
+}
+
+fn parse_response(response: &[u8]) -> Option<HttpLabel> {
+    let text = String::from_utf8_lossy(response);
+    let status = text
+        .strip_prefix("HTTP/")?
+        .split_whitespace()
+        .nth(1)?
+        .parse::<u16>()
+        .ok()?;
+    let (head, body) = text
+        .split_once("\r\n\r\n")
+        .or_else(|| text.split_once("\n\n"))
+        .unwrap_or((&text, ""));
+    let server = head.lines().skip(1).find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("server")
+            .then(|| clean(value))
+            .flatten()
+    });
+    Some(HttpLabel {
+        status,
+        title: title(body),
+        server,
+    })
+}
+
+fn title(body: &str) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets, so indices carry over to `body`.
+    let lower = body.to_ascii_lowercase();
+    let open = lower.find("<title")?;
+    let start = open + lower[open..].find('>')? + 1;
+    let end = start + lower[start..].find("</title")?;
+    clean(&body[start..end])
+}
+
+fn clean(value: &str) -> Option<String> {
+    let decoded = value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    let words = decoded
+        .split(|character: char| character.is_whitespace() || character.is_control())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!words.is_empty()).then(|| words.chars().take(MAX_LABEL_CHARS).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::{PortListener, ProbeCompleteness};
+    use std::net::TcpListener;
+
+    #[test]
+    fn status_title_and_server_come_from_a_bounded_response() {
+        let label = parse_response(
+            b"HTTP/1.1 200 OK\r\nServer:  Vite \r\nContent-Type: text/html\r\n\r\n<html><head><TITLE>\n  Billing &amp; Admin\n</TITLE>",
+        )
+        .unwrap();
+        assert_eq!(label.status, 200);
+        assert_eq!(label.title.as_deref(), Some("Billing & Admin"));
+        assert_eq!(label.server.as_deref(), Some("Vite"));
+        assert_eq!(label.label(), "Billing & Admin");
+
+        let missing = parse_response(b"HTTP/1.0 404 Not Found\r\n\r\n").unwrap();
+        assert_eq!(missing.label(), "HTTP 404");
+        assert!(parse_response(b"\x00\x00\x00\x08postgres").is_none());
+    }
+
+    #[test]
+    fn only_attributed_loopback_listeners_are_asked() {
+        let server = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = server.local_addr().unwrap().port();
+        let responder = std::thread::spawn(move || {
+            let (mut stream, _) = server.accept().unwrap();
+            let mut request = [0_u8; 512];
+            let _ = stream.read(&mut request);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\n\r\n<title>api docs</title>")
+                .unwrap();
+        });
+        let listener = |attribution| PortListener {
+            bind_address: "127.0.0.1".to_owned(),
+            port,
+            pid: Some(1),
+            process: Some("node".to_owned()),
+            workspace_path: None,
+            attribution,
+            http: None,
+        };
+        let mut snapshot = PortSnapshot {
+            listeners: vec![
+                listener(AttributionConfidence::ExactCwd),
+                listener(AttributionConfidence::Unavailable),
+            ],
+            sockets: Vec::new(),
+            completeness: ProbeCompleteness::Full,
+            warning: None,
+        };
+
+        label_listeners(&mut snapshot);
+        responder.join().unwrap();
+
+        assert_eq!(
+            snapshot.listeners[0]
+                .http
+                .as_ref()
+                .and_then(|label| label.title.as_deref()),
+            Some("api docs")
+        );
+        assert_eq!(snapshot.listeners[1].http, None);
+    }
+}
@@ -109,7 +109,8 @@ fn reduce_provider_event(provider: Provider, value: &Value) -> Option<Vec<AgentE
     let event_name = event_name(value)?;
     let event = normalize(event_name);
     match provider {
-        Provider::Codex | Provider::Claude => match event.as_str() {
+        // A configured provider's hooks report the same lifecycle events.
+        Provider::Codex | Provider::Claude | Provider::Custom(_) => match event.as_str() {
             "sessionstart" => Some(vec![healthy_event(), AgentEventKind::Ready]),
             "userpromptsubmit" | "pretooluse" | "posttooluse" => {
                 Some(vec![AgentEventKind::Working])
@@ -4,9 +4,12 @@
 //! attached over SSH cannot stat the working tree. Git is asked on every
 //! refresh (cheap, local); `gh` is asked at most once per repository per
 //! `PR_REFRESH`, because it is a network round trip and the answer changes on
-//! human timescales.
+//! human timescales. The `:worktree prs` list is cached the same way for a
+//! shorter `PR_LIST_REFRESH`, since someone is waiting on it.
 
-use crate::core::{PullRequestState, PullRequestSummary, WorkspaceOverview};
+use crate::core::{
+    OpenPullRequest, PullRequestState, PullRequestSummary, WorkspaceOverview, WorktrunkPruneReason,
+};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::Path;
@@ -14,15 +17,24 @@ use std::process::Command;
 use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
-const PR_REFRESH: Duration = Duration::from_secs(300);
+pub(super) const PR_REFRESH: Duration = Duration::from_secs(300);
+const PR_LIST_REFRESH: Duration = Duration::from_secs(60);
+/// `gh` defaults to 30; a picker wants the whole queue of a busy repository.
+const PR_LIST_LIMIT: &str = "200";
 
 type PullRequestCache = HashMap<String, (Instant, Option<PullRequestSummary>)>;
+type OpenPullRequestCache = HashMap<String, (Instant, Vec<OpenPullRequest>)>;
 
 fn cache() -> &'static Mutex<PullRequestCache> {
     static CACHE: OnceLock<Mutex<PullRequestCache>> = OnceLock::new();
     CACHE.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+fn list_cache() -> &'static Mutex<OpenPullRequestCache> {
+    static CACHE: OnceLock<Mutex<OpenPullRequestCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 /// Git and pull-request state for one checkout. Returns the default overview
 /// when the path is not a repository, which renders as no repository segment
 /// at all rather than as an error.
@@ -40,7 +52,76 @@ pub(super) fn overview(root_path: &str) -> WorkspaceOverview {
         pull_request: pull_request(root_path, path),
         active_tab: None,
         tab_count: None,
+        ci: None,
+    }
+}
+
+/// The branch and why its worktree can go: a clean checkout whose pull
+/// request merged, or whose upstream branch was deleted. Anything uncertain —
+/// uncommitted work, a detached head, no answer from Git — keeps it.
+pub(super) fn prune_reason(root_path: &str) -> Option<(String, WorktrunkPruneReason)> {
+    let path = Path::new(root_path);
+    let status = run_git(&["status", "--porcelain=2", "-b"], path)?;
+    if parse_dirty(&status) {
+        return None;
+    }
+    let branch = parse_head(&status).filter(|head| head != "detached")?;
+    if let Some(upstream) = parse_gone_upstream(&status) {
+        return Some((branch, WorktrunkPruneReason::UpstreamGone { upstream }));
+    }
+    // Fresh rather than cached: this decides what gets deleted.
+    let pull_request = fetch_pull_request(path)?;
+    (pull_request.state == PullRequestState::Merged).then_some((
+        branch,
+        WorktrunkPruneReason::Merged {
+            number: pull_request.number,
+        },
+    ))
+}
+
+/// Cached `gh pr list` for the checkout's repository. Unlike the status-line
+/// lookup, a failure is reported rather than cached: the picker was asked for
+/// explicitly, and an empty list would read as "nothing open".
+pub(super) fn open_pull_requests(root_path: &str) -> Result<Vec<OpenPullRequest>, String> {
+    let mut cache = list_cache()
+        .lock()
+        .map_err(|_| "The pull request cache is unavailable.".to_owned())?;
+    if let Some((fetched_at, pull_requests)) = cache.get(root_path) {
+        if fetched_at.elapsed() < PR_LIST_REFRESH {
+            return Ok(pull_requests.clone());
+        }
+    }
+    let output = Command::new("gh")
+        .args([
+            "pr",
+            "list",
+            "--state",
+            "open",
+            "--limit",
+            PR_LIST_LIMIT,
+            "--json",
+            "number,title,author,isDraft,headRefName",
+        ])
+        .current_dir(root_path)
+        .output()
+        .map_err(|error| {
+            format!("Could not run gh on this host ({error}); install the GitHub CLI and run gh auth login.")
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or("no error output");
+        return Err(format!("gh pr list failed: {reason}"));
     }
+    let pull_requests = parse_pull_request_list(&output.stdout)?;
+    cache.insert(
+        root_path.to_owned(),
+        (Instant::now(), pull_requests.clone()),
+    );
+    Ok(pull_requests)
 }
 
 fn run_git(args: &[&str], cwd: &Path) -> Option<String> {
@@ -95,6 +176,19 @@ fn parse_divergence(output: &str) -> (u32, u32) {
     (ahead, behind)
 }
 
+/// Git prints `branch.upstream` for any configured upstream but
+/// `branch.ab` only while the remote-tracking ref still exists.
+fn parse_gone_upstream(output: &str) -> Option<String> {
+    let upstream = output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# branch.upstream "))?
+        .trim();
+    let tracked = output
+        .lines()
+        .any(|line| line.trim().starts_with("# branch.ab "));
+    (!upstream.is_empty() && !tracked).then(|| upstream.to_owned())
+}
+
 /// Cached `gh pr view`. A missing `gh`, an unauthenticated one, or a branch
 /// with no PR all cache as "no pull request" so the failure costs one lookup
 /// per interval rather than one per refresh.
@@ -147,9 +241,45 @@ fn fetch_pull_request(cwd: &Path) -> Option<PullRequestSummary> {
     })
 }
 
+#[derive(Deserialize)]
+struct GhListedPullRequest {
+    number: u32,
+    title: String,
+    author: Option<GhAuthor>,
+    #[serde(rename = "isDraft")]
+    is_draft: bool,
+    #[serde(rename = "headRefName")]
+    head_ref_name: String,
+}
+
+#[derive(Deserialize)]
+struct GhAuthor {
+    login: String,
+}
+
+fn parse_pull_request_list(json: &[u8]) -> Result<Vec<OpenPullRequest>, String> {
+    let listed: Vec<GhListedPullRequest> = serde_json::from_slice(json)
+        .map_err(|error| format!("gh pr list returned unexpected JSON: {error}"))?;
+    Ok(listed
+        .into_iter()
+        .map(|pull_request| OpenPullRequest {
+            number: pull_request.number,
+            title: pull_request.title,
+            author: pull_request
+                .author
+                .map(|author| author.login)
+                .unwrap_or_default(),
+            draft: pull_request.is_draft,
+            branch: pull_request.head_ref_name,
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{parse_dirty, parse_divergence, parse_head};
+    use super::{
+        parse_dirty, parse_divergence, parse_gone_upstream, parse_head, parse_pull_request_list,
+    };
 
     const CLEAN: &str =
         "# branch.oid abc\n# branch.head main\n# branch.upstream origin/main\n# branch.ab +2 -0\n";
@@ -167,10 +297,40 @@ mod tests {
         assert!(parse_dirty(&output));
     }
 
+    #[test]
+    fn an_upstream_without_divergence_counts_is_gone() {
+        assert_eq!(parse_gone_upstream(CLEAN), None);
+        let gone = "# branch.oid abc\n# branch.head feature\n# branch.upstream origin/feature\n";
+        assert_eq!(parse_gone_upstream(gone).as_deref(), Some("origin/feature"));
+        assert_eq!(
+            parse_gone_upstream("# branch.oid abc\n# branch.head feature\n"),
+            None
+        );
+    }
+
     #[test]
     fn detached_head_is_named_rather_than_dropped() {
         let output = "# branch.oid abc\n# branch.head (detached)\n";
         assert_eq!(parse_head(output).as_deref(), Some("detached"));
         assert_eq!(parse_divergence(output), (0, 0));
     }
+
+    #[test]
+    fn a_listed_pull_request_keeps_its_author_login_and_draft_flag() {
+        let listed = parse_pull_request_list(
+            br#"[
+                {"number": 42, "title": "Fix login", "author": {"login": "ana", "name": "Ana"}, "isDraft": true, "headRefName": "fix-login"},
+                {"number": 7, "title": "Ghost", "author": null, "isDraft": false, "headRefName": "ghost"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(
+            (listed[0].number, listed[0].author.as_str(), listed[0].draft),
+            (42, "ana", true)
+        );
+        assert_eq!(listed[0].branch, "fix-login");
+        assert_eq!(listed[1].author, "");
+        assert!(parse_pull_request_list(b"{}").is_err());
+    }
 }
@@ -61,6 +61,47 @@ fn bound_run(
     (context, binding)
 }
 
+#[test]
+fn a_configured_provider_without_hooks_starts_with_only_the_overlay() {
+    let (_root, paths, registry) = setup();
+    let name = crate::agent_status::ProviderName::new("gemini").unwrap();
+    let capability = |hooks: bool| {
+        let (context, _) = bound_run(&registry, &paths, Provider::Custom(name));
+        let root = registry
+            .workspace(context.workspace_id)
+            .unwrap()
+            .unwrap()
+            .root_path;
+        let config = std::path::Path::new(&root).join(".blackpepper");
+        std::fs::create_dir(&config).unwrap();
+        let integration = if hooks { "hooks" } else { "none" };
+        std::fs::write(
+            config.join("config.toml"),
+            format!("[providers.gemini]\nintegration = \"{integration}\"\n"),
+        )
+        .unwrap();
+        let mut events = HostAgentEvents::open(&paths).unwrap();
+        events.append(context, AgentEventKind::Working).unwrap();
+        // The first event fixed it; the config no longer matters.
+        std::fs::remove_dir_all(&config).unwrap();
+        events.append(context, AgentEventKind::Working).unwrap();
+        events
+            .snapshot(context.run_id)
+            .unwrap()
+            .unwrap()
+            .snapshot
+            .needs_input_capability
+    };
+    assert_eq!(
+        capability(false),
+        crate::agent_status::NeedsInputCapability::BlockerOverlay
+    );
+    assert_eq!(
+        capability(true),
+        crate::agent_status::NeedsInputCapability::ProviderEventsWithOverlay
+    );
+}
+
 #[test]
 fn concurrent_transient_event_store_initialization_is_serialized() {
     let root = tempfile::tempdir().unwrap();
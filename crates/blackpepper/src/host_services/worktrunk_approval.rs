@@ -25,14 +25,36 @@ pub(super) fn authorize(
     repository: &Path,
     mutation: &CommandSpec,
     supplied: Option<&WorktrunkApprovalToken>,
+) -> Result<ApprovalDecision, String> {
+    authorize_all(
+        binary,
+        lock_dir,
+        repository,
+        std::slice::from_ref(mutation),
+        supplied,
+    )
+}
+
+/// `authorize` for a batch run from one repository: a single token covers
+/// every mutation in order, so approving it approves exactly that batch.
+pub(super) fn authorize_all(
+    binary: &Path,
+    lock_dir: &Path,
+    repository: &Path,
+    mutations: &[CommandSpec],
+    supplied: Option<&WorktrunkApprovalToken>,
 ) -> Result<ApprovalDecision, String> {
     let lock = RepositoryLock::acquire(lock_dir, repository)?;
     let plan = load_plan(&lock, binary, repository)?;
-    let expected = approval_token(repository, mutation, &plan)?;
+    let expected = approval_token(repository, mutations, &plan)?;
     let Some(supplied) = supplied else {
         return Ok(ApprovalDecision::Required(Box::new(
             HostServicePayload::WorktrunkApprovalRequired {
-                command: mutation.display(),
+                command: mutations
+                    .iter()
+                    .map(CommandSpec::display)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
                 approval: expected,
                 unapproved_project_commands: plan.unapproved_commands(),
             },
@@ -43,7 +65,7 @@ pub(super) fn authorize(
     }
 
     if plan.requires_approval() {
-        approve_exact_plan(&lock, binary, repository, mutation, &plan, &expected)?;
+        approve_exact_plan(&lock, binary, repository, mutations, &plan, &expected)?;
     }
     Ok(ApprovalDecision::Authorized(lock))
 }
@@ -66,7 +88,7 @@ fn approve_exact_plan(
     lock: &RepositoryLock,
     binary: &Path,
     repository: &Path,
-    mutation: &CommandSpec,
+    mutations: &[CommandSpec],
     reviewed: &WorktrunkApprovalPlan,
     reviewed_token: &WorktrunkApprovalToken,
 ) -> Result<(), String> {
@@ -101,7 +123,7 @@ fn approve_exact_plan(
         // Worktrunk has now loaded the exact command batch it will save. A
         // second read-only list closes the config-read race before answering.
         let current = load_plan(lock, binary, repository)?;
-        let current_token = approval_token(repository, mutation, &current)?;
+        let current_token = approval_token(repository, mutations, &current)?;
         if &current_token != reviewed_token {
             let _ = pty.write_all(b"n\n");
             let _ = drain_bounded(&mut reader);
@@ -132,7 +154,7 @@ fn approve_exact_plan(
 
 fn approval_token(
     repository: &Path,
-    mutation: &CommandSpec,
+    mutations: &[CommandSpec],
     plan: &WorktrunkApprovalPlan,
 ) -> Result<WorktrunkApprovalToken, String> {
     let mut hasher = Sha256::new();
@@ -144,8 +166,12 @@ fn approval_token(
             .as_encoded_bytes(),
     );
     hash_field(&mut hasher, repository.as_os_str().as_encoded_bytes());
-    for argument in &mutation.args {
-        hash_field(&mut hasher, argument.as_os_str().as_encoded_bytes());
+    for mutation in mutations {
+        // The count keeps one batch's boundaries from matching another's.
+        hasher.update((mutation.args.len() as u64).to_le_bytes());
+        for argument in &mutation.args {
+            hash_field(&mut hasher, argument.as_os_str().as_encoded_bytes());
+        }
     }
     let plan = serde_json::to_vec(plan)
         .map_err(|error| format!("Could not encode Worktrunk approval plan: {error}"))?;
@@ -0,0 +1,100 @@
+//! Worktrunk's CI column, shared by `:worktree list` and the sidebar.
+//!
+//! `wt list --full` asks the forge about every branch, so it runs at most
+//! once per repository per `PR_REFRESH`, the same cadence as the `gh pr view`
+//! cache. It runs without the repository lock: it is a read, and holding the
+//! lock through a network round trip would turn away a concurrent mutation.
+
+use super::super::process::run_bounded;
+use super::super::repo_status::PR_REFRESH;
+use super::super::worktrunk_lock::repository_identity;
+use crate::worktrunk::{Ci, WorktreeList, Worktrunk};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+/// Branch name to CI, or `None` when the forge reports no CI at all.
+type BranchCi = Option<BTreeMap<String, Ci>>;
+type CiCache = HashMap<PathBuf, (Instant, BranchCi)>;
+
+fn cache() -> &'static Mutex<CiCache> {
+    static CACHE: OnceLock<Mutex<CiCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Cached CI by branch for the repository holding `repository`. A missing
+/// forge, an unauthenticated `gh`, or a failed list all cache as "no CI" so
+/// the failure costs one lookup per interval rather than one per refresh.
+pub(super) fn branch_ci(binary: &Path, repository: &Path) -> BranchCi {
+    let key = repository_identity(repository);
+    let mut cache = cache().lock().ok()?;
+    if let Some((fetched_at, branches)) = cache.get(&key) {
+        if fetched_at.elapsed() < PR_REFRESH {
+            return branches.clone();
+        }
+    }
+    let branches = fetch(binary, repository);
+    cache.insert(key, (Instant::now(), branches.clone()));
+    branches
+}
+
+/// Fill in CI for the rows of a plain list from the cached full one, so the
+/// browser shows the same verdict the sidebar does.
+pub(super) fn annotate(list: &mut WorktreeList, branches: &BTreeMap<String, Ci>) {
+    list.collected.ci = true;
+    for item in &mut list.items {
+        if item.ci.is_none() {
+            item.ci = item
+                .branch
+                .as_ref()
+                .and_then(|branch| branches.get(branch))
+                .cloned();
+        }
+    }
+}
+
+fn fetch(binary: &Path, repository: &Path) -> BranchCi {
+    let spec = Worktrunk::new(binary).list_full(repository);
+    let output = run_bounded(spec.program.as_os_str(), &spec.args).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let list = WorktreeList::parse(std::str::from_utf8(&output.stdout).ok()?).ok()?;
+    list.collected.ci.then(|| {
+        list.items
+            .into_iter()
+            .filter_map(|item| Some((item.branch?, item.ci?)))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::annotate;
+    use crate::worktrunk::{Ci, WorktreeList};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn a_plain_list_takes_ci_by_branch_from_the_full_one() {
+        let mut list = WorktreeList::parse(
+            r#"{"schema":2,"repo":{"default_branch":"main"},"items":[
+              {"branch":"feature"},{"branch":"other"},{"head":null}
+            ]}"#,
+        )
+        .unwrap();
+        let passing = Ci {
+            status: "passed".to_owned(),
+            url: None,
+            stale: false,
+        };
+        annotate(
+            &mut list,
+            &BTreeMap::from([("feature".to_owned(), passing.clone())]),
+        );
+        assert!(list.collected.ci);
+        assert_eq!(list.items[0].ci.as_ref(), Some(&passing));
+        assert_eq!(list.items[1].ci, None);
+        assert_eq!(list.items[2].ci, None);
+    }
+}
@@ -9,12 +9,14 @@ use crate::core::{
 };
 use crate::transport::WORKTRUNK_VERSION;
 use crate::worktrunk::{
-    CommandSpec, SwitchResult, WorktreeList, Worktrunk, WorktrunkApprovalToken,
+    CiState, CommandSpec, SwitchResult, WorktreeList, Worktrunk, WorktrunkApprovalToken,
 };
 use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+#[path = "worktrunk_ci.rs"]
+mod ci;
 #[path = "worktrunk_reconcile.rs"]
 mod reconcile;
 
@@ -84,6 +86,25 @@ impl WorktrunkExecutor {
         reconcile::remove(self, registry, workspace_id, target_path, approval)
     }
 
+    pub fn prune_merged(
+        &self,
+        registry: &HostRegistry,
+        workspace_id: WorkspaceId,
+        repository_path: &str,
+        approval: Option<&WorktrunkApprovalToken>,
+    ) -> Result<HostServicePayload, String> {
+        reconcile::prune_merged(self, registry, workspace_id, repository_path, approval)
+    }
+
+    /// Worktrunk's CI verdict for one branch of the checkout's repository,
+    /// from the cached full list.
+    pub fn ci_state(&self, root_path: &str, branch: &str) -> Option<CiState> {
+        let repository = canonical_repository(root_path).ok()?;
+        ci::branch_ci(self.binary().ok()?, &repository)?
+            .get(branch)?
+            .state()
+    }
+
     fn switch_mutation(
         &self,
         repository: PathBuf,
@@ -1,3 +1,5 @@
+#[path = "worktrunk_reconcile/prune.rs"]
+mod prune;
 #[path = "worktrunk_reconcile/removal.rs"]
 mod removal;
 
@@ -23,8 +25,13 @@ pub(super) fn list(
     require_success(&spec, &output)?;
     let json = std::str::from_utf8(&output.stdout)
         .map_err(|_| "Worktrunk list output was not valid UTF-8.".to_owned())?;
-    let list = WorktreeList::parse(json)?;
+    let mut list = WorktreeList::parse(json)?;
     reconcile_removal_intents(registry, &repository, &repository_key, &list)?;
+    // CI can be a network round trip; nothing below needs the lock.
+    drop(lock);
+    if let Some(branches) = super::ci::branch_ci(executor.binary()?, &repository) {
+        super::ci::annotate(&mut list, &branches);
+    }
     Ok(HostServicePayload::Worktrees { list })
 }
 
@@ -38,6 +45,16 @@ pub(super) fn remove(
     removal::remove(executor, registry, workspace_id, target_path, approval)
 }
 
+pub(super) fn prune_merged(
+    executor: &WorktrunkExecutor,
+    registry: &HostRegistry,
+    workspace_id: WorkspaceId,
+    repository_path: &str,
+    approval: Option<&WorktrunkApprovalToken>,
+) -> Result<HostServicePayload, String> {
+    prune::prune_merged(executor, registry, workspace_id, repository_path, approval)
+}
+
 fn list_repository(
     registry: &HostRegistry,
     workspace_id: WorkspaceId,
@@ -80,20 +97,26 @@ fn recovery_repository(
     if intent.host_id != local_host_id || intent.target_path != expected_path {
         return Err("Pending Worktrunk removal does not match the selected workspace.".to_owned());
     }
-    let survivor = registry
-        .workspace(intent.surviving_workspace_id)
-        .map_err(|error| error.to_string())?
-        .ok_or_else(|| "The recorded surviving Worktrunk workspace is unavailable.".to_owned())?;
-    if survivor.host_id != local_host_id
-        || survivor.root_path != intent.surviving_path
-        || survivor
-            .repository
-            .as_ref()
-            .is_none_or(|identity| identity.repository_id() != intent.repository_id)
-    {
-        return Err("The recorded surviving Worktrunk workspace changed.".to_owned());
+    // A main-worktree survivor was never registered; the common-directory
+    // check below is what ties it to the intent.
+    if let Some(surviving_workspace_id) = intent.surviving_workspace_id {
+        let survivor = registry
+            .workspace(surviving_workspace_id)
+            .map_err(|error| error.to_string())?
+            .ok_or_else(|| {
+                "The recorded surviving Worktrunk workspace is unavailable.".to_owned()
+            })?;
+        if survivor.host_id != local_host_id
+            || survivor.root_path != intent.surviving_path
+            || survivor
+                .repository
+                .as_ref()
+                .is_none_or(|identity| identity.repository_id() != intent.repository_id)
+        {
+            return Err("The recorded surviving Worktrunk workspace changed.".to_owned());
+        }
     }
-    let repository = canonical_repository(&survivor.root_path)?;
+    let repository = canonical_repository(&intent.surviving_path)?;
     let key = repository_key(&repository)?;
     if key != intent.repository_key {
         return Err("The recorded Worktrunk repository identity changed.".to_owned());
@@ -0,0 +1,228 @@
+//! `:worktree prune-merged`: every registered worktree of one repository whose
+//! branch merged or lost its upstream, removed under a single review.
+//!
+//! Each item is the same non-forced `wt remove` and removal journal as
+//! `:worktree remove`, run one after another under one repository lock.
+//! Worktrunk still refuses a worktree that gained changes after review.
+
+use super::super::super::repo_status::prune_reason;
+use super::super::super::session_lease::SessionInitializationLease;
+use super::super::super::worktrunk_approval::{authorize_all, ApprovalDecision};
+use super::super::{canonical_repository, WorktrunkExecutor};
+use super::removal::{
+    removal_intent, repository_key, require_all_zellij_sessions_exited, run_removal,
+    validate_git_removal_identity, validate_registered_target,
+};
+use crate::core::{
+    HostRegistry, HostServicePayload, WorkspaceId, WorktrunkMutationOutcome, WorktrunkPruneItem,
+    WorktrunkPruneResult, WorktrunkRemovalIntent,
+};
+use crate::worktrunk::{CommandSpec, Worktrunk, WorktrunkApprovalToken};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+struct Planned {
+    item: WorktrunkPruneItem,
+    intent: WorktrunkRemovalIntent,
+    spec: CommandSpec,
+    surviving: PathBuf,
+    target: PathBuf,
+}
+
+pub(super) fn prune_merged(
+    executor: &WorktrunkExecutor,
+    registry: &HostRegistry,
+    workspace_id: WorkspaceId,
+    repository_path: &str,
+    approval: Option<&WorktrunkApprovalToken>,
+) -> Result<HostServicePayload, String> {
+    let planned = plan(executor, registry, workspace_id, repository_path)?;
+    let Some(surviving) = planned.first().map(|planned| planned.surviving.clone()) else {
+        return Ok(pruned(Vec::new()));
+    };
+    let Some(approval) = approval else {
+        return review(executor, &surviving, planned);
+    };
+
+    // Leases before the repository lock, as for a single removal; sorted so
+    // two overlapping batches cannot take them in opposite orders.
+    let mut workspace_ids = planned
+        .iter()
+        .map(|planned| planned.item.workspace_id)
+        .collect::<Vec<_>>();
+    workspace_ids.sort();
+    let _session_leases = workspace_ids
+        .iter()
+        .map(|workspace_id| {
+            SessionInitializationLease::acquire_for_workspace(&executor.paths, *workspace_id)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let current = plan(executor, registry, workspace_id, repository_path)?;
+    let unchanged = current.len() == planned.len()
+        && current.iter().zip(&planned).all(|(current, planned)| {
+            current.item == planned.item && current.intent == planned.intent
+        });
+    if !unchanged {
+        return Err(
+            "The prune plan changed since it was reviewed; run :worktree prune-merged again."
+                .to_owned(),
+        );
+    }
+    for workspace_id in &workspace_ids {
+        require_all_zellij_sessions_exited(registry, *workspace_id)?;
+    }
+    let specs = planned
+        .iter()
+        .map(|planned| planned.spec.clone())
+        .collect::<Vec<_>>();
+    let lock = match authorize_all(
+        executor.binary()?,
+        &executor.lock_dir,
+        &surviving,
+        &specs,
+        Some(approval),
+    )? {
+        ApprovalDecision::Required(_) => {
+            return Err("Worktrunk prune approval was not accepted.".to_owned())
+        }
+        ApprovalDecision::Authorized(lock) => lock,
+    };
+    let results = planned
+        .into_iter()
+        .map(|planned| WorktrunkPruneResult {
+            workspace_id: planned.item.workspace_id,
+            error: run_removal(
+                registry,
+                &lock,
+                &planned.intent,
+                &planned.spec,
+                &planned.target,
+            )
+            .err(),
+            path: planned.target,
+        })
+        .collect();
+    Ok(pruned(results))
+}
+
+/// Registered linked worktrees of the listed workspace's repository that
+/// `prune_reason` accepts, in path order. None of them can be another's
+/// survivor, so every item runs from the same surviving worktree.
+fn plan(
+    executor: &WorktrunkExecutor,
+    registry: &HostRegistry,
+    workspace_id: WorkspaceId,
+    repository_path: &str,
+) -> Result<Vec<Planned>, String> {
+    let local_host_id = registry
+        .local_host_id()
+        .map_err(|error| error.to_string())?;
+    let listed = registry
+        .workspace(workspace_id)
+        .map_err(|error| error.to_string())?
+        .ok_or_else(|| "The selected workspace is not registered.".to_owned())?;
+    validate_registered_target(&listed, local_host_id, repository_path)?;
+    let repository_id = listed
+        .repository
+        .as_ref()
+        .ok_or_else(|| {
+            "The selected workspace has no registered Git repository identity.".to_owned()
+        })?
+        .repository_id();
+    let key = repository_key(&canonical_repository(repository_path)?)?;
+
+    let mut workspaces = registry
+        .snapshot()
+        .map_err(|error| error.to_string())?
+        .workspaces;
+    workspaces.sort_by(|left, right| left.root_path.cmp(&right.root_path));
+    let candidates = workspaces
+        .into_iter()
+        .filter(|workspace| {
+            workspace.host_id == local_host_id
+                && workspace
+                    .repository
+                    .as_ref()
+                    .is_some_and(|identity| identity.repository_id() == repository_id)
+                && canonical_repository(&workspace.root_path).is_ok_and(|path| {
+                    // The main worktree holds the common directory and stays.
+                    path.join(".git") != Path::new(&key)
+                        && repository_key(&path).is_ok_and(|candidate| candidate == key)
+                })
+        })
+        .filter_map(|workspace| {
+            prune_reason(&workspace.root_path).map(|(branch, reason)| (workspace, branch, reason))
+        })
+        .collect::<Vec<_>>();
+    let excluded = candidates
+        .iter()
+        .map(|(workspace, _, _)| workspace.id)
+        .collect::<BTreeSet<_>>();
+
+    let mut planned = Vec::with_capacity(candidates.len());
+    for (workspace, branch, reason) in candidates {
+        let intent = removal_intent(registry, workspace.id, &workspace.root_path, &excluded)?;
+        let (surviving, target) = validate_git_removal_identity(&intent)?;
+        let spec = Worktrunk::new(executor.binary()?).remove(&surviving, &target)?;
+        planned.push(Planned {
+            item: WorktrunkPruneItem {
+                workspace_id: workspace.id,
+                path: target.clone(),
+                branch,
+                reason,
+                command: spec.display(),
+            },
+            intent,
+            spec,
+            surviving,
+            target,
+        });
+    }
+    if planned
+        .windows(2)
+        .any(|pair| pair[0].surviving != pair[1].surviving)
+    {
+        return Err("The prune plan has no single surviving worktree to run from.".to_owned());
+    }
+    Ok(planned)
+}
+
+fn review(
+    executor: &WorktrunkExecutor,
+    surviving: &Path,
+    planned: Vec<Planned>,
+) -> Result<HostServicePayload, String> {
+    let specs = planned
+        .iter()
+        .map(|planned| planned.spec.clone())
+        .collect::<Vec<_>>();
+    match authorize_all(
+        executor.binary()?,
+        &executor.lock_dir,
+        surviving,
+        &specs,
+        None,
+    )? {
+        ApprovalDecision::Required(payload) => match *payload {
+            HostServicePayload::WorktrunkApprovalRequired {
+                approval,
+                unapproved_project_commands,
+                ..
+            } => Ok(HostServicePayload::WorktrunkPruneApprovalRequired {
+                items: planned.into_iter().map(|planned| planned.item).collect(),
+                approval,
+                unapproved_project_commands,
+            }),
+            _ => Err("Worktrunk prune review returned an unexpected plan.".to_owned()),
+        },
+        ApprovalDecision::Authorized(_) => {
+            Err("Worktrunk prune preview unexpectedly executed.".to_owned())
+        }
+    }
+}
+
+fn pruned(results: Vec<WorktrunkPruneResult>) -> HostServicePayload {
+    HostServicePayload::WorktrunkMutation {
+        outcome: WorktrunkMutationOutcome::Pruned { results },
+    }
+}
@@ -1,6 +1,6 @@
 use super::super::super::session_lease::SessionInitializationLease;
 use super::super::super::worktrunk_approval::{authorize, ApprovalDecision};
-use super::super::super::worktrunk_lock::repository_identity;
+use super::super::super::worktrunk_lock::{repository_identity, RepositoryLock};
 use super::super::{
     canonical_repository, execute, reject_declined_commands, require_success, WorktrunkExecutor,
 };
@@ -8,7 +8,8 @@ use crate::core::{
     HostRegistry, HostServicePayload, SessionBackend, SessionState, WorkspaceId, WorkspaceRecord,
     WorktrunkMutationOutcome, WorktrunkRemovalIntent,
 };
-use crate::worktrunk::{Worktrunk, WorktrunkApprovalToken};
+use crate::worktrunk::{CommandSpec, Worktrunk, WorktrunkApprovalToken};
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 pub(super) fn remove(
@@ -18,7 +19,7 @@ pub(super) fn remove(
     target_path: &str,
     approval: Option<&WorktrunkApprovalToken>,
 ) -> Result<HostServicePayload, String> {
-    let intent = removal_intent(registry, workspace_id, target_path)?;
+    let intent = removal_intent(registry, workspace_id, target_path, &BTreeSet::new())?;
     let (surviving, target) = validate_git_removal_identity(&intent)?;
     let spec = Worktrunk::new(executor.binary()?).remove(&surviving, &target)?;
 
@@ -45,7 +46,7 @@ pub(super) fn remove(
     // to recreate a removed or unknown workspace.
     let _session_lease =
         SessionInitializationLease::acquire_for_workspace(&executor.paths, workspace_id)?;
-    let current = removal_intent(registry, workspace_id, target_path)?;
+    let current = removal_intent(registry, workspace_id, target_path, &BTreeSet::new())?;
     if current != intent {
         return Err("Worktrunk workspace identity changed; removal was refused.".to_owned());
     }
@@ -61,22 +62,44 @@ pub(super) fn remove(
         ApprovalDecision::Required(payload) => return Ok(*payload),
         ApprovalDecision::Authorized(lock) => lock,
     };
-    let current = registry
-        .plan_worktrunk_removal(
+    run_removal(registry, &lock, &intent, &spec, &target)?;
+    Ok(HostServicePayload::WorktrunkMutation {
+        outcome: WorktrunkMutationOutcome::Removed { path: target },
+    })
+}
+
+/// Journal and run one authorized removal under `lock`. The marker is written
+/// before Worktrunk runs, so an error here never leaves an unrecorded result.
+pub(super) fn run_removal(
+    registry: &HostRegistry,
+    lock: &RepositoryLock,
+    intent: &WorktrunkRemovalIntent,
+    spec: &CommandSpec,
+    target: &Path,
+) -> Result<(), String> {
+    let current = match intent.surviving_workspace_id {
+        Some(surviving_workspace_id) => registry.plan_worktrunk_removal(
             intent.workspace_id,
-            intent.surviving_workspace_id,
+            surviving_workspace_id,
             &intent.target_path,
             intent.repository_key.clone(),
-        )
-        .map_err(|error| error.to_string())?;
-    if current != intent {
+        ),
+        None => registry.plan_worktrunk_removal_from_main(
+            intent.workspace_id,
+            &intent.surviving_path,
+            &intent.target_path,
+            intent.repository_key.clone(),
+        ),
+    }
+    .map_err(|error| error.to_string())?;
+    if &current != intent {
         return Err("Worktrunk workspace identity changed; removal was refused.".to_owned());
     }
-    validate_git_removal_identity(&intent)?;
+    validate_git_removal_identity(intent)?;
     registry
-        .journal_worktrunk_removal(&intent)
+        .journal_worktrunk_removal(intent)
         .map_err(|error| error.to_string())?;
-    let output = match execute(&lock, &spec) {
+    let output = match execute(lock, spec) {
         Ok(output) => output,
         Err(error) => {
             return Err(format!(
@@ -85,26 +108,24 @@ pub(super) fn remove(
         }
     };
     if let Err(error) =
-        reject_declined_commands(&output).and_then(|()| require_success(&spec, &output))
+        reject_declined_commands(&output).and_then(|()| require_success(spec, &output))
     {
         return Err(format!(
             "{error} The result was not retried; run :worktree list to reconcile it."
         ));
     }
     registry
-        .finish_worktrunk_removal(&intent)
+        .finish_worktrunk_removal(intent)
         .map_err(|error| {
             format!(
                 "Worktrunk removed {}, but registry cleanup is pending: {error}. Run :worktree list to reconcile it.",
                 target.display()
             )
         })?;
-    Ok(HostServicePayload::WorktrunkMutation {
-        outcome: WorktrunkMutationOutcome::Removed { path: target },
-    })
+    Ok(())
 }
 
-fn require_all_zellij_sessions_exited(
+pub(super) fn require_all_zellij_sessions_exited(
     registry: &HostRegistry,
     workspace_id: WorkspaceId,
 ) -> Result<(), String> {
@@ -130,10 +151,13 @@ fn require_all_zellij_sessions_exited(
     ))
 }
 
-fn removal_intent(
+/// Plan removing one workspace. Workspaces in `excluded` are being removed
+/// alongside it, so none of them may be its survivor.
+pub(super) fn removal_intent(
     registry: &HostRegistry,
     workspace_id: WorkspaceId,
     expected_target_path: &str,
+    excluded: &BTreeSet<WorkspaceId>,
 ) -> Result<WorktrunkRemovalIntent, String> {
     if registry
         .worktrunk_removal(workspace_id)
@@ -167,6 +191,7 @@ fn removal_intent(
     candidates.sort_by(|left, right| left.root_path.cmp(&right.root_path));
     let survivor = candidates.into_iter().find(|candidate| {
         candidate.id != target.id
+            && !excluded.contains(&candidate.id)
             && candidate.host_id == local_host_id
             && candidate
                 .repository
@@ -176,20 +201,48 @@ fn removal_intent(
                 .and_then(|path| repository_key(&path))
                 .is_ok_and(|key| key == repository_key_value)
     });
-    let survivor = survivor.ok_or_else(|| {
-        "Register another worktree from this exact Git repository before removing the selected one."
-            .to_owned()
-    })?;
+    if let Some(survivor) = survivor {
+        return registry
+            .plan_worktrunk_removal(
+                target.id,
+                survivor.id,
+                expected_target_path,
+                repository_key_value,
+            )
+            .map_err(|error| error.to_string());
+    }
+    // Only linked worktrees are registered: Worktrunk runs from the main
+    // worktree instead, which `validate_git_removal_identity` proves shares
+    // the target's common directory before anything runs.
+    let main = main_worktree(&repository_key_value)
+        .filter(|main| *main != canonical_target)
+        .ok_or_else(|| {
+            "This repository has no main worktree to run Worktrunk from; register another worktree from it before removing the selected one."
+                .to_owned()
+        })?;
+    let main = main
+        .to_str()
+        .ok_or_else(|| "The main worktree path must be valid UTF-8.".to_owned())?;
     registry
-        .plan_worktrunk_removal(
+        .plan_worktrunk_removal_from_main(
             target.id,
-            survivor.id,
+            main,
             expected_target_path,
             repository_key_value,
         )
         .map_err(|error| error.to_string())
 }
 
+/// The main worktree of a non-bare repository is the folder holding its Git
+/// common directory. A bare repository has none.
+fn main_worktree(repository_key: &str) -> Option<PathBuf> {
+    let common_dir = Path::new(repository_key);
+    if common_dir.file_name()? != ".git" {
+        return None;
+    }
+    canonical_repository(common_dir.parent()?.to_str()?).ok()
+}
+
 pub(super) fn validate_registered_target(
     workspace: &WorkspaceRecord,
     local_host_id: crate::core::HostId,
@@ -204,7 +257,7 @@ pub(super) fn validate_registered_target(
     Ok(())
 }
 
-fn validate_git_removal_identity(
+pub(super) fn validate_git_removal_identity(
     intent: &WorktrunkRemovalIntent,
 ) -> Result<(PathBuf, PathBuf), String> {
     let surviving = canonical_repository(&intent.surviving_path)?;
@@ -169,6 +169,73 @@ fn successful_remove_owns_shared_registry_cleanup() {
         .is_none());
 }
 
+#[cfg(unix)]
+#[test]
+fn an_unregistered_main_worktree_is_the_survivor_for_a_linked_one() {
+    let fixture = RemovalFixture::new();
+    fixture
+        .registry
+        .remove_workspace(fixture.survivor.id)
+        .unwrap();
+    let executor = fixture.executor();
+    let preview = executor
+        .remove(
+            &fixture.registry,
+            fixture.target.id,
+            &fixture.target.root_path,
+            None,
+        )
+        .unwrap();
+    let HostServicePayload::WorktrunkApprovalRequired {
+        approval, command, ..
+    } = preview
+    else {
+        panic!("expected approval preview");
+    };
+    assert!(command.contains(&format!("-C {}", fixture.survivor.root_path)));
+
+    std::fs::write(&fixture.fail_remove, "fail\n").unwrap();
+    executor
+        .remove(
+            &fixture.registry,
+            fixture.target.id,
+            &fixture.target.root_path,
+            Some(&approval),
+        )
+        .unwrap_err();
+    let intent = fixture
+        .registry
+        .worktrunk_removal(fixture.target.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(intent.surviving_workspace_id, None);
+    assert_eq!(intent.surviving_path, fixture.survivor.root_path);
+
+    // Recovery lists from the recorded main worktree, not a registered one.
+    run_git(
+        Path::new(&fixture.survivor.root_path),
+        ["worktree", "remove", &fixture.target.root_path],
+    );
+    write_list(&fixture.list_json, &[&fixture.survivor.root_path]);
+    executor
+        .list(
+            &fixture.registry,
+            fixture.target.id,
+            &fixture.target.root_path,
+        )
+        .unwrap();
+    assert!(fixture
+        .registry
+        .workspace(fixture.target.id)
+        .unwrap()
+        .is_none());
+    assert!(fixture
+        .registry
+        .worktrunk_removal(fixture.target.id)
+        .unwrap()
+        .is_none());
+}
+
 #[cfg(unix)]
 #[test]
 fn list_reconciles_a_crash_after_remove_without_retrying_remove() {
@@ -259,6 +326,80 @@ fn remove_rejects_a_stale_client_path_before_preview() {
     assert!(!fixture.remove_marker.exists());
 }
 
+#[cfg(unix)]
+#[test]
+fn prune_merged_removes_clean_worktrees_whose_upstream_is_gone_under_one_approval() {
+    let fixture = RemovalFixture::new();
+    let target = Path::new(&fixture.target.root_path);
+    run_git(
+        target,
+        ["remote", "add", "origin", "/nonexistent/origin.git"],
+    );
+    run_git(target, ["config", "branch.feature.remote", "origin"]);
+    run_git(
+        target,
+        ["config", "branch.feature.merge", "refs/heads/feature"],
+    );
+    let executor = fixture.executor();
+    let prune = |approval: Option<&WorktrunkApprovalToken>| {
+        executor.prune_merged(
+            &fixture.registry,
+            fixture.survivor.id,
+            &fixture.survivor.root_path,
+            approval,
+        )
+    };
+
+    // Uncommitted work keeps the worktree out of the plan entirely.
+    std::fs::write(target.join("notes.txt"), "draft\n").unwrap();
+    assert!(matches!(
+        prune(None).unwrap(),
+        HostServicePayload::WorktrunkMutation {
+            outcome: WorktrunkMutationOutcome::Pruned { ref results }
+        } if results.is_empty()
+    ));
+    std::fs::remove_file(target.join("notes.txt")).unwrap();
+
+    let HostServicePayload::WorktrunkPruneApprovalRequired {
+        items, approval, ..
+    } = prune(None).unwrap()
+    else {
+        panic!("expected one reviewed prune plan");
+    };
+    assert_eq!(items.len(), 1, "the main worktree is never a candidate");
+    assert_eq!(items[0].workspace_id, fixture.target.id);
+    assert_eq!(items[0].branch, "feature");
+    assert_eq!(
+        items[0].reason,
+        crate::core::WorktrunkPruneReason::UpstreamGone {
+            upstream: "origin/feature".to_owned()
+        }
+    );
+    assert!(items[0].command.contains(&fixture.target.root_path));
+    assert!(!items[0].command.contains("--force"));
+    assert!(!fixture.remove_marker.exists());
+
+    let HostServicePayload::WorktrunkMutation {
+        outcome: WorktrunkMutationOutcome::Pruned { results },
+    } = prune(Some(&approval)).unwrap()
+    else {
+        panic!("expected per-item prune results");
+    };
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].error, None);
+    assert!(fixture.remove_marker.exists());
+    assert!(fixture
+        .registry
+        .workspace(fixture.target.id)
+        .unwrap()
+        .is_none());
+    assert!(fixture
+        .registry
+        .worktrunk_removal(fixture.target.id)
+        .unwrap()
+        .is_none());
+}
+
 #[cfg(unix)]
 #[test]
 fn dispatched_failure_requires_list_before_any_explicit_retry() {
@@ -1,16 +1,6 @@
 use std::process::ExitCode;
 
 fn main() -> ExitCode {
-    match run() {
-        Ok(()) => ExitCode::SUCCESS,
-        Err(error) => {
-            eprintln!("Blackpepper could not start: {error}");
-            ExitCode::FAILURE
-        }
-    }
-}
-
-fn run() -> Result<(), Box<dyn std::error::Error>> {
     let mut arguments = std::env::args();
     let program = arguments
         .next()
@@ -21,24 +11,49 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .map(str::to_owned)
         })
         .unwrap_or_else(|| "bp".to_owned());
-    match arguments.next().as_deref() {
-        Some("--version" | "-V" | "-v") if arguments.next().is_none() => {
+    let arguments = arguments.collect::<Vec<_>>();
+    match arguments.as_slice() {
+        [flag] if matches!(flag.as_str(), "--version" | "-V" | "-v") => {
             println!("blackpepper {}", blackpepper::BUILD_ID);
-            Ok(())
+            ExitCode::SUCCESS
         }
-        Some("--help" | "-h") if arguments.next().is_none() => {
-            println!("Blackpepper {}", blackpepper::BUILD_ID);
-            println!("Remote-first local and SSH agent workspaces backed by Zellij.");
-            println!();
-            println!("Usage: {program}");
-            println!();
-            println!("Commands are entered inside the client. Use :help to list them.");
-            Ok(())
+        [flag] if matches!(flag.as_str(), "--help" | "-h") => {
+            print_help(&program);
+            ExitCode::SUCCESS
         }
-        Some(argument) => Err(format!(
-            "unexpected argument {argument:?}; {program} has no command-line subcommands"
-        )
-        .into()),
-        None => blackpepper::client::run(),
+        [] => match blackpepper::client::run() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("Blackpepper could not start: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        _ => match blackpepper::client::run_script(&program, arguments) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{program}: {error}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+fn print_help(program: &str) {
+    println!("Blackpepper {}", blackpepper::BUILD_ID);
+    println!("Remote-first local and SSH agent workspaces backed by Zellij.");
+    println!();
+    println!("Usage: {program}");
+    println!("       {program} <command> [--json] [--workspace <name|id>]");
+    println!();
+    println!("Without a command, the interactive client opens. Use :help to list its commands.");
+    println!();
+    println!("Scripted commands act on this machine's workspaces:");
+    let width = blackpepper::client::SCRIPT_HELP
+        .iter()
+        .map(|(syntax, _)| syntax.len())
+        .max()
+        .unwrap_or(0);
+    for (syntax, note) in blackpepper::client::SCRIPT_HELP {
+        println!("  {syntax:width$}  {note}");
     }
 }
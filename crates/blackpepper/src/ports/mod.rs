@@ -5,12 +5,13 @@ mod probe;
 
 pub use model::{
     choose_initial_local_port, port_is_available, resolve_forward_target, target_is_ambiguous,
-    AttributionConfidence, ForwardState, ForwardStatus, PortListener, PortSnapshot,
-    ProbeCompleteness, RemotePortTarget,
+    AttributionConfidence, ForwardState, ForwardStatus, HttpLabel, PortListener, PortSnapshot,
+    ProbeCompleteness, RemotePortTarget, ReverseForwardState, SocketForwardState, SocketListener,
 };
 pub use probe::{
-    attribute_linux_cwds, failed_probe, parse_linux_ss, parse_macos_lsof, platform_probe,
-    ProbeCommand,
+    add_linux_unix_sockets, add_macos_unix_sockets, attribute_linux_cwds, failed_probe,
+    macos_listening_socket_paths, parse_linux_ss, parse_macos_lsof, platform_probe,
+    platform_socket_probe, platform_socket_state_probe, ProbeCommand,
 };
 
 #[cfg(test)]
@@ -45,6 +45,27 @@ pub enum AttributionConfidence {
     Unavailable,
 }
 
+/// What a workspace listener answered to one bounded `GET /`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpLabel {
+    pub status: u16,
+    pub title: Option<String>,
+    pub server: Option<String>,
+}
+
+impl HttpLabel {
+    /// The page title, else the server name; a non-success status leads so a
+    /// 404 or 502 is not mistaken for a working app.
+    pub fn label(&self) -> String {
+        let name = self.title.as_deref().or(self.server.as_deref());
+        match (name, (200..300).contains(&self.status)) {
+            (Some(name), true) => name.to_owned(),
+            (Some(name), false) => format!("{} {name}", self.status),
+            (None, _) => format!("HTTP {}", self.status),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PortListener {
     pub bind_address: String,
@@ -53,6 +74,10 @@ pub struct PortListener {
     pub process: Option<String>,
     pub workspace_path: Option<PathBuf>,
     pub attribution: AttributionConfidence,
+    /// Only set by an explicit `:ports`; periodic refreshes carry the
+    /// previous label forward instead of probing again.
+    #[serde(default)]
+    pub http: Option<HttpLabel>,
 }
 
 impl PortListener {
@@ -71,15 +96,59 @@ impl PortListener {
     pub fn bind_endpoint(&self) -> String {
         format_endpoint(&self.bind_address, self.port)
     }
+
+    /// The same socket as `other`, as seen by an earlier probe.
+    pub fn same_socket(&self, other: &PortListener) -> bool {
+        self.bind_address == other.bind_address && self.port == other.port && self.pid == other.pid
+    }
+}
+
+/// A listening Unix stream socket. Only filesystem paths are kept: abstract
+/// and unnamed sockets cannot be reached through an SSH forward.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SocketListener {
+    pub path: String,
+    pub pid: Option<u32>,
+    pub process: Option<String>,
+    pub workspace_path: Option<PathBuf>,
+    pub attribution: AttributionConfidence,
+}
+
+impl SocketListener {
+    /// The last path component, which is what people call the socket.
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PortSnapshot {
     pub listeners: Vec<PortListener>,
+    /// Older helpers report no sockets, which reads as none discovered.
+    #[serde(default)]
+    pub sockets: Vec<SocketListener>,
     pub completeness: ProbeCompleteness,
     pub warning: Option<String>,
 }
 
+impl PortSnapshot {
+    /// Keep the HTTP labels an earlier snapshot found for sockets that are
+    /// still the same process, since periodic refreshes do not probe.
+    pub fn keep_http_labels(&mut self, previous: &PortSnapshot) {
+        for listener in self
+            .listeners
+            .iter_mut()
+            .filter(|listener| listener.http.is_none())
+        {
+            listener.http = previous
+                .listeners
+                .iter()
+                .find(|earlier| earlier.same_socket(listener))
+                .and_then(|earlier| earlier.http.clone());
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ForwardStatus {
@@ -112,9 +181,30 @@ impl ForwardState {
         workspace_id: WorkspaceId,
         target: RemotePortTarget,
     ) -> std::io::Result<Self> {
-        let local_port = choose_initial_local_port(target.remote_port)?;
-        let requested_local_address =
-            SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), target.remote_port);
+        Self::with_local_port(host_id, workspace_id, target, None)
+    }
+
+    /// Like `new`, but listens on `local_port` when one is given. An explicit
+    /// port is never swapped for a free one: two hosts forwarded side by side
+    /// must keep the ports they were given.
+    pub fn with_local_port(
+        host_id: HostId,
+        workspace_id: WorkspaceId,
+        target: RemotePortTarget,
+        local_port: Option<u16>,
+    ) -> std::io::Result<Self> {
+        let preferred = local_port.unwrap_or(target.remote_port);
+        let requested_local_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), preferred);
+        let local_port = match local_port {
+            Some(port) if !port_is_available(port) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AddrInUse,
+                    format!("client loopback port {port} is already in use"),
+                ))
+            }
+            Some(port) => port,
+            None => choose_initial_local_port(preferred)?,
+        };
         Ok(Self {
             id: uuid::Uuid::new_v4(),
             host_id,
@@ -152,6 +242,99 @@ impl ForwardState {
     }
 }
 
+/// A port on the workspace host's loopback carried back to a service on the
+/// client, the reverse of [`ForwardState`]. `Direct` means the workspace host
+/// is the client itself and the two ports already coincide.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReverseForwardState {
+    pub id: uuid::Uuid,
+    pub host_id: HostId,
+    pub workspace_id: WorkspaceId,
+    pub remote_port: u16,
+    pub local_port: u16,
+    pub status: ForwardStatus,
+}
+
+impl ReverseForwardState {
+    pub fn new(
+        host_id: HostId,
+        workspace_id: WorkspaceId,
+        local_port: u16,
+        remote_port: u16,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4(),
+            host_id,
+            workspace_id,
+            remote_port,
+            local_port,
+            status: ForwardStatus::Active,
+        }
+    }
+
+    pub fn remote_endpoint(&self) -> String {
+        format_endpoint("127.0.0.1", self.remote_port)
+    }
+
+    pub fn local_endpoint(&self) -> String {
+        format_endpoint("127.0.0.1", self.local_port)
+    }
+
+    /// The host listener went away with the connection; the next restore
+    /// asks for the same remote port again.
+    pub fn mark_reconnecting(&mut self) {
+        if self.status != ForwardStatus::Direct {
+            self.status = ForwardStatus::Reconnecting;
+        }
+    }
+}
+
+/// A Unix socket on the workspace host reached through a socket path on the
+/// client. On the client's own machine the socket is already reachable, so
+/// the state is `Direct` and both paths coincide.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SocketForwardState {
+    pub id: uuid::Uuid,
+    pub host_id: HostId,
+    pub workspace_id: WorkspaceId,
+    pub remote_path: String,
+    pub local_path: PathBuf,
+    pub status: ForwardStatus,
+}
+
+impl SocketForwardState {
+    pub fn new(
+        host_id: HostId,
+        workspace_id: WorkspaceId,
+        remote_path: String,
+        local_path: PathBuf,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4(),
+            host_id,
+            workspace_id,
+            remote_path,
+            local_path,
+            status: ForwardStatus::Active,
+        }
+    }
+
+    pub fn remote_name(&self) -> &str {
+        self.remote_path
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.remote_path)
+    }
+
+    /// The client path is kept across reconnects; whatever connects to it
+    /// keeps working once the master listens there again.
+    pub fn mark_reconnecting(&mut self) {
+        if self.status != ForwardStatus::Direct {
+            self.status = ForwardStatus::Reconnecting;
+        }
+    }
+}
+
 /// Select one exact discovered listener. Port-only selection is accepted only
 /// when it cannot silently choose between interfaces or processes.
 pub fn resolve_forward_target<'a>(
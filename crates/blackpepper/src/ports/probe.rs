@@ -1,4 +1,5 @@
-use super::{AttributionConfidence, PortListener, PortSnapshot, ProbeCompleteness};
+use super::{AttributionConfidence, PortListener, PortSnapshot, ProbeCompleteness, SocketListener};
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,6 +24,33 @@ pub fn platform_probe() -> Result<ProbeCommand, String> {
     }
 }
 
+/// Unix stream listeners, probed separately so a failure here never hides the
+/// TCP list.
+pub fn platform_socket_probe() -> Result<ProbeCommand, String> {
+    if cfg!(target_os = "linux") {
+        Ok(ProbeCommand {
+            program: "ss",
+            args: &["-H", "-lxp"],
+        })
+    } else if cfg!(target_os = "macos") {
+        Ok(ProbeCommand {
+            program: "lsof",
+            args: &["-nP", "-U", "-Fpcn"],
+        })
+    } else {
+        Err("Unix socket discovery is supported on Linux and macOS only.".to_string())
+    }
+}
+
+/// Which of the sockets `platform_socket_probe` lists are listening, where
+/// that probe cannot say so itself.
+pub fn platform_socket_state_probe() -> Option<ProbeCommand> {
+    cfg!(target_os = "macos").then_some(ProbeCommand {
+        program: "netstat",
+        args: &["-anf", "unix"],
+    })
+}
+
 pub fn parse_linux_ss(stdout: &str, stderr: &str) -> PortSnapshot {
     let mut listeners = Vec::new();
     let mut rejected = 0_usize;
@@ -63,6 +91,7 @@ pub fn parse_linux_ss(stdout: &str, stderr: &str) -> PortSnapshot {
         } else {
             ProbeCompleteness::Full
         },
+        sockets: Vec::new(),
         warning,
     }
 }
@@ -119,6 +148,7 @@ fn listener(
         process,
         workspace_path: None,
         attribution: AttributionConfidence::Unavailable,
+        http: None,
     }
 }
 
@@ -138,6 +168,134 @@ fn value_after<'a>(input: &'a str, marker: &str) -> Option<&'a str> {
     Some(&tail[..end])
 }
 
+/// Add the stream rows of `ss -H -lxp` to `snapshot`. Datagram and seqpacket
+/// sockets, abstract names, and unnamed sockets are skipped, not rejected.
+pub fn add_linux_unix_sockets(snapshot: &mut PortSnapshot, stdout: &str) {
+    let mut rejected = 0_usize;
+    for line in stdout.lines().filter(|line| !line.trim().is_empty()) {
+        let (columns, processes) = match line.find(" users:((") {
+            Some(start) => line.split_at(start),
+            None => (line, ""),
+        };
+        let mut fields = columns.split_whitespace();
+        if fields.next().is_some_and(|netid| netid != "u_str") {
+            continue;
+        }
+        let Some(path) = (fields.next() == Some("LISTEN"))
+            .then(|| ss_unix_path(columns))
+            .flatten()
+        else {
+            rejected += 1;
+            continue;
+        };
+        if !path.starts_with('/') {
+            continue;
+        }
+        let processes = linux_processes(processes);
+        if processes.is_empty() {
+            snapshot.sockets.push(socket_listener(path, None, None));
+        }
+        for (pid, process) in processes {
+            snapshot
+                .sockets
+                .push(socket_listener(path, Some(pid), process));
+        }
+    }
+    finish_sockets(snapshot, rejected, "ss unix socket row(s)");
+}
+
+/// The local address of an `ss -x` row. A path may contain spaces, so it is
+/// whatever lies between the four leading columns and the local inode, peer
+/// address and peer inode that end the row.
+fn ss_unix_path(columns: &str) -> Option<&str> {
+    let mut rest = columns.trim();
+    for _ in 0..4 {
+        rest = rest.split_once(char::is_whitespace)?.1.trim_start();
+    }
+    for _ in 0..3 {
+        rest = rest.rsplit_once(char::is_whitespace)?.0.trim_end();
+    }
+    (!rest.is_empty()).then_some(rest)
+}
+
+/// The paths of bound, unconnected stream sockets in `netstat -anf unix`.
+/// lsof has no listening state for Unix sockets, and a server's accepted
+/// connections and some clients report a path too, so this is what tells a
+/// listener apart.
+pub fn macos_listening_socket_paths(stdout: &str) -> BTreeSet<String> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            // Address Type Recv-Q Send-Q Inode Conn Refs Nextref Addr
+            let mut rest = line.trim();
+            let mut fields = Vec::with_capacity(8);
+            for _ in 0..8 {
+                let (field, tail) = rest.split_once(char::is_whitespace)?;
+                fields.push(field);
+                rest = tail.trim_start();
+            }
+            (fields[1] == "stream" && fields[4] != "0" && fields[5] == "0")
+                .then_some(rest)
+                .filter(|path| path.starts_with('/'))
+                .map(str::to_owned)
+        })
+        .collect()
+}
+
+/// Add the socket paths of `lsof -U -Fpcn` that `listening` names to
+/// `snapshot`, once per process.
+pub fn add_macos_unix_sockets(
+    snapshot: &mut PortSnapshot,
+    stdout: &str,
+    listening: &BTreeSet<String>,
+) {
+    let mut pid = None;
+    let mut process = None;
+    let mut rejected = 0_usize;
+    for line in stdout.lines() {
+        match line.chars().next() {
+            Some('p') => pid = line[1..].parse().ok(),
+            Some('c') => process = Some(line[1..].to_string()),
+            Some('n') if listening.contains(&line[1..]) => {
+                let path = &line[1..];
+                if !snapshot
+                    .sockets
+                    .iter()
+                    .any(|socket| socket.pid == pid && socket.path == path)
+                {
+                    snapshot
+                        .sockets
+                        .push(socket_listener(path, pid, process.clone()));
+                }
+            }
+            Some('n' | 'f') => {}
+            Some(_) if !line.trim().is_empty() => rejected += 1,
+            _ => {}
+        }
+    }
+    finish_sockets(snapshot, rejected, "lsof unix socket record(s)");
+}
+
+fn socket_listener(path: &str, pid: Option<u32>, process: Option<String>) -> SocketListener {
+    SocketListener {
+        path: path.to_string(),
+        pid,
+        process,
+        workspace_path: None,
+        attribution: AttributionConfidence::Unavailable,
+    }
+}
+
+fn finish_sockets(snapshot: &mut PortSnapshot, rejected: usize, what: &str) {
+    snapshot
+        .sockets
+        .sort_by(|left, right| left.path.cmp(&right.path));
+    if rejected != 0 {
+        snapshot.completeness = ProbeCompleteness::Partial;
+        append_warning(snapshot, &format!("Could not parse {rejected} {what}."));
+    }
+}
+
 pub fn parse_macos_lsof(stdout: &str, stderr: &str) -> PortSnapshot {
     let mut listeners = Vec::new();
     let mut pid = None;
@@ -158,6 +316,7 @@ pub fn parse_macos_lsof(stdout: &str, stderr: &str) -> PortSnapshot {
                         process: process.clone(),
                         workspace_path: None,
                         attribution: AttributionConfidence::Unavailable,
+                        http: None,
                     });
                 } else {
                     rejected += 1;
@@ -188,6 +347,7 @@ pub fn parse_macos_lsof(stdout: &str, stderr: &str) -> PortSnapshot {
         } else {
             ProbeCompleteness::Full
         },
+        sockets: Vec::new(),
         warning,
     }
 }
@@ -207,19 +367,25 @@ fn attribute_linux_cwds_with(
         return;
     }
     let mut failures = 0_usize;
-    for listener in &mut snapshot.listeners {
-        let Some(pid) = listener.pid else {
-            continue;
-        };
-        let Ok(cwd) = resolve(pid) else {
+    let mut attribute = |pid: Option<u32>| -> Option<PathBuf> {
+        let Ok(cwd) = resolve(pid?) else {
             failures += 1;
-            continue;
+            return None;
         };
-        if let Some(workspace) = longest_containing_path(&cwd, workspaces) {
-            listener.workspace_path = Some(workspace.clone());
+        longest_containing_path(&cwd, workspaces).cloned()
+    };
+    for listener in &mut snapshot.listeners {
+        if let Some(workspace) = attribute(listener.pid) {
+            listener.workspace_path = Some(workspace);
             listener.attribution = AttributionConfidence::ExactCwd;
         }
     }
+    for socket in &mut snapshot.sockets {
+        if let Some(workspace) = attribute(socket.pid) {
+            socket.workspace_path = Some(workspace);
+            socket.attribution = AttributionConfidence::ExactCwd;
+        }
+    }
     if failures != 0 {
         snapshot.completeness = ProbeCompleteness::Partial;
         append_warning(
@@ -249,6 +415,7 @@ pub fn failed_probe(message: impl Into<String>) -> PortSnapshot {
     PortSnapshot {
         listeners: Vec::new(),
         completeness: ProbeCompleteness::Failed,
+        sockets: Vec::new(),
         warning: Some(message.into()),
     }
 }
@@ -268,6 +435,70 @@ mod tests {
         assert!(macos.warning.unwrap().contains("Could not parse"));
     }
 
+    #[test]
+    fn unix_stream_listeners_keep_their_path_and_owner() {
+        let mut snapshot = parse_linux_ss("", "");
+        add_linux_unix_sockets(
+            &mut snapshot,
+            concat!(
+                "u_str LISTEN 0 244 /run/postgresql/.s.PGSQL.5432 31337 * 0 users:((\"postgres\",pid=42,fd=6))\n",
+                "u_str LISTEN 0 4096 @/tmp/.X11-unix/X0 1234 * 0\n",
+                "u_dgr UNCONN 0 0 /run/systemd/notify 99 * 0\n",
+                "u_str LISTEN 0 128    /home/me/My Projects/app/dev.sock 4242   * 0    users:((\"node\",pid=7,fd=20))\n",
+            ),
+        );
+        assert_eq!(snapshot.completeness, ProbeCompleteness::Full);
+        assert_eq!(snapshot.sockets.len(), 2);
+        assert_eq!(
+            snapshot.sockets[0].path,
+            "/home/me/My Projects/app/dev.sock"
+        );
+        assert_eq!(snapshot.sockets[0].name(), "dev.sock");
+        assert_eq!(snapshot.sockets[0].pid, Some(7));
+        snapshot.sockets.remove(0);
+        let socket = &snapshot.sockets[0];
+        assert_eq!(socket.path, "/run/postgresql/.s.PGSQL.5432");
+        assert_eq!(socket.name(), ".s.PGSQL.5432");
+        assert_eq!(socket.pid, Some(42));
+        assert_eq!(socket.process.as_deref(), Some("postgres"));
+
+        // The daemon's listener and an accepted connection share the path, as
+        // does the client that bound its own end; only the bound, unconnected
+        // stream socket is listening.
+        let listening = macos_listening_socket_paths(concat!(
+            "Active LOCAL (UNIX) domain sockets\n",
+            "Address          Type   Recv-Q Send-Q            Inode             Conn             Refs          Nextref Addr\n",
+            "3b1eb2f0b8bd5f8f stream      0      0                0 3b1eb2f0b8bd5e07                0                0 /Users/me/.colima/client.sock\n",
+            "3b1eb2f0b8bd55cf stream      0      0 3b1eb2f0b8c33a0f                0                0                0 /var/run/docker.sock\n",
+            "3b1eb2f0b8bd5e07 stream      0      0 3b1eb2f0b8c33b1f 3b1eb2f0b8bd5f8f                0                0 /Users/me/.colima/client.sock\n",
+            "3b1eb2f0b8bd5aa1 dgram       0      0 3b1eb2f0b8c3aa00                0                0                0 /var/run/syslog\n",
+            "3b1eb2f0b8bd5bb2 stream      0      0 3b1eb2f0b8c3bb00                0                0                0 /Users/me/My Sockets/dev.sock\n",
+        ));
+        assert_eq!(
+            listening.iter().map(String::as_str).collect::<Vec<_>>(),
+            ["/Users/me/My Sockets/dev.sock", "/var/run/docker.sock"]
+        );
+        let mut macos = parse_macos_lsof("", "");
+        add_macos_unix_sockets(
+            &mut macos,
+            concat!(
+                "p7\ncdockerd\nf3\nn/var/run/docker.sock\nf4\nn->0x1234\nf5\nn/var/run/docker.sock\n",
+                "p9\ncssh\nf6\nn/Users/me/.colima/client.sock\nf7\nn/var/run/syslog\n",
+            ),
+            &listening,
+        );
+        assert_eq!(macos.sockets.len(), 1);
+        assert_eq!(macos.sockets[0].pid, Some(7));
+
+        attribute_linux_cwds_with(&mut snapshot, &[PathBuf::from("/srv/app")], |_| {
+            Ok(PathBuf::from("/srv/app/db"))
+        });
+        assert_eq!(
+            snapshot.sockets[0].workspace_path.as_deref(),
+            Some(Path::new("/srv/app"))
+        );
+    }
+
     #[test]
     fn denied_linux_cwd_attribution_is_visible() {
         let mut snapshot = PortSnapshot {
@@ -278,6 +509,7 @@ mod tests {
                 Some("api".to_string()),
             )],
             completeness: ProbeCompleteness::Full,
+            sockets: Vec::new(),
             warning: None,
         };
         attribute_linux_cwds_with(&mut snapshot, &[PathBuf::from("/srv/app")], |_| {
@@ -1,6 +1,6 @@
 use super::{
     parse_linux_ss, parse_macos_lsof, resolve_forward_target, AttributionConfidence, ForwardState,
-    ForwardStatus, PortListener, ProbeCompleteness, RemotePortTarget,
+    ForwardStatus, HttpLabel, PortListener, PortSnapshot, ProbeCompleteness, RemotePortTarget,
 };
 use std::net::{Ipv4Addr, TcpListener};
 
@@ -81,6 +81,31 @@ fn initial_forward_falls_back_but_reconnect_does_not_change_url() {
     drop(blocker);
 }
 
+#[test]
+fn an_explicit_local_port_is_kept_or_refused_never_replaced() {
+    let free = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let free_port = free.local_addr().unwrap().port();
+    drop(free);
+    let forward = |local_port| {
+        ForwardState::with_local_port(
+            crate::core::HostId::new(),
+            crate::core::WorkspaceId::new(),
+            RemotePortTarget::from_bind_address("127.0.0.1", 3000).unwrap(),
+            Some(local_port),
+        )
+    };
+    let chosen = forward(free_port).unwrap();
+    assert_eq!(chosen.remote_port, 3000);
+    assert_eq!(chosen.requested_local_address.port(), free_port);
+    assert_eq!(chosen.local_address.port(), free_port);
+
+    let occupied = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let occupied_port = occupied.local_addr().unwrap().port();
+    let error = forward(occupied_port).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::AddrInUse);
+    assert!(error.to_string().contains(&occupied_port.to_string()));
+}
+
 #[test]
 fn listener_targets_preserve_specific_addresses_and_normalize_wildcards() {
     let target = |address, port| RemotePortTarget::from_bind_address(address, port).unwrap();
@@ -133,6 +158,34 @@ fn wildcard_and_specific_interface_overlap_is_rejected() {
     assert!(error.contains("overlapping listener"));
 }
 
+#[test]
+fn refreshed_listeners_keep_labels_only_while_the_process_is_the_same() {
+    let mut labelled = listener("127.0.0.1", 3000, Some(40));
+    labelled.http = Some(HttpLabel {
+        status: 200,
+        title: Some("Billing".to_owned()),
+        server: None,
+    });
+    let previous = PortSnapshot {
+        listeners: vec![labelled.clone()],
+        sockets: Vec::new(),
+        completeness: ProbeCompleteness::Full,
+        warning: None,
+    };
+    let mut refreshed = PortSnapshot {
+        listeners: vec![
+            listener("127.0.0.1", 3000, Some(40)),
+            listener("127.0.0.1", 3000, Some(41)),
+        ],
+        ..previous.clone()
+    };
+
+    refreshed.keep_http_labels(&previous);
+
+    assert_eq!(refreshed.listeners[0].http, labelled.http);
+    assert_eq!(refreshed.listeners[1].http, None);
+}
+
 fn listener(address: &str, port: u16, pid: Option<u32>) -> PortListener {
     PortListener {
         bind_address: address.to_string(),
@@ -141,5 +194,6 @@ fn listener(address: &str, port: u16, pid: Option<u32>) -> PortListener {
         process: None,
         workspace_path: None,
         attribution: AttributionConfidence::Unavailable,
+        http: None,
     }
 }
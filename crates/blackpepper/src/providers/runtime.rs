@@ -4,8 +4,9 @@
 mod assets;
 mod integrations;
 
+use crate::agent_status::ProviderName;
 use crate::core::{AgentRunId, PaneId, WorkspaceId};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
@@ -19,39 +20,67 @@ pub const OPENCODE_HEARTBEAT_INTERVAL_MS: u64 = 2_000;
 pub const OPENCODE_HEALTH_STALE_AFTER_MS: u64 = 10_000;
 pub const AGENT_RUN_ID_ENV: &str = "BLACKPEPPER_AGENT_RUN_ID";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProviderKind {
     Codex,
     Claude,
     OpenCode,
+    /// A `[providers.<name>]` entry. Its program and arguments come from
+    /// configuration, so the launch built here carries only Blackpepper's
+    /// environment.
+    Custom {
+        name: ProviderName,
+        integration: ProviderIntegration,
+    },
 }
 
 impl ProviderKind {
-    pub fn command(self) -> &'static str {
+    /// The program looked up on the host. A configured provider may name
+    /// another one.
+    pub fn command(&self) -> &str {
         match self {
             Self::Codex => "codex",
             Self::Claude => "claude",
             Self::OpenCode => "opencode",
+            Self::Custom { name, .. } => name.as_str(),
         }
     }
 
     pub fn needs_input_capability(self) -> &'static str {
         match self {
             Self::OpenCode => "full",
-            Self::Codex | Self::Claude => "partial",
+            Self::Codex | Self::Claude | Self::Custom { .. } => "partial",
         }
     }
 
-    pub fn display_name(self) -> &'static str {
+    pub fn display_name(&self) -> &str {
         match self {
             Self::Codex => "Codex",
             Self::Claude => "Claude Code",
             Self::OpenCode => "OpenCode",
+            Self::Custom { name, .. } => name.as_str(),
         }
     }
 }
 
+/// How a configured provider reports its lifecycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderIntegration {
+    /// Status comes from the pane's process and screen blockers only. The
+    /// agent is never reported idle while it runs, so `:agent send` cannot
+    /// prompt it.
+    #[default]
+    None,
+    /// The provider runs `$BLACKPEPPER_HOOK_COMMAND` with a JSON event on
+    /// stdin, the way Codex and Claude Code hooks do.
+    Hooks,
+}
+
+/// The environment variable holding the hook command for a configured
+/// provider with `integration = "hooks"`.
+pub const HOOK_COMMAND_ENV: &str = "BLACKPEPPER_HOOK_COMMAND";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ManagedAsset {
     pub path: PathBuf,
@@ -76,6 +105,18 @@ impl ProviderLaunch {
         Ok(())
     }
 
+    /// False when no integration will ever report health, so there is no
+    /// handshake to wait for.
+    pub fn reports_health(&self) -> bool {
+        !matches!(
+            self.provider,
+            ProviderKind::Custom {
+                integration: ProviderIntegration::None,
+                ..
+            }
+        )
+    }
+
     /// Return a non-interactive command that makes the provider parse the
     /// launch-scoped integration without starting an agent session. The real
     /// health handshake still has to arrive after the interactive process
@@ -87,7 +128,8 @@ impl ProviderLaunch {
             ProviderKind::Claude => args.push("doctor".to_string()),
             // OpenCode does not expose a stable config-validation command. Its
             // managed plugin emits an explicit fail-closed runtime handshake.
-            ProviderKind::OpenCode => return None,
+            // A configured provider's hooks are its own to validate.
+            ProviderKind::OpenCode | ProviderKind::Custom { .. } => return None,
         }
         Some(args)
     }
@@ -103,6 +145,9 @@ impl ProviderLaunch {
             ProviderKind::OpenCode => format!(
                 "OpenCode did not complete Blackpepper's managed-plugin handshake within {INTEGRATION_HEALTH_TIMEOUT_SECS}s in Zellij tab {tab_id}. Authenticate OpenCode or inspect its plugin logs on this host, then retry."
             ),
+            ProviderKind::Custom { name, .. } => format!(
+                "{name} did not send a SessionStart event through ${HOOK_COMMAND_ENV} within {INTEGRATION_HEALTH_TIMEOUT_SECS}s in Zellij tab {tab_id}. Check that its hooks run that command, then close the tab and retry."
+            ),
         }
     }
 }
@@ -163,6 +208,12 @@ pub fn build_launch(
                 }],
             )
         }
+        ProviderKind::Custom { integration, .. } => {
+            if integration == ProviderIntegration::Hooks {
+                env.insert(HOOK_COMMAND_ENV.to_string(), hook_command);
+            }
+            (Vec::new(), Vec::new())
+        }
     };
     Ok(ProviderLaunch {
         provider,
@@ -171,7 +222,9 @@ pub fn build_launch(
         env,
         assets,
         health_event: match provider {
-            ProviderKind::Codex | ProviderKind::Claude => "SessionStart",
+            ProviderKind::Codex | ProviderKind::Claude | ProviderKind::Custom { .. } => {
+                "SessionStart"
+            }
             ProviderKind::OpenCode => "blackpepper.integration.ready",
         },
     })
@@ -6,11 +6,8 @@ pub(super) fn hook_command(provider: ProviderKind, helper: &Path) -> String {
     format!(
         "{} agent-event --provider {} --workspace-id \"$BLACKPEPPER_WORKSPACE_ID\" --run-id \"$BLACKPEPPER_AGENT_RUN_ID\" --pane-id \"$BLACKPEPPER_PANE_ID\"",
         quote_posix(&helper.to_string_lossy()),
-        match provider {
-            ProviderKind::Codex => "codex",
-            ProviderKind::Claude => "claude",
-            ProviderKind::OpenCode => "opencode",
-        }
+        // Provider names are restricted to lowercase letters, digits and '-'.
+        provider.command()
     )
 }
 
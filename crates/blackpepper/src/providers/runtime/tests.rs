@@ -106,6 +106,34 @@ fn opencode_plugin_only_forwards_compact_fields() {
     assert!(launch.preflight_args().is_none());
 }
 
+#[test]
+fn configured_providers_get_the_hook_command_only_when_they_declare_hooks() {
+    let name = crate::agent_status::ProviderName::new("gemini").unwrap();
+    let build = |integration| {
+        build_launch(
+            ProviderKind::Custom { name, integration },
+            WorkspaceId::new(),
+            AgentRunId::new(),
+            PaneId::new(),
+            Path::new("/opt/bp-host"),
+            Path::new("/state/integrations"),
+        )
+        .unwrap()
+    };
+
+    let hooks = build(ProviderIntegration::Hooks);
+    assert_eq!(hooks.program, "gemini");
+    assert!(hooks.args.is_empty() && hooks.assets.is_empty());
+    assert!(hooks.env[HOOK_COMMAND_ENV].contains("agent-event --provider gemini"));
+    assert!(hooks.reports_health());
+    assert!(hooks.preflight_args().is_none());
+
+    let plain = build(ProviderIntegration::None);
+    assert!(!plain.env.contains_key(HOOK_COMMAND_ENV));
+    assert!(plain.env.contains_key(AGENT_RUN_ID_ENV));
+    assert!(!plain.reports_health());
+}
+
 #[test]
 fn managed_assets_are_private_and_atomic() {
     let temp = TempDir::new().unwrap();
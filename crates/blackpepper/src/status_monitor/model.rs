@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::agent_status::{
-    BlockerConfidence, BlockerExplain, BlockerObservation, IntegrationHealth, Provider,
+    BlockerAnswer, BlockerConfidence, BlockerExplain, BlockerManifestSource, BlockerObservation,
+    IntegrationHealth, Provider,
 };
 use crate::core::{AgentRunId, HostId, PaneId, WorkspaceId};
 
@@ -30,6 +31,8 @@ pub enum BlockerChange {
         rule_id: String,
         confidence: BlockerConfidence,
         priority: i32,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        answers: Vec<BlockerAnswer>,
     },
     Cleared,
 }
@@ -49,6 +52,8 @@ pub struct BlockerTransition {
     pub sequence: u64,
     pub observed_at_ms: u64,
     pub source: BlockerSource,
+    #[serde(default)]
+    pub manifest: BlockerManifestSource,
     pub manifest_version: String,
     pub state: BlockerChange,
 }
@@ -65,12 +70,15 @@ impl BlockerTransition {
                     rule_id,
                     confidence,
                     priority,
+                    answers,
                 } => Some(BlockerExplain {
                     provider: self.provider,
+                    manifest: self.manifest,
                     manifest_version: self.manifest_version.clone(),
                     rule_id: rule_id.clone(),
                     confidence: *confidence,
                     priority: *priority,
+                    answers: answers.clone(),
                 }),
                 BlockerChange::Cleared => None,
             },
@@ -33,14 +33,30 @@ impl ViewportBlockerMonitor {
         last_sequence: u64,
     ) -> Result<Self, BlockerManifestError> {
         let overlay = BlockerOverlay::bundled(context.provider)?;
-        Ok(Self {
+        Ok(Self::with_overlay_after(
+            context,
+            zellij_pane_id,
+            overlay,
+            last_sequence,
+        ))
+    }
+
+    /// Resume with an overlay the caller loaded, such as a configured
+    /// provider's own manifest.
+    pub fn with_overlay_after(
+        context: MonitorContext,
+        zellij_pane_id: impl Into<String>,
+        overlay: BlockerOverlay,
+        last_sequence: u64,
+    ) -> Self {
+        Self {
             context,
             zellij_pane_id: zellij_pane_id.into(),
             overlay,
             last_match: None,
             current: None,
             sequence: last_sequence,
-        })
+        }
     }
 
     pub fn zellij_pane_id(&self) -> &str {
@@ -106,6 +122,7 @@ impl ViewportBlockerMonitor {
                 rule_id: blocker.rule_id.clone(),
                 confidence: blocker.confidence,
                 priority: blocker.priority,
+                answers: blocker.answers.clone(),
             },
             None => BlockerChange::Cleared,
         };
@@ -124,7 +141,16 @@ impl ViewportBlockerMonitor {
             sequence: self.sequence,
             observed_at_ms,
             source: BlockerSource::ZellijViewport,
-            manifest_version: self.overlay.version().to_string(),
+            // A visible blocker names the manifest its rule came from; a
+            // cleared one names the base manifest.
+            manifest: self
+                .current
+                .as_ref()
+                .map_or(self.overlay.source(), |blocker| blocker.manifest),
+            manifest_version: self.current.as_ref().map_or_else(
+                || self.overlay.version().to_string(),
+                |blocker| blocker.manifest_version.clone(),
+            ),
             state,
         }
     }
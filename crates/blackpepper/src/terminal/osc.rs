@@ -9,6 +9,7 @@ mod notification;
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use notification::notification_sequence;
+pub(crate) use notification::{osc777_notification, osc9_notification};
 
 const MAX_CLIPBOARD_BYTES: usize = 1024 * 1024;
 // OSC 777 carries two independently capped UTF-8 fields. Four bytes per
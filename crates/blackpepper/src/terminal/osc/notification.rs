@@ -27,6 +27,24 @@ pub(super) fn notification_sequence(command: &[u8]) -> Option<Vec<u8>> {
     })
 }
 
+/// OSC 9 for a notification Blackpepper raises itself. The format has no
+/// title field, so callers fold the title into the text.
+pub(crate) fn osc9_notification(text: &str) -> Vec<u8> {
+    format!("\x1b]9;{}\x07", sanitize_field(text)).into_bytes()
+}
+
+/// OSC 777 for a notification Blackpepper raises itself, sanitized exactly
+/// like a forwarded one so a workspace name cannot smuggle an escape out.
+/// A `;` would end the title early, so the title spells it `:`.
+pub(crate) fn osc777_notification(title: &str, body: &str) -> Vec<u8> {
+    format!(
+        "\x1b]777;notify;{};{}\x07",
+        sanitize_field(title).replace(';', ":"),
+        sanitize_field(body)
+    )
+    .into_bytes()
+}
+
 fn sanitize_field(field: &str) -> String {
     field
         .chars()
@@ -200,3 +200,15 @@ fn notification_control_bytes_are_stripped_before_forwarding() {
         )]
     );
 }
+
+#[test]
+fn client_raised_notifications_are_sanitized_like_forwarded_ones() {
+    assert_eq!(
+        osc777_notification("[bp] api\x1b]0;x", "codex asks\x07"),
+        b"\x1b]777;notify;[bp] api]0:x;codex asks\x07".to_vec()
+    );
+    assert_eq!(
+        osc9_notification("[bp] api: codex done"),
+        b"\x1b]9;[bp] api: codex done\x07".to_vec()
+    );
+}
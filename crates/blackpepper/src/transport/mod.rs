@@ -98,6 +98,66 @@ impl LocalForward {
     }
 }
 
+/// A listener on the workspace host's loopback backed by a port on the client.
+/// The remote side always binds 127.0.0.1, so nothing beyond the host itself
+/// can reach the client through it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RemoteForward {
+    pub remote_port: u16,
+    pub local_host: String,
+    pub local_port: u16,
+}
+
+impl RemoteForward {
+    pub fn loopback(remote_port: u16, local_port: u16) -> Self {
+        Self {
+            remote_port,
+            local_host: "127.0.0.1".to_string(),
+            local_port,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), TransportError> {
+        if self.local_port == 0 || self.remote_port == 0 {
+            return Err(TransportError::InvalidForward(
+                "local and remote ports must be non-zero".to_string(),
+            ));
+        }
+        if self.local_host.trim().is_empty() || self.local_host.chars().any(char::is_whitespace) {
+            return Err(TransportError::InvalidForward(
+                "local host must be a non-empty host name or address".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A Unix socket on the client backed by a Unix socket on the workspace host.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SocketForward {
+    pub local_path: std::path::PathBuf,
+    pub remote_path: String,
+}
+
+impl SocketForward {
+    pub fn validate(&self) -> Result<(), TransportError> {
+        // OpenSSH splits `-L` specifications on ':' and cannot quote it.
+        let usable = |path: &str| {
+            path.starts_with('/')
+                && !path.chars().any(|character| {
+                    character == ':' || character.is_whitespace() || character == '\0'
+                })
+        };
+        let local = self.local_path.to_str().unwrap_or_default();
+        if !usable(local) || !usable(&self.remote_path) {
+            return Err(TransportError::InvalidForward(
+                "socket paths must be absolute and contain no ':' or whitespace".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Common execution surface used by the workspace runtime.
 pub trait HostTransport {
     fn kind(&self) -> HostKind;
@@ -122,6 +182,39 @@ pub trait HostTransport {
 
     fn cancel_local_forward(&mut self, forward: &LocalForward) -> Result<(), TransportError>;
 
+    /// Listen on the host's loopback and carry connections back to the client.
+    fn forward_remote_port(
+        &mut self,
+        forward: RemoteForward,
+    ) -> Result<RemoteForward, TransportError> {
+        let _ = forward;
+        Err(TransportError::Unsupported(
+            "this transport cannot forward host ports back to the client",
+        ))
+    }
+
+    fn cancel_remote_forward(&mut self, forward: &RemoteForward) -> Result<(), TransportError> {
+        let _ = forward;
+        Err(TransportError::Unsupported(
+            "this transport cannot forward host ports back to the client",
+        ))
+    }
+
+    /// Listen on a client socket path and carry connections to a host socket.
+    fn forward_socket(&mut self, forward: SocketForward) -> Result<SocketForward, TransportError> {
+        let _ = forward;
+        Err(TransportError::Unsupported(
+            "this transport cannot forward Unix sockets",
+        ))
+    }
+
+    fn cancel_socket_forward(&mut self, forward: &SocketForward) -> Result<(), TransportError> {
+        let _ = forward;
+        Err(TransportError::Unsupported(
+            "this transport cannot forward Unix sockets",
+        ))
+    }
+
     fn exec(&mut self, command: &HostCommand) -> Result<CommandOutput, TransportError> {
         self.spawn_exec(command)?.wait_with_output()
     }
@@ -166,6 +259,8 @@ pub enum TransportError {
         cancellation_error: Option<String>,
     },
     ForwardNotOwned(LocalForward),
+    RemoteForwardNotOwned(RemoteForward),
+    SocketForwardNotOwned(SocketForward),
     CommandFailed {
         operation: String,
         status: Option<i32>,
@@ -242,6 +337,16 @@ impl fmt::Display for TransportError {
                 "Blackpepper did not create the local forward on port {}",
                 forward.local_port
             ),
+            Self::RemoteForwardNotOwned(forward) => write!(
+                formatter,
+                "Blackpepper did not create the host forward on port {}",
+                forward.remote_port
+            ),
+            Self::SocketForwardNotOwned(forward) => write!(
+                formatter,
+                "Blackpepper did not create the socket forward at {}",
+                forward.local_path.display()
+            ),
             Self::CommandFailed {
                 operation,
                 status,
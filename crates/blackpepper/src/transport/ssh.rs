@@ -7,7 +7,7 @@ use portable_pty::PtySize;
 use super::ssh_command::{self, ControlAction};
 use super::{
     CommandOutput, ControlSocket, HostCommand, HostKind, HostTransport, LocalForward, ProcessSpec,
-    PtyProcess, RunningCommand, SshConfig, TransportError,
+    PtyProcess, RemoteForward, RunningCommand, SocketForward, SshConfig, TransportError,
 };
 
 mod background;
@@ -26,6 +26,8 @@ pub struct SshTransport {
     master: Option<PtyProcess>,
     state: ConnectionState,
     forwards: BTreeSet<LocalForward>,
+    remote_forwards: BTreeSet<RemoteForward>,
+    socket_forwards: BTreeSet<SocketForward>,
 }
 
 impl fmt::Debug for SshTransport {
@@ -36,6 +38,8 @@ impl fmt::Debug for SshTransport {
             .field("socket", &self.socket)
             .field("state", &self.state)
             .field("forwards", &self.forwards)
+            .field("remote_forwards", &self.remote_forwards)
+            .field("socket_forwards", &self.socket_forwards)
             .finish_non_exhaustive()
     }
 }
@@ -49,6 +53,8 @@ impl SshTransport {
             master: None,
             state: ConnectionState::Disconnected,
             forwards: BTreeSet::new(),
+            remote_forwards: BTreeSet::new(),
+            socket_forwards: BTreeSet::new(),
         })
     }
 
@@ -97,6 +103,8 @@ impl SshTransport {
                 status: Some(exit.code),
             };
             self.forwards.clear();
+            self.remote_forwards.clear();
+            self.socket_forwards.clear();
             return Ok(self.state.clone());
         }
 
@@ -105,6 +113,8 @@ impl SshTransport {
         if !self.socket()?.path().exists() {
             self.state = if was_ready {
                 self.forwards.clear();
+                self.remote_forwards.clear();
+                self.socket_forwards.clear();
                 ConnectionState::Failed { status: None }
             } else {
                 ConnectionState::Connecting
@@ -173,6 +183,8 @@ impl SshTransport {
         }
         self.state = ConnectionState::Failed { status: None };
         self.forwards.clear();
+        self.remote_forwards.clear();
+        self.socket_forwards.clear();
         Err(command_failure("SSH control-master preflight", output))
     }
 
@@ -188,11 +200,15 @@ impl SshTransport {
                 status: Some(exit.code),
             };
             self.forwards.clear();
+            self.remote_forwards.clear();
+            self.socket_forwards.clear();
             return Err(TransportError::MasterExited(Some(exit.code)));
         }
         if !self.socket()?.path().exists() {
             self.state = ConnectionState::Failed { status: None };
             self.forwards.clear();
+            self.remote_forwards.clear();
+            self.socket_forwards.clear();
             return Err(TransportError::MasterExited(None));
         }
         Ok(())
@@ -209,6 +225,8 @@ impl SshTransport {
         self.master.take();
         self.socket.take();
         self.forwards.clear();
+        self.remote_forwards.clear();
+        self.socket_forwards.clear();
         self.state = ConnectionState::Disconnected;
     }
 }
@@ -268,6 +286,66 @@ impl HostTransport for SshTransport {
         self.forwards.remove(forward);
         Ok(())
     }
+
+    fn forward_remote_port(
+        &mut self,
+        forward: RemoteForward,
+    ) -> Result<RemoteForward, TransportError> {
+        self.ensure_ready()?;
+        forward.validate()?;
+        if self.remote_forwards.contains(&forward) {
+            return Ok(forward);
+        }
+        let output = self.run_control(ControlAction::ForwardRemote(&forward))?;
+        if !output.success {
+            return Err(command_failure("SSH remote port forwarding", output));
+        }
+        self.remote_forwards.insert(forward.clone());
+        Ok(forward)
+    }
+
+    fn cancel_remote_forward(&mut self, forward: &RemoteForward) -> Result<(), TransportError> {
+        self.ensure_ready()?;
+        if !self.remote_forwards.contains(forward) {
+            return Err(TransportError::RemoteForwardNotOwned(forward.clone()));
+        }
+        let output = self.run_control(ControlAction::CancelRemote(forward))?;
+        if !output.success {
+            return Err(command_failure(
+                "SSH remote port-forward cancellation",
+                output,
+            ));
+        }
+        self.remote_forwards.remove(forward);
+        Ok(())
+    }
+
+    fn forward_socket(&mut self, forward: SocketForward) -> Result<SocketForward, TransportError> {
+        self.ensure_ready()?;
+        forward.validate()?;
+        if self.socket_forwards.contains(&forward) {
+            return Ok(forward);
+        }
+        let output = self.run_control(ControlAction::ForwardSocket(&forward))?;
+        if !output.success {
+            return Err(command_failure("SSH socket forwarding", output));
+        }
+        self.socket_forwards.insert(forward.clone());
+        Ok(forward)
+    }
+
+    fn cancel_socket_forward(&mut self, forward: &SocketForward) -> Result<(), TransportError> {
+        self.ensure_ready()?;
+        if !self.socket_forwards.contains(forward) {
+            return Err(TransportError::SocketForwardNotOwned(forward.clone()));
+        }
+        let output = self.run_control(ControlAction::CancelSocket(forward))?;
+        if !output.success {
+            return Err(command_failure("SSH socket-forward cancellation", output));
+        }
+        self.socket_forwards.remove(forward);
+        Ok(())
+    }
 }
 
 impl Drop for SshTransport {
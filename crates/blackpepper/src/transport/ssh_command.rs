@@ -1,13 +1,20 @@
 use std::ffi::OsString;
 use std::net::IpAddr;
 
-use super::{ControlSocket, HostCommand, LocalForward, ProcessSpec, SshConfig, TransportError};
+use super::{
+    ControlSocket, HostCommand, LocalForward, ProcessSpec, RemoteForward, SocketForward, SshConfig,
+    TransportError,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum ControlAction<'a> {
     Check,
     Forward(&'a LocalForward),
     Cancel(&'a LocalForward),
+    ForwardRemote(&'a RemoteForward),
+    CancelRemote(&'a RemoteForward),
+    ForwardSocket(&'a SocketForward),
+    CancelSocket(&'a SocketForward),
 }
 
 pub(crate) fn master_spec(
@@ -94,11 +101,27 @@ pub(crate) fn control_spec(
         ControlAction::Check => ("check", None),
         ControlAction::Forward(forward) => {
             forward.validate()?;
-            ("forward", Some(forward))
+            ("forward", Some(("-L", format_forward(forward))))
         }
         ControlAction::Cancel(forward) => {
             forward.validate()?;
-            ("cancel", Some(forward))
+            ("cancel", Some(("-L", format_forward(forward))))
+        }
+        ControlAction::ForwardRemote(forward) => {
+            forward.validate()?;
+            ("forward", Some(("-R", format_remote_forward(forward))))
+        }
+        ControlAction::CancelRemote(forward) => {
+            forward.validate()?;
+            ("cancel", Some(("-R", format_remote_forward(forward))))
+        }
+        ControlAction::ForwardSocket(forward) => {
+            forward.validate()?;
+            ("forward", Some(("-L", format_socket_forward(forward))))
+        }
+        ControlAction::CancelSocket(forward) => {
+            forward.validate()?;
+            ("cancel", Some(("-L", format_socket_forward(forward))))
         }
     };
 
@@ -115,9 +138,9 @@ pub(crate) fn control_spec(
         OsString::from("-O"),
         OsString::from(operation),
     ]);
-    if let Some(forward) = forward {
-        arguments.push(OsString::from("-L"));
-        arguments.push(OsString::from(format_forward(forward)));
+    if let Some((flag, forward)) = forward {
+        arguments.push(OsString::from(flag));
+        arguments.push(OsString::from(forward));
     }
     arguments.extend([OsString::from("--"), OsString::from(&config.destination)]);
     Ok(ssh_process_spec(config, arguments))
@@ -174,6 +197,21 @@ fn format_forward(forward: &LocalForward) -> String {
     )
 }
 
+/// The host side is pinned to IPv4 loopback; sshd's `GatewayPorts` could
+/// otherwise widen an empty bind address to every interface.
+fn format_remote_forward(forward: &RemoteForward) -> String {
+    format!(
+        "127.0.0.1:{}:{}:{}",
+        forward.remote_port,
+        format_host(&forward.local_host),
+        forward.local_port
+    )
+}
+
+fn format_socket_forward(forward: &SocketForward) -> String {
+    format!("{}:{}", forward.local_path.display(), forward.remote_path)
+}
+
 fn format_ip(address: IpAddr) -> String {
     match address {
         IpAddr::V4(address) => address.to_string(),
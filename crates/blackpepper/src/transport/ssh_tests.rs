@@ -7,8 +7,8 @@ use portable_pty::PtySize;
 
 use super::ssh_command::{self, ControlAction};
 use super::{
-    ConnectionState, ControlSocket, HostCommand, HostTransport, LocalForward, SshConfig,
-    SshTransport,
+    ConnectionState, ControlSocket, HostCommand, HostTransport, LocalForward, RemoteForward,
+    SocketForward, SshConfig, SshTransport,
 };
 
 fn arguments(spec: &super::ProcessSpec) -> Vec<String> {
@@ -142,6 +142,81 @@ fn forwarding_keeps_an_exact_ipv6_remote_target() {
         .any(|pair| { pair == ["-L", "127.0.0.1:49152:[::1]:3000"] }));
 }
 
+#[test]
+fn reverse_forwarding_binds_only_host_loopback() {
+    let root = tempfile::tempdir().unwrap();
+    let socket = ControlSocket::allocate(Some(root.path())).unwrap();
+    let config = SshConfig::new("devbox");
+    let forward = RemoteForward::loopback(15432, 5432);
+
+    for (action, operation) in [
+        (ControlAction::ForwardRemote(&forward), "forward"),
+        (ControlAction::CancelRemote(&forward), "cancel"),
+    ] {
+        let args = arguments(&ssh_command::control_spec(&config, &socket, action).unwrap());
+        assert!(args.windows(2).any(|pair| pair == ["-O", operation]));
+        assert!(args
+            .windows(2)
+            .any(|pair| { pair == ["-R", "127.0.0.1:15432:127.0.0.1:5432"] }));
+        assert!(!args.iter().any(|argument| argument == "-L"));
+    }
+    assert!(ssh_command::control_spec(
+        &config,
+        &socket,
+        ControlAction::ForwardRemote(&RemoteForward::loopback(0, 5432)),
+    )
+    .is_err());
+}
+
+#[test]
+fn reverse_forwards_need_a_ready_master() {
+    let mut transport = SshTransport::new(SshConfig::new("devbox")).unwrap();
+    assert!(transport
+        .forward_remote_port(RemoteForward::loopback(15432, 5432))
+        .is_err());
+    assert!(matches!(
+        transport.cancel_remote_forward(&RemoteForward::loopback(15432, 5432)),
+        Err(super::TransportError::NotConnected)
+    ));
+}
+
+#[test]
+fn socket_forwarding_maps_one_path_to_another() {
+    let root = tempfile::tempdir().unwrap();
+    let socket = ControlSocket::allocate(Some(root.path())).unwrap();
+    let config = SshConfig::new("devbox");
+    let forward = SocketForward {
+        local_path: "/run/user/1000/blackpepper/sockets/pg.sock".into(),
+        remote_path: "/run/postgresql/.s.PGSQL.5432".to_owned(),
+    };
+
+    for (action, operation) in [
+        (ControlAction::ForwardSocket(&forward), "forward"),
+        (ControlAction::CancelSocket(&forward), "cancel"),
+    ] {
+        let args = arguments(&ssh_command::control_spec(&config, &socket, action).unwrap());
+        assert!(args.windows(2).any(|pair| pair == ["-O", operation]));
+        assert!(args.windows(2).any(|pair| {
+            pair == [
+                "-L",
+                "/run/user/1000/blackpepper/sockets/pg.sock:/run/postgresql/.s.PGSQL.5432",
+            ]
+        }));
+    }
+    for (local_path, remote_path) in [("relative.sock", "/tmp/a"), ("/tmp/a", "/tmp/b:c")] {
+        let forward = SocketForward {
+            local_path: local_path.into(),
+            remote_path: remote_path.to_owned(),
+        };
+        assert!(ssh_command::control_spec(
+            &config,
+            &socket,
+            ControlAction::ForwardSocket(&forward)
+        )
+        .is_err());
+    }
+}
+
 #[cfg(unix)]
 #[test]
 fn master_reader_handoff_keeps_prompt_input_and_readiness_polling_working() {
@@ -4,8 +4,8 @@ mod model;
 
 pub(crate) use model::WorktrunkApprovalPlan;
 pub use model::{
-    SwitchResult, WorktreeItem, WorktreeList, WorktrunkApprovalToken, WorktrunkProjectCommand,
-    APPROVAL_TOKEN_SCHEMA_VERSION, LIST_SCHEMA_VERSION,
+    Changes, Ci, CiState, SwitchResult, WorktreeItem, WorktreeList, WorktrunkApprovalToken,
+    WorktrunkProjectCommand, APPROVAL_TOKEN_SCHEMA_VERSION, LIST_SCHEMA_VERSION,
 };
 
 use std::ffi::{OsStr, OsString};
@@ -77,6 +77,15 @@ impl Worktrunk {
         ])
     }
 
+    /// `list` plus `--full`, which asks the forge for each branch's CI. It
+    /// is a network round trip, so callers cache it rather than run it on
+    /// every list.
+    pub fn list_full(&self, repo: &Path) -> CommandSpec {
+        let mut spec = self.list(repo);
+        spec.args.push(OsString::from("--full"));
+        spec
+    }
+
     pub fn create(
         &self,
         repo: &Path,
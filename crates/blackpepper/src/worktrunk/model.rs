@@ -69,6 +69,52 @@ pub struct WorktreeItem {
     pub worktree: Option<Worktree>,
     #[serde(default)]
     pub display: Option<Display>,
+    /// Present only when the list was collected with CI (`collected.ci`).
+    #[serde(default)]
+    pub ci: Option<Ci>,
+}
+
+/// Worktrunk's CI column for one branch: its pull request's checks, or the
+/// branch's own workflow runs when there is no pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ci {
+    pub status: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// The checks ran on an older commit than the branch head.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+impl Ci {
+    /// The three states worth a column. Anything else Worktrunk reports — no
+    /// CI configured, a lookup error, merge conflicts — has no CI verdict.
+    pub fn state(&self) -> Option<CiState> {
+        match self.status.as_str() {
+            "pending" | "running" | "queued" => Some(CiState::Pending),
+            "passed" | "passing" | "success" => Some(CiState::Passing),
+            "failed" | "failing" | "failure" => Some(CiState::Failing),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CiState {
+    Pending,
+    Passing,
+    Failing,
+}
+
+impl CiState {
+    pub const fn word(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Passing => "passing",
+            Self::Failing => "failing",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
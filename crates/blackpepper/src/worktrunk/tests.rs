@@ -141,6 +141,46 @@ fn schema_two_fixture_parses_worktree_and_branch_rows() {
     assert!(parsed.items[1].worktree.is_none());
 }
 
+#[test]
+fn a_full_list_asks_for_ci_and_maps_its_status_to_three_states() {
+    let spec = Worktrunk::new("wt").list_full(Path::new("/repo"));
+    assert_eq!(spec.kind, OperationKind::Read);
+    assert_eq!(
+        spec.args
+            .last()
+            .map(|arg| arg.to_string_lossy().into_owned()),
+        Some("--full".to_owned())
+    );
+
+    let parsed = WorktreeList::parse(
+        r#"{"schema":2,"repo":{"default_branch":"main"},"collected":{"ci":true,"summary":false},"items":[
+          {"branch":"green","ci":{"status":"passed","url":"https://ci/1"}},
+          {"branch":"red","ci":{"status":"failed","stale":true}},
+          {"branch":"busy","ci":{"status":"running"}},
+          {"branch":"none","ci":{"status":"no_ci"}},
+          {"branch":"plain"}
+        ]}"#,
+    )
+    .unwrap();
+    assert!(parsed.collected.ci);
+    let states = parsed
+        .items
+        .iter()
+        .map(|item| item.ci.as_ref().and_then(super::Ci::state))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        states,
+        [
+            Some(super::CiState::Passing),
+            Some(super::CiState::Failing),
+            Some(super::CiState::Pending),
+            None,
+            None
+        ]
+    );
+    assert!(parsed.items[1].ci.as_ref().unwrap().stale);
+}
+
 #[test]
 fn approval_plan_is_strict_bounded_and_state_consistent() {
     let valid = br#"{"state":"approval_required","commands":[{"phase":"post-start","name":"dev","template":"npm run dev","approved":false}],"stale":["old command"]}"#;
@@ -28,6 +28,7 @@ pub struct ZellijRuntime {
     expected_version: String,
     socket_directory: Option<String>,
     config_file: Option<String>,
+    layout_file: Option<String>,
 }
 
 impl ZellijRuntime {
@@ -67,6 +68,7 @@ impl ZellijRuntime {
             expected_version,
             socket_directory: None,
             config_file: None,
+            layout_file: None,
         })
     }
 
@@ -95,7 +97,11 @@ impl ZellijRuntime {
     }
 
     pub fn check_configuration_command(&self) -> HostCommand {
-        self.command(["setup", "--check"])
+        self.command(
+            self.layout_arguments()
+                .into_iter()
+                .chain(["setup".to_owned(), "--check".to_owned()]),
+        )
     }
 
     /// Select one Blackpepper-owned configuration without changing Zellij's
@@ -111,6 +117,27 @@ impl ZellijRuntime {
         Ok(self)
     }
 
+    /// Open new sessions with a project's own layout. Only session creation
+    /// and `setup --check` carry it, so the check parses the layout before the
+    /// first session is created from it and later commands never see it.
+    pub fn with_layout_file(mut self, path: impl Into<String>) -> Result<Self, ZellijError> {
+        let path = path.into();
+        if !std::path::Path::new(&path).is_absolute() || path.contains(['\0', '\n', '\r']) {
+            return Err(ZellijError::InvalidName(
+                "Zellij layout path must be absolute".to_owned(),
+            ));
+        }
+        self.layout_file = Some(path);
+        Ok(self)
+    }
+
+    pub(super) fn layout_arguments(&self) -> Vec<String> {
+        self.layout_file
+            .iter()
+            .flat_map(|path| ["--layout".to_owned(), path.clone()])
+            .collect()
+    }
+
     /// Validate Zellij's native effective configuration and report whether
     /// the pinned binary found user or system configuration to own the UI.
     ///
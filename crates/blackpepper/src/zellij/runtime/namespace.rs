@@ -70,6 +70,7 @@ impl ZellijRuntime {
             expected_version: self.expected_version.clone(),
             socket_directory: Some(socket_directory),
             config_file: self.config_file.clone(),
+            layout_file: self.layout_file.clone(),
         }
     }
 }
@@ -1,10 +1,12 @@
 use crate::transport::{HostCommand, HostTransport};
 
 use super::super::model::{
-    classify_pane_process, PaneProcessState, ZellijError, ZellijPane, ZellijTab,
+    checked, classify_pane_process, PaneProcessState, ZellijError, ZellijPane, ZellijTab,
 };
 use super::metadata::read_json;
-use super::validation::{validate_name, validate_pane_selector, validate_typed_pane_selector};
+use super::validation::{
+    validate_name, validate_pane_input, validate_pane_selector, validate_typed_pane_selector,
+};
 use super::{ZellijRuntime, METADATA_TIMEOUT};
 
 impl ZellijRuntime {
@@ -144,4 +146,73 @@ impl ZellijRuntime {
         }
         Ok(pane)
     }
+
+    pub fn write_chars_command(
+        &self,
+        session: &str,
+        pane_selector: &str,
+        text: &str,
+    ) -> Result<HostCommand, ZellijError> {
+        validate_name("session", session)?;
+        validate_typed_pane_selector(pane_selector)?;
+        validate_pane_input(text)?;
+        Ok(self.session_action(
+            session,
+            ["write-chars", "--pane-id", pane_selector, "--", text],
+        ))
+    }
+
+    pub fn write_bytes_command(
+        &self,
+        session: &str,
+        pane_selector: &str,
+        bytes: &[u8],
+    ) -> Result<HostCommand, ZellijError> {
+        validate_name("session", session)?;
+        validate_typed_pane_selector(pane_selector)?;
+        if bytes.is_empty() {
+            return Err(ZellijError::InvalidName(
+                "Pane input needs at least one byte".to_string(),
+            ));
+        }
+        Ok(self
+            .session_action(session, ["write", "--pane-id", pane_selector])
+            .args(bytes.iter().map(u8::to_string)))
+    }
+
+    /// Type `text`, then `bytes`, into one pane only while it is the live
+    /// terminal pane of its tab and still carries the caller's launch
+    /// identity. Writing moves no client's focus, so attached clients are
+    /// left alone. Returns `false` without writing anything otherwise.
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_to_pane_if_matches(
+        &self,
+        host: &mut dyn HostTransport,
+        session: &str,
+        tab_id: u64,
+        tab_name: &str,
+        pane_selector: &str,
+        expected_command_argument: &str,
+        text: &str,
+        bytes: &[u8],
+    ) -> Result<bool, ZellijError> {
+        let write_chars = (!text.is_empty())
+            .then(|| self.write_chars_command(session, pane_selector, text))
+            .transpose()?;
+        let write_bytes = (!bytes.is_empty())
+            .then(|| self.write_bytes_command(session, pane_selector, bytes))
+            .transpose()?;
+        let pane = self.terminal_pane_for_tab(host, session, tab_id)?;
+        if pane.tab_name != tab_name
+            || pane.selector() != pane_selector
+            || !pane.has_command_argument(expected_command_argument)
+            || pane.process_state() != PaneProcessState::Live
+        {
+            return Ok(false);
+        }
+        for command in write_chars.iter().chain(&write_bytes) {
+            checked(host.exec(command)?, "write to Zellij pane")?;
+        }
+        Ok(true)
+    }
 }
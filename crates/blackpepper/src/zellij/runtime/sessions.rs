@@ -37,7 +37,11 @@ impl ZellijRuntime {
     ) -> Result<HostCommand, ZellijError> {
         validate_name("session", session)?;
         let mut command = self
-            .command(["attach", "--create-background", "--forget", session])
+            .command(
+                self.layout_arguments().into_iter().chain(
+                    ["attach", "--create-background", "--forget", session].map(str::to_owned),
+                ),
+            )
             .cwd(cwd);
         command.env.clone_from(env);
         // This internal override is inherited only by development E2E
@@ -3,8 +3,8 @@ use std::time::{Duration, Instant};
 
 use crate::transport::{HostCommand, HostTransport};
 
-use super::super::super::model::{checked, ZellijError};
-use super::super::validation::validate_name;
+use super::super::super::model::{checked, ClientOperation, ZellijError};
+use super::super::validation::{validate_name, validate_pane_selector};
 use super::super::ZellijRuntime;
 
 const FOCUS_MUTATION_TIMEOUT: Duration = Duration::from_secs(5);
@@ -76,6 +76,59 @@ impl ZellijRuntime {
         Ok(self.session_action(session, ["go-to-tab-by-id", &tab_id.to_string()]))
     }
 
+    pub fn focus_pane_command(
+        &self,
+        session: &str,
+        pane_selector: &str,
+    ) -> Result<HostCommand, ZellijError> {
+        validate_name("session", session)?;
+        validate_pane_selector(pane_selector)?;
+        Ok(self.session_action(session, ["focus-pane-id", pane_selector]))
+    }
+
+    /// Focus a recorded agent tab, then its exact pane, only while at most
+    /// one client is attached and only while that pane still carries the
+    /// run's launch identity. Returns `false` without sending anything when
+    /// the tab no longer belongs to that run.
+    pub fn focus_agent_tab(
+        &self,
+        host: &mut dyn HostTransport,
+        session: &str,
+        tab_id: u64,
+        tab_name: &str,
+        pane_selector: &str,
+        expected_command_argument: &str,
+    ) -> Result<bool, ZellijError> {
+        self.enforce_client_safety(host, session, ClientOperation::FocusChange)?;
+        let pane = self.terminal_pane_for_tab(host, session, tab_id)?;
+        if pane.tab_name != tab_name
+            || pane.selector() != pane_selector
+            || !pane.has_command_argument(expected_command_argument)
+        {
+            return Ok(false);
+        }
+        // A user may have split the agent's tab since launch, so selecting
+        // the tab alone could leave the cursor in someone else's pane. The
+        // pair is masked: cancelling between them would strand focus halfway.
+        crate::transport::CommandCancellation::mask_current(|| {
+            checked(
+                host.exec_timeout(
+                    &self.focus_tab_command(session, tab_id)?,
+                    FOCUS_MUTATION_TIMEOUT,
+                )?,
+                "focus agent tab",
+            )?;
+            checked(
+                host.exec_timeout(
+                    &self.focus_pane_command(session, pane_selector)?,
+                    FOCUS_MUTATION_TIMEOUT,
+                )?,
+                "focus agent pane",
+            )?;
+            Ok(true)
+        })
+    }
+
     /// Focus the initial workspace shell only while one stable client owns the
     /// session. The caller holds Blackpepper's workspace lifecycle lease, so
     /// another Blackpepper client cannot attach between validation and focus.
@@ -36,6 +36,18 @@ pub(super) fn validate_name(kind: &str, value: &str) -> Result<(), ZellijError>
     Ok(())
 }
 
+/// Typed pane input is one line of printable text: a newline or escape
+/// sequence inside it could answer a prompt the user never saw.
+pub(super) fn validate_pane_input(value: &str) -> Result<(), ZellijError> {
+    if value.is_empty() || value.len() > 4096 || value.chars().any(char::is_control) {
+        return Err(ZellijError::InvalidName(
+            "Pane input must be 1-4096 bytes of single-line text without control characters"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
 pub(super) fn validate_pane_selector(value: &str) -> Result<(), ZellijError> {
     let number = value
         .strip_prefix("terminal_")
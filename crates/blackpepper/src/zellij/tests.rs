@@ -427,6 +427,48 @@ fn managed_configuration_is_an_explicit_single_file() {
         .is_err());
 }
 
+#[test]
+fn a_project_layout_shapes_only_creation_and_its_check() {
+    let runtime = ZellijRuntime::new("/opt/zellij")
+        .unwrap()
+        .with_layout_file("/srv/repo/.blackpepper/layout.kdl")
+        .unwrap();
+
+    assert_eq!(
+        wrapped_zellij_args(&runtime.check_configuration_command(), "/opt/zellij"),
+        [
+            "--layout",
+            "/srv/repo/.blackpepper/layout.kdl",
+            "setup",
+            "--check"
+        ]
+    );
+    assert_eq!(
+        wrapped_zellij_args(
+            &runtime
+                .create_session_command("repo-main", Path::new("/srv/repo"))
+                .unwrap(),
+            "/opt/zellij"
+        ),
+        [
+            "--layout",
+            "/srv/repo/.blackpepper/layout.kdl",
+            "attach",
+            "--create-background",
+            "--forget",
+            "repo-main"
+        ]
+    );
+    assert_eq!(
+        wrapped_zellij_args(&runtime.list_sessions_command(), "/opt/zellij"),
+        ["list-sessions", "--short", "--no-formatting"]
+    );
+    assert!(ZellijRuntime::new("/opt/zellij")
+        .unwrap()
+        .with_layout_file(".blackpepper/layout.kdl")
+        .is_err());
+}
+
 #[test]
 fn native_configuration_detection_distinguishes_absence_from_user_intent() {
     let runtime = ZellijRuntime::new("/opt/zellij").unwrap();
@@ -203,6 +203,144 @@ fn verified_close_rechecks_identity_immediately_before_mutation() {
     );
 }
 
+#[test]
+fn agent_focus_selects_the_exact_tab_then_pane_for_a_single_client() {
+    let runtime = ZellijRuntime::new("/opt/zellij").unwrap();
+    let one_client =
+        success("CLIENT_ID ZELLIJ_PANE_ID RUNNING_COMMAND\n9 terminal_1 zellij attach repo-main\n");
+    let owned_pane = ready_terminal_pane_with_command(7, "agent-run", "RUN=expected");
+    let mut host = ScriptedTransport::new([one_client, owned_pane, success(""), success("")]);
+
+    let focused = runtime
+        .focus_agent_tab(
+            &mut host,
+            "repo-main",
+            7,
+            "agent-run",
+            "terminal_4",
+            "RUN=expected",
+        )
+        .unwrap();
+
+    assert!(focused);
+    assert_eq!(
+        wrapped_zellij_args(&host.commands[2], "/opt/zellij"),
+        ["--session", "repo-main", "action", "go-to-tab-by-id", "7"]
+    );
+    assert_eq!(
+        wrapped_zellij_args(&host.commands[3], "/opt/zellij"),
+        [
+            "--session",
+            "repo-main",
+            "action",
+            "focus-pane-id",
+            "terminal_4"
+        ]
+    );
+}
+
+#[test]
+fn agent_focus_refuses_a_second_client_before_reading_panes() {
+    let runtime = ZellijRuntime::new("/opt/zellij").unwrap();
+    let mut host = ScriptedTransport::new([success(
+        "CLIENT_ID ZELLIJ_PANE_ID RUNNING_COMMAND\n9 terminal_1 zellij attach repo-main\n10 terminal_2 zellij attach repo-main\n",
+    )]);
+
+    let error = runtime
+        .focus_agent_tab(
+            &mut host,
+            "repo-main",
+            7,
+            "agent-run",
+            "terminal_4",
+            "RUN=expected",
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        ZellijError::ClientConflict {
+            operation: ClientOperation::FocusChange,
+            ref clients,
+        } if clients.len() == 2
+    ));
+    assert_eq!(host.commands.len(), 1);
+}
+
+#[test]
+fn agent_input_types_the_text_then_presses_enter_in_the_owned_pane() {
+    let runtime = ZellijRuntime::new("/opt/zellij").unwrap();
+    let owned_pane = ready_terminal_pane_with_command(7, "agent-run", "RUN=expected");
+    let mut host = ScriptedTransport::new([owned_pane, success(""), success("")]);
+
+    let written = runtime
+        .write_to_pane_if_matches(
+            &mut host,
+            "repo-main",
+            7,
+            "agent-run",
+            "terminal_4",
+            "RUN=expected",
+            "don't stop now",
+            b"\r",
+        )
+        .unwrap();
+
+    assert!(written);
+    assert_eq!(
+        wrapped_zellij_args(&host.commands[1], "/opt/zellij"),
+        [
+            "--session",
+            "repo-main",
+            "action",
+            "write-chars",
+            "--pane-id",
+            "terminal_4",
+            "--",
+            "don't stop now"
+        ]
+    );
+    assert_eq!(
+        wrapped_zellij_args(&host.commands[2], "/opt/zellij"),
+        [
+            "--session",
+            "repo-main",
+            "action",
+            "write",
+            "--pane-id",
+            "terminal_4",
+            "13"
+        ]
+    );
+}
+
+#[test]
+fn agent_input_writes_nothing_to_a_reused_pane_or_control_characters() {
+    let runtime = ZellijRuntime::new("/opt/zellij").unwrap();
+    let reused_pane = ready_terminal_pane_with_command(7, "agent-run", "RUN=reused");
+    let mut host = ScriptedTransport::new([reused_pane]);
+    let write = |host: &mut ScriptedTransport, text: &str| {
+        runtime.write_to_pane_if_matches(
+            host,
+            "repo-main",
+            7,
+            "agent-run",
+            "terminal_4",
+            "RUN=expected",
+            text,
+            b"\r",
+        )
+    };
+
+    assert!(!write(&mut host, "continue").unwrap());
+    assert_eq!(host.commands.len(), 1);
+    assert!(matches!(
+        write(&mut host, "continue\n"),
+        Err(ZellijError::InvalidName(_))
+    ));
+    assert_eq!(host.commands.len(), 1);
+}
+
 fn ready_terminal_pane(tab_id: u64, tab_name: &str) -> CommandOutput {
     ready_terminal_pane_with_command(tab_id, tab_name, "api-server")
 }